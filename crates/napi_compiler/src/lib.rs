@@ -1,8 +1,13 @@
+use std::cell::RefCell;
+
+use napi::bindgen_prelude::{Either, ObjectFinalize};
+use napi::{Env, JsFunction, JsString, JsUnknown, Ref};
 use napi_derive::napi;
 use svelte_compiler::{
-    CompileOptions, CompileResult, CssMode, GenerateMode, ModuleCompileOptions, Namespace,
+    CompileOptions, CompileResult, CompileStats, Compiler, CssHash, CssHashInput, CssMode,
+    CssTarget, DependencyKind, GenerateMode, ModuleCompileOptions, Namespace,
 };
-use svelte_diagnostics::LineIndex;
+use svelte_diagnostics::{LineIndex, Location};
 
 #[napi(object)]
 pub struct NativeDiagnostic {
@@ -13,7 +18,24 @@ pub struct NativeDiagnostic {
     pub start_col: u32,
     pub end_line: u32,
     pub end_col: u32,
+    pub start: NativeLocation,
+    pub end: NativeLocation,
     pub frame: Option<String>,
+    pub fixes: Vec<NativeTextEdit>,
+}
+
+#[napi(object)]
+pub struct NativeLocation {
+    pub line: u32,
+    pub column: u32,
+    pub character: u32,
+}
+
+#[napi(object)]
+pub struct NativeTextEdit {
+    pub start: u32,
+    pub end: u32,
+    pub replacement: String,
 }
 
 #[napi(object)]
@@ -21,9 +43,35 @@ pub struct NativeCompileResult {
     pub js: Option<String>,
     pub css: Option<String>,
     pub diagnostics: Vec<NativeDiagnostic>,
+    pub dependencies: Vec<NativeDependency>,
+    pub stats: Option<NativeCompileStats>,
 }
 
 #[napi(object)]
+pub struct NativeDependency {
+    pub specifier: String,
+    pub kind: String,
+}
+
+#[napi(object)]
+pub struct NativeCompileStats {
+    pub total_ms: f64,
+    pub phases: Vec<NativeTiming>,
+    pub analyze_passes: Vec<NativeTiming>,
+    pub nodes: u32,
+    pub fragments: u32,
+    pub symbols: u32,
+    pub template_effects: u32,
+    pub css_rules_pruned: u32,
+}
+
+#[napi(object)]
+pub struct NativeTiming {
+    pub name: String,
+    pub ms: f64,
+}
+
+#[napi(object, object_to_js = false)]
 #[derive(Default)]
 pub struct NativeCompileOptions {
     pub dev: Option<bool>,
@@ -33,16 +81,26 @@ pub struct NativeCompileOptions {
     pub custom_element: Option<bool>,
     pub namespace: Option<String>,
     pub css: Option<String>,
+    pub css_hash: Option<Either<String, JsFunction>>,
+    pub css_target: Option<String>,
+    pub css_minify: Option<bool>,
     pub runes: Option<bool>,
     pub preserve_comments: Option<bool>,
     pub preserve_whitespace: Option<bool>,
     pub disclose_version: Option<bool>,
     pub hmr: Option<bool>,
+    pub minify: Option<bool>,
+    pub inline_constants: Option<bool>,
+    pub runtime_path: Option<String>,
     pub accessors: Option<bool>,
     pub immutable: Option<bool>,
     pub compatibility_component_api: Option<u8>,
     pub experimental_async: Option<bool>,
+    pub lint_effect_to_derived: Option<bool>,
+    pub lint_unused_declarations: Option<bool>,
+    pub lint_strict_html_nesting: Option<bool>,
     pub generate: Option<String>,
+    pub stats: Option<bool>,
 }
 
 #[napi(object)]
@@ -51,14 +109,145 @@ pub struct NativeModuleCompileOptions {
     pub dev: Option<bool>,
     pub filename: Option<String>,
     pub root_dir: Option<String>,
+    pub minify: Option<bool>,
+    pub runtime_path: Option<String>,
     pub generate: Option<String>,
 }
 
 #[napi]
-pub fn compile(source: String, options: Option<NativeCompileOptions>) -> NativeCompileResult {
-    let options = to_compile_options(options.unwrap_or_default());
-    let result = svelte_compiler::compile(&source, &options);
-    to_node_result(result, &source)
+pub fn compile(
+    env: Env,
+    source: String,
+    options: Option<NativeCompileOptions>,
+) -> napi::Result<NativeCompileResult> {
+    let (options, css_hash_getter) = to_validated_compile_options(options)?;
+    let Some(getter) = css_hash_getter else {
+        let result = svelte_compiler::compile(&source, &options);
+        return Ok(to_node_result(result, &source));
+    };
+    let callback_error = RefCell::new(None);
+    let result = svelte_compiler::compile_with_css_hash_getter(
+        &source,
+        &options,
+        &css_hash_getter_fn(env, &getter, &callback_error),
+    );
+    if let Some(err) = callback_error.take() {
+        return Err(err);
+    }
+    Ok(to_node_result(result, &source))
+}
+
+#[napi(js_name = "Compiler", custom_finalize)]
+pub struct NativeCompiler {
+    compiler: Compiler,
+    css_hash_getter: Option<Ref<()>>,
+}
+
+#[napi]
+impl NativeCompiler {
+    #[napi(constructor)]
+    pub fn new(env: Env, options: Option<NativeCompileOptions>) -> napi::Result<Self> {
+        let (options, css_hash_getter) = to_validated_compile_options(options)?;
+        let css_hash_getter = css_hash_getter
+            .map(|getter| env.create_reference(getter))
+            .transpose()?;
+        Ok(Self {
+            compiler: Compiler::new(options).map_err(napi::Error::from_reason)?,
+            css_hash_getter,
+        })
+    }
+
+    #[napi]
+    pub fn compile(
+        &mut self,
+        env: Env,
+        source: String,
+        filename: String,
+    ) -> napi::Result<NativeCompileResult> {
+        let Some(getter) = &self.css_hash_getter else {
+            let result = self.compiler.compile(&source, &filename);
+            return Ok(to_node_result(result, &source));
+        };
+        let getter: JsFunction = env.get_reference_value(getter)?;
+        let callback_error = RefCell::new(None);
+        let result = self.compiler.compile_with_css_hash_getter(
+            &source,
+            &filename,
+            &css_hash_getter_fn(env, &getter, &callback_error),
+        );
+        if let Some(err) = callback_error.take() {
+            return Err(err);
+        }
+        Ok(to_node_result(result, &source))
+    }
+
+    #[napi(js_name = "compileModule")]
+    pub fn compile_module(&mut self, source: String, filename: String) -> NativeCompileResult {
+        let result = self.compiler.compile_module(&source, &filename);
+        to_node_result(result, &source)
+    }
+}
+
+impl ObjectFinalize for NativeCompiler {
+    fn finalize(mut self, env: Env) -> napi::Result<()> {
+        if let Some(mut getter) = self.css_hash_getter.take() {
+            getter.unref(env)?;
+        }
+        Ok(())
+    }
+}
+
+fn to_validated_compile_options(
+    options: Option<NativeCompileOptions>,
+) -> napi::Result<(CompileOptions, Option<JsFunction>)> {
+    let mut native = options.unwrap_or_default();
+    let css_hash = native.css_hash.take();
+    let css_target = native.css_target.take();
+    let mut options = to_compile_options(native);
+    if let Some(query) = css_target {
+        options.css_target = CssTarget::parse(&query).map_err(napi::Error::from_reason)?;
+    }
+    match css_hash {
+        Some(Either::A(template)) => {
+            options.css_hash = CssHash::Template(template);
+            Ok((options, None))
+        }
+        Some(Either::B(getter)) => Ok((options, Some(getter))),
+        None => Ok((options, None)),
+    }
+}
+
+fn css_hash_getter_fn<'g>(
+    env: Env,
+    getter: &'g JsFunction,
+    callback_error: &'g RefCell<Option<napi::Error>>,
+) -> impl Fn(&CssHashInput<'_>) -> String + 'g {
+    move |input: &CssHashInput<'_>| match call_css_hash_getter(env, getter, input) {
+        Ok(hash) => hash,
+        Err(err) => {
+            callback_error.borrow_mut().get_or_insert(err);
+            String::new()
+        }
+    }
+}
+
+fn call_css_hash_getter(
+    env: Env,
+    getter: &JsFunction,
+    input: &CssHashInput<'_>,
+) -> napi::Result<String> {
+    let mut args = env.create_object()?;
+    args.set_named_property("css", env.create_string(input.css)?)?;
+    args.set_named_property("name", env.create_string(input.name)?)?;
+    args.set_named_property("filename", env.create_string(input.filename)?)?;
+    let hash = env.create_function_from_closure("hash", |ctx| {
+        let value = ctx.get::<JsString>(0)?.into_utf8()?;
+        ctx.env
+            .create_string(&svelte_compiler::css_hash_digest(value.as_str()?))
+    })?;
+    args.set_named_property("hash", hash)?;
+    let returned: JsUnknown = getter.call(None, &[args])?;
+    returned.coerce_to_string()?.into_utf8()?.into_owned()
 }
 
 #[napi(js_name = "compileModule")]
@@ -97,6 +286,9 @@ fn to_compile_options(native: NativeCompileOptions) -> CompileOptions {
     if let Some(value) = native.css {
         options.css = parse_css_mode(&value);
     }
+    if let Some(value) = native.css_minify {
+        options.css_minify = value;
+    }
     if let Some(value) = native.runes {
         options.runes = Some(value);
     }
@@ -112,6 +304,15 @@ fn to_compile_options(native: NativeCompileOptions) -> CompileOptions {
     if let Some(value) = native.hmr {
         options.hmr = value;
     }
+    if let Some(value) = native.minify {
+        options.minify = value;
+    }
+    if let Some(value) = native.inline_constants {
+        options.inline_constants = value;
+    }
+    if let Some(value) = native.runtime_path {
+        options.runtime_path = Some(value);
+    }
     if let Some(value) = native.accessors {
         options.accessors = value;
     }
@@ -124,6 +325,18 @@ fn to_compile_options(native: NativeCompileOptions) -> CompileOptions {
     if let Some(value) = native.experimental_async {
         options.experimental.async_ = value;
     }
+    if let Some(value) = native.lint_effect_to_derived {
+        options.lint.effect_to_derived = value;
+    }
+    if let Some(value) = native.lint_unused_declarations {
+        options.lint.unused_declarations = value;
+    }
+    if let Some(value) = native.lint_strict_html_nesting {
+        options.lint.strict_html_nesting = value;
+    }
+    if let Some(value) = native.stats {
+        options.stats = value;
+    }
     options
 }
 
@@ -141,6 +354,12 @@ fn to_module_compile_options(native: NativeModuleCompileOptions) -> ModuleCompil
     if let Some(value) = native.root_dir {
         options.root_dir = Some(value);
     }
+    if let Some(value) = native.minify {
+        options.minify = value;
+    }
+    if let Some(value) = native.runtime_path {
+        options.runtime_path = Some(value);
+    }
     options
 }
 
@@ -167,6 +386,14 @@ fn parse_css_mode(raw: &str) -> CssMode {
     }
 }
 
+fn to_native_location(location: Location) -> NativeLocation {
+    NativeLocation {
+        line: location.line as u32,
+        column: location.column as u32,
+        character: location.character as u32,
+    }
+}
+
 fn to_node_result(result: CompileResult, source: &str) -> NativeCompileResult {
     let line_index = LineIndex::new(source);
 
@@ -174,8 +401,8 @@ fn to_node_result(result: CompileResult, source: &str) -> NativeCompileResult {
         .diagnostics
         .iter()
         .map(|diagnostic| {
-            let (start_line, start_col) = line_index.line_col(diagnostic.span.start as usize);
-            let (end_line, end_col) = line_index.line_col(diagnostic.span.end as usize);
+            let start = line_index.location(source, diagnostic.span.start as usize);
+            let end = line_index.location(source, diagnostic.span.end as usize);
             let mut message = diagnostic.kind.message();
             if let Some(url) = diagnostic.kind.svelte_doc_url() {
                 message.push('\n');
@@ -186,11 +413,22 @@ fn to_node_result(result: CompileResult, source: &str) -> NativeCompileResult {
                 code: diagnostic.kind.code().to_string(),
                 message,
                 severity: format!("{:?}", diagnostic.severity),
-                start_line: start_line as u32,
-                start_col: start_col as u32,
-                end_line: end_line as u32,
-                end_col: end_col as u32,
+                start_line: start.line as u32 - 1,
+                start_col: start.column as u32,
+                end_line: end.line as u32 - 1,
+                end_col: end.column as u32,
+                start: to_native_location(start),
+                end: to_native_location(end),
                 frame: line_index.code_frame(source, diagnostic.span),
+                fixes: diagnostic
+                    .fixes
+                    .iter()
+                    .map(|edit| NativeTextEdit {
+                        start: line_index.utf16_offset(source, edit.span.start as usize) as u32,
+                        end: line_index.utf16_offset(source, edit.span.end as usize) as u32,
+                        replacement: edit.replacement.clone(),
+                    })
+                    .collect(),
             }
         })
         .collect();
@@ -199,5 +437,49 @@ fn to_node_result(result: CompileResult, source: &str) -> NativeCompileResult {
         js: result.js,
         css: result.css,
         diagnostics,
+        dependencies: result
+            .dependencies
+            .into_iter()
+            .map(|dep| NativeDependency {
+                specifier: dep.specifier,
+                kind: dependency_kind_name(dep.kind).to_string(),
+            })
+            .collect(),
+        stats: result.stats.map(to_native_stats),
+    }
+}
+
+fn dependency_kind_name(kind: DependencyKind) -> &'static str {
+    match kind {
+        DependencyKind::Import => "import",
+        DependencyKind::Component => "component",
+        DependencyKind::CssImport => "css_import",
+    }
+}
+
+fn to_native_stats(stats: CompileStats) -> NativeCompileStats {
+    NativeCompileStats {
+        total_ms: stats.total().as_secs_f64() * 1000.0,
+        phases: stats
+            .phases
+            .into_iter()
+            .map(|timing| NativeTiming {
+                name: timing.phase.to_string(),
+                ms: timing.duration.as_secs_f64() * 1000.0,
+            })
+            .collect(),
+        analyze_passes: stats
+            .analyze_passes
+            .into_iter()
+            .map(|timing| NativeTiming {
+                name: timing.pass,
+                ms: timing.duration.as_secs_f64() * 1000.0,
+            })
+            .collect(),
+        nodes: stats.nodes,
+        fragments: stats.fragments,
+        symbols: stats.symbols,
+        template_effects: stats.template_effects,
+        css_rules_pruned: stats.css_rules_pruned,
     }
 }
@@ -119,7 +119,7 @@ mod tests {
     };
     use svelte_ast::{AwaitBlock, Component, Node};
 
-    fn first_await_block(component: &Component) -> &AwaitBlock {
+    fn first_await_block<'a>(component: &'a Component) -> &'a AwaitBlock {
         fn walk<'a>(
             component: &'a Component,
             nodes: &[svelte_ast::NodeId],
@@ -46,7 +46,7 @@ mod tests {
     use svelte_ast::{Component, ConstTag, Node};
     use svelte_component_semantics::walk_bindings;
 
-    fn first_const_tag(component: &Component) -> &ConstTag {
+    fn first_const_tag<'a>(component: &'a Component) -> &'a ConstTag {
         fn walk<'a>(
             component: &'a Component,
             nodes: &[svelte_ast::NodeId],
@@ -168,7 +168,7 @@ mod tests {
         out
     }
 
-    fn first_if_block(component: &Component) -> &IfBlock {
+    fn first_if_block<'a>(component: &'a Component) -> &'a IfBlock {
         let id = all_if_blocks(component)
             .into_iter()
             .next()
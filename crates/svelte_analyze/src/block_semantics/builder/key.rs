@@ -33,7 +33,7 @@ mod tests {
     use crate::{BlockSemantics, KeyAsyncKind, KeyBlockSemantics};
     use svelte_ast::{Component, KeyBlock, Node};
 
-    fn first_key_block(component: &Component) -> &KeyBlock {
+    fn first_key_block<'a>(component: &'a Component) -> &'a KeyBlock {
         fn walk<'a>(
             component: &'a Component,
             nodes: &[svelte_ast::NodeId],
@@ -197,7 +197,7 @@ mod tests {
     };
     use svelte_ast::{Component, Node, NodeId, RenderTag};
 
-    fn first_render_tag(component: &Component) -> &RenderTag {
+    fn first_render_tag<'a>(component: &'a Component) -> &'a RenderTag {
         fn walk<'a>(component: &'a Component, nodes: &[NodeId]) -> Option<&'a RenderTag> {
             for &id in nodes {
                 let node = component.store.get(id);
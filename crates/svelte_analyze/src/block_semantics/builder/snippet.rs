@@ -94,7 +94,7 @@ mod tests {
     use crate::{BlockSemantics, SnippetBlockSemantics, SnippetParam};
     use svelte_ast::{Component, Node, SnippetBlock};
 
-    fn first_snippet(component: &Component) -> &SnippetBlock {
+    fn first_snippet<'a>(component: &'a Component) -> &'a SnippetBlock {
         fn walk<'a>(
             component: &'a Component,
             nodes: &[svelte_ast::NodeId],
@@ -104,7 +104,7 @@ pub(super) struct SnippetScope {
 }
 
 pub(super) struct Ctx<'c, 'a> {
-    pub(super) component: &'c Component,
+    pub(super) component: &'c Component<'a>,
     pub(super) parsed: &'c JsAst<'a>,
     pub(super) semantics: &'c ComponentSemantics<'a>,
     pub(super) reactivity: &'c ReactivitySemantics,
@@ -135,7 +135,7 @@ impl<'a> Ctx<'_, 'a> {
             Node::EachBlock(block) => super::each::populate(self, block),
             Node::AwaitBlock(block) => super::await_::populate(self, block),
             Node::Element(el) => {
-                self.check_bind_group_in_attrs(&el.attributes);
+                self.check_bind_group_in_attrs(el.attributes);
                 self.visit_fragment(el.fragment);
             }
             Node::SlotElementLegacy(el) => self.visit_fragment(el.fragment),
@@ -154,7 +154,7 @@ impl<'a> Ctx<'_, 'a> {
             Node::SvelteHead(el) => self.visit_fragment(el.fragment),
             Node::SvelteFragmentLegacy(el) => self.visit_fragment(el.fragment),
             Node::SvelteElement(el) => {
-                self.check_bind_group_in_attrs(&el.attributes);
+                self.check_bind_group_in_attrs(el.attributes);
                 self.visit_fragment(el.fragment);
             }
             Node::SvelteBoundary(el) => self.visit_fragment(el.fragment),
@@ -0,0 +1,704 @@
+use oxc_ast::ast::{
+    BindingPattern, ChainElement, Declaration, Expression, ImportDeclarationSpecifier, Program,
+    PropertyKey, Statement, TSSignature, TSType, TSTypeName, VariableDeclarator,
+};
+use rustc_hash::FxHashMap;
+use svelte_ast::{Attribute, Component, ComponentNode, FragmentId, Node};
+use svelte_component_semantics::SymbolId;
+use svelte_diagnostics::{Diagnostic, DiagnosticKind};
+use svelte_span::Span;
+
+use crate::types::script::RuneKind;
+use crate::utils::script_info::detect_rune_from_call;
+use crate::{AnalysisData, BindingSemantics, JsAst, PropDefaultLowering};
+
+const COMPONENT_USAGE_CODES: [&str; 4] = [
+    "component_unknown_prop",
+    "component_missing_prop",
+    "component_bind_non_bindable",
+    "component_snippet_prop_mismatch",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeclaredPropKind {
+    Snippet,
+    Value,
+    Unknown,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeclaredProp {
+    pub name: String,
+    pub required: bool,
+    pub bindable: bool,
+    pub kind: DeclaredPropKind,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ComponentInterface {
+    pub props: Vec<DeclaredProp>,
+    pub accepts_any_prop: bool,
+    pub slots: Vec<String>,
+}
+
+impl ComponentInterface {
+    pub fn prop(&self, name: &str) -> Option<&DeclaredProp> {
+        self.props.iter().find(|p| p.name == name)
+    }
+
+    fn has_slot_for(&self, prop_name: &str) -> bool {
+        let slot = if prop_name == "children" {
+            "default"
+        } else {
+            prop_name
+        };
+        self.slots.iter().any(|s| s == slot)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PassedPropKind {
+    Value,
+    Bind,
+    Snippet,
+}
+
+#[derive(Debug, Clone)]
+pub struct PassedProp {
+    pub name: String,
+    pub span: Span,
+    pub kind: PassedPropKind,
+}
+
+#[derive(Debug, Clone)]
+pub struct ComponentUsage {
+    pub name: String,
+    pub source: String,
+    pub span: Span,
+    pub props: Vec<PassedProp>,
+    pub has_spread: bool,
+    pub ignored: Vec<&'static str>,
+}
+
+impl ComponentUsage {
+    fn passes(&self, name: &str) -> bool {
+        self.props.iter().any(|p| p.name == name)
+    }
+
+    fn is_ignored(&self, kind: &DiagnosticKind) -> bool {
+        self.ignored.contains(&kind.code())
+    }
+}
+
+pub fn component_interface(
+    component: &Component,
+    parsed: &JsAst<'_>,
+    data: &AnalysisData<'_>,
+) -> ComponentInterface {
+    let mut interface = ComponentInterface {
+        slots: legacy_slot_names(component),
+        ..ComponentInterface::default()
+    };
+
+    if data.script.runes {
+        let Some(decl) = data.script.props_declaration() else {
+            return interface;
+        };
+        interface.accepts_any_prop = decl.is_identifier_pattern || decl.rest_pattern_span.is_some();
+        let types = props_type_members(parsed);
+        let rendered = rendered_snippet_symbols(component, parsed, data);
+        let instance_scope = data.scoping.instance_scope_id();
+        for prop in &decl.props {
+            if prop.is_rest || prop.is_reserved() {
+                continue;
+            }
+            let member = types
+                .as_ref()
+                .and_then(|members| members.get(prop.prop_name.as_str()));
+            let sym =
+                instance_scope.and_then(|scope| data.scoping.get_binding(scope, &prop.local_name));
+            let rendered_optionally = sym.and_then(|sym| rendered.get(&sym).copied());
+            let kind = if rendered_optionally.is_some() {
+                DeclaredPropKind::Snippet
+            } else if let Some(&(_, kind)) = member {
+                kind
+            } else if prop.is_simple_default
+                && prop.default_text.as_deref().is_some_and(is_literal_default)
+            {
+                DeclaredPropKind::Value
+            } else {
+                DeclaredPropKind::Unknown
+            };
+            // Only a declared type can make a prop required: an untyped
+            // destructured entry without a default may still be optional.
+            let typed_required = member.is_some_and(|&(optional, _)| !optional);
+            interface.props.push(DeclaredProp {
+                name: prop.prop_name.to_string(),
+                required: typed_required
+                    && prop.default_span.is_none()
+                    && !prop.is_bindable
+                    && rendered_optionally != Some(true),
+                bindable: prop.is_bindable,
+                kind,
+            });
+        }
+        return interface;
+    }
+
+    interface.accepts_any_prop =
+        data.reactivity.legacy_uses_props() || data.reactivity.legacy_uses_rest_props();
+    let mut legacy: Vec<(SymbolId, DeclaredProp)> = data
+        .scoping
+        .symbol_ids()
+        .filter_map(|sym| {
+            let BindingSemantics::LegacyBindableProp(semantics) = data.binding_semantics(sym)
+            else {
+                return None;
+            };
+            let local = data.scoping.symbol_name(sym);
+            let name = data
+                .script
+                .info
+                .as_ref()
+                .and_then(|info| info.exports.iter().find(|e| e.name == local))
+                .and_then(|e| e.alias.as_deref())
+                .unwrap_or(local)
+                .to_string();
+            Some((
+                sym,
+                DeclaredProp {
+                    name,
+                    required: semantics.default_lowering == PropDefaultLowering::None,
+                    bindable: true,
+                    kind: DeclaredPropKind::Unknown,
+                },
+            ))
+        })
+        .collect();
+    legacy.sort_unstable_by_key(|(sym, _)| data.scoping.symbol_span(*sym).start);
+    interface.props = legacy.into_iter().map(|(_, prop)| prop).collect();
+
+    interface
+}
+
+pub fn component_usages(
+    component: &Component,
+    parsed: &JsAst<'_>,
+    data: &AnalysisData<'_>,
+) -> Vec<ComponentUsage> {
+    let imports = svelte_default_imports(parsed);
+    if imports.is_empty() {
+        return Vec::new();
+    }
+    let snippets = &data.template.snippets.snippet_name_symbols;
+    let source = component.source;
+
+    let mut usages = Vec::new();
+    for node in component.store.iter_nodes() {
+        let Node::ComponentNode(cn) = node else {
+            continue;
+        };
+        let Some(specifier) = data
+            .elements
+            .flags
+            .component_binding_sym(cn.id)
+            .and_then(|sym| imports.get(&sym))
+        else {
+            continue;
+        };
+
+        let mut usage = ComponentUsage {
+            name: cn.name.to_string(),
+            source: specifier.clone(),
+            span: cn.span,
+            props: Vec::new(),
+            has_spread: false,
+            ignored: COMPONENT_USAGE_CODES
+                .into_iter()
+                .filter(|code| data.output.ignore_data.is_ignored(cn.id, code))
+                .collect(),
+        };
+        for attr in cn.attributes {
+            let (name, kind) = match attr {
+                Attribute::SpreadAttribute(_) => {
+                    usage.has_spread = true;
+                    continue;
+                }
+                Attribute::BindDirective(bind) if bind.name != "this" => {
+                    (bind.name.as_str(), PassedPropKind::Bind)
+                }
+                Attribute::ExpressionAttribute(attr) => {
+                    let is_snippet = match parsed.expr(attr.expression.id()) {
+                        Some(Expression::Identifier(ident)) => ident
+                            .reference_id
+                            .get()
+                            .and_then(|ref_id| data.scoping.get_reference(ref_id).symbol_id())
+                            .is_some_and(|sym| snippets.contains_key(&sym)),
+                        _ => false,
+                    };
+                    let kind = if is_snippet {
+                        PassedPropKind::Snippet
+                    } else {
+                        PassedPropKind::Value
+                    };
+                    (attr.name.as_str(), kind)
+                }
+                Attribute::StringAttribute(_)
+                | Attribute::BooleanAttribute(_)
+                | Attribute::ConcatenationAttribute(_) => {
+                    (attr.name().unwrap_or_default(), PassedPropKind::Value)
+                }
+                _ => continue,
+            };
+            if name == "slot" || name.starts_with("--") {
+                continue;
+            }
+            usage.props.push(PassedProp {
+                name: name.to_string(),
+                span: attr.span(),
+                kind,
+            });
+        }
+        collect_children_props(component, cn, source, &mut usage);
+        usages.push(usage);
+    }
+    usages.sort_unstable_by_key(|usage| usage.span.start);
+    usages
+}
+
+pub fn validate_component_usage(
+    usage: &ComponentUsage,
+    interface: &ComponentInterface,
+) -> Vec<Diagnostic> {
+    let mut diags = Vec::new();
+    let mut emit = |kind: DiagnosticKind, span: Span| {
+        if !usage.is_ignored(&kind) {
+            diags.push(Diagnostic::warning(kind, span));
+        }
+    };
+
+    for passed in &usage.props {
+        let component = usage.name.clone();
+        let name = passed.name.clone();
+        let Some(declared) = interface.prop(&passed.name) else {
+            let via_slot =
+                passed.kind == PassedPropKind::Snippet && interface.has_slot_for(&passed.name);
+            if !interface.accepts_any_prop && !via_slot {
+                emit(
+                    DiagnosticKind::ComponentUnknownProp { component, name },
+                    passed.span,
+                );
+            }
+            continue;
+        };
+        match passed.kind {
+            PassedPropKind::Bind if !declared.bindable => {
+                emit(
+                    DiagnosticKind::ComponentBindNonBindable { component, name },
+                    passed.span,
+                );
+            }
+            PassedPropKind::Snippet if declared.kind == DeclaredPropKind::Value => {
+                emit(
+                    DiagnosticKind::ComponentSnippetPropMismatch { component, name },
+                    passed.span,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    if !usage.has_spread {
+        for declared in &interface.props {
+            if declared.required && !usage.passes(&declared.name) {
+                emit(
+                    DiagnosticKind::ComponentMissingProp {
+                        component: usage.name.clone(),
+                        name: declared.name.clone(),
+                    },
+                    usage.span,
+                );
+            }
+        }
+    }
+
+    diags
+}
+
+fn collect_children_props(
+    component: &Component,
+    cn: &ComponentNode,
+    source: &str,
+    usage: &mut ComponentUsage,
+) {
+    let mut has_default_content = false;
+    for &id in component.fragment_nodes(cn.fragment) {
+        match component.store.get(id) {
+            Node::SnippetBlock(block) => {
+                let name = block.name(source);
+                usage.props.push(PassedProp {
+                    name: name.to_string(),
+                    span: Span::new(
+                        block.decl.span.start,
+                        block.decl.span.start + name.len() as u32,
+                    ),
+                    kind: PassedPropKind::Snippet,
+                });
+            }
+            Node::Text(text) => {
+                has_default_content |= !text.value(source).trim().is_empty();
+            }
+            Node::Comment(_) | Node::ConstTag(_) => {}
+            _ => has_default_content = true,
+        }
+    }
+    for slot in cn.legacy_slots {
+        if slot.name != "default" && !usage.passes(&slot.name) {
+            usage.props.push(PassedProp {
+                name: slot.name.to_string(),
+                span: fragment_span(component, slot.fragment).unwrap_or(cn.span),
+                kind: PassedPropKind::Snippet,
+            });
+        }
+    }
+    if has_default_content && !usage.passes("children") {
+        usage.props.push(PassedProp {
+            name: "children".to_string(),
+            span: cn.span,
+            kind: PassedPropKind::Snippet,
+        });
+    }
+}
+
+fn fragment_span(component: &Component, fragment: FragmentId) -> Option<Span> {
+    let nodes = component.fragment_nodes(fragment);
+    let first = component.store.node_span(*nodes.first()?)?;
+    let last = component.store.node_span(*nodes.last()?)?;
+    Some(Span::new(first.start, last.end))
+}
+
+fn legacy_slot_names(component: &Component) -> Vec<String> {
+    let mut slots: Vec<String> = Vec::new();
+    for node in component.store.iter_nodes() {
+        let Node::SlotElementLegacy(slot) = node else {
+            continue;
+        };
+        let name = slot
+            .attributes
+            .iter()
+            .find_map(|attr| match attr {
+                Attribute::StringAttribute(a) if a.name == "name" => {
+                    Some(a.value_span.source_text(component.source).to_string())
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| "default".to_string());
+        if !slots.contains(&name) {
+            slots.push(name);
+        }
+    }
+    slots
+}
+
+fn svelte_default_imports(parsed: &JsAst<'_>) -> FxHashMap<SymbolId, String> {
+    let mut imports = FxHashMap::default();
+    for program in [parsed.module_program.as_ref(), parsed.program.as_ref()]
+        .into_iter()
+        .flatten()
+    {
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else {
+                continue;
+            };
+            if !decl.source.value.ends_with(".svelte") {
+                continue;
+            }
+            for spec in decl.specifiers.iter().flatten() {
+                if let ImportDeclarationSpecifier::ImportDefaultSpecifier(default) = spec
+                    && let Some(sym) = default.local.symbol_id.get()
+                {
+                    imports.insert(sym, decl.source.value.to_string());
+                }
+            }
+        }
+    }
+    imports
+}
+
+fn rendered_snippet_symbols(
+    component: &Component,
+    parsed: &JsAst<'_>,
+    data: &AnalysisData<'_>,
+) -> FxHashMap<SymbolId, bool> {
+    let mut rendered: FxHashMap<SymbolId, bool> = FxHashMap::default();
+    for node in component.store.iter_nodes() {
+        let Node::RenderTag(tag) = node else {
+            continue;
+        };
+        let call = match parsed.expr(tag.expression.id()) {
+            Some(Expression::CallExpression(call)) => call,
+            Some(Expression::ChainExpression(chain)) => match &chain.expression {
+                ChainElement::CallExpression(call) => call,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        let Expression::Identifier(ident) = &call.callee else {
+            continue;
+        };
+        let Some(sym) = ident
+            .reference_id
+            .get()
+            .and_then(|ref_id| data.scoping.get_reference(ref_id).symbol_id())
+        else {
+            continue;
+        };
+        *rendered.entry(sym).or_insert(true) &= call.optional;
+    }
+    rendered
+}
+
+fn props_declarator<'p, 'a>(program: &'p Program<'a>) -> Option<&'p VariableDeclarator<'a>> {
+    program.body.iter().find_map(|stmt| {
+        let Statement::VariableDeclaration(decl) = stmt else {
+            return None;
+        };
+        decl.declarations.iter().find(|declarator| {
+            matches!(
+                &declarator.init,
+                Some(Expression::CallExpression(call))
+                    if detect_rune_from_call(call) == Some(RuneKind::Props)
+            )
+        })
+    })
+}
+
+type PropMembers = FxHashMap<String, (bool, DeclaredPropKind)>;
+
+fn props_type_members(parsed: &JsAst<'_>) -> Option<PropMembers> {
+    let program = parsed.program.as_ref()?;
+    let declarator = props_declarator(program)?;
+    if !matches!(declarator.id, BindingPattern::ObjectPattern(_)) {
+        return None;
+    }
+    let Some(annotation) = &declarator.type_annotation else {
+        return jsdoc_props_members(program, declarator);
+    };
+    let mut aliases = FxHashMap::default();
+    for program in [parsed.module_program.as_ref(), Some(program)]
+        .into_iter()
+        .flatten()
+    {
+        collect_type_aliases(program, &mut aliases);
+    }
+    resolve_members(&annotation.type_annotation, &aliases).map(owned_members)
+}
+
+/// Reads an inline `/** @type {{ ... }} */` annotation on the `$props()`
+/// declaration. Named typedefs are not resolved.
+fn jsdoc_props_members(
+    program: &Program<'_>,
+    declarator: &VariableDeclarator<'_>,
+) -> Option<PropMembers> {
+    let stmt_start = program.body.iter().find_map(|stmt| match stmt {
+        Statement::VariableDeclaration(decl)
+            if decl.span.start <= declarator.span.start && declarator.span.end <= decl.span.end =>
+        {
+            Some(decl.span.start)
+        }
+        _ => None,
+    })?;
+    let comment = program
+        .comments
+        .iter()
+        .rev()
+        .find(|comment| comment.attached_to == stmt_start && comment.is_jsdoc())?;
+    let span = comment.content_span();
+    let text = program
+        .source_text
+        .get(span.start as usize..span.end as usize)?;
+    let type_text = jsdoc_type_text(text)?;
+
+    let allocator = oxc_allocator::Allocator::default();
+    let source = format!("type __Props = {type_text};");
+    let parsed = oxc_parser::Parser::new(&allocator, &source, oxc_span::SourceType::ts()).parse();
+    if !parsed.errors.is_empty() {
+        return None;
+    }
+    let mut aliases = FxHashMap::default();
+    collect_type_aliases(&parsed.program, &mut aliases);
+    let TypeAlias::Type(ty) = aliases.get("__Props")? else {
+        return None;
+    };
+    resolve_members(ty, &aliases).map(owned_members)
+}
+
+fn jsdoc_type_text(comment: &str) -> Option<&str> {
+    let after_tag = &comment[comment.find("@type")? + "@type".len()..];
+    let body = after_tag.trim_start().strip_prefix('{')?;
+    let mut depth = 1usize;
+    for (idx, ch) in body.char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&body[..idx]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn owned_members(members: FxHashMap<&str, (bool, DeclaredPropKind)>) -> PropMembers {
+    members
+        .into_iter()
+        .map(|(name, member)| (name.to_string(), member))
+        .collect()
+}
+
+enum TypeAlias<'p, 'a> {
+    Members(&'p [TSSignature<'a>]),
+    Type(&'p TSType<'a>),
+    Opaque,
+}
+
+fn collect_type_aliases<'p, 'a>(
+    program: &'p Program<'a>,
+    aliases: &mut FxHashMap<&'p str, TypeAlias<'p, 'a>>,
+) {
+    for stmt in &program.body {
+        let decl = match stmt {
+            Statement::TSInterfaceDeclaration(_) | Statement::TSTypeAliasDeclaration(_) => {
+                stmt.as_declaration()
+            }
+            Statement::ExportNamedDeclaration(export) => export.declaration.as_ref(),
+            _ => None,
+        };
+        match decl {
+            Some(Declaration::TSInterfaceDeclaration(iface)) => {
+                let alias = if iface.extends.is_empty() && iface.type_parameters.is_none() {
+                    TypeAlias::Members(&iface.body.body)
+                } else {
+                    TypeAlias::Opaque
+                };
+                aliases.insert(iface.id.name.as_str(), alias);
+            }
+            Some(Declaration::TSTypeAliasDeclaration(alias)) => {
+                let value = if alias.type_parameters.is_none() {
+                    TypeAlias::Type(&alias.type_annotation)
+                } else {
+                    TypeAlias::Opaque
+                };
+                aliases.insert(alias.id.name.as_str(), value);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn resolve_members<'p>(
+    ty: &'p TSType<'_>,
+    aliases: &FxHashMap<&'p str, TypeAlias<'p, '_>>,
+) -> Option<FxHashMap<&'p str, (bool, DeclaredPropKind)>> {
+    let mut current = ty;
+    for _ in 0..8 {
+        match current {
+            TSType::TSTypeLiteral(lit) => return Some(signature_members(&lit.members)),
+            TSType::TSParenthesizedType(paren) => current = &paren.type_annotation,
+            TSType::TSTypeReference(reference) if reference.type_arguments.is_none() => {
+                let TSTypeName::IdentifierReference(ident) = &reference.type_name else {
+                    return None;
+                };
+                match aliases.get(ident.name.as_str())? {
+                    TypeAlias::Members(members) => return Some(signature_members(members)),
+                    TypeAlias::Type(ty) => current = ty,
+                    TypeAlias::Opaque => return None,
+                }
+            }
+            _ => return None,
+        }
+    }
+    None
+}
+
+fn signature_members<'p>(
+    members: &'p [TSSignature<'_>],
+) -> FxHashMap<&'p str, (bool, DeclaredPropKind)> {
+    members
+        .iter()
+        .filter_map(|member| {
+            let TSSignature::TSPropertySignature(prop) = member else {
+                return None;
+            };
+            let name = match &prop.key {
+                PropertyKey::StaticIdentifier(ident) => ident.name.as_str(),
+                PropertyKey::StringLiteral(lit) => lit.value.as_str(),
+                _ => return None,
+            };
+            let kind = prop
+                .type_annotation
+                .as_ref()
+                .map_or(DeclaredPropKind::Unknown, |ann| {
+                    type_kind(&ann.type_annotation)
+                });
+            Some((name, (prop.optional, kind)))
+        })
+        .collect()
+}
+
+fn type_kind(ty: &TSType<'_>) -> DeclaredPropKind {
+    match ty {
+        TSType::TSTypeReference(reference) => match &reference.type_name {
+            TSTypeName::IdentifierReference(ident) if ident.name == "Snippet" => {
+                DeclaredPropKind::Snippet
+            }
+            TSTypeName::IdentifierReference(ident)
+                if matches!(
+                    ident.name.as_str(),
+                    "Array" | "Record" | "Date" | "Map" | "Set"
+                ) =>
+            {
+                DeclaredPropKind::Value
+            }
+            _ => DeclaredPropKind::Unknown,
+        },
+        TSType::TSStringKeyword(_)
+        | TSType::TSNumberKeyword(_)
+        | TSType::TSBooleanKeyword(_)
+        | TSType::TSBigIntKeyword(_)
+        | TSType::TSLiteralType(_)
+        | TSType::TSTemplateLiteralType(_)
+        | TSType::TSArrayType(_)
+        | TSType::TSTupleType(_)
+        | TSType::TSTypeLiteral(_) => DeclaredPropKind::Value,
+        TSType::TSParenthesizedType(paren) => type_kind(&paren.type_annotation),
+        TSType::TSUnionType(union) => {
+            let mut kinds = union
+                .types
+                .iter()
+                .filter(|ty| {
+                    !matches!(ty, TSType::TSUndefinedKeyword(_) | TSType::TSNullKeyword(_))
+                })
+                .map(type_kind);
+            let Some(first) = kinds.next() else {
+                return DeclaredPropKind::Unknown;
+            };
+            if kinds.all(|kind| kind == first) {
+                first
+            } else {
+                DeclaredPropKind::Unknown
+            }
+        }
+        _ => DeclaredPropKind::Unknown,
+    }
+}
+
+fn is_literal_default(text: &str) -> bool {
+    let text = text.trim();
+    text.starts_with(['"', '\'', '`', '[', '{'])
+        || text.parse::<f64>().is_ok()
+        || matches!(text, "true" | "false")
+}
@@ -1,9 +1,73 @@
+pub struct CssHashInput<'a> {
+    pub css: &'a str,
+    pub name: &'a str,
+    pub filename: &'a str,
+}
+
+pub type CssHashGetter<'g> = &'g dyn Fn(&CssHashInput<'_>) -> String;
+
+#[derive(Debug, Clone, Default)]
+pub enum CssHash {
+    #[default]
+    Default,
+    Template(String),
+}
+
+impl CssHash {
+    pub fn resolve(&self, input: &CssHashInput<'_>) -> String {
+        match self {
+            Self::Default => css_component_hash(input.css),
+            Self::Template(template) => expand_css_hash_template(template, input),
+        }
+    }
+}
+
+fn expand_css_hash_template(template: &str, input: &CssHashInput<'_>) -> String {
+    let mut out = String::with_capacity(template.len() + 16);
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let tail = &rest[open..];
+        let Some(close) = tail.find('}') else {
+            out.push_str(tail);
+            return out;
+        };
+        match &tail[1..close] {
+            "hash" => out.push_str(&css_hash_digest(input.css)),
+            "name" => out.push_str(input.name),
+            "filename" => out.push_str(&filename_class_segment(input.filename)),
+            _ => out.push_str(&tail[..=close]),
+        }
+        rest = &tail[close + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn filename_class_segment(filename: &str) -> String {
+    let basename = filename.rsplit(['/', '\\']).next().unwrap_or(filename);
+    let stem = basename.split_once('.').map_or(basename, |(stem, _)| stem);
+    stem.chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-') {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
 pub(crate) fn css_component_hash(css: &str) -> String {
+    format!("svelte-{}", css_hash_digest(css))
+}
+
+pub fn css_hash_digest(input: &str) -> String {
     let mut h: u32 = 5381;
-    for ch in css.chars().rev() {
+    for ch in input.chars().rev() {
         h = h.wrapping_shl(5).wrapping_sub(h) ^ (ch as u32);
     }
-    format!("svelte-{}", to_base36(h))
+    to_base36(h)
 }
 
 fn to_base36(mut n: u32) -> String {
@@ -11,19 +75,26 @@ fn to_base36(mut n: u32) -> String {
     if n == 0 {
         return "0".to_string();
     }
-    let mut buf = Vec::with_capacity(8);
+    let mut digits = Vec::with_capacity(8);
     while n > 0 {
-        buf.push(DIGITS[(n % 36) as usize]);
+        digits.push(char::from(DIGITS[(n % 36) as usize]));
         n /= 36;
     }
-    buf.reverse();
-    String::from_utf8(buf).expect("DIGITS contains only ASCII bytes — always valid UTF-8")
+    digits.iter().rev().collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn input<'a>(css: &'a str, name: &'a str, filename: &'a str) -> CssHashInput<'a> {
+        CssHashInput {
+            css,
+            name,
+            filename,
+        }
+    }
+
     #[test]
     fn css_component_hash_basic() {
         let css = "\n\tp {\n\t\tcolor: red;\n\t}\n";
@@ -35,4 +106,26 @@ mod tests {
         let h = css_component_hash("");
         assert!(h.starts_with("svelte-"));
     }
+
+    #[test]
+    fn css_hash_default_matches_component_hash() {
+        let css = "\n\tp {\n\t\tcolor: red;\n\t}\n";
+        let resolved = CssHash::Default.resolve(&input(css, "App", "App.svelte"));
+        assert_eq!(resolved, "svelte-1a7i8ec");
+    }
+
+    #[test]
+    fn css_hash_template_expands_placeholders() {
+        let css = "\n\tp {\n\t\tcolor: red;\n\t}\n";
+        let hash = CssHash::Template("{name}-{filename}-{hash}".to_string());
+        let resolved = hash.resolve(&input(css, "Counter", "src/lib/my counter.svelte"));
+        assert_eq!(resolved, "Counter-my_counter-1a7i8ec");
+    }
+
+    #[test]
+    fn css_hash_template_keeps_unknown_placeholders() {
+        let hash = CssHash::Template("x-{other}-{hash".to_string());
+        let resolved = hash.resolve(&input("", "App", "App.svelte"));
+        assert_eq!(resolved, "x-{other}-{hash");
+    }
 }
@@ -0,0 +1,135 @@
+use oxc_ast::ast::{Program, Statement};
+use rustc_hash::FxHashMap;
+use svelte_ast::{Component, Node};
+use svelte_component_semantics::SymbolId;
+use svelte_css::{Rule, StyleSheet, StyleSheetChild};
+
+use crate::{AnalysisData, JsAst};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyKind {
+    Import,
+    Component,
+    CssImport,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
+pub struct Dependency {
+    pub specifier: String,
+    pub kind: DependencyKind,
+}
+
+pub fn component_dependencies(
+    component: &Component,
+    parsed: &JsAst<'_>,
+    data: &AnalysisData<'_>,
+    stylesheet: Option<&StyleSheet>,
+) -> Vec<Dependency> {
+    let mut deps = Vec::new();
+    let mut imported: FxHashMap<SymbolId, &str> = FxHashMap::default();
+    for program in [parsed.module_program.as_ref(), parsed.program.as_ref()]
+        .into_iter()
+        .flatten()
+    {
+        collect_program_imports(program, &mut deps);
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else {
+                continue;
+            };
+            for spec in decl.specifiers.iter().flatten() {
+                if let Some(sym) = spec.local().symbol_id.get() {
+                    imported.insert(sym, decl.source.value.as_str());
+                }
+            }
+        }
+    }
+
+    for node in component.store.iter_nodes() {
+        let Node::ComponentNode(cn) = node else {
+            continue;
+        };
+        if let Some(specifier) = data
+            .elements
+            .flags
+            .component_binding_sym(cn.id)
+            .and_then(|sym| imported.get(&sym))
+        {
+            push_unique(&mut deps, specifier, DependencyKind::Component);
+        }
+    }
+
+    if let (Some(stylesheet), Some(css)) = (stylesheet, component.css.as_ref()) {
+        let css_source = component.source_text(css.content_span);
+        for child in &stylesheet.children {
+            let StyleSheetChild::Rule(Rule::AtRule(at_rule)) = child else {
+                continue;
+            };
+            if !at_rule.name.eq_ignore_ascii_case("import") {
+                continue;
+            }
+            let prelude = at_rule.prelude.source_text(css_source);
+            if let Some(specifier) = css_import_specifier(prelude) {
+                push_unique(&mut deps, specifier, DependencyKind::CssImport);
+            }
+        }
+    }
+    deps
+}
+
+pub fn module_dependencies(parsed: &JsAst<'_>) -> Vec<Dependency> {
+    let mut deps = Vec::new();
+    if let Some(program) = parsed.program.as_ref() {
+        collect_program_imports(program, &mut deps);
+    }
+    deps
+}
+
+fn collect_program_imports(program: &Program<'_>, deps: &mut Vec<Dependency>) {
+    for stmt in &program.body {
+        let source = match stmt {
+            Statement::ImportDeclaration(decl) if !decl.import_kind.is_type() => &decl.source,
+            Statement::ExportNamedDeclaration(decl) if !decl.export_kind.is_type() => {
+                let Some(source) = &decl.source else {
+                    continue;
+                };
+                source
+            }
+            Statement::ExportAllDeclaration(decl) if !decl.export_kind.is_type() => &decl.source,
+            _ => continue,
+        };
+        push_unique(deps, source.value.as_str(), DependencyKind::Import);
+    }
+}
+
+fn push_unique(deps: &mut Vec<Dependency>, specifier: &str, kind: DependencyKind) {
+    if !deps
+        .iter()
+        .any(|dep| dep.kind == kind && dep.specifier == specifier)
+    {
+        deps.push(Dependency {
+            specifier: specifier.to_string(),
+            kind,
+        });
+    }
+}
+
+fn css_import_specifier(prelude: &str) -> Option<&str> {
+    let prelude = prelude.trim_start();
+    let specifier = if prelude
+        .get(..4)
+        .is_some_and(|head| head.eq_ignore_ascii_case("url("))
+    {
+        let inner = prelude[4..prelude.find(')')?].trim();
+        quoted_prefix(inner).unwrap_or(inner)
+    } else {
+        quoted_prefix(prelude)?
+    };
+    Some(specifier).filter(|specifier| !specifier.is_empty())
+}
+
+fn quoted_prefix(value: &str) -> Option<&str> {
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let rest = &value[1..];
+    Some(&rest[..rest.find(quote)?])
+}
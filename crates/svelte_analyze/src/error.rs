@@ -0,0 +1,15 @@
+use svelte_span::Span;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnalyzeError {
+    pub span: Option<Span>,
+    pub message: String,
+}
+
+impl std::fmt::Display for AnalyzeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "analyze: {}", self.message)
+    }
+}
+
+impl std::error::Error for AnalyzeError {}
@@ -1,8 +1,12 @@
 pub mod block_semantics;
+pub mod component_interface;
 pub(crate) mod css;
+pub mod dependencies;
+mod error;
 pub(crate) mod passes;
 pub mod reactivity_semantics;
 
+pub use css::{CssHash, CssHashGetter, CssHashInput, css_hash_digest};
 pub use passes::css_analyze::analyze_css_pass;
 pub mod scope;
 pub mod types;
@@ -18,6 +22,16 @@ pub use block_semantics::{
     KeyBlockSemantics, RenderArgLowering, RenderAsyncKind, RenderCalleeShape,
     RenderTagBlockSemantics, SnippetBlockSemantics, SnippetParam,
 };
+pub use component_interface::{
+    ComponentInterface, ComponentUsage, DeclaredProp, DeclaredPropKind, PassedProp, PassedPropKind,
+    component_interface, component_usages, validate_component_usage,
+};
+pub use dependencies::{Dependency, DependencyKind, component_dependencies, module_dependencies};
+pub use error::AnalyzeError;
+pub use reactivity_semantics::graph::{
+    ReactivityEdge, ReactivityEdgeKind, ReactivityGraph, ReactivityNode, ReactivityNodeKind,
+    build_reactivity_graph,
+};
 pub use scope::ComponentScoping;
 pub use types::data::{
     AnalysisData, AsyncStmtMeta, AttrIndex, BindHostKind, BindPropertyKind, BindTargetSemantics,
@@ -73,6 +87,22 @@ pub use utils::{
 use svelte_ast::Component;
 use svelte_diagnostics::{Diagnostic, Severity};
 
+#[derive(Debug, Clone, Copy, Default, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LintOptions {
+    pub effect_to_derived: bool,
+    pub unused_declarations: bool,
+    pub strict_html_nesting: bool,
+}
+
+pub type PassClock = fn() -> std::time::Duration;
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PassTiming {
+    pub pass: String,
+    pub duration: std::time::Duration,
+}
+
 pub struct AnalyzeOptions {
     pub custom_element: bool,
     pub experimental_async: bool,
@@ -81,9 +111,12 @@ pub struct AnalyzeOptions {
     pub immutable: bool,
     pub preserve_whitespace: bool,
     pub dev: bool,
+    pub inline_constants: bool,
     pub component_name: String,
     pub filename_basename: String,
+    pub lint: LintOptions,
     pub warning_filter: Option<Box<dyn Fn(&Diagnostic) -> bool>>,
+    pub pass_clock: Option<PassClock>,
 }
 
 impl Default for AnalyzeOptions {
@@ -96,25 +129,28 @@ impl Default for AnalyzeOptions {
             immutable: false,
             preserve_whitespace: false,
             dev: false,
+            inline_constants: false,
             component_name: "Self".to_string(),
             filename_basename: "Self.svelte".to_string(),
+            lint: LintOptions::default(),
             warning_filter: None,
+            pass_clock: None,
         }
     }
 }
 
 pub fn analyze<'a>(
-    component: &Component,
+    component: &Component<'a>,
     parsed: JsAst<'a>,
-) -> (AnalysisData<'a>, JsAst<'a>, Vec<Diagnostic>) {
+) -> Result<(AnalysisData<'a>, JsAst<'a>, Vec<Diagnostic>), AnalyzeError> {
     analyze_with_options(component, parsed, &AnalyzeOptions::default())
 }
 
 pub fn analyze_with_options<'a>(
-    component: &Component,
+    component: &Component<'a>,
     mut parsed: JsAst<'a>,
     options: &AnalyzeOptions,
-) -> (AnalysisData<'a>, JsAst<'a>, Vec<Diagnostic>) {
+) -> Result<(AnalysisData<'a>, JsAst<'a>, Vec<Diagnostic>), AnalyzeError> {
     let mut diags = Vec::new();
 
     let mut data = AnalysisData::new_empty(component.node_count());
@@ -132,24 +168,32 @@ pub fn analyze_with_options<'a>(
             .is_some();
     data.output.component_name = options.component_name.clone();
     data.script.experimental_async = options.experimental_async;
-    let execution_order = passes::resolve_default_execution_order()
-        .unwrap_or_else(|err| panic!("invalid analyze pass configuration: {err:?}"));
+    let execution_order =
+        passes::resolve_default_execution_order().map_err(|err| AnalyzeError {
+            span: None,
+            message: format!("invalid analyze pass configuration: {err:?}"),
+        })?;
     debug_assert_eq!(execution_order, passes::default_stage_execution_order());
 
-    for &key in passes::PRE_TEMPLATE_SCRIPT_STAGE {
-        passes::execute_pass(key, component, &mut parsed, &mut data, options, &mut diags);
-    }
-    for &key in passes::INDEX_BUILD_STAGE {
-        passes::execute_pass(key, component, &mut parsed, &mut data, options, &mut diags);
-    }
-    for &key in passes::POST_TEMPLATE_ANALYSIS_STAGE {
-        passes::execute_pass(key, component, &mut parsed, &mut data, options, &mut diags);
-    }
-    for &key in passes::TEMPLATE_EXECUTION_STAGE {
-        passes::execute_pass(key, component, &mut parsed, &mut data, options, &mut diags);
-    }
-    for &key in passes::VALIDATION_STAGE {
+    let stages = [
+        passes::PRE_TEMPLATE_SCRIPT_STAGE,
+        passes::INDEX_BUILD_STAGE,
+        passes::POST_TEMPLATE_ANALYSIS_STAGE,
+        passes::TEMPLATE_EXECUTION_STAGE,
+        passes::VALIDATION_STAGE,
+    ];
+    for &key in stages.into_iter().flatten() {
+        let started = options.pass_clock.map(|clock| clock());
         passes::execute_pass(key, component, &mut parsed, &mut data, options, &mut diags);
+        if let Some(err) = data.take_internal_error() {
+            return Err(err);
+        }
+        if let (Some(clock), Some(started)) = (options.pass_clock, started) {
+            data.pass_timings.push(PassTiming {
+                pass: format!("{key:?}"),
+                duration: clock().saturating_sub(started),
+            });
+        }
     }
 
     if let Some(ref filter) = options.warning_filter {
@@ -166,7 +210,7 @@ pub fn analyze_with_options<'a>(
 
     data.output.runtime_plan = build_runtime_plan(&data, options.dev);
 
-    (data, parsed, diags)
+    Ok((data, parsed, diags))
 }
 
 pub fn analyze_module<'a>(
@@ -197,8 +241,7 @@ pub fn analyze_module<'a>(
             validate::validate_standalone_module(&data, &program, 0, true, &mut diags);
 
             parsed.program = Some(program);
-            let stub_component =
-                svelte_ast::Component::dummy_for_standalone_module(source.to_string());
+            let stub_component = svelte_ast::Component::dummy_for_standalone_module(alloc, source);
             reactivity_semantics::build_v2(&stub_component, &parsed, &mut data);
         }
         Err(errs) => diags.extend(errs),
@@ -110,11 +110,11 @@ impl<'s> TemplateVisitor for BindSemanticsVisitor<'s> {
         });
         let bind_group_value_attr_id = ctx
             .data
-            .expression_attribute(el.id, &el.attributes, "value")
+            .expression_attribute(el.id, el.attributes, "value")
             .map(|attr| attr.id);
         let has_contenteditable = ctx.data.has_true_boolean_attribute(
             el.id,
-            &el.attributes,
+            el.attributes,
             "contenteditable",
             self.source,
         );
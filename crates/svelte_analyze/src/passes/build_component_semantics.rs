@@ -13,7 +13,7 @@ use crate::types::data::{AnalysisData, JsAst};
 use crate::utils::script_info;
 
 pub(crate) fn build<'d, 'a>(
-    component: &'d Component,
+    component: &'d Component<'a>,
     parsed: &'d mut JsAst<'a>,
     data: &mut AnalysisData<'a>,
 ) {
@@ -32,7 +32,7 @@ pub(crate) fn build<'d, 'a>(
     {
         let mut walker = AnalyzeTemplateWalker {
             store: &component.store,
-            source: &component.source,
+            source: component.source,
             root: component.root,
             parsed,
             data,
@@ -75,7 +75,7 @@ pub(crate) fn build<'d, 'a>(
 }
 
 struct AnalyzeTemplateWalker<'d, 'a> {
-    store: &'d svelte_ast::AstStore,
+    store: &'d svelte_ast::AstStore<'a>,
     source: &'d str,
     root: svelte_ast::FragmentId,
     parsed: &'d mut JsAst<'a>,
@@ -92,11 +92,11 @@ impl<'d, 'a> AnalyzeTemplateWalker<'d, 'a> {
         for &id in nodes {
             match self.store.get(id) {
                 Node::Element(el) => {
-                    self.walk_attributes(&el.attributes, ctx);
+                    self.walk_attributes(el.attributes, ctx);
                     self.walk_fragment(el.fragment, ctx);
                 }
                 Node::SlotElementLegacy(el) => {
-                    self.walk_attributes(&el.attributes, ctx);
+                    self.walk_attributes(el.attributes, ctx);
                     self.walk_fragment(el.fragment, ctx);
                 }
                 Node::ComponentNode(node) => self.walk_component_node(node, ctx),
@@ -174,27 +174,27 @@ impl<'d, 'a> AnalyzeTemplateWalker<'d, 'a> {
                     ctx.leave_scope();
                 }
                 Node::SvelteFragmentLegacy(node) => {
-                    self.walk_attributes(&node.attributes, ctx);
+                    self.walk_attributes(node.attributes, ctx);
                     self.walk_fragment(node.fragment, ctx);
                 }
                 Node::SvelteElement(el) => {
-                    self.walk_attributes(&el.attributes, ctx);
+                    self.walk_attributes(el.attributes, ctx);
                     ctx.enter_fragment_scope_by_id(el.fragment);
                     self.walk_fragment(el.fragment, ctx);
                     ctx.leave_scope();
                 }
                 Node::SvelteBoundary(boundary) => {
-                    self.walk_attributes(&boundary.attributes, ctx);
+                    self.walk_attributes(boundary.attributes, ctx);
                     ctx.enter_fragment_scope_by_id(boundary.fragment);
                     self.walk_fragment(boundary.fragment, ctx);
                     ctx.leave_scope();
                 }
                 Node::AwaitBlock(block) => self.walk_await_block(block, ctx),
-                Node::SvelteWindow(node) => self.walk_attributes(&node.attributes, ctx),
-                Node::SvelteDocument(node) => self.walk_attributes(&node.attributes, ctx),
-                Node::SvelteBody(node) => self.walk_attributes(&node.attributes, ctx),
+                Node::SvelteWindow(node) => self.walk_attributes(node.attributes, ctx),
+                Node::SvelteDocument(node) => self.walk_attributes(node.attributes, ctx),
+                Node::SvelteBody(node) => self.walk_attributes(node.attributes, ctx),
                 Node::DebugTag(tag) => {
-                    for ident_ref in &tag.identifier_refs {
+                    for ident_ref in tag.identifier_refs {
                         if let Some(expr) = self.parsed.pending_expr(ident_ref.span.start) {
                             ctx.visit_js_expression(ident_ref, expr);
                         }
@@ -332,7 +332,7 @@ impl<'d, 'a> AnalyzeTemplateWalker<'d, 'a> {
                     }
                 }
                 Attribute::ConcatenationAttribute(attr) => {
-                    for part in &attr.parts {
+                    for part in attr.parts {
                         if let svelte_ast::ConcatPart::Dynamic { id, expr } = part {
                             self.record_dynamic_expr(*id, expr.span.start, expr, ctx);
                         }
@@ -355,7 +355,7 @@ impl<'d, 'a> AnalyzeTemplateWalker<'d, 'a> {
         node: &'d svelte_ast::ComponentNode,
         ctx: &mut TemplateBuildContext<'_, 'a>,
     ) {
-        self.walk_component_like(&node.attributes, node.fragment, &node.legacy_slots, ctx);
+        self.walk_component_like(node.attributes, node.fragment, node.legacy_slots, ctx);
     }
 
     fn walk_svelte_component_legacy(
@@ -363,7 +363,7 @@ impl<'d, 'a> AnalyzeTemplateWalker<'d, 'a> {
         node: &'d svelte_ast::SvelteComponentLegacy,
         ctx: &mut TemplateBuildContext<'_, 'a>,
     ) {
-        self.walk_component_like(&node.attributes, node.fragment, &node.legacy_slots, ctx);
+        self.walk_component_like(node.attributes, node.fragment, node.legacy_slots, ctx);
     }
 
     fn walk_component_like(
@@ -453,7 +453,7 @@ impl<'d, 'a> AnalyzeTemplateWalker<'d, 'a> {
                 self.walk_expr_attr(dir.id, &dir.expression, dir.expression.span, ctx);
             }
             StyleDirectiveValue::Concatenation(parts) => {
-                for part in parts {
+                for part in *parts {
                     if let svelte_ast::ConcatPart::Dynamic { id, expr } = part {
                         self.record_dynamic_expr(*id, expr.span.start, expr, ctx);
                     }
@@ -626,7 +626,7 @@ fn collect_ref_ids(
                     record_expr(&d.expression, d.expression.span.start, expr_ids);
                 }
                 StyleDirectiveValue::Concatenation(parts) => {
-                    for part in parts {
+                    for part in *parts {
                         if let svelte_ast::ConcatPart::Dynamic { expr, .. } = part {
                             record_expr(expr, expr.span.start, expr_ids);
                         }
@@ -659,7 +659,7 @@ fn collect_ref_ids(
                 }
             }
             Attribute::ConcatenationAttribute(a) => {
-                for part in &a.parts {
+                for part in a.parts {
                     if let svelte_ast::ConcatPart::Dynamic { expr, .. } = part {
                         record_expr(expr, expr.span.start, expr_ids);
                     }
@@ -689,13 +689,13 @@ fn collect_ref_ids(
         for id in nodes {
             match component.store.get(id) {
                 Node::Element(el) => {
-                    for attr in &el.attributes {
+                    for attr in el.attributes {
                         walk_attr(attr, expr_ids, stmt_ids);
                     }
                     walk_fragment(component, el.fragment, expr_ids, stmt_ids);
                 }
                 Node::SlotElementLegacy(el) => {
-                    for attr in &el.attributes {
+                    for attr in el.attributes {
                         walk_attr(attr, expr_ids, stmt_ids);
                     }
                     walk_fragment(component, el.fragment, expr_ids, stmt_ids);
@@ -761,7 +761,7 @@ fn collect_ref_ids(
                     walk_fragment(component, head.fragment, expr_ids, stmt_ids);
                 }
                 Node::SvelteFragmentLegacy(node) => {
-                    for attr in &node.attributes {
+                    for attr in node.attributes {
                         walk_attr(attr, expr_ids, stmt_ids);
                     }
                     walk_fragment(component, node.fragment, expr_ids, stmt_ids);
@@ -770,13 +770,13 @@ fn collect_ref_ids(
                     if let Some(tag_ref) = el.this_expr() {
                         record_expr(tag_ref, el.tag_span.start, expr_ids);
                     }
-                    for attr in &el.attributes {
+                    for attr in el.attributes {
                         walk_attr(attr, expr_ids, stmt_ids);
                     }
                     walk_fragment(component, el.fragment, expr_ids, stmt_ids);
                 }
                 Node::SvelteBoundary(b) => {
-                    for attr in &b.attributes {
+                    for attr in b.attributes {
                         walk_attr(attr, expr_ids, stmt_ids);
                     }
                     walk_fragment(component, b.fragment, expr_ids, stmt_ids);
@@ -800,22 +800,22 @@ fn collect_ref_ids(
                     }
                 }
                 Node::SvelteWindow(node) => {
-                    for attr in &node.attributes {
+                    for attr in node.attributes {
                         walk_attr(attr, expr_ids, stmt_ids);
                     }
                 }
                 Node::SvelteDocument(node) => {
-                    for attr in &node.attributes {
+                    for attr in node.attributes {
                         walk_attr(attr, expr_ids, stmt_ids);
                     }
                 }
                 Node::SvelteBody(node) => {
-                    for attr in &node.attributes {
+                    for attr in node.attributes {
                         walk_attr(attr, expr_ids, stmt_ids);
                     }
                 }
                 Node::DebugTag(tag) => {
-                    for r in &tag.identifier_refs {
+                    for r in tag.identifier_refs {
                         record_expr(r, r.span.start, expr_ids);
                     }
                 }
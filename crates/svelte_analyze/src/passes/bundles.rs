@@ -86,9 +86,9 @@ pub(crate) struct TemplateValidationBundle {
 }
 
 impl TemplateValidationBundle {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(strict_html_nesting: bool) -> Self {
         Self {
-            validation: template_validation::TemplateValidationVisitor::new(),
+            validation: template_validation::TemplateValidationVisitor::new(strict_html_nesting),
         }
     }
 
@@ -78,7 +78,7 @@ impl TemplateVisitor for CollectSymbolsVisitor {
         attr: &svelte_ast::ConcatenationAttribute,
         ctx: &mut VisitContext<'_, '_>,
     ) {
-        merge_concat_expression_info(&attr.parts, attr.id, ctx);
+        merge_concat_expression_info(attr.parts, attr.id, ctx);
     }
 
     fn leave_style_directive(
@@ -171,17 +171,17 @@ fn set_pending_flags(
 ) {
     match attr {
         Attribute::ExpressionAttribute(ea) => {
-            *pending_shorthand = Some((ea.id, ea.name.clone()));
+            *pending_shorthand = Some((ea.id, ea.name.to_string()));
             if ea.name == "class" {
                 *pending_clsx = true;
             }
         }
         Attribute::ClassDirective(cd) => {
-            *pending_shorthand = Some((cd.id, cd.name.clone()));
+            *pending_shorthand = Some((cd.id, cd.name.to_string()));
         }
         Attribute::StyleDirective(sd) => {
             if matches!(sd.value, StyleDirectiveValue::Expression) {
-                *pending_shorthand = Some((sd.id, sd.name.clone()));
+                *pending_shorthand = Some((sd.id, sd.name.to_string()));
             }
         }
         _ => {}
@@ -28,7 +28,27 @@ fn element_needs_var(el: &Element, data: &AnalysisData, store: &AstStore) -> boo
         return true;
     }
 
-    if data.has_runtime_attrs(id) {
+    // The lowered value is set through the element var even when its
+    // children fold into static text.
+    if data.elements.flags.needs_textarea_value_lowering(id)
+        || data
+            .elements
+            .flags
+            .option_synthetic_value_expr(id)
+            .is_some()
+    {
+        return true;
+    }
+
+    if data.has_runtime_attrs(id)
+        && !el.attributes.iter().all(|attr| match attr {
+            Attribute::StringAttribute(_) | Attribute::BooleanAttribute(_) => true,
+            Attribute::ExpressionAttribute(attr) => {
+                data.inline_attribute_constant(id, attr).is_some()
+            }
+            _ => false,
+        })
+    {
         return true;
     }
 
@@ -49,7 +69,7 @@ fn element_needs_var(el: &Element, data: &AnalysisData, store: &AstStore) -> boo
 fn item_needs_var(id: NodeId, data: &AnalysisData, store: &AstStore) -> bool {
     match store.get(id) {
         Node::Text(_) => false,
-        Node::ExpressionTag(_) => true,
+        Node::ExpressionTag(tag) => data.inline_text_constant(tag.id).is_none(),
         Node::Element(_) => data.elements.flags.needs_var.contains(&id),
         Node::SlotElementLegacy(_) => true,
         Node::SvelteFragmentLegacy(el) => {
@@ -1,6 +1,6 @@
 use compact_str::CompactString;
 use svelte_css::{
-    AtRule, Block, BlockChild, CombinatorKind, ComplexSelector, RelativeSelector, SelectorList,
+    AtRule, BlockChild, CombinatorKind, ComplexSelector, RelativeSelector, SelectorList,
     SimpleSelector, StyleRule, StyleSheet, Visit,
 };
 use svelte_diagnostics::Diagnostic;
@@ -10,7 +10,7 @@ use svelte_span::GetSpan;
 use svelte_ast::Component as SvelteComponent;
 use svelte_parser::JsAst;
 
-use crate::css::css_component_hash;
+use crate::css::{CssHashGetter, CssHashInput};
 use crate::types::data::{AnalysisData, CssAnalysis};
 use crate::types::node_table::NodeBitSet;
 
@@ -19,6 +19,8 @@ pub fn analyze_css_pass(
     stylesheet: &StyleSheet,
     parsed: &JsAst<'_>,
     inject_styles: bool,
+    css_hash: CssHashGetter<'_>,
+    filename: &str,
     data: &mut AnalysisData,
     diagnostics: &mut Vec<Diagnostic>,
 ) {
@@ -26,7 +28,11 @@ pub fn analyze_css_pass(
         return;
     };
     let css_text = component.source_text(css_block.content_span);
-    let hash = css_component_hash(css_text);
+    let hash = css_hash(&CssHashInput {
+        css: css_text,
+        name: data.component_name(),
+        filename,
+    });
 
     let keyframes = collect_keyframe_names(stylesheet, css_text);
 
@@ -227,17 +233,12 @@ impl<'a> CssValidator<'a> {
 
                             if is_lone_global
                                 && rule.prelude.children.len() == 1
-                                && has_declaration(&rule.block)
-                            {
-                                let decl_span = rule
-                                    .block
-                                    .children
-                                    .iter()
-                                    .find_map(|c| match c {
+                                && let Some(decl_span) =
+                                    rule.block.children.iter().find_map(|c| match c {
                                         BlockChild::Declaration(d) => Some(d.span),
                                         _ => None,
                                     })
-                                    .expect("has_declaration returned true");
+                            {
                                 self.emit(
                                     DiagnosticKind::CssGlobalBlockInvalidDeclaration,
                                     decl_span,
@@ -299,10 +300,11 @@ impl<'a> CssValidator<'a> {
     }
 
     fn validate_complex_selector(&mut self, node: &ComplexSelector) {
-        if let Some(global_rel) = node
+        if let Some((idx, global_rel)) = node
             .children
             .iter()
-            .find(|r| is_global_relative_selector(r))
+            .enumerate()
+            .find(|(_, r)| is_global_relative_selector(r))
         {
             if self.in_pseudo_class && is_global_block_selector_in_rel(global_rel) {
                 self.emit(
@@ -311,11 +313,6 @@ impl<'a> CssValidator<'a> {
                 );
             }
 
-            let idx = node
-                .children
-                .iter()
-                .position(is_global_relative_selector)
-                .expect("caller guarantees selector contains a :global(...) child");
             if let SimpleSelector::Global {
                 args: Some(_),
                 span,
@@ -400,13 +397,6 @@ fn is_global_block_selector_in_rel(rel: &RelativeSelector) -> bool {
     rel.selectors.first().is_some_and(is_global_block_selector)
 }
 
-fn has_declaration(block: &Block) -> bool {
-    block
-        .children
-        .iter()
-        .any(|c| matches!(c, BlockChild::Declaration(_)))
-}
-
 impl Visit for CssValidator<'_> {
     fn visit_style_rule(&mut self, node: &StyleRule) {
         self.validate_rule(node);
@@ -75,31 +75,24 @@ struct SelectorPlan {
 }
 
 struct RuleContext<'a> {
-    rule_id: CssNodeId,
+    rule: &'a StyleRule,
     parent_rules: &'a [CssNodeId],
     rule_lookup: &'a FxHashMap<CssNodeId, &'a StyleRule>,
 }
 
 impl<'a> RuleContext<'a> {
     fn new(
-        rule_id: CssNodeId,
+        rule: &'a StyleRule,
         parent_rules: &'a [CssNodeId],
         rule_lookup: &'a FxHashMap<CssNodeId, &'a StyleRule>,
     ) -> Self {
         Self {
-            rule_id,
+            rule,
             parent_rules,
             rule_lookup,
         }
     }
 
-    fn rule(&self) -> &'a StyleRule {
-        self.rule_lookup
-            .get(&self.rule_id)
-            .copied()
-            .expect("style rule id should resolve during prune")
-    }
-
     fn parent_rule(&self) -> Option<&'a StyleRule> {
         self.parent_rules
             .last()
@@ -199,7 +192,7 @@ fn collect_rule<'a>(rule: &'a svelte_css::Rule, rules: &mut FxHashMap<CssNodeId,
 }
 
 struct PruneVisitor<'a, 'b, 'p, 's> {
-    component: &'a SvelteComponent,
+    component: &'a SvelteComponent<'a>,
     css_source: &'a str,
     parsed: &'p JsAst<'p>,
     template: &'b TemplateAnalysis,
@@ -226,7 +219,7 @@ impl Visit for PruneVisitor<'_, '_, '_, '_> {
         self.rule_stack.push(node.id);
         let parent_rules = self.rule_stack[..parent_len].to_vec();
         let rule_lookup = self.rule_lookup.clone();
-        let rule_ctx = RuleContext::new(node.id, &parent_rules, &rule_lookup);
+        let rule_ctx = RuleContext::new(node, &parent_rules, &rule_lookup);
 
         for complex in &node.prelude.children {
             let plan = build_rule_selector_plan(complex, &rule_ctx);
@@ -236,18 +229,12 @@ impl Visit for PruneVisitor<'_, '_, '_, '_> {
                 continue;
             }
 
-            if plan.relatives.is_empty() {
+            let Some(last_relative) = plan.relatives.last() else {
                 self.used.insert(plan.complex_id);
                 continue;
-            }
+            };
 
-            for elem_id in candidate_elements(
-                self.elements,
-                plan.relatives
-                    .last()
-                    .expect("plan always has at least one relative selector"),
-                plan.needs_full_scan,
-            ) {
+            for elem_id in candidate_elements(self.elements, last_relative, plan.needs_full_scan) {
                 if apply_selector(
                     self,
                     &plan.relatives,
@@ -602,7 +589,7 @@ fn is_global(selector: &RelativeSelector, rule_ctx: &RuleContext<'_>) -> bool {
                 };
                 selector_list = Some(&parent_rule.prelude);
                 nested_rule_ctx = Some(RuleContext::new(
-                    parent_rule.id,
+                    parent_rule,
                     rule_ctx.parent_rules_without_last(),
                     rule_ctx.rule_lookup,
                 ));
@@ -908,11 +895,14 @@ fn relative_selector_matches(
 
     for selector in &relative_selector.selectors {
         match selector {
-            SimpleSelector::PseudoClass(pc) if pc.name == "has" && pc.args.is_some() => {
+            SimpleSelector::PseudoClass(svelte_css::PseudoClassSelector {
+                name,
+                args: Some(args),
+                ..
+            }) if name == "has" => {
                 let include_self =
                     *include_self.get_or_insert_with(|| has_global_or_root_selector(rule_ctx));
                 let mut matched = false;
-                let args = pc.args.as_ref().expect("guarded above");
 
                 for complex in &args.children {
                     let inner = build_truncated_relatives(complex);
@@ -1091,7 +1081,7 @@ fn relative_selector_matches(
                     return false;
                 };
                 let parent_rule_ctx = RuleContext::new(
-                    parent_rule.id,
+                    parent_rule,
                     rule_ctx.parent_rules_without_last(),
                     rule_ctx.rule_lookup,
                 );
@@ -1176,11 +1166,10 @@ fn has_global_or_root_selector(rule_ctx: &RuleContext<'_>) -> bool {
     if rule_ctx
         .parent_rules
         .iter()
-        .copied()
-        .chain(std::iter::once(rule_ctx.rule_id))
-        .any(|rule_id| {
-            let nested_ctx = RuleContext::new(rule_id, &[], rule_ctx.rule_lookup);
-            let rule = nested_ctx.rule();
+        .filter_map(|rule_id| rule_ctx.rule_lookup.get(rule_id).copied())
+        .chain(std::iter::once(rule_ctx.rule))
+        .any(|rule| {
+            let nested_ctx = RuleContext::new(rule, &[], rule_ctx.rule_lookup);
             rule.prelude.children.iter().any(|complex| {
                 let selectors = build_truncated_relatives(complex);
                 selectors
@@ -1192,8 +1181,7 @@ fn has_global_or_root_selector(rule_ctx: &RuleContext<'_>) -> bool {
         return true;
     }
 
-    let rule = rule_ctx.rule();
-    rule.prelude.children.iter().any(|complex| {
+    rule_ctx.rule.prelude.children.iter().any(|complex| {
         complex.children.iter().any(|relative| {
             relative.selectors.iter().any(|selector| match selector {
                 SimpleSelector::PseudoClass(pc) => pc.name == "root",
@@ -1884,14 +1872,14 @@ fn attribute_matches(
                 return expected_value.is_none();
             }
             Attribute::StringAttribute(attr) => {
-                if expected_value.is_none() {
+                let Some(expected_value) = expected_value else {
                     return true;
-                }
+                };
                 let matches = test_attribute(
                     operator,
-                    expected_value.expect("is_none branch returns above"),
+                    expected_value,
                     case_insensitive,
-                    attr.value_span.source_text(&pruner.component.source),
+                    attr.value_span.source_text(pruner.component.source),
                 );
                 if !matches && (name_lower == "class" || name_lower == "style") {
                     continue;
@@ -1899,18 +1887,13 @@ fn attribute_matches(
                 return matches;
             }
             Attribute::ExpressionAttribute(attr) => {
-                if expected_value.is_none() {
+                let Some(expected_value) = expected_value else {
                     return true;
-                }
+                };
                 let Some(chunks) = attribute_chunks_for_expression_attr(pruner, attr) else {
                     return true;
                 };
-                if match_attribute_chunks(
-                    operator,
-                    expected_value.expect("is_none branch returns above"),
-                    case_insensitive,
-                    &chunks,
-                ) {
+                if match_attribute_chunks(operator, expected_value, case_insensitive, &chunks) {
                     return true;
                 }
                 if name_lower == "class" || name_lower == "style" {
@@ -1919,16 +1902,11 @@ fn attribute_matches(
                 return false;
             }
             Attribute::ConcatenationAttribute(attr) => {
-                if expected_value.is_none() {
+                let Some(expected_value) = expected_value else {
                     return true;
-                }
-                let chunks = attribute_chunks_for_concat(pruner, &attr.parts);
-                if match_attribute_chunks(
-                    operator,
-                    expected_value.expect("is_none branch returns above"),
-                    case_insensitive,
-                    &chunks,
-                ) {
+                };
+                let chunks = attribute_chunks_for_concat(pruner, attr.parts);
+                if match_attribute_chunks(operator, expected_value, case_insensitive, &chunks) {
                     return true;
                 }
                 if name_lower == "class" || name_lower == "style" {
@@ -2195,10 +2173,13 @@ fn ends_with_whitespace(value: &str) -> bool {
     value.chars().next_back().is_some_and(char::is_whitespace)
 }
 
-fn element_attributes(component: &SvelteComponent, elem_id: NodeId) -> Option<&[Attribute]> {
+fn element_attributes<'a>(
+    component: &SvelteComponent<'a>,
+    elem_id: NodeId,
+) -> Option<&'a [Attribute<'a>]> {
     match component.store.get(elem_id) {
-        Node::Element(element) => Some(&element.attributes),
-        Node::SvelteElement(element) => Some(&element.attributes),
+        Node::Element(element) => Some(element.attributes),
+        Node::SvelteElement(element) => Some(element.attributes),
         _ => None,
     }
 }
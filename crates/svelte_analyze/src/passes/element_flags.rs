@@ -1,4 +1,6 @@
-use svelte_ast::{Attribute, ComponentNode, Element, SVELTE_SELF, is_mathml, is_svg, is_void};
+use svelte_ast::{
+    Atom, Attribute, ComponentNode, Element, SVELTE_SELF, is_mathml, is_svg, is_void,
+};
 use svelte_diagnostics::{Diagnostic, DiagnosticKind};
 use svelte_span::Span;
 
@@ -6,6 +8,7 @@ use crate::types::data::{
     BindTargetSemantics, ClassDirectiveInfo, ComponentBindMode, ComponentPropInfo,
     ComponentPropKind, EventHandlerMode, EventModifier, ParentKind, RichContentParentKind,
 };
+use crate::utils::fixes;
 use crate::walker::{TemplateVisitor, VisitContext};
 
 pub(crate) struct ElementFlagsVisitor<'src> {
@@ -21,7 +24,7 @@ impl<'src> ElementFlagsVisitor<'src> {
         &self.source[span.start as usize..span.end as usize]
     }
 
-    fn modifier_flags(modifiers: &[String]) -> EventModifier {
+    fn modifier_flags(modifiers: &[Atom<'_>]) -> EventModifier {
         modifiers
             .iter()
             .fold(EventModifier::empty(), |mut flags, modifier| {
@@ -50,12 +53,16 @@ impl<'src> ElementFlagsVisitor<'src> {
 impl<'src> TemplateVisitor for ElementFlagsVisitor<'src> {
     fn visit_element(&mut self, el: &Element, ctx: &mut VisitContext<'_, '_>) {
         if el.self_closing && !is_void(&el.name) && !is_svg(&el.name) && !is_mathml(&el.name) {
-            ctx.warnings_mut().push(Diagnostic::warning(
-                DiagnosticKind::ElementInvalidSelfClosingTag {
-                    name: el.name.clone(),
-                },
-                el.span,
-            ));
+            let fixes = fixes::expand_self_closing(ctx.source, el);
+            ctx.warnings_mut().push(
+                Diagnostic::warning(
+                    DiagnosticKind::ElementInvalidSelfClosingTag {
+                        name: el.name.to_string(),
+                    },
+                    el.span,
+                )
+                .with_fixes(fixes),
+            );
         }
 
         let has_value_attr = ctx.data.has_attribute(el.id, "value");
@@ -131,18 +138,26 @@ impl<'src> TemplateVisitor for ElementFlagsVisitor<'src> {
                     .get_or_default(el_id)
                     .push(ClassDirectiveInfo {
                         id: cd.id,
-                        name: cd.name.clone(),
+                        name: cd.name.to_string(),
                         has_expression: true,
                         expr_id: cd.expression.id(),
                     });
             }
             Attribute::StyleDirective(sd) => {
-                ctx.data
-                    .elements
-                    .flags
-                    .style_directives
-                    .get_or_default(el_id)
-                    .push(sd.clone());
+                let store = ctx.store;
+                if let Some(Attribute::StyleDirective(sd)) = store
+                    .get(el_id)
+                    .attributes()
+                    .iter()
+                    .find(|a| a.id() == sd.id)
+                {
+                    ctx.data
+                        .elements
+                        .flags
+                        .style_directives
+                        .get_or_default(el_id)
+                        .push(sd.clone());
+                }
             }
             Attribute::ExpressionAttribute(ea) => {
                 if ea.name == "class" {
@@ -193,13 +208,13 @@ impl<'src> TemplateVisitor for ElementFlagsVisitor<'src> {
                 ctx.data
                     .elements
                     .directive_modifiers
-                    .record(dir.id, Self::modifier_flags(&dir.modifiers));
+                    .record(dir.id, Self::modifier_flags(dir.modifiers));
             }
             Attribute::TransitionDirective(dir) => {
                 ctx.data
                     .elements
                     .directive_modifiers
-                    .record(dir.id, Self::modifier_flags(&dir.modifiers));
+                    .record(dir.id, Self::modifier_flags(dir.modifiers));
             }
             Attribute::UseDirective(_) => {
                 ctx.data.elements.flags.has_use_directive.insert(el_id);
@@ -213,11 +228,11 @@ impl<'src> TemplateVisitor for ElementFlagsVisitor<'src> {
         cn: &svelte_ast::SvelteComponentLegacy,
         ctx: &mut VisitContext<'_, '_>,
     ) {
-        self.process_component_like(cn.id, svelte_ast::SVELTE_COMPONENT, &cn.attributes, ctx);
+        self.process_component_like(cn.id, svelte_ast::SVELTE_COMPONENT, ctx);
     }
 
     fn visit_component_node(&mut self, cn: &ComponentNode, ctx: &mut VisitContext<'_, '_>) {
-        self.process_component_like(cn.id, cn.name.as_str(), &cn.attributes, ctx);
+        self.process_component_like(cn.id, cn.name.as_str(), ctx);
     }
 }
 
@@ -226,9 +241,9 @@ impl<'src> ElementFlagsVisitor<'src> {
         &self,
         cn_id: svelte_ast::NodeId,
         cn_name: &str,
-        attributes: &[Attribute],
         ctx: &mut VisitContext<'_, '_>,
     ) {
+        let attributes = ctx.store.get(cn_id).attributes();
         let data = &mut *ctx.data;
         let base_name = cn_name.split('.').next().unwrap_or(cn_name);
         if let Some(sym_id) = data.scoping.find_binding(ctx.scope, base_name) {
@@ -265,16 +280,16 @@ impl<'src> ElementFlagsVisitor<'src> {
             }
             let kind = match attr {
                 Attribute::StringAttribute(a) => ComponentPropKind::String {
-                    name: a.name.clone(),
+                    name: a.name.to_string(),
                     value_span: a.value_span,
                 },
                 Attribute::BooleanAttribute(a) => ComponentPropKind::Boolean {
-                    name: a.name.clone(),
+                    name: a.name.to_string(),
                 },
                 Attribute::ExpressionAttribute(a) => {
                     let needs_memo = data.component_attr_needs_memo(a.id);
                     ComponentPropKind::Expression {
-                        name: a.name.clone(),
+                        name: a.name.to_string(),
                         attr_id: a.id,
                         expr_id: a.expression.id(),
                         shorthand: a.shorthand,
@@ -282,9 +297,9 @@ impl<'src> ElementFlagsVisitor<'src> {
                     }
                 }
                 Attribute::ConcatenationAttribute(a) => ComponentPropKind::Concatenation {
-                    name: a.name.clone(),
+                    name: a.name.to_string(),
                     attr_id: a.id,
-                    parts: a.parts.clone(),
+                    parts: a.parts,
                 },
                 Attribute::SpreadAttribute(a) => ComponentPropKind::Spread {
                     attr_id: a.id,
@@ -330,7 +345,7 @@ impl<'src> ElementFlagsVisitor<'src> {
 
                         if is_store {
                             ComponentPropKind::Bind {
-                                name: b.name.clone(),
+                                name: b.name.to_string(),
                                 bind_id,
                                 expr_id: b.expression.id(),
                                 mode: ComponentBindMode::StoreSub,
@@ -342,8 +357,8 @@ impl<'src> ElementFlagsVisitor<'src> {
                                 Some(text) if crate::utils::is_simple_identifier(text.trim()) => {
                                     text.trim().to_string()
                                 }
-                                Some(_) => b.name.clone(),
-                                None => b.name.clone(),
+                                Some(_) => b.name.to_string(),
+                                None => b.name.to_string(),
                             };
                             let root = data.scoping.root_scope_id();
                             let mode = data
@@ -388,7 +403,7 @@ impl<'src> ElementFlagsVisitor<'src> {
                                 _ => None,
                             };
                             ComponentPropKind::Bind {
-                                name: b.name.clone(),
+                                name: b.name.to_string(),
                                 bind_id,
                                 expr_id: b.expression.id(),
                                 mode,
@@ -403,10 +418,10 @@ impl<'src> ElementFlagsVisitor<'src> {
                     expr_id: a.expression.id(),
                 },
                 Attribute::OnDirectiveLegacy(a) => {
-                    let flags = Self::modifier_flags(&a.modifiers);
+                    let flags = Self::modifier_flags(a.modifiers);
                     data.elements.directive_modifiers.record(a.id, flags);
                     ComponentPropKind::Event {
-                        name: a.name.clone(),
+                        name: a.name.to_string(),
                         attr_id: a.id,
                         expr_id: a.expression.as_ref().map(|r| r.id()),
                         has_expression: a.expression.is_some(),
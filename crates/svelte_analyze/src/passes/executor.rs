@@ -9,7 +9,7 @@ use super::{
 };
 
 fn run_template_bundle<'d, 'a, const N: usize>(
-    component: &'d Component,
+    component: &'d Component<'a>,
     data: &'d mut AnalysisData<'a>,
     source: &'d str,
     runes: bool,
@@ -33,7 +33,7 @@ fn run_template_bundle<'d, 'a, const N: usize>(
 }
 
 fn run_parsed_template_bundle<'d, 'a, const N: usize>(
-    component: &'d Component,
+    component: &'d Component<'a>,
     data: &'d mut AnalysisData<'a>,
     parsed: &'d JsAst<'a>,
     source: &'d str,
@@ -60,7 +60,7 @@ fn run_parsed_template_bundle<'d, 'a, const N: usize>(
 
 pub(crate) fn execute_pass<'a>(
     key: super::PassKey,
-    component: &Component,
+    component: &Component<'a>,
     parsed: &mut JsAst<'a>,
     data: &mut AnalysisData<'a>,
     options: &AnalyzeOptions,
@@ -163,6 +163,9 @@ pub(crate) fn execute_pass<'a>(
         }
         super::PassKey::PostResolve => {
             post_resolve::run_post_resolve_passes(data);
+            if options.inline_constants {
+                post_resolve::collect_inline_constants(parsed, data);
+            }
             if !data.output.needs_context {
                 data.output.needs_context = data
                     .expressions
@@ -228,7 +231,8 @@ pub(crate) fn execute_pass<'a>(
             bundle.finish(data);
         }
         super::PassKey::ValidateTemplate => {
-            let mut bundle = bundles::TemplateValidationBundle::new();
+            let mut bundle =
+                bundles::TemplateValidationBundle::new(options.lint.strict_html_nesting);
             let mut visitors = bundle.visitors();
             run_parsed_template_bundle(
                 component,
@@ -242,7 +246,7 @@ pub(crate) fn execute_pass<'a>(
             );
         }
         super::PassKey::Validate => {
-            validate::validate(component, data, parsed, runes, diags);
+            validate::validate(component, data, parsed, runes, options.lint, diags);
         }
     }
 }
@@ -37,7 +37,7 @@ fn visit_fragment(
     let mut debug_ids: Option<Vec<NodeId>> = None;
     let mut title_ids: Option<Vec<NodeId>> = None;
 
-    let nodes = fragment.nodes.clone();
+    let nodes = fragment.nodes.to_vec();
     for id in nodes {
         match store.get(id) {
             Node::DebugTag(tag) => {
@@ -50,7 +50,7 @@ fn visit_fragment(
                 visit_fragment(el.fragment, component, data);
             }
             Node::SlotElementLegacy(el) => {
-                record_custom_element_slot_name(data, &el.attributes, &component.source);
+                record_custom_element_slot_name(data, el.attributes, component.source);
                 visit_fragment(el.fragment, component, data);
             }
             Node::ComponentNode(_) | Node::SvelteComponentLegacy(_) => {
@@ -220,7 +220,7 @@ fn visit_slot(
             }
             Node::Element(el) => visit_fragment(el.fragment, component, data),
             Node::SlotElementLegacy(el) => {
-                record_custom_element_slot_name(data, &el.attributes, &component.source);
+                record_custom_element_slot_name(data, el.attributes, component.source);
                 visit_fragment(el.fragment, component, data);
             }
             Node::ComponentNode(_) | Node::SvelteComponentLegacy(_) => {
@@ -273,12 +273,9 @@ pub fn fragment_items(store: &AstStore, fragment_id: svelte_ast::FragmentId) ->
     let fragment = store.fragment(fragment_id);
     let inside_head = fragment.role == FragmentRole::SvelteHeadBody;
     let nodes: &[NodeId] = if fragment.role == FragmentRole::NamedSlot
-        && fragment.nodes.len() == 1
-        && matches!(store.get(fragment.nodes[0]), Node::SvelteFragmentLegacy(_))
+        && let [only] = fragment.nodes[..]
+        && let Node::SvelteFragmentLegacy(el) = store.get(only)
     {
-        let Node::SvelteFragmentLegacy(el) = store.get(fragment.nodes[0]) else {
-            unreachable!()
-        };
         &store.fragment(el.fragment).nodes
     } else {
         &fragment.nodes
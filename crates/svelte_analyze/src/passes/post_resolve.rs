@@ -1,4 +1,6 @@
-use crate::types::data::AnalysisData;
+use oxc_ast::ast::{BindingPattern, Declaration, Expression, Program, Statement};
+
+use crate::types::data::{AnalysisData, InlineConstant, JsAst};
 use crate::types::script::{DeclarationKind, RuneKind};
 
 pub fn run_post_resolve_passes(data: &mut AnalysisData) {
@@ -44,6 +46,67 @@ fn analyze_declarations(data: &mut AnalysisData) {
     mark_rest_prop_symbol(data);
 }
 
+pub(crate) fn collect_inline_constants(parsed: &JsAst<'_>, data: &mut AnalysisData) {
+    for program in [parsed.module_program.as_ref(), parsed.program.as_ref()]
+        .into_iter()
+        .flatten()
+    {
+        collect_program_inline_constants(program, data);
+    }
+}
+
+fn collect_program_inline_constants(program: &Program<'_>, data: &mut AnalysisData) {
+    for stmt in &program.body {
+        let decl = match stmt {
+            Statement::VariableDeclaration(decl) => decl,
+            Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                Some(Declaration::VariableDeclaration(decl)) => decl,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        if !decl.kind.is_const() || decl.declare {
+            continue;
+        }
+        for declarator in &decl.declarations {
+            let BindingPattern::BindingIdentifier(ident) = &declarator.id else {
+                continue;
+            };
+            let Some(constant) = declarator.init.as_ref().and_then(inline_constant) else {
+                continue;
+            };
+            if let Some(sym_id) = ident.symbol_id.get()
+                && !data.scoping.is_mutated(sym_id)
+            {
+                data.script.inline_constants.insert(sym_id, constant);
+            }
+        }
+    }
+}
+
+fn inline_constant(expr: &Expression<'_>) -> Option<InlineConstant> {
+    let value = match expr.without_parentheses() {
+        Expression::BooleanLiteral(b) => {
+            return Some(InlineConstant {
+                value: b.value.to_string(),
+                is_boolean: true,
+            });
+        }
+        Expression::StringLiteral(s) => s.value.to_string(),
+        Expression::NumericLiteral(n) if n.value.fract() == 0.0 && n.value.abs() < 1e15 => {
+            (n.value as i64).to_string()
+        }
+        Expression::TemplateLiteral(t) if t.expressions.is_empty() => {
+            t.quasis.first()?.value.cooked.as_ref()?.to_string()
+        }
+        _ => return None,
+    };
+    Some(InlineConstant {
+        value,
+        is_boolean: false,
+    })
+}
+
 fn mark_rest_prop_symbol(data: &mut AnalysisData) {
     let Some(decl) = data.script.props_declaration() else {
         return;
@@ -10,7 +10,7 @@ use crate::types::data::NamespaceKind;
 use crate::walker::{TemplateVisitor, VisitContext};
 
 pub(crate) struct TemplateSideTablesVisitor<'c> {
-    pub component: &'c svelte_ast::Component,
+    pub component: &'c svelte_ast::Component<'c>,
 }
 
 fn root_namespace(component: &svelte_ast::Component) -> NamespaceKind {
@@ -188,7 +188,7 @@ pub(crate) fn collect_fragment_facts(
     collect_fragment_facts_in(
         component.root,
         &component.store,
-        &component.source,
+        component.source,
         &mut data.template.fragment_facts,
     );
 }
@@ -200,7 +200,7 @@ pub(crate) fn collect_rich_content_facts(
     collect_rich_content_facts_in(
         component.root,
         &component.store,
-        &component.source,
+        component.source,
         &mut data.template.rich_content_facts,
     );
 }
@@ -505,11 +505,11 @@ impl TemplateVisitor for TemplateSideTablesVisitor<'_> {
             .is_some_and(|d| !matches!(&d.id, BindingPattern::BindingIdentifier(_)));
 
         if is_destructured {
-            let child_scope = ctx
-                .data
-                .scoping
-                .fragment_scope_by_id(block.body)
-                .expect("EachBody scope must exist");
+            let Some(child_scope) = ctx.data.scoping.fragment_scope_by_id(block.body) else {
+                ctx.data
+                    .internal_error(block.span, "each block body has no scope");
+                return;
+            };
             let _ctx_sym = ctx
                 .data
                 .scoping
@@ -525,11 +525,11 @@ impl TemplateVisitor for TemplateSideTablesVisitor<'_> {
     }
 
     fn leave_each_block(&mut self, block: &EachBlock, ctx: &mut VisitContext<'_, '_>) {
-        let child_scope = ctx
-            .data
-            .scoping
-            .fragment_scope_by_id(block.body)
-            .expect("EachBody scope must exist");
+        let Some(child_scope) = ctx.data.scoping.fragment_scope_by_id(block.body) else {
+            ctx.data
+                .internal_error(block.span, "each block body has no scope");
+            return;
+        };
 
         if let Some(idx_ref) = block.index.as_ref() {
             let idx_name = ctx
@@ -549,7 +549,7 @@ impl TemplateVisitor for TemplateSideTablesVisitor<'_> {
 
     fn leave_snippet_block(&mut self, block: &SnippetBlock, ctx: &mut VisitContext<'_, '_>) {
         ctx.data.template.snippets.local_snippets.push(block.id);
-        let name = block.name(&self.component.source);
+        let name = block.name(self.component.source);
         if let Some(name_sym) = ctx.data.scoping.find_binding(ctx.scope, name) {
             ctx.data
                 .template
@@ -574,24 +574,18 @@ impl TemplateVisitor for TemplateSideTablesVisitor<'_> {
         let parent_element = ctx.nearest_element();
         let inherited = inherited_namespace(self.component, ctx, parent_element);
         let facts = ElementFactsEntry::build(
-            &el.attributes,
+            el.attributes,
             ctx.source,
             namespace_for_element(&el.name, inherited),
             creation_namespace_for_element(&el.name, inherited),
             is_void(&el.name),
             el.name.contains('-'),
         );
-        ctx.data.elements.facts.record_entry(el.id, facts);
-        let facts = ctx
-            .data
-            .elements
-            .facts
-            .entry(el.id)
-            .expect("element facts recorded before template element index");
         ctx.data
             .template
             .template_elements
-            .record(el.id, &el.name, facts, parent_element);
+            .record(el.id, &el.name, &facts, parent_element);
+        ctx.data.elements.facts.record_entry(el.id, facts);
     }
 
     fn visit_component_node(&mut self, cn: &ComponentNode, ctx: &mut VisitContext<'_, '_>) {
@@ -602,7 +596,7 @@ impl TemplateVisitor for TemplateSideTablesVisitor<'_> {
         ctx.data.record_element_facts(
             cn.id,
             ElementFactsEntry::build(
-                &cn.attributes,
+                cn.attributes,
                 ctx.source,
                 inherited_namespace(self.component, ctx, ctx.nearest_element()),
                 inherited_namespace(self.component, ctx, ctx.nearest_element()).as_namespace(),
@@ -624,7 +618,7 @@ impl TemplateVisitor for TemplateSideTablesVisitor<'_> {
         ctx.data.record_element_facts(
             cn.id,
             ElementFactsEntry::build(
-                &cn.attributes,
+                cn.attributes,
                 ctx.source,
                 inherited_namespace(self.component, ctx, ctx.nearest_element()),
                 inherited_namespace(self.component, ctx, ctx.nearest_element()).as_namespace(),
@@ -640,29 +634,21 @@ impl TemplateVisitor for TemplateSideTablesVisitor<'_> {
             .template_topology
             .record_node_parent(el.id, ctx.parent());
         let parent_element = ctx.nearest_element();
-        let namespace = static_xmlns_namespace(&el.attributes, ctx.source)
+        let namespace = static_xmlns_namespace(el.attributes, ctx.source)
             .unwrap_or_else(|| inherited_namespace(self.component, ctx, parent_element));
-        ctx.data.elements.facts.record_entry(
-            el.id,
-            ElementFactsEntry::build(
-                &el.attributes,
-                ctx.source,
-                namespace,
-                namespace.as_namespace(),
-                false,
-                false,
-            ),
+        let facts = ElementFactsEntry::build(
+            el.attributes,
+            ctx.source,
+            namespace,
+            namespace.as_namespace(),
+            false,
+            false,
         );
-        let facts = ctx
-            .data
-            .elements
-            .facts
-            .entry(el.id)
-            .expect("svelte:element facts recorded before template element index");
         ctx.data
             .template
             .template_elements
-            .record(el.id, "*", facts, parent_element);
+            .record(el.id, "*", &facts, parent_element);
+        ctx.data.elements.facts.record_entry(el.id, facts);
     }
 
     fn visit_svelte_window(&mut self, el: &SvelteWindow, ctx: &mut VisitContext<'_, '_>) {
@@ -673,7 +659,7 @@ impl TemplateVisitor for TemplateSideTablesVisitor<'_> {
         ctx.data.record_element_facts(
             el.id,
             ElementFactsEntry::build(
-                &el.attributes,
+                el.attributes,
                 ctx.source,
                 NamespaceKind::Html,
                 Namespace::Html,
@@ -691,7 +677,7 @@ impl TemplateVisitor for TemplateSideTablesVisitor<'_> {
         ctx.data.record_element_facts(
             el.id,
             ElementFactsEntry::build(
-                &el.attributes,
+                el.attributes,
                 ctx.source,
                 NamespaceKind::Html,
                 Namespace::Html,
@@ -709,7 +695,7 @@ impl TemplateVisitor for TemplateSideTablesVisitor<'_> {
         ctx.data.record_element_facts(
             el.id,
             ElementFactsEntry::build(
-                &el.attributes,
+                el.attributes,
                 ctx.source,
                 NamespaceKind::Html,
                 Namespace::Html,
@@ -727,7 +713,7 @@ impl TemplateVisitor for TemplateSideTablesVisitor<'_> {
         ctx.data.record_element_facts(
             el.id,
             ElementFactsEntry::build(
-                &el.attributes,
+                el.attributes,
                 ctx.source,
                 NamespaceKind::Html,
                 Namespace::Html,
@@ -18,6 +18,7 @@ use svelte_diagnostics::{Diagnostic, DiagnosticKind};
 use svelte_span::Span;
 
 use crate::types::data::{BindHostKind, BindPropertyKind};
+use crate::utils::fixes;
 use crate::walker::{ParentKind, ParentRef, TemplateVisitor, VisitContext};
 use crate::{AnalysisData, EventModifier};
 
@@ -587,10 +588,10 @@ const A11Y_GLOBAL_ROLE_SUPPORTED_PROPS: &[&str] = &[
     "aria-roledescription",
 ];
 
-struct BindParentInfo {
+struct BindParentInfo<'a> {
     id: svelte_ast::NodeId,
-    name: String,
-    attrs: Vec<Attribute>,
+    name: &'a str,
+    attrs: &'a [Attribute<'a>],
 }
 
 enum BindExpressionShape {
@@ -652,10 +653,12 @@ pub(crate) struct TemplateValidationVisitor {
     first_legacy_slot_span: Option<Span>,
     saw_render_tag: bool,
     emitted_slot_snippet_conflict: bool,
+    strict_html_nesting: bool,
+    on_directive_fixes: Option<bool>,
 }
 
 impl TemplateValidationVisitor {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(strict_html_nesting: bool) -> Self {
         Self {
             current_expr_offset: 0,
             element_event_state: Vec::new(),
@@ -663,6 +666,8 @@ impl TemplateValidationVisitor {
             first_legacy_slot_span: None,
             saw_render_tag: false,
             emitted_slot_snippet_conflict: false,
+            strict_html_nesting,
+            on_directive_fixes: None,
         }
     }
 
@@ -870,7 +875,7 @@ impl TemplateVisitor for TemplateValidationVisitor {
                 .parent(el.id)
                 .is_some_and(|p| p.kind == ParentKind::SvelteHead)
         {
-            for attr in &el.attributes {
+            for attr in el.attributes {
                 ctx.warnings_mut().push(Diagnostic::error(
                     DiagnosticKind::TitleIllegalAttribute,
                     attr.span(),
@@ -910,8 +915,8 @@ impl TemplateVisitor for TemplateValidationVisitor {
             (
                 ctx.data.has_attribute(el.id, "slot"),
                 ctx.data.has_spread(el.id),
-                ctx.data.attribute(el.id, &el.attributes, "accesskey"),
-                ctx.data.attribute(el.id, &el.attributes, "tabindex"),
+                ctx.data.attribute(el.id, el.attributes, "accesskey"),
+                ctx.data.attribute(el.id, el.attributes, "tabindex"),
                 ctx.data.has_attribute(el.id, "autofocus"),
                 if !ctx.data.has_spread(el.id) {
                     check_a11y_missing_attribute(el, ctx.data, source)
@@ -919,7 +924,7 @@ impl TemplateVisitor for TemplateValidationVisitor {
                     None
                 },
                 ctx.data.has_attribute(el.id, "value"),
-                ctx.data.attribute(el.id, &el.attributes, "slot"),
+                ctx.data.attribute(el.id, el.attributes, "slot"),
             )
         };
 
@@ -956,7 +961,7 @@ impl TemplateVisitor for TemplateValidationVisitor {
 
         a11y::check_element_warnings(
             el,
-            &el.attributes,
+            el.attributes,
             accesskey_attr,
             tabindex_attr,
             has_autofocus,
@@ -966,15 +971,17 @@ impl TemplateVisitor for TemplateValidationVisitor {
         );
 
         check_component_name_lowercase(el, ctx);
-        check_plain_attr_warnings(el.id, el.span, &el.attributes, ctx);
-        check_attribute_unquoted_sequence(&el.attributes, ctx);
-        check_event_handler_value(&el.attributes, ctx);
+        check_plain_attr_warnings(el.id, el.span, el.attributes, ctx);
+        check_attribute_unquoted_sequence(el.attributes, ctx);
+        check_event_handler_value(el.attributes, ctx);
 
         if el.name.contains('-') {
-            check_attribute_quoted(&el.attributes, ctx);
+            check_attribute_quoted(el.attributes, ctx);
         }
 
-        check_node_invalid_placement(el, ctx);
+        if !check_node_invalid_placement(el, ctx) && self.strict_html_nesting {
+            check_node_invalid_placement_strict(el, ctx);
+        }
 
         let _ = has_spread;
     }
@@ -994,7 +1001,7 @@ impl TemplateVisitor for TemplateValidationVisitor {
             ));
         }
 
-        for attr in &el.attributes {
+        for attr in el.attributes {
             match attr {
                 Attribute::StringAttribute(attr) if attr.name == "name" => {
                     if attr.value_span.source_text(ctx.source) == "default" {
@@ -1053,11 +1060,11 @@ impl TemplateVisitor for TemplateValidationVisitor {
 
     fn visit_component_node(&mut self, cn: &ComponentNode, ctx: &mut VisitContext<'_, '_>) {
         self.maybe_warn_legacy_special_element(&cn.name, cn.span, ctx);
-        check_component_directives(&cn.attributes, ctx);
-        check_component_attribute_warnings(&cn.attributes, ctx);
-        check_attribute_unquoted_sequence(&cn.attributes, ctx);
-        check_attribute_quoted(&cn.attributes, ctx);
-        check_event_handler_value(&cn.attributes, ctx);
+        check_component_directives(cn.attributes, ctx);
+        check_component_attribute_warnings(cn.attributes, ctx);
+        check_attribute_unquoted_sequence(cn.attributes, ctx);
+        check_attribute_quoted(cn.attributes, ctx);
+        check_event_handler_value(cn.attributes, ctx);
     }
 
     fn visit_svelte_component_legacy(
@@ -1067,11 +1074,11 @@ impl TemplateVisitor for TemplateValidationVisitor {
     ) {
         self.maybe_warn_legacy_special_element(SVELTE_COMPONENT, cn.span, ctx);
         validate_svelte_component_legacy_this(cn, ctx);
-        check_component_directives(&cn.attributes, ctx);
-        check_component_attribute_warnings(&cn.attributes, ctx);
-        check_attribute_unquoted_sequence(&cn.attributes, ctx);
-        check_attribute_quoted(&cn.attributes, ctx);
-        check_event_handler_value(&cn.attributes, ctx);
+        check_component_directives(cn.attributes, ctx);
+        check_component_attribute_warnings(cn.attributes, ctx);
+        check_attribute_unquoted_sequence(cn.attributes, ctx);
+        check_attribute_quoted(cn.attributes, ctx);
+        check_event_handler_value(cn.attributes, ctx);
     }
 
     fn visit_svelte_fragment_legacy(
@@ -1090,7 +1097,7 @@ impl TemplateVisitor for TemplateValidationVisitor {
             ));
         }
 
-        for attr in &el.attributes {
+        for attr in el.attributes {
             match attr {
                 Attribute::StringAttribute(attr) if attr.name == "slot" => {
                     if !is_direct_child_of_component {
@@ -1155,13 +1162,13 @@ impl TemplateVisitor for TemplateValidationVisitor {
     fn visit_svelte_element(&mut self, el: &SvelteElement, ctx: &mut VisitContext<'_, '_>) {
         self.element_event_state.push(ElementEventState::default());
         validate_svelte_element_this(el, ctx);
-        check_plain_attr_warnings(el.id, el.span, &el.attributes, ctx);
-        check_attribute_unquoted_sequence(&el.attributes, ctx);
-        check_event_handler_value(&el.attributes, ctx);
+        check_plain_attr_warnings(el.id, el.span, el.attributes, ctx);
+        check_attribute_unquoted_sequence(el.attributes, ctx);
+        check_event_handler_value(el.attributes, ctx);
     }
 
     fn visit_svelte_head(&mut self, head: &SvelteHead, ctx: &mut VisitContext<'_, '_>) {
-        for attr in &head.attributes {
+        for attr in head.attributes {
             ctx.warnings_mut().push(Diagnostic::error(
                 DiagnosticKind::SvelteHeadIllegalAttribute,
                 attr.span(),
@@ -1172,7 +1179,7 @@ impl TemplateVisitor for TemplateValidationVisitor {
     fn visit_svelte_window(&mut self, window: &SvelteWindow, ctx: &mut VisitContext<'_, '_>) {
         self.visit_special_element(
             SpecialElementKind::Window,
-            &window.attributes,
+            window.attributes,
             window.fragment,
             ctx,
         );
@@ -1181,7 +1188,7 @@ impl TemplateVisitor for TemplateValidationVisitor {
     fn visit_svelte_document(&mut self, document: &SvelteDocument, ctx: &mut VisitContext<'_, '_>) {
         self.visit_special_element(
             SpecialElementKind::Document,
-            &document.attributes,
+            document.attributes,
             document.fragment,
             ctx,
         );
@@ -1190,7 +1197,7 @@ impl TemplateVisitor for TemplateValidationVisitor {
     fn visit_svelte_body(&mut self, body: &SvelteBody, ctx: &mut VisitContext<'_, '_>) {
         self.visit_special_element(
             SpecialElementKind::Body,
-            &body.attributes,
+            body.attributes,
             body.fragment,
             ctx,
         );
@@ -1199,7 +1206,7 @@ impl TemplateVisitor for TemplateValidationVisitor {
     fn visit_svelte_boundary(&mut self, boundary: &SvelteBoundary, ctx: &mut VisitContext<'_, '_>) {
         const VALID: &[&str] = &["onerror", "failed", "pending"];
 
-        for attr in &boundary.attributes {
+        for attr in boundary.attributes {
             let invalid_attr = match attr {
                 Attribute::StringAttribute(a) => !VALID.contains(&a.name.as_str()),
                 Attribute::ExpressionAttribute(a) => !VALID.contains(&a.name.as_str()),
@@ -1255,7 +1262,7 @@ impl TemplateVisitor for TemplateValidationVisitor {
         {
             ctx.warnings_mut().push(Diagnostic::warning(
                 DiagnosticKind::AttributeGlobalEventReference {
-                    name: attr.name.clone(),
+                    name: attr.name.to_string(),
                 },
                 attr.expression.span,
             ));
@@ -1426,7 +1433,7 @@ impl TemplateVisitor for TemplateValidationVisitor {
 
         if !is_component {
             let list = EVENT_MODIFIERS.join(", ");
-            for modifier in &dir.modifiers {
+            for modifier in dir.modifiers {
                 if !EVENT_MODIFIERS.contains(&modifier.as_str()) {
                     ctx.warnings_mut().push(Diagnostic::error(
                         DiagnosticKind::EventHandlerInvalidModifier { list: list.clone() },
@@ -1450,18 +1457,29 @@ impl TemplateVisitor for TemplateValidationVisitor {
         }
 
         if ctx.runes && is_regular_or_svelte_element {
-            ctx.warnings_mut().push(Diagnostic::warning(
-                DiagnosticKind::EventDirectiveDeprecated {
-                    name: dir.name.clone(),
-                },
-                dir.name_span,
-            ));
+            let convertible = *self
+                .on_directive_fixes
+                .get_or_insert_with(|| fixes::all_on_directives_convertible(ctx.source, ctx.store));
+            let fixes = if convertible {
+                fixes::on_directive_to_attribute(ctx.source, dir)
+            } else {
+                Vec::new()
+            };
+            ctx.warnings_mut().push(
+                Diagnostic::warning(
+                    DiagnosticKind::EventDirectiveDeprecated {
+                        name: dir.name.to_string(),
+                    },
+                    dir.name_span,
+                )
+                .with_fixes(fixes),
+            );
         }
 
         if !is_component && let Some(state) = self.element_event_state.last_mut() {
             state
                 .first_on_directive
-                .get_or_insert((dir.span, dir.name.clone()));
+                .get_or_insert((dir.span, dir.name.to_string()));
         }
     }
 
@@ -1611,31 +1629,24 @@ impl TemplateVisitor for TemplateValidationVisitor {
             (parent, ancestors.next())
         };
 
-        let diag_kind = match grandparent.map(|p| p.kind) {
-            Some(ParentKind::EachBlock) => {
-                let each_id = grandparent
-                    .expect("EachBlock parent implies grandparent is the each block")
-                    .id;
-                let each_block = ctx
-                    .store
-                    .get(each_id)
-                    .as_each_block()
-                    .expect("grandparent id resolved from ParentKind::EachBlock");
-                if each_block.key.is_none() {
-                    Some(DiagnosticKind::AnimationMissingKey)
+        let each_block = grandparent
+            .filter(|p| matches!(p.kind, ParentKind::EachBlock))
+            .and_then(|p| ctx.store.get(p.id).as_each_block());
+        let diag_kind = match each_block {
+            Some(each_block) if each_block.key.is_none() => {
+                Some(DiagnosticKind::AnimationMissingKey)
+            }
+            Some(each_block) => {
+                let only_child = ctx
+                    .data
+                    .fragment_single_non_trivial_child_by_id(each_block.body);
+                if only_child != parent.map(|p| p.id) {
+                    Some(DiagnosticKind::AnimationInvalidPlacement)
                 } else {
-                    let _ = each_id;
-                    let only_child = ctx
-                        .data
-                        .fragment_single_non_trivial_child_by_id(each_block.body);
-                    if only_child != parent.map(|p| p.id) {
-                        Some(DiagnosticKind::AnimationInvalidPlacement)
-                    } else {
-                        None
-                    }
+                    None
                 }
             }
-            _ => Some(DiagnosticKind::AnimationInvalidPlacement),
+            None => Some(DiagnosticKind::AnimationInvalidPlacement),
         };
 
         if let Some(kind) = diag_kind {
@@ -1701,38 +1712,41 @@ impl TemplateVisitor for TemplateValidationVisitor {
     }
 }
 
-fn current_bind_parent(bind_id: NodeId, ctx: &VisitContext<'_, '_>) -> Option<BindParentInfo> {
+fn current_bind_parent<'a>(
+    bind_id: NodeId,
+    ctx: &VisitContext<'_, 'a>,
+) -> Option<BindParentInfo<'a>> {
     let parent = ctx.data.parent(bind_id)?;
     match ctx.store.get(parent.id) {
         Node::Element(el) => Some(BindParentInfo {
             id: el.id,
-            name: el.name.clone(),
-            attrs: el.attributes.clone(),
+            name: el.name.as_str(),
+            attrs: el.attributes,
         }),
         Node::ComponentNode(node) => Some(BindParentInfo {
             id: node.id,
-            name: node.name.clone(),
-            attrs: node.attributes.clone(),
+            name: node.name.as_str(),
+            attrs: node.attributes,
         }),
         Node::SvelteElement(el) => Some(BindParentInfo {
             id: el.id,
-            name: SVELTE_ELEMENT.to_string(),
-            attrs: el.attributes.clone(),
+            name: SVELTE_ELEMENT,
+            attrs: el.attributes,
         }),
         Node::SvelteWindow(el) => Some(BindParentInfo {
             id: el.id,
-            name: SVELTE_WINDOW.to_string(),
-            attrs: el.attributes.clone(),
+            name: SVELTE_WINDOW,
+            attrs: el.attributes,
         }),
         Node::SvelteDocument(el) => Some(BindParentInfo {
             id: el.id,
-            name: SVELTE_DOCUMENT.to_string(),
-            attrs: el.attributes.clone(),
+            name: SVELTE_DOCUMENT,
+            attrs: el.attributes,
         }),
         Node::SvelteBody(el) => Some(BindParentInfo {
             id: el.id,
-            name: SVELTE_BODY.to_string(),
-            attrs: el.attributes.clone(),
+            name: SVELTE_BODY,
+            attrs: el.attributes,
         }),
         _ => None,
     }
@@ -1772,7 +1786,7 @@ fn emit_bind_error(ctx: &mut VisitContext<'_, '_>, span: Span, kind: DiagnosticK
 
 fn validate_bind_name_and_target(
     dir: &BindDirective,
-    parent: &BindParentInfo,
+    parent: &BindParentInfo<'_>,
     ctx: &mut VisitContext<'_, '_>,
 ) {
     let Some(bind_semantics) = ctx.data.bind_target_semantics(dir.id).copied() else {
@@ -1780,7 +1794,7 @@ fn validate_bind_name_and_target(
             fuzzymatch(dir.name.as_str(), BindPropertyKind::KNOWN_NAMES).and_then(|suggestion| {
                 BindPropertyKind::from_host_and_name(BindHostKind::Element, suggestion)
                     .map(|property| property.validation_spec())
-                    .is_some_and(|spec| spec.allows(&parent.name))
+                    .is_some_and(|spec| spec.allows(parent.name))
                     .then(|| format!("Did you mean '{suggestion}'?"))
             });
 
@@ -1788,7 +1802,7 @@ fn validate_bind_name_and_target(
             ctx,
             dir.expression.span,
             DiagnosticKind::BindInvalidName {
-                name: dir.name.clone(),
+                name: dir.name.to_string(),
                 explanation,
             },
         );
@@ -1801,7 +1815,7 @@ fn validate_bind_name_and_target(
 
     let validation = bind_semantics.validation_spec();
 
-    if !validation.valid_elements().is_empty() && !validation.allows(&parent.name) {
+    if !validation.valid_elements().is_empty() && !validation.allows(parent.name) {
         let elements = validation
             .valid_elements()
             .iter()
@@ -1812,24 +1826,21 @@ fn validate_bind_name_and_target(
             ctx,
             dir.expression.span,
             DiagnosticKind::BindInvalidTarget {
-                name: dir.name.clone(),
+                name: dir.name.to_string(),
                 elements,
             },
         );
         return;
     }
 
-    if validation
-        .invalid_elements()
-        .contains(&parent.name.as_str())
-    {
+    if validation.invalid_elements().contains(&parent.name) {
         let mut valid_bindings = BindPropertyKind::KNOWN_NAMES
             .iter()
             .copied()
             .filter(|candidate| {
                 BindPropertyKind::from_host_and_name(BindHostKind::Element, candidate)
                     .map(|property| property.validation_spec())
-                    .is_some_and(|spec| spec.allows(&parent.name))
+                    .is_some_and(|spec| spec.allows(parent.name))
             })
             .collect::<Vec<_>>();
         valid_bindings.sort_unstable();
@@ -1838,7 +1849,7 @@ fn validate_bind_name_and_target(
             ctx,
             dir.expression.span,
             DiagnosticKind::BindInvalidName {
-                name: dir.name.clone(),
+                name: dir.name.to_string(),
                 explanation: Some(format!(
                     "Possible bindings for <{}> are {}",
                     parent.name,
@@ -1851,7 +1862,7 @@ fn validate_bind_name_and_target(
 
 fn validate_bind_parent_specifics(
     dir: &BindDirective,
-    parent: &BindParentInfo,
+    parent: &BindParentInfo<'_>,
     ctx: &mut VisitContext<'_, '_>,
 ) {
     let bind_semantics = ctx.data.bind_target_semantics(dir.id).copied();
@@ -1862,7 +1873,7 @@ fn validate_bind_parent_specifics(
     }
 
     if parent.name == "select" && bind_semantics.is_none_or(|semantics| !semantics.is_this()) {
-        let multiple = ctx.data.attribute(parent.id, &parent.attrs, "multiple");
+        let multiple = ctx.data.attribute(parent.id, parent.attrs, "multiple");
         if let Some(a) = multiple
             && !attr_is_text(a)
             && !matches!(a, Attribute::BooleanAttribute(_))
@@ -1880,13 +1891,13 @@ fn validate_bind_parent_specifics(
         Some(BindPropertyKind::ElementSize(
             crate::types::data::ElementSizeKind::OffsetWidth
         ))
-    ) && is_svg(&parent.name)
+    ) && is_svg(parent.name)
     {
         emit_bind_error(
             ctx,
             dir.expression.span,
             DiagnosticKind::BindInvalidTarget {
-                name: dir.name.clone(),
+                name: dir.name.to_string(),
                 elements: "non-`<svg>` elements. Use `bind:clientWidth` for `<svg>` instead"
                     .to_string(),
             },
@@ -1897,7 +1908,7 @@ fn validate_bind_parent_specifics(
     if bind_semantics.is_some_and(|semantics| semantics.is_contenteditable()) {
         let contenteditable = ctx
             .data
-            .attribute(parent.id, &parent.attrs, "contenteditable");
+            .attribute(parent.id, parent.attrs, "contenteditable");
         match contenteditable {
             None => emit_bind_error(
                 ctx,
@@ -1919,14 +1930,14 @@ fn validate_bind_parent_specifics(
 
 fn validate_input_bindings(
     dir: &BindDirective,
-    parent: &BindParentInfo,
+    parent: &BindParentInfo<'_>,
     ctx: &mut VisitContext<'_, '_>,
 ) {
     let bind_property = ctx
         .data
         .bind_target_semantics(dir.id)
         .map(|semantics| semantics.property());
-    let Some(type_attr) = ctx.data.attribute(parent.id, &parent.attrs, "type") else {
+    let Some(type_attr) = ctx.data.attribute(parent.id, parent.attrs, "type") else {
         return;
     };
 
@@ -1944,7 +1955,7 @@ fn validate_input_bindings(
 
     let type_value = ctx
         .data
-        .static_text_attribute_value(parent.id, &parent.attrs, "type", ctx.source)
+        .static_text_attribute_value(parent.id, parent.attrs, "type", ctx.source)
         .unwrap_or_default();
     if bind_property == Some(BindPropertyKind::Checked) && type_value != "checkbox" {
         let elements = if type_value == "radio" {
@@ -1957,7 +1968,7 @@ fn validate_input_bindings(
             ctx,
             dir.expression.span,
             DiagnosticKind::BindInvalidTarget {
-                name: dir.name.clone(),
+                name: dir.name.to_string(),
                 elements,
             },
         );
@@ -1966,7 +1977,7 @@ fn validate_input_bindings(
             ctx,
             dir.expression.span,
             DiagnosticKind::BindInvalidTarget {
-                name: dir.name.clone(),
+                name: dir.name.to_string(),
                 elements: "`<input type=\"file\">`".to_string(),
             },
         );
@@ -1997,7 +2008,7 @@ fn validate_bind_sequence_expression(
             ctx,
             dir.expression.span,
             DiagnosticKind::BindInvalidParens {
-                name: dir.name.clone(),
+                name: dir.name.to_string(),
             },
         );
         return;
@@ -2355,7 +2366,7 @@ fn should_emit_snippet_conflict(
     }
 
     if !has_implicit {
-        for slot in &component.legacy_slots {
+        for slot in component.legacy_slots {
             for wrapper_id in ctx.store.fragment_nodes(slot.fragment) {
                 let wrapper_id = *wrapper_id;
                 if wrapper_id == snippet.id {
@@ -2434,7 +2445,7 @@ fn validate_component_slot_conflicts(
         ctx.warnings_mut().push(Diagnostic::error(
             DiagnosticKind::SlotAttributeDuplicate {
                 name: slot_name.to_string(),
-                component: component.name.clone(),
+                component: component.name.to_string(),
             },
             slot_attr.value_span,
         ));
@@ -2495,13 +2506,14 @@ fn is_bidi_control(ch: char) -> bool {
     )
 }
 
-fn check_node_invalid_placement(el: &Element, ctx: &mut VisitContext<'_, '_>) {
+fn check_node_invalid_placement(el: &Element, ctx: &mut VisitContext<'_, '_>) -> bool {
     let Some(parent_element) = ctx.element_name().map(str::to_owned) else {
-        return;
+        return false;
     };
 
     let mut past_parent = false;
     let mut only_warn = false;
+    let mut emitted = false;
     let mut ancestors: Vec<String> = vec![parent_element.clone()];
 
     let path: Vec<_> = ctx.ancestors().copied().collect();
@@ -2520,7 +2532,7 @@ fn check_node_invalid_placement(el: &Element, ctx: &mut VisitContext<'_, '_>) {
         if !past_parent {
             if ancestor.kind == ParentKind::Element {
                 let name = match ctx.store.get(ancestor.id).as_element() {
-                    Some(e) => e.name.clone(),
+                    Some(e) => e.name,
                     None => continue,
                 };
                 if name == parent_element {
@@ -2531,21 +2543,23 @@ fn check_node_invalid_placement(el: &Element, ctx: &mut VisitContext<'_, '_>) {
                         )
                     {
                         emit_invalid_placement(el, message, only_warn, ctx);
+                        emitted = true;
                     }
                     past_parent = true;
                 }
             }
         } else if ancestor.kind == ParentKind::Element {
             let name = match ctx.store.get(ancestor.id).as_element() {
-                Some(e) => e.name.clone(),
+                Some(e) => e.name,
                 None => continue,
             };
-            ancestors.push(name);
+            ancestors.push(name.to_string());
             let refs: Vec<&str> = ancestors.iter().map(String::as_str).collect();
             if let Some(message) =
                 crate::utils::html_tree_validation::is_tag_valid_with_ancestor(&el.name, &refs)
             {
                 emit_invalid_placement(el, message, only_warn, ctx);
+                emitted = true;
             }
         } else if matches!(
             ancestor.kind,
@@ -2554,6 +2568,53 @@ fn check_node_invalid_placement(el: &Element, ctx: &mut VisitContext<'_, '_>) {
             break;
         }
     }
+    emitted
+}
+
+fn check_node_invalid_placement_strict(el: &Element, ctx: &mut VisitContext<'_, '_>) {
+    let mut ancestors: Vec<&str> = Vec::new();
+    for ancestor in ctx.ancestors() {
+        match ancestor.kind {
+            ParentKind::Element => {
+                if let Some(element) = ctx.store.get(ancestor.id).as_element() {
+                    ancestors.push(element.name.as_str());
+                }
+            }
+            ParentKind::ComponentNode | ParentKind::SvelteElement | ParentKind::SnippetBlock => {
+                break;
+            }
+            _ => {}
+        }
+    }
+    let interactive = is_interactive_element(el, ctx);
+    if let Some(message) = crate::utils::html_tree_validation::is_tag_valid_with_ancestors_strict(
+        &el.name,
+        interactive,
+        &ancestors,
+    ) {
+        ctx.warnings_mut().push(Diagnostic::warning(
+            DiagnosticKind::NodeInvalidPlacementSsr { message },
+            el.span,
+        ));
+    }
+}
+
+fn is_interactive_element(el: &Element, ctx: &VisitContext<'_, '_>) -> bool {
+    match el.name.as_str() {
+        "button" | "details" | "embed" | "iframe" | "label" | "select" | "textarea" => true,
+        "a" => ctx.data.has_attribute(el.id, "href"),
+        "audio" | "video" => ctx.data.has_attribute(el.id, "controls"),
+        "input" => ctx
+            .data
+            .string_attribute(el.id, el.attributes, "type")
+            .is_none_or(|attr| {
+                !attr
+                    .value_span
+                    .source_text(ctx.source)
+                    .eq_ignore_ascii_case("hidden")
+            }),
+        _ => false,
+    }
 }
 
 fn emit_invalid_placement(
@@ -2817,7 +2878,7 @@ fn component_has_implicit_default_children(
         return Some(child.span());
     }
 
-    for slot in &component.legacy_slots {
+    for slot in component.legacy_slots {
         let slot_nodes = ctx.store.fragment_nodes(slot.fragment).to_vec();
         for wrapper_id in slot_nodes {
             if Some(wrapper_id) == excluded_child_id {
@@ -3164,13 +3225,66 @@ fn check_component_name_lowercase(el: &Element, ctx: &mut VisitContext<'_, '_>)
             .get_resolved_reference_ids(sym_id)
             .is_empty()
     {
-        ctx.warnings_mut().push(Diagnostic::warning(
-            DiagnosticKind::ComponentNameLowercase {
-                name: el.name.clone(),
-            },
-            el.span,
-        ));
+        let fixes = component_name_fixes(el, sym_id, ctx).unwrap_or_default();
+        ctx.warnings_mut().push(
+            Diagnostic::warning(
+                DiagnosticKind::ComponentNameLowercase {
+                    name: el.name.to_string(),
+                },
+                el.span,
+            )
+            .with_fixes(fixes),
+        );
+    }
+}
+
+fn component_name_fixes(
+    el: &Element,
+    sym_id: SymbolId,
+    ctx: &VisitContext<'_, '_>,
+) -> Option<Vec<svelte_diagnostics::TextEdit>> {
+    let mut chars = el.name.chars();
+    let first = chars.next()?;
+    let name = format!("{}{}", first.to_ascii_uppercase(), chars.as_str());
+    if ctx.data.scoping.find_binding(ctx.scope, &name).is_some() {
+        return None;
     }
+    let parsed = ctx.parsed()?;
+    let (program, offset) =
+        if ctx.data.scoping.module_scope_id() == Some(ctx.data.scoping.symbol_scope_id(sym_id)) {
+            (
+                parsed.module_program.as_ref()?,
+                parsed.module_script_content_span?.start,
+            )
+        } else {
+            (parsed.program.as_ref()?, parsed.script_content_span?.start)
+        };
+    let (binding, shorthand) = program.body.iter().find_map(|stmt| {
+        let Statement::ImportDeclaration(decl) = stmt else {
+            return None;
+        };
+        decl.specifiers.as_ref()?.iter().find_map(|spec| {
+            let local = spec.local();
+            if local.symbol_id.get() != Some(sym_id) {
+                return None;
+            }
+            let shorthand = matches!(
+                spec,
+                oxc_ast::ast::ImportDeclarationSpecifier::ImportSpecifier(s)
+                    if s.imported.span() == s.local.span
+            );
+            Some((local.span, shorthand))
+        })
+    })?;
+    let binding = Span::new(binding.start + offset, binding.end + offset);
+    let replacement = if shorthand {
+        format!("{} as {name}", el.name)
+    } else {
+        name.clone()
+    };
+    let mut edits = fixes::rename_element_tag(ctx.source, el, &name)?;
+    edits.push(svelte_diagnostics::TextEdit::new(binding, replacement));
+    Some(edits)
 }
 
 fn check_attribute_quoted(attrs: &[Attribute], ctx: &mut VisitContext<'_, '_>) {
@@ -3182,10 +3296,11 @@ fn check_attribute_quoted(attrs: &[Attribute], ctx: &mut VisitContext<'_, '_>) {
             && ca.parts.len() == 1
             && matches!(ca.parts[0], ConcatPart::Dynamic { .. })
         {
-            ctx.warnings_mut().push(Diagnostic::warning(
-                DiagnosticKind::AttributeQuoted,
-                attr_value_span(attr),
-            ));
+            let fixes = fixes::unquote_attribute(ctx.source, ca);
+            ctx.warnings_mut().push(
+                Diagnostic::warning(DiagnosticKind::AttributeQuoted, attr_value_span(attr))
+                    .with_fixes(fixes),
+            );
         }
     }
 }
@@ -3220,7 +3335,7 @@ fn check_a11y_missing_attribute(
             if data.has_attribute(el.id, "id") || data.has_attribute(el.id, "name") {
                 return None;
             }
-            if data.has_true_boolean_attribute(el.id, &el.attributes, "aria-disabled", source) {
+            if data.has_true_boolean_attribute(el.id, el.attributes, "aria-disabled", source) {
                 return None;
             }
             Some(warn_missing_attr(el, &["href"]))
@@ -3253,7 +3368,7 @@ fn warn_missing_attr(el: &Element, attrs: &[&str]) -> Diagnostic {
 
     Diagnostic::warning(
         DiagnosticKind::A11yMissingAttribute {
-            name: el.name.clone(),
+            name: el.name.to_string(),
             article,
             sequence,
         },
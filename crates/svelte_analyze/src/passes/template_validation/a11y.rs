@@ -119,7 +119,7 @@ pub(super) fn check_element_warnings(
     if matches!(el.name.as_str(), "marquee" | "blink") {
         ctx.warnings_mut().push(Diagnostic::warning(
             DiagnosticKind::A11yDistractingElements {
-                name: el.name.clone(),
+                name: el.name.to_string(),
             },
             el.span,
         ));
@@ -144,8 +144,13 @@ pub(super) fn check_element_warnings(
     }
 
     if has_autofocus && el.name != "dialog" && dialog_depth == 0 {
+        let fixes = ctx
+            .data
+            .attribute(el.id, attrs, "autofocus")
+            .map(|attr| fixes::remove_attribute(ctx.source, attr.span()))
+            .unwrap_or_default();
         ctx.warnings_mut()
-            .push(Diagnostic::warning(DiagnosticKind::A11yAutofocus, el.span));
+            .push(Diagnostic::warning(DiagnosticKind::A11yAutofocus, el.span).with_fixes(fixes));
     }
 
     if let Some(diag) = missing_attr_diag {
@@ -169,13 +174,13 @@ fn static_attr_value<'a>(attr: &Attribute, source: &'a str) -> Option<StaticAttr
     }
 }
 
-fn attr_named_name(attr: &Attribute) -> Option<&str> {
+fn attr_named_name<'a>(attr: &Attribute<'a>) -> Option<&'a str> {
     match attr {
-        Attribute::StringAttribute(attr) => Some(&attr.name),
-        Attribute::ExpressionAttribute(attr) => Some(&attr.name),
-        Attribute::BooleanAttribute(attr) => Some(&attr.name),
-        Attribute::ConcatenationAttribute(attr) => Some(&attr.name),
-        Attribute::BindDirective(attr) => Some(&attr.name),
+        Attribute::StringAttribute(attr) => Some(attr.name.as_str()),
+        Attribute::ExpressionAttribute(attr) => Some(attr.name.as_str()),
+        Attribute::BooleanAttribute(attr) => Some(attr.name.as_str()),
+        Attribute::ConcatenationAttribute(attr) => Some(attr.name.as_str()),
+        Attribute::BindDirective(attr) => Some(attr.name.as_str()),
         Attribute::SpreadAttribute(_)
         | Attribute::ClassDirective(_)
         | Attribute::LetDirectiveLegacy(_)
@@ -353,7 +358,7 @@ fn check_a11y_aria_attribute_warnings(
         if super::A11Y_INVISIBLE_ELEMENTS.contains(&el.name.as_str()) {
             ctx.warnings_mut().push(Diagnostic::warning(
                 DiagnosticKind::A11yAriaAttributes {
-                    name: el.name.clone(),
+                    name: el.name.to_string(),
                 },
                 attr_value_span(attr),
             ));
@@ -375,7 +380,7 @@ fn check_a11y_aria_attribute_warnings(
         if name == "aria-hidden" && is_heading_tag(&el.name) {
             ctx.warnings_mut().push(Diagnostic::warning(
                 DiagnosticKind::A11yHidden {
-                    name: el.name.clone(),
+                    name: el.name.to_string(),
                 },
                 attr_value_span(attr),
             ));
@@ -405,7 +410,7 @@ fn check_a11y_role_warnings(el: &Element, attrs: &[Attribute], ctx: &mut VisitCo
         if super::A11Y_INVISIBLE_ELEMENTS.contains(&el.name.as_str()) {
             ctx.warnings_mut().push(Diagnostic::warning(
                 DiagnosticKind::A11yMisplacedRole {
-                    name: el.name.clone(),
+                    name: el.name.to_string(),
                 },
                 attr_value_span(attr),
             ));
@@ -443,7 +448,7 @@ fn check_a11y_role_warnings(el: &Element, attrs: &[Attribute], ctx: &mut VisitCo
             {
                 ctx.warnings_mut().push(Diagnostic::warning(
                     DiagnosticKind::A11yNoInteractiveElementToNoninteractiveRole {
-                        element: el.name.clone(),
+                        element: el.name.to_string(),
                         role: role.to_string(),
                     },
                     el.span,
@@ -457,7 +462,7 @@ fn check_a11y_role_warnings(el: &Element, attrs: &[Attribute], ctx: &mut VisitCo
             {
                 ctx.warnings_mut().push(Diagnostic::warning(
                     DiagnosticKind::A11yNoNoninteractiveElementToInteractiveRole {
-                        element: el.name.clone(),
+                        element: el.name.to_string(),
                         role: role.to_string(),
                     },
                     el.span,
@@ -546,7 +551,7 @@ fn check_a11y_role_supported_aria_props_warnings(
             DiagnosticKind::A11yRoleSupportsAriaPropsImplicit {
                 attribute: name.clone(),
                 role: role_value.to_string(),
-                name: el.name.clone(),
+                name: el.name.to_string(),
             }
         } else {
             DiagnosticKind::A11yRoleSupportsAriaProps {
@@ -639,7 +644,7 @@ fn check_a11y_role_attribute_interaction_warnings(
     {
         ctx.warnings_mut().push(Diagnostic::warning(
             DiagnosticKind::A11yNoNoninteractiveElementInteractions {
-                element: el.name.clone(),
+                element: el.name.to_string(),
             },
             el.span,
         ));
@@ -663,7 +668,7 @@ fn check_a11y_role_attribute_interaction_warnings(
             ctx.push_warning_if_not_ignored(
                 el.id,
                 DiagnosticKind::A11yNoStaticElementInteractions {
-                    element: el.name.clone(),
+                    element: el.name.to_string(),
                     handler: format_handler_list(&interactive_handlers),
                 },
                 el.span,
@@ -912,7 +917,7 @@ fn check_a11y_element_specific_content_warnings(
         ctx.push_warning_if_not_ignored(
             el.id,
             DiagnosticKind::A11yMissingContent {
-                name: el.name.clone(),
+                name: el.name.to_string(),
             },
             el.span,
         );
@@ -1030,8 +1035,8 @@ fn collect_element_handlers(attrs: &[Attribute]) -> Vec<String> {
     attrs
         .iter()
         .filter_map(|attr| match attr {
-            Attribute::ExpressionAttribute(attr) => attr.event_name.clone(),
-            Attribute::OnDirectiveLegacy(attr) => Some(attr.name.clone()),
+            Attribute::ExpressionAttribute(attr) => attr.event_name.map(|name| name.to_string()),
+            Attribute::OnDirectiveLegacy(attr) => Some(attr.name.to_string()),
             _ => None,
         })
         .collect()
@@ -1046,7 +1051,7 @@ fn has_content(fragment_id: svelte_ast::FragmentId, ctx: &VisitContext<'_, '_>)
             Node::Element(child_el) => {
                 let popover_is_static = ctx
                     .data
-                    .attribute(child_el.id, &child_el.attributes, "popover")
+                    .attribute(child_el.id, child_el.attributes, "popover")
                     .and_then(|attr| static_attr_value(attr, ctx.source))
                     .is_some();
                 if popover_is_static {
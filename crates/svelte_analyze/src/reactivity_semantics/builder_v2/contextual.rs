@@ -43,7 +43,7 @@ impl ContextualStaging {
 }
 
 pub(super) fn collect_template_declarations<'a>(
-    component: &Component,
+    component: &Component<'a>,
     parsed: &JsAst<'a>,
     data: &mut AnalysisData<'a>,
 ) {
@@ -55,7 +55,7 @@ pub(super) fn collect_template_declarations<'a>(
         data,
         &component.store,
         parsed,
-        &component.source,
+        component.source,
         data.uses_runes(),
         &component_name,
         "",
@@ -277,7 +277,7 @@ fn ensure_slot_let_carrier(
 }
 
 pub(super) fn promote_each_sources_to_legacy_state<'a>(
-    component: &Component,
+    component: &Component<'a>,
     parsed: &JsAst<'a>,
     data: &mut AnalysisData<'a>,
 ) {
@@ -291,7 +291,7 @@ pub(super) fn promote_each_sources_to_legacy_state<'a>(
         data,
         &component.store,
         parsed,
-        &component.source,
+        component.source,
         false,
         &component_name,
         "",
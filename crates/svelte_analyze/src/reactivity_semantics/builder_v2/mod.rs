@@ -38,7 +38,11 @@ use svelte_component_semantics::{OxcNodeId, ReferenceId};
 
 const JS_UNDEFINED_NAME: &str = "undefined";
 
-pub(crate) fn build_v2<'a>(component: &Component, parsed: &JsAst<'a>, data: &mut AnalysisData<'a>) {
+pub(crate) fn build_v2<'a>(
+    component: &Component<'a>,
+    parsed: &JsAst<'a>,
+    data: &mut AnalysisData<'a>,
+) {
     data.reactivity.set_uses_runes(data.script.runes);
     let lr_collected = build_script_semantics_v2(
         parsed,
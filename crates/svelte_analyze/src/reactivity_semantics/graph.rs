@@ -0,0 +1,668 @@
+use std::fmt::Write as _;
+
+use oxc_ast::AstKind;
+use oxc_ast::ast::BindingPattern;
+use oxc_semantic::ScopeId;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use svelte_ast::{Attribute, Component, ConcatPart, ExprRef, Node, NodeId, StmtRef};
+use svelte_component_semantics::{OxcNodeId, ReferenceId, SymbolId};
+use svelte_span::Span;
+
+use super::data::{BindingSemantics, ReferenceSemantics};
+use crate::types::data::{AnalysisData, JsAst};
+use crate::types::script::RuneKind;
+use crate::utils::script_info::detect_rune_from_call;
+
+const CONST_TAG_PREFIX_LEN: u32 = "const ".len() as u32;
+const LABEL_MAX_CHARS: usize = 40;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReactivityGraph {
+    pub component: String,
+    pub nodes: Vec<ReactivityNode>,
+    pub edges: Vec<ReactivityEdge>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReactivityNode {
+    pub id: usize,
+    pub kind: ReactivityNodeKind,
+    pub name: String,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReactivityNodeKind {
+    State,
+    Derived,
+    Prop,
+    Store,
+    Effect,
+    TemplateEffect,
+    Script,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReactivityEdge {
+    pub from: usize,
+    pub to: usize,
+    pub kind: ReactivityEdgeKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReactivityEdgeKind {
+    Read,
+    Write,
+}
+
+impl ReactivityNodeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::State => "state",
+            Self::Derived => "derived",
+            Self::Prop => "prop",
+            Self::Store => "store",
+            Self::Effect => "effect",
+            Self::TemplateEffect => "template_effect",
+            Self::Script => "script",
+        }
+    }
+
+    fn dot_shape(self) -> &'static str {
+        match self {
+            Self::State | Self::Prop | Self::Store => "ellipse",
+            Self::Derived => "diamond",
+            Self::Effect | Self::Script => "box",
+            Self::TemplateEffect => "note",
+        }
+    }
+}
+
+impl ReactivityEdgeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+        }
+    }
+}
+
+impl ReactivityGraph {
+    pub fn node(&self, id: usize) -> Option<&ReactivityNode> {
+        self.nodes.get(id)
+    }
+
+    pub fn find_node(&self, kind: ReactivityNodeKind, name: &str) -> Option<&ReactivityNode> {
+        self.nodes
+            .iter()
+            .find(|node| node.kind == kind && node.name == name)
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "digraph {} {{", dot_quote(&self.component));
+        let _ = writeln!(out, "  rankdir=LR;");
+        for node in &self.nodes {
+            let label = format!("{}\n{}", node.name, node.kind.as_str());
+            let _ = writeln!(
+                out,
+                "  n{} [label={}, shape={}];",
+                node.id,
+                dot_quote(&label),
+                node.kind.dot_shape()
+            );
+        }
+        let mut merged: Vec<(usize, usize, ReactivityEdgeKind, usize)> = Vec::new();
+        for edge in &self.edges {
+            match merged.iter_mut().find(|(from, to, kind, _)| {
+                *from == edge.from && *to == edge.to && *kind == edge.kind
+            }) {
+                Some(entry) => entry.3 += 1,
+                None => merged.push((edge.from, edge.to, edge.kind, 1)),
+            }
+        }
+        for (from, to, kind, count) in merged {
+            let label = if count > 1 {
+                format!("{} x{count}", kind.as_str())
+            } else {
+                kind.as_str().to_string()
+            };
+            let style = match kind {
+                ReactivityEdgeKind::Read => "solid",
+                ReactivityEdgeKind::Write => "dashed",
+            };
+            let _ = writeln!(
+                out,
+                "  n{from} -> n{to} [label={}, style={style}];",
+                dot_quote(&label)
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+fn dot_quote(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            _ => out.push(ch),
+        }
+    }
+    out.push('"');
+    out
+}
+
+pub fn build_reactivity_graph(
+    component: &Component,
+    parsed: &JsAst<'_>,
+    analysis: &AnalysisData<'_>,
+) -> ReactivityGraph {
+    let mut builder = GraphBuilder {
+        component,
+        analysis,
+        instance_offset: parsed.script_content_span.map_or(0, |s| s.start),
+        module_offset: parsed.module_script_content_span.map_or(0, |s| s.start),
+        instance_span: parsed.script_content_span.unwrap_or_default(),
+        module_span: parsed.module_script_content_span.unwrap_or_default(),
+        template_roots: collect_template_roots(component),
+        nodes: Vec::new(),
+        edges: Vec::new(),
+        symbol_nodes: FxHashMap::default(),
+        consumer_nodes: FxHashMap::default(),
+    };
+    builder.collect_symbol_nodes();
+    builder.collect_edges();
+    ReactivityGraph {
+        component: analysis.output.component_name.clone(),
+        nodes: builder.nodes,
+        edges: builder.edges,
+    }
+}
+
+#[derive(Clone, Copy)]
+struct TemplateRoot {
+    owner: NodeId,
+    owner_span: Span,
+    span: Span,
+    prefix_len: Option<u32>,
+}
+
+#[derive(Clone, Copy)]
+struct Owner {
+    id: NodeId,
+    span: Option<Span>,
+}
+
+impl Owner {
+    fn node(id: NodeId) -> Self {
+        Self { id, span: None }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum ConsumerKey {
+    Effect(OxcNodeId),
+    Derived(SymbolId),
+    Template(NodeId),
+    Script { module: bool },
+}
+
+#[derive(Clone, Copy)]
+enum Origin {
+    Script { module: bool },
+    Template(TemplateRoot),
+}
+
+struct ReferenceSite {
+    consumer: Option<(ConsumerKey, Span)>,
+    origin: Origin,
+    tracked: bool,
+}
+
+struct GraphBuilder<'g, 'a> {
+    component: &'g Component<'a>,
+    analysis: &'g AnalysisData<'a>,
+    instance_offset: u32,
+    module_offset: u32,
+    instance_span: Span,
+    module_span: Span,
+    template_roots: FxHashMap<OxcNodeId, TemplateRoot>,
+    nodes: Vec<ReactivityNode>,
+    edges: Vec<ReactivityEdge>,
+    symbol_nodes: FxHashMap<SymbolId, usize>,
+    consumer_nodes: FxHashMap<ConsumerKey, usize>,
+}
+
+impl<'a> GraphBuilder<'_, 'a> {
+    fn push_node(&mut self, kind: ReactivityNodeKind, name: String, span: Span) -> usize {
+        let id = self.nodes.len();
+        self.nodes.push(ReactivityNode {
+            id,
+            kind,
+            name,
+            span,
+        });
+        id
+    }
+
+    fn collect_symbol_nodes(&mut self) {
+        let scoping = &self.analysis.scoping;
+        for sym in scoping.symbol_ids() {
+            let kind = match self.analysis.binding_semantics(sym) {
+                BindingSemantics::State(_) | BindingSemantics::LegacyState(_) => {
+                    ReactivityNodeKind::State
+                }
+                BindingSemantics::Derived(_) => ReactivityNodeKind::Derived,
+                BindingSemantics::Prop(_) | BindingSemantics::LegacyBindableProp(_) => {
+                    ReactivityNodeKind::Prop
+                }
+                BindingSemantics::Store(_) => ReactivityNodeKind::Store,
+                _ => continue,
+            };
+            let name = match kind {
+                ReactivityNodeKind::Store => format!("${}", scoping.symbol_name(sym)),
+                _ => scoping.symbol_name(sym).to_string(),
+            };
+            let span = self.symbol_span(sym);
+            let id = self.push_node(kind, name, span);
+            self.symbol_nodes.insert(sym, id);
+        }
+    }
+
+    fn symbol_span(&self, sym: SymbolId) -> Span {
+        let scoping = &self.analysis.scoping;
+        let module = self.is_module_scope(scoping.symbol_scope_id(sym));
+        self.absolute_span(Origin::Script { module }, scoping.symbol_span(sym))
+    }
+
+    fn is_module_scope(&self, mut scope: ScopeId) -> bool {
+        let scoping = &self.analysis.scoping;
+        let Some(module_scope) = scoping.module_scope_id() else {
+            return false;
+        };
+        let instance_scope = scoping.instance_scope_id();
+        loop {
+            if scope == module_scope {
+                return true;
+            }
+            if Some(scope) == instance_scope {
+                return false;
+            }
+            match scoping.scope_parent_id(scope) {
+                Some(parent) => scope = parent,
+                None => return false,
+            }
+        }
+    }
+
+    fn collect_edges(&mut self) {
+        let scoping = &self.analysis.scoping;
+        for index in 0..scoping.references_len() {
+            let ref_id = ReferenceId::from_usize(index);
+            let Some((kind, payload_symbol)) = edge_kind(self.analysis.reference_semantics(ref_id))
+            else {
+                continue;
+            };
+            let reference = scoping.get_reference(ref_id);
+            let Some(sym) = payload_symbol.or(reference.symbol_id()) else {
+                continue;
+            };
+            let Some(&symbol_node) = self.symbol_nodes.get(&sym) else {
+                continue;
+            };
+            let node_id = reference.node_id();
+            let Some(site) = self.resolve_site(node_id) else {
+                continue;
+            };
+            if kind == ReactivityEdgeKind::Read && !site.tracked {
+                continue;
+            }
+            let Some(AstKind::IdentifierReference(ident)) = scoping.js_kind(node_id) else {
+                continue;
+            };
+            let span = self.absolute_span(site.origin, ident.span);
+            let consumer = match site.consumer {
+                Some((key, span)) => self.consumer_node(key, span),
+                None => match (kind, site.origin) {
+                    (ReactivityEdgeKind::Write, Origin::Script { module }) => {
+                        self.consumer_node(ConsumerKey::Script { module }, Span::default())
+                    }
+                    _ => continue,
+                },
+            };
+            if consumer == symbol_node {
+                continue;
+            }
+            let (from, to) = match kind {
+                ReactivityEdgeKind::Read => (symbol_node, consumer),
+                ReactivityEdgeKind::Write => (consumer, symbol_node),
+            };
+            self.edges.push(ReactivityEdge {
+                from,
+                to,
+                kind,
+                span,
+            });
+        }
+    }
+
+    fn consumer_node(&mut self, key: ConsumerKey, span: Span) -> usize {
+        if let Some(&id) = self.consumer_nodes.get(&key) {
+            return id;
+        }
+        let id = match key {
+            ConsumerKey::Derived(sym) => match self.symbol_nodes.get(&sym) {
+                Some(&id) => id,
+                None => {
+                    let name = self.analysis.scoping.symbol_name(sym).to_string();
+                    self.push_node(ReactivityNodeKind::Derived, name, span)
+                }
+            },
+            ConsumerKey::Effect(node_id) => {
+                let name = self.effect_name(node_id);
+                self.push_node(ReactivityNodeKind::Effect, name, span)
+            }
+            ConsumerKey::Template(_) => {
+                let name = template_label(self.component.source_text(span));
+                self.push_node(ReactivityNodeKind::TemplateEffect, name, span)
+            }
+            ConsumerKey::Script { module } => {
+                let (name, span) = if module {
+                    ("<script module>", self.module_span)
+                } else {
+                    ("<script>", self.instance_span)
+                };
+                self.push_node(ReactivityNodeKind::Script, name.to_string(), span)
+            }
+        };
+        self.consumer_nodes.insert(key, id);
+        id
+    }
+
+    fn effect_name(&self, node_id: OxcNodeId) -> String {
+        match self.analysis.scoping.js_kind(node_id) {
+            Some(AstKind::CallExpression(call)) => detect_rune_from_call(call)
+                .map_or("$effect", |rune| rune.display_name())
+                .to_string(),
+            _ => "$:".to_string(),
+        }
+    }
+
+    fn resolve_site(&self, node_id: OxcNodeId) -> Option<ReferenceSite> {
+        let scoping = &self.analysis.scoping;
+        let mut consumer = None;
+        let mut tracked = true;
+        let mut current = node_id;
+        loop {
+            let kind = scoping.js_kind(current)?;
+            if let Some(&root) = self.template_roots.get(&current) {
+                let is_function = matches!(
+                    kind,
+                    AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                );
+                return Some(ReferenceSite {
+                    consumer: Some((ConsumerKey::Template(root.owner), root.owner_span)),
+                    origin: Origin::Template(root),
+                    tracked: tracked && !is_function,
+                });
+            }
+            let parent = scoping.js_parent_id(current);
+            if consumer.is_none() {
+                match kind {
+                    AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                        let rune_callback =
+                            parent
+                                .and_then(|p| scoping.js_kind(p))
+                                .is_some_and(|p| match p {
+                                    AstKind::CallExpression(call) => matches!(
+                                        detect_rune_from_call(call),
+                                        Some(
+                                            RuneKind::Effect
+                                                | RuneKind::EffectPre
+                                                | RuneKind::DerivedBy
+                                        )
+                                    ),
+                                    _ => false,
+                                });
+                        if !rune_callback {
+                            tracked = false;
+                        }
+                    }
+                    AstKind::CallExpression(call) => {
+                        if matches!(
+                            detect_rune_from_call(call),
+                            Some(RuneKind::Effect | RuneKind::EffectPre)
+                        ) {
+                            consumer = Some((ConsumerKey::Effect(current), call.span));
+                        }
+                    }
+                    AstKind::VariableDeclarator(declarator) => {
+                        if let BindingPattern::BindingIdentifier(ident) = &declarator.id
+                            && let Some(sym) = ident.symbol_id.get()
+                            && matches!(
+                                self.analysis.binding_semantics(sym),
+                                BindingSemantics::Derived(_)
+                            )
+                        {
+                            consumer = Some((ConsumerKey::Derived(sym), declarator.span));
+                        }
+                    }
+                    AstKind::LabeledStatement(labeled) if labeled.label.name == "$" => {
+                        consumer = Some((ConsumerKey::Effect(current), labeled.span));
+                    }
+                    _ => {}
+                }
+            }
+            match parent {
+                Some(parent) => current = parent,
+                None => {
+                    let module = self.is_module_scope(scoping.js_node(current)?.scope_id());
+                    let origin = Origin::Script { module };
+                    let consumer =
+                        consumer.map(|(key, span)| (key, self.absolute_span(origin, span)));
+                    return Some(ReferenceSite {
+                        consumer,
+                        origin,
+                        tracked: tracked && consumer.is_some(),
+                    });
+                }
+            }
+        }
+    }
+
+    fn absolute_span(&self, origin: Origin, span: oxc_span::Span) -> Span {
+        match origin {
+            Origin::Script { module: true } => Span::shifted_from_oxc(self.module_offset, span),
+            Origin::Script { module: false } => Span::shifted_from_oxc(self.instance_offset, span),
+            Origin::Template(root) => match root.prefix_len {
+                Some(prefix) if span.start >= prefix => Span::new(
+                    root.span.start + span.start - prefix,
+                    root.span.start + span.end - prefix,
+                ),
+                _ => root.span,
+            },
+        }
+    }
+}
+
+fn edge_kind(semantics: ReferenceSemantics) -> Option<(ReactivityEdgeKind, Option<SymbolId>)> {
+    match semantics {
+        ReferenceSemantics::SignalRead { .. }
+        | ReferenceSemantics::PropRead(_)
+        | ReferenceSemantics::LegacyStateRead { .. } => Some((ReactivityEdgeKind::Read, None)),
+        ReferenceSemantics::StoreRead { symbol } => Some((ReactivityEdgeKind::Read, Some(symbol))),
+        ReferenceSemantics::SignalWrite { .. }
+        | ReferenceSemantics::SignalUpdate { .. }
+        | ReferenceSemantics::LegacyStateWrite
+        | ReferenceSemantics::LegacyStateUpdate { .. } => Some((ReactivityEdgeKind::Write, None)),
+        ReferenceSemantics::StoreWrite { symbol }
+        | ReferenceSemantics::StoreUpdate { symbol }
+        | ReferenceSemantics::PropMutation { symbol, .. }
+        | ReferenceSemantics::PropSourceMemberMutationRoot { symbol, .. }
+        | ReferenceSemantics::PropNonSourceMemberMutationRoot { symbol }
+        | ReferenceSemantics::LegacyStateMemberMutationRoot { symbol } => {
+            Some((ReactivityEdgeKind::Write, Some(symbol)))
+        }
+        _ => None,
+    }
+}
+
+fn template_label(source: &str) -> String {
+    let collapsed = source.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= LABEL_MAX_CHARS {
+        return collapsed;
+    }
+    let mut label: String = collapsed.chars().take(LABEL_MAX_CHARS - 1).collect();
+    label.push('…');
+    label
+}
+
+fn collect_template_roots(component: &Component) -> FxHashMap<OxcNodeId, TemplateRoot> {
+    let mut roots = FxHashMap::default();
+    for node in component.store.iter_nodes() {
+        match node {
+            Node::ExpressionTag(tag) => add_expr(&mut roots, Owner::node(tag.id), &tag.expression),
+            Node::IfBlock(block) => add_expr(&mut roots, Owner::node(block.id), &block.test),
+            Node::EachBlock(block) => {
+                add_expr(&mut roots, Owner::node(block.id), &block.expression);
+                if let Some(key) = &block.key {
+                    add_expr(&mut roots, Owner::node(block.id), key);
+                }
+                for stmt in [&block.context, &block.index].into_iter().flatten() {
+                    add_stmt(&mut roots, Owner::node(block.id), stmt, None);
+                }
+            }
+            Node::SnippetBlock(block) => {
+                add_stmt(&mut roots, Owner::node(block.id), &block.decl, None)
+            }
+            Node::RenderTag(tag) => add_expr(&mut roots, Owner::node(tag.id), &tag.expression),
+            Node::HtmlTag(tag) => add_expr(&mut roots, Owner::node(tag.id), &tag.expression),
+            Node::ConstTag(tag) => {
+                add_stmt(
+                    &mut roots,
+                    Owner::node(tag.id),
+                    &tag.decl,
+                    Some(CONST_TAG_PREFIX_LEN),
+                );
+            }
+            Node::DebugTag(tag) => {
+                for expr in tag.identifier_refs {
+                    add_expr(&mut roots, Owner::node(tag.id), expr);
+                }
+            }
+            Node::KeyBlock(block) => add_expr(&mut roots, Owner::node(block.id), &block.expression),
+            Node::AwaitBlock(block) => {
+                add_expr(&mut roots, Owner::node(block.id), &block.expression);
+                for stmt in [&block.value, &block.error].into_iter().flatten() {
+                    add_stmt(&mut roots, Owner::node(block.id), stmt, None);
+                }
+            }
+            Node::Element(el) => add_attrs(&mut roots, el.attributes),
+            Node::SlotElementLegacy(el) => add_attrs(&mut roots, el.attributes),
+            Node::ComponentNode(cn) => add_attrs(&mut roots, cn.attributes),
+            Node::SvelteComponentLegacy(cn) => add_attrs(&mut roots, cn.attributes),
+            Node::SvelteFragmentLegacy(el) => add_attrs(&mut roots, el.attributes),
+            Node::SvelteElement(el) => add_attrs(&mut roots, el.attributes),
+            Node::SvelteWindow(w) => add_attrs(&mut roots, w.attributes),
+            Node::SvelteDocument(d) => add_attrs(&mut roots, d.attributes),
+            Node::SvelteBody(b) => add_attrs(&mut roots, b.attributes),
+            Node::SvelteBoundary(b) => add_attrs(&mut roots, b.attributes),
+            Node::Text(_) | Node::Comment(_) | Node::SvelteHead(_) | Node::Error(_) => {}
+        }
+    }
+    roots
+}
+
+fn add_attrs(roots: &mut FxHashMap<OxcNodeId, TemplateRoot>, attrs: &[Attribute]) {
+    for attr in attrs {
+        let owner = Owner {
+            id: attr.id(),
+            span: Some(attr.span()),
+        };
+        match attr {
+            Attribute::ExpressionAttribute(a) => add_expr(roots, owner, &a.expression),
+            Attribute::SpreadAttribute(a) => add_expr(roots, owner, &a.expression),
+            Attribute::ClassDirective(a) => add_expr(roots, owner, &a.expression),
+            Attribute::StyleDirective(a) => {
+                add_expr(roots, owner, &a.expression);
+                if let svelte_ast::StyleDirectiveValue::Concatenation(parts) = &a.value {
+                    add_concat(roots, owner, parts);
+                }
+            }
+            Attribute::BindDirective(a) => add_expr(roots, owner, &a.expression),
+            Attribute::ConcatenationAttribute(a) => add_concat(roots, owner, a.parts),
+            Attribute::UseDirective(a) => add_opt_expr(roots, owner, a.expression.as_ref()),
+            Attribute::OnDirectiveLegacy(a) => add_opt_expr(roots, owner, a.expression.as_ref()),
+            Attribute::TransitionDirective(a) => {
+                add_opt_expr(roots, owner, a.expression.as_ref());
+            }
+            Attribute::AnimateDirective(a) => add_opt_expr(roots, owner, a.expression.as_ref()),
+            Attribute::AttachTag(a) => add_expr(roots, owner, &a.expression),
+            Attribute::StringAttribute(_)
+            | Attribute::BooleanAttribute(_)
+            | Attribute::LetDirectiveLegacy(_) => {}
+        }
+    }
+}
+
+fn add_concat(roots: &mut FxHashMap<OxcNodeId, TemplateRoot>, owner: Owner, parts: &[ConcatPart]) {
+    for part in parts {
+        if let ConcatPart::Dynamic { expr, .. } = part {
+            add_expr(roots, owner, expr);
+        }
+    }
+}
+
+fn add_opt_expr(
+    roots: &mut FxHashMap<OxcNodeId, TemplateRoot>,
+    owner: Owner,
+    expr: Option<&ExprRef>,
+) {
+    if let Some(expr) = expr {
+        add_expr(roots, owner, expr);
+    }
+}
+
+fn add_expr(roots: &mut FxHashMap<OxcNodeId, TemplateRoot>, owner: Owner, expr: &ExprRef) {
+    let id = expr.oxc_id.get();
+    if id != OxcNodeId::DUMMY {
+        roots.insert(
+            id,
+            TemplateRoot {
+                owner: owner.id,
+                owner_span: owner.span.unwrap_or(expr.span),
+                span: expr.span,
+                prefix_len: Some(0),
+            },
+        );
+    }
+}
+
+fn add_stmt(
+    roots: &mut FxHashMap<OxcNodeId, TemplateRoot>,
+    owner: Owner,
+    stmt: &StmtRef,
+    prefix_len: Option<u32>,
+) {
+    let id = stmt.oxc_id.get();
+    if id != OxcNodeId::DUMMY {
+        roots.insert(
+            id,
+            TemplateRoot {
+                owner: owner.id,
+                owner_span: owner.span.unwrap_or(stmt.span),
+                span: stmt.span,
+                prefix_len,
+            },
+        );
+    }
+}
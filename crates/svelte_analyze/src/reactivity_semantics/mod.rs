@@ -1,5 +1,6 @@
 pub(crate) mod builder_v2;
 pub(crate) mod data;
+pub mod graph;
 pub mod legacy_reactive;
 
 pub(crate) use builder_v2::build_v2;
@@ -13,7 +13,7 @@ use svelte_span::Span;
 use super::*;
 
 fn frag_nodes(component: &Component, fragment: FragmentId) -> Vec<NodeId> {
-    component.store.fragment(fragment).nodes.clone()
+    component.store.fragment(fragment).nodes.to_vec()
 }
 
 fn frag_items(component: &Component, fragment: FragmentId) -> Vec<NodeId> {
@@ -101,9 +101,9 @@ fn find_render_tag(fragment: FragmentId, component: &Component, target: &str) ->
 
 fn find_element<'a>(
     fragment: FragmentId,
-    component: &'a Component,
+    component: &'a Component<'a>,
     tag_name: &str,
-) -> Option<&'a Element> {
+) -> Option<&'a Element<'a>> {
     for id in frag_nodes(component, fragment) {
         match component.store.get(id) {
             Node::Element(el) if el.name == tag_name => return Some(el),
@@ -155,17 +155,17 @@ fn find_element<'a>(
 
 fn find_nth_element<'a>(
     fragment: FragmentId,
-    component: &'a Component,
+    component: &'a Component<'a>,
     tag_name: &str,
     target_index: usize,
-) -> Option<&'a Element> {
+) -> Option<&'a Element<'a>> {
     fn visit<'a>(
         fragment: FragmentId,
-        component: &'a Component,
+        component: &'a Component<'a>,
         tag_name: &str,
         target_index: usize,
         seen: &mut usize,
-    ) -> Option<&'a Element> {
+    ) -> Option<&'a Element<'a>> {
         for id in frag_nodes(component, fragment) {
             match component.store.get(id) {
                 Node::Element(el) if el.name == tag_name => {
@@ -184,7 +184,7 @@ fn find_nth_element<'a>(
                     {
                         return Some(found);
                     }
-                    for slot in &node.legacy_slots {
+                    for slot in node.legacy_slots {
                         if let Some(found) =
                             visit(slot.fragment, component, tag_name, target_index, seen)
                         {
@@ -278,7 +278,7 @@ fn find_component_node_id(
     None
 }
 
-fn find_let_directive(attrs: &[Attribute]) -> Option<&LetDirectiveLegacy> {
+fn find_let_directive<'a>(attrs: &'a [Attribute<'a>]) -> Option<&'a LetDirectiveLegacy<'a>> {
     attrs.iter().find_map(|attr| match attr {
         Attribute::LetDirectiveLegacy(dir) => Some(dir),
         _ => None,
@@ -537,7 +537,7 @@ fn find_attribute_id(
 
 fn find_if_block<'a>(
     fragment: FragmentId,
-    component: &'a Component,
+    component: &'a Component<'a>,
     test_text: &str,
 ) -> Option<&'a IfBlock> {
     for id in frag_nodes(component, fragment) {
@@ -552,7 +552,7 @@ fn find_if_block<'a>(
 
 fn find_each_block<'a>(
     fragment: FragmentId,
-    component: &'a Component,
+    component: &'a Component<'a>,
     expr_text: &str,
 ) -> Option<&'a EachBlock> {
     for id in frag_nodes(component, fragment) {
@@ -567,12 +567,12 @@ fn find_each_block<'a>(
 
 fn find_snippet_block<'a>(
     fragment: FragmentId,
-    component: &'a Component,
+    component: &'a Component<'a>,
     name: &str,
 ) -> Option<&'a svelte_ast::SnippetBlock> {
     for id in frag_nodes(component, fragment) {
         match component.store.get(id) {
-            Node::SnippetBlock(block) if block.name(&component.source) == name => {
+            Node::SnippetBlock(block) if block.name(component.source) == name => {
                 return Some(block);
             }
             Node::ComponentNode(node) => {
@@ -606,28 +606,28 @@ fn find_snippet_block<'a>(
     None
 }
 
-pub(crate) fn analyze_source(source: &str) -> (Component, AnalysisData<'static>) {
+pub(crate) fn analyze_source(source: &str) -> (Component<'static>, AnalysisData<'static>) {
     let alloc = Box::leak(Box::new(oxc_allocator::Allocator::default()));
     let (component, js_result, parse_diags) = svelte_parser::parse_with_js(alloc, source);
     assert!(
         parse_diags.is_empty(),
         "unexpected parse diagnostics: {parse_diags:?}"
     );
-    let (data, _parsed, diags) = analyze(&component, js_result);
+    let (data, _parsed, diags) = analyze(&component, js_result).expect("analyze");
     assert!(diags.is_empty(), "unexpected diagnostics: {diags:?}");
     (component, data)
 }
 
 fn analyze_source_with_parsed(
     source: &'static str,
-) -> (Component, AnalysisData<'static>, JsAst<'static>) {
+) -> (Component<'static>, AnalysisData<'static>, JsAst<'static>) {
     let alloc = Box::leak(Box::new(oxc_allocator::Allocator::default()));
     let (component, js_result, parse_diags) = svelte_parser::parse_with_js(alloc, source);
     assert!(
         parse_diags.is_empty(),
         "unexpected parse diagnostics: {parse_diags:?}"
     );
-    let (data, parsed, diags) = analyze(&component, js_result);
+    let (data, parsed, diags) = analyze(&component, js_result).expect("analyze");
     assert!(diags.is_empty(), "unexpected diagnostics: {diags:?}");
     (component, data, parsed)
 }
@@ -635,19 +635,20 @@ fn analyze_source_with_parsed(
 fn analyze_source_with_options(
     source: &str,
     options: AnalyzeOptions,
-) -> (Component, AnalysisData<'static>) {
+) -> (Component<'static>, AnalysisData<'static>) {
     let alloc = Box::leak(Box::new(oxc_allocator::Allocator::default()));
     let (component, js_result, parse_diags) = svelte_parser::parse_with_js(alloc, source);
     assert!(
         parse_diags.is_empty(),
         "unexpected parse diagnostics: {parse_diags:?}"
     );
-    let (data, _parsed, diags) = analyze_with_options(&component, js_result, &options);
+    let (data, _parsed, diags) =
+        analyze_with_options(&component, js_result, &options).expect("analyze");
     assert!(diags.is_empty(), "unexpected diagnostics: {diags:?}");
     (component, data)
 }
 
-fn analyze_source_with_css(source: &str) -> (Component, AnalysisData<'static>) {
+fn analyze_source_with_css(source: &str) -> (Component<'static>, AnalysisData<'static>) {
     let (component, data, css_pass_diags) = analyze_source_with_css_diags(source);
     assert!(
         css_pass_diags.is_empty(),
@@ -658,14 +659,14 @@ fn analyze_source_with_css(source: &str) -> (Component, AnalysisData<'static>) {
 
 fn analyze_source_with_css_diags(
     source: &str,
-) -> (Component, AnalysisData<'static>, Vec<Diagnostic>) {
+) -> (Component<'static>, AnalysisData<'static>, Vec<Diagnostic>) {
     let alloc = Box::leak(Box::new(oxc_allocator::Allocator::default()));
     let (component, js_result, parse_diags) = svelte_parser::parse_with_js(alloc, source);
     assert!(
         parse_diags.is_empty(),
         "unexpected parse diagnostics: {parse_diags:?}"
     );
-    let (mut data, parsed, diags) = analyze(&component, js_result);
+    let (mut data, parsed, diags) = analyze(&component, js_result).expect("analyze");
     assert!(diags.is_empty(), "unexpected diagnostics: {diags:?}");
     let Some((stylesheet, css_diags)) = svelte_parser::parse_css_block(&component) else {
         panic!("expected style block")
@@ -680,6 +681,8 @@ fn analyze_source_with_css_diags(
         &stylesheet,
         &parsed,
         false,
+        &|input| CssHash::Default.resolve(input),
+        "Self.svelte",
         &mut data,
         &mut css_pass_diags,
     );
@@ -689,7 +692,7 @@ fn analyze_source_with_css_diags(
 fn analyze_source_with_diags(
     source: &str,
 ) -> (
-    Component,
+    Component<'static>,
     AnalysisData<'static>,
     Vec<svelte_diagnostics::Diagnostic>,
 ) {
@@ -699,7 +702,7 @@ fn analyze_source_with_diags(
         parse_diags.is_empty(),
         "unexpected parse diagnostics: {parse_diags:?}"
     );
-    let (data, _parsed, diags) = analyze(&component, js_result);
+    let (data, _parsed, diags) = analyze(&component, js_result).expect("analyze");
     (component, data, diags)
 }
 
@@ -1347,7 +1350,7 @@ fn each_block_shadowing_does_not_mutate_rune() {
     let source = r#"<script>let count = $state(0); let items = $state([]);</script>{#each items as count}{count = 99}{/each}"#;
     let (component, js_result, parse_diags) = svelte_parser::parse_with_js(&alloc, source);
     assert!(parse_diags.is_empty());
-    let (data, _parsed, _diags) = analyze(&component, js_result);
+    let (data, _parsed, _diags) = analyze(&component, js_result).expect("analyze");
     let root = data.scoping.root_scope_id();
     let count_sym = data
         .scoping
@@ -1792,7 +1795,7 @@ fn bare_global_tail_only_scopes_local_prefix() {
         parse_diags.is_empty(),
         "unexpected parse diagnostics: {parse_diags:?}"
     );
-    let (mut data, parsed, diags) = analyze(&component, js_result);
+    let (mut data, parsed, diags) = analyze(&component, js_result).expect("analyze");
     assert!(diags.is_empty(), "unexpected diagnostics: {diags:?}");
     let Some((stylesheet, css_diags)) = svelte_parser::parse_css_block(&component) else {
         panic!("expected style block")
@@ -1807,6 +1810,8 @@ fn bare_global_tail_only_scopes_local_prefix() {
         &stylesheet,
         &parsed,
         false,
+        &|input| CssHash::Default.resolve(input),
+        "Self.svelte",
         &mut data,
         &mut css_pass_diags,
     );
@@ -2009,7 +2014,7 @@ fn dynamic_contenteditable_does_not_mark_bound_contenteditable() {
         parse_diags.is_empty(),
         "unexpected parse diagnostics: {parse_diags:?}"
     );
-    let (data, _parsed, _diags) = analyze(&component, js_result);
+    let (data, _parsed, _diags) = analyze(&component, js_result).expect("analyze");
     let el = find_element(component.root, &component, "div").expect("no element <div>");
     assert!(!data.elements.flags.is_bound_contenteditable(el.id));
 }
@@ -2225,7 +2230,7 @@ fn module_imports_are_visible_from_instance_scope() {
     let (component, js_result, parse_diags) = svelte_parser::parse_with_js(&alloc, source);
     assert!(parse_diags.is_empty());
 
-    let (data, parsed, diags) = analyze(&component, js_result);
+    let (data, parsed, diags) = analyze(&component, js_result).expect("analyze");
     assert!(diags.is_empty(), "unexpected diagnostics: {diags:?}");
 
     let root = data.scoping.root_scope_id();
@@ -3122,7 +3127,7 @@ fn legacy_slot_let_alias_uses_statement_backed_binding() {
         Node::ComponentNode(node) => node,
         _ => panic!("<List> did not lower to ComponentNode"),
     };
-    let let_dir = find_let_directive(&list_node.attributes)
+    let let_dir = find_let_directive(list_node.attributes)
         .unwrap_or_else(|| panic!("missing default-slot let directive"));
 
     let _ = data;
@@ -3189,7 +3194,7 @@ fn legacy_slot_let_destructure_registers_carrier_binding() {
         Node::SvelteFragmentLegacy(node) => node,
         _ => panic!("wrapper id did not resolve to <svelte:fragment>"),
     };
-    let let_dir = find_let_directive(&wrapper.attributes)
+    let let_dir = find_let_directive(wrapper.attributes)
         .unwrap_or_else(|| panic!("missing named-slot let directive"));
 
     let _ = data;
@@ -3497,7 +3502,8 @@ fn reactivity_semantics_v2_reference_semantics_cover_first_cluster() {
         warning_filter: Some(Box::new(|_| false)),
         ..AnalyzeOptions::default()
     };
-    let (data, parsed, diags) = analyze_with_options(&component, js_result, &options);
+    let (data, parsed, diags) =
+        analyze_with_options(&component, js_result, &options).expect("analyze");
     assert!(diags.is_empty(), "unexpected diagnostics: {diags:?}");
 
     assert_eq!(
@@ -3582,7 +3588,8 @@ fn reactivity_semantics_v2_state_references_distinguish_plain_and_mutated_reads(
         warning_filter: Some(Box::new(|_| false)),
         ..AnalyzeOptions::default()
     };
-    let (data, parsed, diags) = analyze_with_options(&component, js_result, &options);
+    let (data, parsed, diags) =
+        analyze_with_options(&component, js_result, &options).expect("analyze");
     assert!(diags.is_empty(), "unexpected diagnostics: {diags:?}");
 
     assert_eq!(
@@ -3640,7 +3647,8 @@ fn reactivity_semantics_v2_state_raw_distinguishes_plain_and_mutated_bindings()
         warning_filter: Some(Box::new(|_| false)),
         ..AnalyzeOptions::default()
     };
-    let (data, parsed, diags) = analyze_with_options(&component, js_result, &options);
+    let (data, parsed, diags) =
+        analyze_with_options(&component, js_result, &options).expect("analyze");
     assert!(diags.is_empty(), "unexpected diagnostics: {diags:?}");
 
     assert!(matches!(
@@ -3884,9 +3892,12 @@ fn runtime_plan_dev_custom_element_uses_exports_and_props() {
             immutable: false,
             preserve_whitespace: false,
             dev: true,
+            inline_constants: false,
             component_name: "Self".to_string(),
             filename_basename: "Self.svelte".to_string(),
             warning_filter: None,
+            lint: LintOptions::default(),
+            pass_clock: None,
         },
     );
     let plan = data.output.runtime_plan;
@@ -4196,7 +4207,7 @@ fn legacy_classification_skipped_in_runes_mode() {
     let source = "<script>let local = 1;</script><p>{local}</p>";
     let (component, js_result, parse_diags) = svelte_parser::parse_with_js(alloc, source);
     assert!(parse_diags.is_empty());
-    let (data, _parsed, _diags) = analyze(&component, js_result);
+    let (data, _parsed, _diags) = analyze(&component, js_result).expect("analyze");
     let sym = data.scoping.find_binding_in_any_scope("local");
     if let Some(sym) = sym {
         let decl = data.binding_semantics(sym);
@@ -4922,7 +4933,7 @@ fn shorthand_anchor_href_is_indexed_for_a11y_presence_checks() {
         "expected shorthand href to be indexed as a named attribute"
     );
     assert!(matches!(
-        data.attribute(anchor.id, &anchor.attributes, "href"),
+        data.attribute(anchor.id, anchor.attributes, "href"),
         Some(Attribute::ExpressionAttribute(a)) if a.shorthand
     ));
     assert!(
@@ -5590,7 +5601,7 @@ fn options_preserve_whitespace_keeps_raw_text_nodes() {
     let rendered: String = lowered
         .iter()
         .filter_map(|id| match component.store.get(*id) {
-            Node::Text(t) => Some(t.value(&component.source)),
+            Node::Text(t) => Some(t.value(component.source)),
             _ => None,
         })
         .collect();
@@ -5919,7 +5930,7 @@ mod block_semantics_each_tests {
 
     fn first_each_semantics<'a>(
         data: &'a crate::AnalysisData<'_>,
-        component: &'a svelte_ast::Component,
+        component: &'a svelte_ast::Component<'a>,
     ) -> &'a crate::block_semantics::EachBlockSemantics {
         let block_id = component
             .store
@@ -6131,12 +6142,12 @@ mod expr_ref_bind_tests {
         let mut total = 0;
         for node in component.store.iter_nodes() {
             let attrs: &[Attribute] = match node {
-                Node::Element(el) => &el.attributes,
+                Node::Element(el) => el.attributes,
                 _ => continue,
             };
             for attr in attrs {
                 let parts = match attr {
-                    Attribute::ConcatenationAttribute(a) => &a.parts,
+                    Attribute::ConcatenationAttribute(a) => a.parts,
                     _ => continue,
                 };
                 for part in parts {
@@ -6165,7 +6176,7 @@ mod expr_ref_bind_tests {
         let mut total = 0;
         for node in component.store.iter_nodes() {
             let Node::Element(el) = node else { continue };
-            for attr in &el.attributes {
+            for attr in el.attributes {
                 let Attribute::ExpressionAttribute(ea) = attr else {
                     continue;
                 };
@@ -6225,3 +6236,601 @@ mod svelte_head_title_diagnostics {
         assert_diag_codes(&diags, &[]);
     }
 }
+
+mod reactivity_graph_tests {
+    use super::analyze_source_with_parsed;
+    use crate::reactivity_semantics::graph::{
+        ReactivityEdgeKind, ReactivityGraph, ReactivityNodeKind, build_reactivity_graph,
+    };
+
+    fn graph(source: &'static str) -> ReactivityGraph {
+        let (component, data, parsed) = analyze_source_with_parsed(source);
+        build_reactivity_graph(&component, &parsed, &data)
+    }
+
+    fn edges(graph: &ReactivityGraph, source: &str) -> Vec<(String, String, &'static str, String)> {
+        graph
+            .edges
+            .iter()
+            .map(|edge| {
+                let from = &graph.nodes[edge.from];
+                let to = &graph.nodes[edge.to];
+                (
+                    from.name.clone(),
+                    to.name.clone(),
+                    edge.kind.as_str(),
+                    source[edge.span.start as usize..edge.span.end as usize].to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn edge(
+        from: &str,
+        to: &str,
+        kind: &'static str,
+        text: &str,
+    ) -> (String, String, &'static str, String) {
+        (from.to_string(), to.to_string(), kind, text.to_string())
+    }
+
+    #[test]
+    fn runes_graph_links_state_derived_effects_and_template() {
+        let source = "<script>\n\tlet { step } = $props();\n\tlet count = $state(0);\n\tlet doubled = $derived(count * step);\n\t$effect(() => { console.log(doubled); });\n</script>\n<button onclick={() => count++}>{doubled}</button>";
+        let graph = graph(source);
+
+        let kinds: Vec<_> = graph
+            .nodes
+            .iter()
+            .map(|n| (n.name.as_str(), n.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("step", ReactivityNodeKind::Prop),
+                ("count", ReactivityNodeKind::State),
+                ("doubled", ReactivityNodeKind::Derived),
+                ("$effect", ReactivityNodeKind::Effect),
+                (
+                    "onclick={() => count++}",
+                    ReactivityNodeKind::TemplateEffect
+                ),
+                ("doubled", ReactivityNodeKind::TemplateEffect),
+            ]
+        );
+        assert_eq!(
+            edges(&graph, source),
+            vec![
+                edge("count", "doubled", "read", "count"),
+                edge("step", "doubled", "read", "step"),
+                edge("doubled", "$effect", "read", "doubled"),
+                edge("onclick={() => count++}", "count", "write", "count"),
+                edge("doubled", "doubled", "read", "doubled"),
+            ]
+        );
+        let count = graph
+            .find_node(ReactivityNodeKind::State, "count")
+            .expect("count node");
+        assert_eq!(
+            &source[count.span.start as usize..count.span.end as usize],
+            "count"
+        );
+    }
+
+    #[test]
+    fn untracked_reads_are_dropped_and_script_writes_are_kept() {
+        let source = "<script>\n\tlet count = $state(0);\n\tfunction read() { return count; }\n\tfunction reset() { count = 0; }\n\t$effect(() => { setTimeout(() => count, 1); });\n</script>";
+        let graph = graph(source);
+
+        assert_eq!(
+            edges(&graph, source),
+            vec![edge("<script>", "count", "write", "count")]
+        );
+        assert!(
+            graph
+                .edges
+                .iter()
+                .all(|e| e.kind == ReactivityEdgeKind::Write)
+        );
+    }
+
+    #[test]
+    fn const_tag_edge_spans_point_at_the_reference() {
+        let source = "<script>\n\tlet count = $state(0);\n</script>\n<button onclick={() => count++}>+</button>\n{#if true}{@const tripled = count * 3}{tripled}{/if}";
+        let graph = graph(source);
+
+        assert_eq!(
+            edges(&graph, source),
+            vec![
+                edge("onclick={() => count++}", "count", "write", "count"),
+                edge("count", "tripled = count * 3", "read", "count"),
+            ]
+        );
+    }
+
+    #[test]
+    fn dot_output_merges_parallel_edges() {
+        let source = "<script>\n\tlet count = $state(0);\n</script>\n<p title=\"{count}-{count}\"></p>\n<button onclick={() => count++}>+</button>";
+        let dot = graph(source).to_dot();
+
+        assert!(dot.starts_with("digraph \"Self\" {\n"), "{dot}");
+        assert!(
+            dot.contains("n0 [label=\"count\\nstate\", shape=ellipse];"),
+            "{dot}"
+        );
+        assert!(
+            dot.contains("n0 -> n1 [label=\"read x2\", style=solid];"),
+            "{dot}"
+        );
+    }
+}
+
+mod effect_to_derived_lint_tests {
+    use super::{AnalyzeOptions, Diagnostic, LintOptions, analyze_with_options, assert_diag_codes};
+
+    fn lint(source: &str, effect_to_derived: bool) -> Vec<Diagnostic> {
+        let alloc = Box::leak(Box::new(oxc_allocator::Allocator::default()));
+        let (component, js_result, parse_diags) = svelte_parser::parse_with_js(alloc, source);
+        assert!(
+            parse_diags.is_empty(),
+            "unexpected parse diagnostics: {parse_diags:?}"
+        );
+        let options = AnalyzeOptions {
+            lint: LintOptions {
+                effect_to_derived,
+                ..LintOptions::default()
+            },
+            ..AnalyzeOptions::default()
+        };
+        analyze_with_options(&component, js_result, &options)
+            .expect("analyze")
+            .2
+    }
+
+    const SYNC: &str = r#"<script>
+let count = $state(0);
+let doubled = $state(0);
+$effect(() => {
+    doubled = count * 2;
+});
+</script>
+<button onclick={() => count++}>{doubled}</button>"#;
+
+    #[test]
+    fn sync_effect_suggests_derived() {
+        let diags = lint(SYNC, true);
+        assert_diag_codes(&diags, &["effect_can_be_derived"]);
+        let message = diags[0].kind.message();
+        assert!(
+            message.contains("let doubled = $derived(count * 2);"),
+            "unexpected message: {message}"
+        );
+        let start = SYNC.find("$effect").expect("effect call");
+        assert_eq!(diags[0].span.start as usize, start);
+    }
+
+    #[test]
+    fn lint_is_opt_in() {
+        assert_diag_codes(&lint(SYNC, false), &[]);
+    }
+
+    #[test]
+    fn each_synced_name_is_quoted() {
+        let diags = lint(
+            r#"<script>
+let count = $state(0);
+let doubled = $state(0);
+let tripled = $state(0);
+$effect(() => {
+    doubled = count * 2;
+    tripled = count * 3;
+});
+</script>
+<button onclick={() => count++}>{doubled} {tripled}</button>"#,
+            true,
+        );
+        assert_diag_codes(&diags, &["effect_can_be_derived"]);
+        let message = diags[0].kind.message();
+        assert!(
+            message.contains("only keeps `doubled`, `tripled` in sync"),
+            "unexpected message: {message}"
+        );
+    }
+
+    #[test]
+    fn effect_with_side_effect_is_not_reported() {
+        let diags = lint(
+            r#"<script>
+let count = $state(0);
+let doubled = $state(0);
+$effect(() => {
+    doubled = count * 2;
+    console.log(doubled);
+});
+</script>
+<button onclick={() => count++}>{doubled}</button>"#,
+            true,
+        );
+        assert_diag_codes(&diags, &[]);
+    }
+
+    #[test]
+    fn target_written_elsewhere_is_not_reported() {
+        let diags = lint(
+            r#"<script>
+let count = $state(0);
+let doubled = $state(0);
+$effect(() => {
+    doubled = count * 2;
+});
+</script>
+<button onclick={() => count++}>{doubled}</button>
+<button onclick={() => doubled = 0}>reset</button>"#,
+            true,
+        );
+        assert_diag_codes(&diags, &[]);
+    }
+
+    #[test]
+    fn svelte_ignore_suppresses_warning() {
+        let diags = lint(
+            r#"<script>
+let count = $state(0);
+let doubled = $state(0);
+// svelte-ignore effect_can_be_derived
+$effect(() => {
+    doubled = count * 2;
+});
+</script>
+<button onclick={() => count++}>{doubled}</button>"#,
+            true,
+        );
+        assert_diag_codes(&diags, &[]);
+    }
+}
+
+mod unused_declarations_lint_tests {
+    use super::{AnalyzeOptions, Diagnostic, LintOptions, analyze_with_options, assert_diag_codes};
+
+    fn lint(source: &str) -> Vec<Diagnostic> {
+        let alloc = Box::leak(Box::new(oxc_allocator::Allocator::default()));
+        let (component, js_result, parse_diags) = svelte_parser::parse_with_js(alloc, source);
+        assert!(
+            parse_diags.is_empty(),
+            "unexpected parse diagnostics: {parse_diags:?}"
+        );
+        let options = AnalyzeOptions {
+            lint: LintOptions {
+                unused_declarations: true,
+                ..LintOptions::default()
+            },
+            ..AnalyzeOptions::default()
+        };
+        analyze_with_options(&component, js_result, &options)
+            .expect("analyze")
+            .2
+    }
+
+    fn span_text<'s>(source: &'s str, diag: &Diagnostic) -> &'s str {
+        &source[diag.span.start as usize..diag.span.end as usize]
+    }
+
+    #[test]
+    fn unused_prop_points_at_binding() {
+        let source = r#"<script>
+let { title, subtitle: sub = '', value = $bindable() } = $props();
+value = 1;
+</script>
+<h1>{title}</h1>"#;
+        let diags = lint(source);
+        assert_diag_codes(&diags, &["unused_prop"]);
+        assert_eq!(span_text(source, &diags[0]), "sub");
+        assert!(diags[0].kind.message().contains("`subtitle`"));
+    }
+
+    #[test]
+    fn state_written_but_never_read() {
+        let source = r#"<script>
+let count = $state(0);
+let shown = $state(0);
+</script>
+<button onclick={() => { count = 1; shown++; }}>{shown}</button>"#;
+        let diags = lint(source);
+        assert_diag_codes(&diags, &["unused_state"]);
+        assert_eq!(span_text(source, &diags[0]), "count");
+    }
+
+    #[test]
+    fn snippet_never_rendered() {
+        let source = r#"<script>
+import Child from './Child.svelte';
+</script>
+{#snippet used()}a{/snippet}
+{#snippet unused()}b{/snippet}
+<Child>{#snippet header()}c{/snippet}</Child>
+{@render used()}"#;
+        let diags = lint(source);
+        assert_diag_codes(&diags, &["unused_snippet"]);
+        assert_eq!(span_text(source, &diags[0]), "unused");
+    }
+
+    #[test]
+    fn unused_and_type_only_imports() {
+        let source = r#"<script lang="ts">
+import { format, parse, type Options } from './utils';
+import type { Config } from './config';
+import { Item } from './item';
+let options: Options = {};
+let item: Item | null = null;
+let config: Config | null = null;
+</script>
+<p>{format(options, item, config)}</p>"#;
+        let diags = lint(source);
+        assert_diag_codes(&diags, &["unused_import", "import_only_used_as_type"]);
+        assert_eq!(span_text(source, &diags[0]), "parse");
+        assert_eq!(span_text(source, &diags[1]), "Item");
+    }
+
+    #[test]
+    fn store_and_directive_usage_counts() {
+        let source = r#"<script>
+import { writable } from 'svelte/store';
+import { fade } from 'svelte/transition';
+const count = writable(0);
+</script>
+<p transition:fade>{$count}</p>"#;
+        assert_diag_codes(&lint(source), &[]);
+    }
+
+    #[test]
+    fn svelte_ignore_suppresses_warnings() {
+        let source = r#"<script>
+// svelte-ignore unused_import
+import { helper } from './helper';
+// svelte-ignore unused_state
+let count = $state(0);
+</script>
+<!-- svelte-ignore unused_snippet -->
+{#snippet unused()}b{/snippet}"#;
+        assert_diag_codes(&lint(source), &[]);
+    }
+
+    #[test]
+    fn lint_is_opt_in() {
+        let alloc = Box::leak(Box::new(oxc_allocator::Allocator::default()));
+        let (component, js_result, _) = svelte_parser::parse_with_js(
+            alloc,
+            "<script>import { helper } from './helper';</script>",
+        );
+        let (_, _, diags) = analyze_with_options(&component, js_result, &AnalyzeOptions::default())
+            .expect("analyze");
+        assert_diag_codes(&diags, &[]);
+    }
+}
+
+mod diagnostic_fix_tests {
+    use super::{AnalyzeOptions, LintOptions, analyze_with_options};
+    use svelte_diagnostics::apply_fixes;
+
+    fn fixed(source: &str, code: &str, lint: LintOptions) -> String {
+        let alloc = Box::leak(Box::new(oxc_allocator::Allocator::default()));
+        let (component, js_result, parse_diags) = svelte_parser::parse_with_js(alloc, source);
+        assert!(
+            parse_diags.is_empty(),
+            "unexpected parse diagnostics: {parse_diags:?}"
+        );
+        let options = AnalyzeOptions {
+            lint,
+            ..AnalyzeOptions::default()
+        };
+        let (_, _, diags) = analyze_with_options(&component, js_result, &options).expect("analyze");
+        let diags: Vec<_> = diags
+            .into_iter()
+            .filter(|d| d.kind.code() == code)
+            .collect();
+        assert!(!diags.is_empty(), "expected {code} diagnostic");
+        apply_fixes(source, &diags)
+    }
+
+    fn fix(source: &str, code: &str) -> String {
+        fixed(source, code, LintOptions::default())
+    }
+
+    #[test]
+    fn attribute_quoted_fix_removes_quotes() {
+        assert_eq!(
+            fix(
+                r#"<script>import Child from './Child.svelte'; let x = 1;</script><Child value="{x}" />"#,
+                "attribute_quoted",
+            ),
+            r#"<script>import Child from './Child.svelte'; let x = 1;</script><Child value={x} />"#
+        );
+    }
+
+    #[test]
+    fn self_closing_fix_adds_closing_tag() {
+        assert_eq!(
+            fix(r#"<div class="a" />"#, "element_invalid_self_closing_tag"),
+            r#"<div class="a"></div>"#
+        );
+    }
+
+    #[test]
+    fn autofocus_fix_removes_attribute() {
+        assert_eq!(
+            fix(r#"<input type="text" autofocus />"#, "a11y_autofocus"),
+            r#"<input type="text" />"#
+        );
+    }
+
+    #[test]
+    fn event_directive_fix_uses_event_attribute() {
+        assert_eq!(
+            fix(
+                r#"<script>function go() {}</script><button on:click={go}>go</button>"#,
+                "event_directive_deprecated",
+            ),
+            r#"<script>function go() {}</script><button onclick={go}>go</button>"#
+        );
+    }
+
+    #[test]
+    fn event_directive_with_modifiers_has_no_fix() {
+        let source = r#"<script>function go() {}</script><button on:click|once={go}>go</button>"#;
+        assert_eq!(fix(source, "event_directive_deprecated"), source);
+    }
+
+    #[test]
+    fn event_directive_fix_is_withheld_when_another_directive_cannot_convert() {
+        let source = r#"<script>function go() {}</script><button on:click={go}>go</button><div on:keydown|self={go}></div>"#;
+        assert_eq!(fix(source, "event_directive_deprecated"), source);
+    }
+
+    #[test]
+    fn event_directive_fix_is_withheld_when_the_event_attribute_exists() {
+        let source =
+            r#"<script>function go() {}</script><button on:click={go} onclick={go}>go</button>"#;
+        assert_eq!(fix(source, "event_directive_deprecated"), source);
+    }
+
+    #[test]
+    fn event_directive_fix_converts_every_directive() {
+        assert_eq!(
+            fix(
+                r#"<script>function go() {}</script><button on:click={go}>go</button><div on:keydown={go}></div>"#,
+                "event_directive_deprecated",
+            ),
+            r#"<script>function go() {}</script><button onclick={go}>go</button><div onkeydown={go}></div>"#
+        );
+    }
+
+    #[test]
+    fn component_name_lowercase_fix_renames_tag_and_import() {
+        assert_eq!(
+            fix(
+                r#"<script>import { widget } from './lib';</script><widget>a</widget><widget />"#,
+                "component_name_lowercase",
+            ),
+            r#"<script>import { widget as Widget } from './lib';</script><Widget>a</Widget><Widget />"#
+        );
+    }
+
+    #[test]
+    fn state_referenced_locally_fix_wraps_in_derived() {
+        assert_eq!(
+            fix(
+                r#"<script>let count = $state(0); const doubled = count * 2;</script><button onclick={() => count++}>{doubled}</button>"#,
+                "state_referenced_locally",
+            ),
+            r#"<script>let count = $state(0); const doubled = $derived(count * 2);</script><button onclick={() => count++}>{doubled}</button>"#
+        );
+    }
+
+    #[test]
+    fn effect_can_be_derived_fix_replaces_state_and_removes_effect() {
+        assert_eq!(
+            fixed(
+                "<script>\nlet count = $state(0);\nlet doubled = $state(0);\n$effect(() => {\n    doubled = count * 2;\n});\n</script>\n<button onclick={() => count++}>{doubled}</button>",
+                "effect_can_be_derived",
+                LintOptions {
+                    effect_to_derived: true,
+                    ..LintOptions::default()
+                },
+            ),
+            "<script>\nlet count = $state(0);\nlet doubled = $derived(count * 2);\n</script>\n<button onclick={() => count++}>{doubled}</button>"
+        );
+    }
+}
+
+mod strict_html_nesting_lint_tests {
+    use super::{AnalyzeOptions, Diagnostic, LintOptions, analyze_with_options, assert_diag_codes};
+
+    fn lint(source: &str, strict_html_nesting: bool) -> Vec<Diagnostic> {
+        let alloc = Box::leak(Box::new(oxc_allocator::Allocator::default()));
+        let (component, js_result, parse_diags) = svelte_parser::parse_with_js(alloc, source);
+        assert!(
+            parse_diags.is_empty(),
+            "unexpected parse diagnostics: {parse_diags:?}"
+        );
+        let options = AnalyzeOptions {
+            lint: LintOptions {
+                strict_html_nesting,
+                ..LintOptions::default()
+            },
+            ..AnalyzeOptions::default()
+        };
+        analyze_with_options(&component, js_result, &options)
+            .expect("analyze")
+            .2
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let diags = lint("<button>go <input /></button>", false);
+        assert_diag_codes(&diags, &[]);
+    }
+
+    #[test]
+    fn interactive_content_inside_button_and_anchor() {
+        let diags = lint(
+            r#"<button>go <span><input /></span></button>
+<a href="/">pick <select></select></a>
+<button>go <input type="hidden" /></button>"#,
+            true,
+        );
+        assert_diag_codes(
+            &diags,
+            &["node_invalid_placement_ssr", "node_invalid_placement_ssr"],
+        );
+        assert!(diags[0].kind.message().contains("interactive content"));
+    }
+
+    #[test]
+    fn flow_content_inside_phrasing_parent() {
+        let diags = lint("<section><span><div></div></span></section>", true);
+        assert_diag_codes(&diags, &["node_invalid_placement_ssr"]);
+        assert!(diags[0].kind.message().contains("phrasing content"));
+    }
+
+    #[test]
+    fn existing_placement_errors_are_not_duplicated() {
+        let diags = lint("<p><span><div></div></span></p>", true);
+        assert_diag_codes(&diags, &["node_invalid_placement"]);
+    }
+
+    #[test]
+    fn select_children_and_option_placement() {
+        let diags = lint(
+            r#"<select><option>a</option><p>b</p></select>
+<ul><option>c</option></ul>
+<select><div><option>d</option></div></select>"#,
+            true,
+        );
+        assert_diag_codes(
+            &diags,
+            &["node_invalid_placement_ssr", "node_invalid_placement_ssr"],
+        );
+    }
+}
+
+#[test]
+fn pass_timings_are_recorded_only_with_a_clock() {
+    let (_, data) = analyze_source("<p>{1}</p>");
+    assert!(data.pass_timings.is_empty());
+
+    let (_, data) = analyze_source_with_options(
+        "<p>{1}</p>",
+        AnalyzeOptions {
+            pass_clock: Some(|| std::time::Duration::from_millis(1)),
+            ..AnalyzeOptions::default()
+        },
+    );
+    let passes: Vec<&str> = data
+        .pass_timings
+        .iter()
+        .map(|timing| timing.pass.as_str())
+        .collect();
+    assert_eq!(passes.len(), passes::default_stage_execution_order().len());
+    assert_eq!(passes.first(), Some(&"AnalyzeScript"));
+    assert_eq!(passes.last(), Some(&"Validate"));
+}
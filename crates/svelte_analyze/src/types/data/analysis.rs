@@ -22,6 +22,12 @@ pub struct ScriptAnalysis {
     pub pickled_await_offsets: PickledAwaitOffsets,
     pub blocker_data: BlockerData,
     pub script_rune_calls: ScriptRuneCalls,
+    pub(crate) inline_constants: FxHashMap<SymbolId, InlineConstant>,
+}
+
+pub(crate) struct InlineConstant {
+    pub(crate) value: String,
+    pub(crate) is_boolean: bool,
 }
 
 impl ScriptAnalysis {
@@ -45,6 +51,7 @@ impl ScriptAnalysis {
             pickled_await_offsets: PickledAwaitOffsets::new(),
             blocker_data: BlockerData::default(),
             script_rune_calls: ScriptRuneCalls::new(),
+            inline_constants: FxHashMap::default(),
         }
     }
 
@@ -53,15 +60,15 @@ impl ScriptAnalysis {
     }
 }
 
-pub struct ElementAnalysis {
+pub struct ElementAnalysis<'a> {
     pub(crate) facts: ElementFacts,
-    pub flags: ElementFlags,
+    pub flags: ElementFlags<'a>,
     pub directive_modifiers: DirectiveModifierFlags,
     pub(crate) html_tag_in_svg: NodeBitSet,
     pub(crate) html_tag_in_mathml: NodeBitSet,
 }
 
-impl ElementAnalysis {
+impl ElementAnalysis<'_> {
     fn new(node_count: u32) -> Self {
         Self {
             facts: ElementFacts::new(node_count),
@@ -170,13 +177,15 @@ pub struct AnalysisData<'a> {
     pub attr_expressions: NodeTable<ExpressionInfo>,
     pub scoping: ComponentScoping<'a>,
     pub script: ScriptAnalysis,
-    pub elements: ElementAnalysis,
+    pub elements: ElementAnalysis<'a>,
     pub template: TemplateAnalysis,
     pub blocks: BlockAnalysis,
     pub output: OutputPlanData,
     pub reactivity: ReactivitySemantics,
     pub(crate) block_semantics_store: crate::block_semantics::BlockSemanticsStore,
     pub dynamism: crate::passes::dynamism::DynamismData,
+    pub pass_timings: Vec<crate::PassTiming>,
+    internal_error: Option<crate::AnalyzeError>,
 }
 
 impl<'a> AnalysisData<'a> {
@@ -193,8 +202,24 @@ impl<'a> AnalysisData<'a> {
             reactivity: ReactivitySemantics::new(node_count),
             block_semantics_store: crate::block_semantics::BlockSemanticsStore::new(node_count),
             dynamism: crate::passes::dynamism::DynamismData::new(node_count),
+            pass_timings: Vec::new(),
+            internal_error: None,
         }
     }
+
+    /// Records a broken internal invariant at `span`. `analyze` stops after
+    /// the current pass and returns the first one as `Err`.
+    pub(crate) fn internal_error(&mut self, span: svelte_span::Span, message: &str) {
+        self.internal_error
+            .get_or_insert_with(|| crate::AnalyzeError {
+                span: Some(span),
+                message: message.to_string(),
+            });
+    }
+
+    pub(crate) fn take_internal_error(&mut self) -> Option<crate::AnalyzeError> {
+        self.internal_error.take()
+    }
 }
 
 impl<'a> AnalysisData<'a> {
@@ -390,17 +415,17 @@ impl<'a> AnalysisData<'a> {
     pub fn attribute<'n>(
         &self,
         id: NodeId,
-        attrs: &'n [Attribute],
+        attrs: &'n [Attribute<'n>],
         name: &str,
-    ) -> Option<&'n Attribute> {
+    ) -> Option<&'n Attribute<'n>> {
         self.attr_index(id)?.first(attrs, name)
     }
     pub fn string_attribute<'n>(
         &self,
         id: NodeId,
-        attrs: &'n [Attribute],
+        attrs: &'n [Attribute<'n>],
         name: &str,
-    ) -> Option<&'n svelte_ast::StringAttribute> {
+    ) -> Option<&'n svelte_ast::StringAttribute<'n>> {
         self.attribute(id, attrs, name).and_then(|attr| match attr {
             Attribute::StringAttribute(attr) => Some(attr),
             _ => None,
@@ -409,9 +434,9 @@ impl<'a> AnalysisData<'a> {
     pub fn expression_attribute<'n>(
         &self,
         id: NodeId,
-        attrs: &'n [Attribute],
+        attrs: &'n [Attribute<'n>],
         name: &str,
-    ) -> Option<&'n ExpressionAttribute> {
+    ) -> Option<&'n ExpressionAttribute<'n>> {
         self.attribute(id, attrs, name).and_then(|attr| match attr {
             Attribute::ExpressionAttribute(attr) => Some(attr),
             _ => None,
@@ -420,9 +445,9 @@ impl<'a> AnalysisData<'a> {
     pub fn bind_directive<'n>(
         &self,
         id: NodeId,
-        attrs: &'n [Attribute],
+        attrs: &'n [Attribute<'n>],
         name: &str,
-    ) -> Option<&'n BindDirective> {
+    ) -> Option<&'n BindDirective<'n>> {
         self.attribute(id, attrs, name).and_then(|attr| match attr {
             Attribute::BindDirective(attr) => Some(attr),
             _ => None,
@@ -431,12 +456,12 @@ impl<'a> AnalysisData<'a> {
     pub fn static_text_attribute_value<'n>(
         &self,
         id: NodeId,
-        attrs: &'n [Attribute],
+        attrs: &'n [Attribute<'n>],
         name: &str,
         source: &'n str,
     ) -> Option<&'n str> {
         self.string_attribute(id, attrs, name)
-            .map(|attr: &'n StringAttribute| attr.value_span.source_text(source))
+            .map(|attr: &'n StringAttribute<'n>| attr.value_span.source_text(source))
     }
     pub fn has_true_boolean_attribute(
         &self,
@@ -713,6 +738,33 @@ impl<'a> AnalysisData<'a> {
         result.sort_unstable();
         result
     }
+    fn inline_constant(&self, info: &ExpressionInfo) -> Option<&InlineConstant> {
+        match info.ref_symbols() {
+            [sym] if info.is_identifier() => self.script.inline_constants.get(sym),
+            _ => None,
+        }
+    }
+    pub fn inline_text_constant(&self, tag_id: NodeId) -> Option<&str> {
+        let value = &self.inline_constant(self.expressions.get(tag_id)?)?.value;
+        (!value.trim().is_empty() && !value.contains(['<', '&']) && is_template_safe(value))
+            .then_some(value.as_str())
+    }
+    pub fn inline_attribute_constant(
+        &self,
+        element_id: NodeId,
+        attr: &ExpressionAttribute,
+    ) -> Option<&str> {
+        if self.elements.facts.is_custom_element(element_id)
+            || !is_inlinable_attribute_name(&attr.name)
+        {
+            return None;
+        }
+        let constant = self.inline_constant(self.attr_expressions.get(attr.id)?)?;
+        (!constant.is_boolean
+            && !constant.value.contains(['"', '&'])
+            && is_template_safe(&constant.value))
+        .then_some(constant.value.as_str())
+    }
     pub fn known_value(&self, name: &str) -> Option<&str> {
         let root = self.scoping.root_scope_id();
         let sym_id = self.scoping.find_binding(root, name)?;
@@ -824,3 +876,27 @@ impl<'a> AnalysisData<'a> {
             .is_some_and(|info| info.ref_symbols().iter().any(|s| syms.contains(s)))
     }
 }
+
+fn is_inlinable_attribute_name(name: &str) -> bool {
+    !name.starts_with("on")
+        && !name.contains(':')
+        && !matches!(
+            name,
+            "class"
+                | "style"
+                | "value"
+                | "checked"
+                | "selected"
+                | "muted"
+                | "autofocus"
+                | "indeterminate"
+                | "defaultValue"
+                | "defaultChecked"
+        )
+}
+
+/// Static template text ends up verbatim in a JS template literal, so values
+/// that would need escaping there stay runtime expressions.
+fn is_template_safe(value: &str) -> bool {
+    !value.contains(['`', '\\']) && !value.contains("${")
+}
@@ -27,16 +27,20 @@ impl AttrIndex {
     }
 
     #[inline]
-    pub fn first<'a>(&self, attrs: &'a [Attribute], name: &str) -> Option<&'a Attribute> {
+    pub fn first<'a, 's>(
+        &self,
+        attrs: &'a [Attribute<'s>],
+        name: &str,
+    ) -> Option<&'a Attribute<'s>> {
         let pos = *self.by_name.get(name)?.first()?;
         Some(&attrs[pos as usize])
     }
 
-    pub fn all<'idx, 'attrs>(
+    pub fn all<'idx, 'attrs, 's>(
         &'idx self,
-        attrs: &'attrs [Attribute],
+        attrs: &'attrs [Attribute<'s>],
         name: &str,
-    ) -> impl Iterator<Item = &'attrs Attribute> + 'idx
+    ) -> impl Iterator<Item = &'attrs Attribute<'s>> + 'idx
     where
         'attrs: 'idx,
     {
@@ -47,7 +51,11 @@ impl AttrIndex {
     }
 
     #[inline]
-    pub fn find_by_id<'a>(&self, attrs: &'a [Attribute], id: NodeId) -> Option<&'a Attribute> {
+    pub fn find_by_id<'a, 's>(
+        &self,
+        attrs: &'a [Attribute<'s>],
+        id: NodeId,
+    ) -> Option<&'a Attribute<'s>> {
         let pos = *self.by_id.get(&id)?;
         Some(&attrs[pos as usize])
     }
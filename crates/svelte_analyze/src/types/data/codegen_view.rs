@@ -1,5 +1,6 @@
 use super::*;
 use crate::types::script::PropsDeclaration;
+use svelte_ast::ExpressionAttribute;
 
 #[derive(Clone, Copy)]
 pub struct CodegenView<'d, 'a> {
@@ -231,9 +232,9 @@ impl<'d, 'a> CodegenView<'d, 'a> {
     pub fn string_attribute<'b>(
         &self,
         id: NodeId,
-        attrs: &'b [svelte_ast::Attribute],
+        attrs: &'b [svelte_ast::Attribute<'b>],
         name: &str,
-    ) -> Option<&'b svelte_ast::StringAttribute> {
+    ) -> Option<&'b svelte_ast::StringAttribute<'b>> {
         self.data.string_attribute(id, attrs, name)
     }
     pub fn attr_index(&self, id: NodeId) -> Option<&AttrIndex> {
@@ -276,7 +277,7 @@ impl<'d, 'a> CodegenView<'d, 'a> {
     pub fn has_style_directives(&self, id: NodeId) -> bool {
         self.data.elements.flags.has_style_directives(id)
     }
-    pub fn style_directives(&self, id: NodeId) -> &[StyleDirective] {
+    pub fn style_directives(&self, id: NodeId) -> &'d [StyleDirective<'a>] {
         self.data.elements.flags.style_directives(id)
     }
     pub fn needs_input_defaults(&self, id: NodeId) -> bool {
@@ -318,7 +319,7 @@ impl<'d, 'a> CodegenView<'d, 'a> {
     pub fn is_expression_shorthand(&self, id: NodeId) -> bool {
         self.data.elements.flags.is_expression_shorthand(id)
     }
-    pub fn component_props(&self, id: NodeId) -> &[ComponentPropInfo] {
+    pub fn component_props(&self, id: NodeId) -> &'d [ComponentPropInfo<'a>] {
         self.data.elements.flags.component_props(id)
     }
 
@@ -420,6 +421,16 @@ impl<'d, 'a> CodegenView<'d, 'a> {
     pub fn known_value(&self, name: &str) -> Option<&str> {
         self.data.known_value(name)
     }
+    pub fn inline_text_constant(&self, tag_id: NodeId) -> Option<&str> {
+        self.data.inline_text_constant(tag_id)
+    }
+    pub fn inline_attribute_constant(
+        &self,
+        element_id: NodeId,
+        attr: &ExpressionAttribute,
+    ) -> Option<&str> {
+        self.data.inline_attribute_constant(element_id, attr)
+    }
     pub fn html_tag_in_svg(&self, id: NodeId) -> bool {
         self.data.html_tag_in_svg(id)
     }
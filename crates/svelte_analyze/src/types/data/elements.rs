@@ -8,13 +8,13 @@ pub struct ClassDirectiveInfo {
 }
 
 #[derive(Clone)]
-pub struct ComponentPropInfo {
-    pub kind: ComponentPropKind,
+pub struct ComponentPropInfo<'a> {
+    pub kind: ComponentPropKind<'a>,
     pub is_dynamic: bool,
 }
 
 #[derive(Clone)]
-pub enum ComponentPropKind {
+pub enum ComponentPropKind<'a> {
     String {
         name: String,
         value_span: Span,
@@ -33,7 +33,7 @@ pub enum ComponentPropKind {
     Concatenation {
         name: String,
         attr_id: NodeId,
-        parts: Vec<ConcatPart>,
+        parts: &'a [ConcatPart<'a>],
     },
     BindThis {
         bind_id: NodeId,
@@ -83,12 +83,12 @@ pub enum EventHandlerMode {
     Direct { capture: bool, passive: bool },
 }
 
-pub struct ElementFlags {
+pub struct ElementFlags<'a> {
     pub(crate) class_attr_id: NodeTable<NodeId>,
     pub(crate) class_directive_info: NodeTable<Vec<ClassDirectiveInfo>>,
     pub(crate) needs_clsx: NodeBitSet,
     pub(crate) static_class: NodeTable<String>,
-    pub(crate) style_directives: NodeTable<Vec<StyleDirective>>,
+    pub(crate) style_directives: NodeTable<Vec<StyleDirective<'a>>>,
     pub(crate) static_style: NodeTable<String>,
     pub(crate) needs_input_defaults: NodeBitSet,
     pub(crate) needs_var: NodeBitSet,
@@ -97,7 +97,7 @@ pub struct ElementFlags {
     pub(crate) has_use_directive: NodeBitSet,
     pub(crate) has_dynamic_class_directives: NodeBitSet,
     pub(crate) expression_shorthand: NodeBitSet,
-    pub(crate) component_props: NodeTable<Vec<ComponentPropInfo>>,
+    pub(crate) component_props: NodeTable<Vec<ComponentPropInfo<'a>>>,
     pub(crate) component_binding_sym: NodeTable<SymbolId>,
 
     pub(crate) component_css_props: NodeTable<Vec<(String, NodeId, oxc_syntax::node::NodeId)>>,
@@ -116,7 +116,7 @@ pub struct ElementFlags {
     pub(crate) is_svelte_self: NodeBitSet,
 }
 
-impl ElementFlags {
+impl<'a> ElementFlags<'a> {
     pub fn new(node_count: u32) -> Self {
         Self {
             class_attr_id: NodeTable::new(node_count),
@@ -162,7 +162,7 @@ impl ElementFlags {
     pub fn has_style_directives(&self, id: NodeId) -> bool {
         self.style_directives.contains_key(id)
     }
-    pub fn style_directives(&self, id: NodeId) -> &[StyleDirective] {
+    pub fn style_directives(&self, id: NodeId) -> &[StyleDirective<'a>] {
         self.style_directives.get(id).map_or(&[], |v| v.as_slice())
     }
     pub fn needs_input_defaults(&self, id: NodeId) -> bool {
@@ -193,7 +193,7 @@ impl ElementFlags {
     pub fn is_expression_shorthand(&self, id: NodeId) -> bool {
         self.expression_shorthand.contains(&id)
     }
-    pub fn component_props(&self, id: NodeId) -> &[ComponentPropInfo] {
+    pub fn component_props(&self, id: NodeId) -> &[ComponentPropInfo<'a>] {
         self.component_props.get(id).map_or(&[], |v| v.as_slice())
     }
     pub fn component_binding_sym(&self, id: NodeId) -> Option<SymbolId> {
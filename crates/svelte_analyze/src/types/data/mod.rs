@@ -40,6 +40,7 @@ pub use crate::reactivity_semantics::data::{
     ReferenceSemantics, RuntimeRuneKind, SignalReferenceKind, SnippetParamStrategy,
     StateBindingSemantics, StateDeclarationSemantics, StateKind, StoreBindingSemantics,
 };
+pub(crate) use analysis::InlineConstant;
 pub use analysis::{
     AnalysisData, BlockAnalysis, ElementAnalysis, OutputPlanData, ScriptAnalysis, TemplateAnalysis,
 };
@@ -0,0 +1,109 @@
+use svelte_ast::{AstStore, Attribute, ConcatenationAttribute, Element, Node, OnDirectiveLegacy};
+use svelte_diagnostics::TextEdit;
+use svelte_span::Span;
+
+pub(crate) fn expand_self_closing(source: &str, el: &Element) -> Vec<TextEdit> {
+    let end = el.span.end as usize;
+    let Some(tag) = source.get(el.span.start as usize..end) else {
+        return Vec::new();
+    };
+    if !tag.ends_with("/>") {
+        return Vec::new();
+    }
+    let slash = end - 2;
+    let start = source[..slash].trim_end().len() as u32;
+    vec![TextEdit::new(
+        Span::new(start, el.span.end),
+        format!("></{}>", el.name),
+    )]
+}
+
+pub(crate) fn unquote_attribute(source: &str, attr: &ConcatenationAttribute) -> Vec<TextEdit> {
+    if !attr.quoted {
+        return Vec::new();
+    }
+    let Some(text) = source.get(attr.span.start as usize..attr.span.end as usize) else {
+        return Vec::new();
+    };
+    let Some(quote_pos) = text.find(['"', '\'']) else {
+        return Vec::new();
+    };
+    let quote = text.as_bytes()[quote_pos];
+    if text.len() < quote_pos + 2 || text.as_bytes()[text.len() - 1] != quote {
+        return Vec::new();
+    }
+    let inner = &text[quote_pos + 1..text.len() - 1];
+    vec![TextEdit::new(
+        Span::new(attr.span.start + quote_pos as u32, attr.span.end),
+        inner,
+    )]
+}
+
+pub(crate) fn remove_attribute(source: &str, span: Span) -> Vec<TextEdit> {
+    let start = source[..span.start as usize].trim_end().len() as u32;
+    vec![TextEdit::delete(Span::new(start, span.end))]
+}
+
+pub(crate) fn on_directive_to_attribute(source: &str, dir: &OnDirectiveLegacy) -> Vec<TextEdit> {
+    if dir.expression.is_none() || !dir.modifiers.is_empty() {
+        return Vec::new();
+    }
+    let prefix = format!("on:{}", dir.name);
+    let start = dir.span.start as usize;
+    if source.get(start..start + prefix.len()) != Some(prefix.as_str()) {
+        return Vec::new();
+    }
+    vec![TextEdit::new(
+        Span::new(dir.span.start, dir.span.start + prefix.len() as u32),
+        format!("on{}", dir.name),
+    )]
+}
+
+pub(crate) fn all_on_directives_convertible(source: &str, store: &AstStore<'_>) -> bool {
+    store
+        .iter_nodes()
+        .filter(|node| matches!(node, Node::Element(_) | Node::SvelteElement(_)))
+        .all(|node| {
+            let mut names = Vec::new();
+            for attr in node.attributes() {
+                let Attribute::OnDirectiveLegacy(dir) = attr else {
+                    continue;
+                };
+                if names.contains(&dir.name.as_str())
+                    || has_event_attribute(node.attributes(), &dir.name)
+                    || on_directive_to_attribute(source, dir).is_empty()
+                {
+                    return false;
+                }
+                names.push(dir.name.as_str());
+            }
+            true
+        })
+}
+
+fn has_event_attribute(attributes: &[Attribute<'_>], event: &str) -> bool {
+    attributes.iter().any(|attr| {
+        attr.html_name()
+            .strip_prefix("on")
+            .is_some_and(|name| name.eq_ignore_ascii_case(event))
+    })
+}
+
+pub(crate) fn rename_element_tag(source: &str, el: &Element, name: &str) -> Option<Vec<TextEdit>> {
+    let open = el.span.start + 1;
+    let open_end = open + el.name.len() as u32;
+    if source.get(open as usize..open_end as usize) != Some(el.name.as_str()) {
+        return None;
+    }
+    let mut edits = vec![TextEdit::new(Span::new(open, open_end), name)];
+    if !el.self_closing {
+        let close = format!("</{}>", el.name);
+        let text = source.get(el.span.start as usize..el.span.end as usize)?;
+        if !text.ends_with(&close) {
+            return None;
+        }
+        let close_name = el.span.end - 1 - el.name.len() as u32;
+        edits.push(TextEdit::new(Span::new(close_name, el.span.end - 1), name));
+    }
+    Some(edits)
+}
@@ -240,6 +240,122 @@ pub fn is_tag_valid_with_ancestor(child_tag: &str, ancestors: &[&str]) -> Option
     None
 }
 
+const PHRASING_ONLY_PARENTS: &[&str] = &[
+    "abbr", "b", "bdi", "bdo", "button", "cite", "code", "data", "dfn", "em", "h1", "h2", "h3",
+    "h4", "h5", "h6", "i", "kbd", "label", "mark", "output", "p", "pre", "q", "s", "samp", "small",
+    "span", "strong", "sub", "sup", "time", "u", "var",
+];
+const FLOW_ONLY_CONTENT: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "dd",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "li",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "ul",
+];
+const SELECT_CHILDREN: &[&str] = &[
+    "option", "optgroup", "hr", "button", "div", "span", "noscript", "script", "template",
+];
+const OPTGROUP_CHILDREN: &[&str] = &[
+    "option", "legend", "div", "span", "noscript", "script", "template",
+];
+const OPTION_CONTAINERS: &[&str] = &["select", "optgroup", "datalist"];
+
+pub fn is_tag_valid_with_ancestors_strict(
+    child_tag: &str,
+    interactive: bool,
+    ancestors: &[&str],
+) -> Option<String> {
+    if child_tag.contains('-') {
+        return None;
+    }
+    let parent_tag = *ancestors.first()?;
+    if parent_tag.contains('-') || parent_tag == "template" {
+        return None;
+    }
+
+    let only = match parent_tag {
+        "select" => Some(SELECT_CHILDREN),
+        "optgroup" => Some(OPTGROUP_CHILDREN),
+        _ => None,
+    };
+    if let Some(only) = only
+        && !only.contains(&child_tag)
+    {
+        let allowed = only
+            .iter()
+            .map(|d| format!("`<{d}>`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Some(format!(
+            "`<{child_tag}>` cannot be a child of `<{parent_tag}>`. `<{parent_tag}>` only allows these children: {allowed}"
+        ));
+    }
+
+    if child_tag == "option"
+        && let Some(container) = ancestors
+            .iter()
+            .find(|tag| !matches!(**tag, "div" | "span"))
+        && !container.contains('-')
+        && !OPTION_CONTAINERS.contains(container)
+    {
+        return Some(format!(
+            "`<option>` must be inside a `<select>`, `<optgroup>` or `<datalist>`, not a `<{container}>`"
+        ));
+    }
+
+    for &ancestor in ancestors {
+        if ancestor.contains('-') || ancestor == "template" {
+            return None;
+        }
+        if interactive && matches!(ancestor, "a" | "button") {
+            return Some(format!(
+                "`<{child_tag}>` is interactive content and cannot be a descendant of `<{ancestor}>`"
+            ));
+        }
+        if is_tag_valid_with_ancestor(child_tag, &[ancestor]).is_some() {
+            continue;
+        }
+        if child_tag == "label" && ancestor == "label" {
+            return Some("`<label>` cannot be a descendant of `<label>`".to_string());
+        }
+        if PHRASING_ONLY_PARENTS.contains(&ancestor) && FLOW_ONLY_CONTENT.contains(&child_tag) {
+            return Some(format!(
+                "`<{child_tag}>` cannot be a descendant of `<{ancestor}>`, which only allows phrasing content"
+            ));
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -316,4 +432,34 @@ mod tests {
         let msg = is_tag_valid_with_parent("#text", "table").expect("expected diagnostic message");
         assert!(msg.contains("only allows"));
     }
+
+    #[test]
+    fn strict_interactive_inside_button_and_anchor() {
+        assert!(is_tag_valid_with_ancestors_strict("input", true, &["span", "button"]).is_some());
+        assert!(is_tag_valid_with_ancestors_strict("button", true, &["a"]).is_some());
+        assert!(is_tag_valid_with_ancestors_strict("button", true, &["button"]).is_some());
+        assert!(is_tag_valid_with_ancestors_strict("button", true, &["span", "button"]).is_some());
+        assert!(is_tag_valid_with_ancestors_strict("a", true, &["a"]).is_some());
+        assert!(is_tag_valid_with_ancestors_strict("input", false, &["button"]).is_none());
+    }
+
+    #[test]
+    fn strict_flow_content_in_phrasing_parent() {
+        let msg = is_tag_valid_with_ancestors_strict("div", false, &["span", "section"])
+            .expect("expected diagnostic message");
+        assert!(msg.contains("phrasing content"));
+        assert!(is_tag_valid_with_ancestors_strict("div", false, &["a", "div"]).is_none());
+        assert!(is_tag_valid_with_ancestors_strict("div", false, &["span", "p"]).is_some());
+        assert!(is_tag_valid_with_ancestors_strict("div", false, &["p"]).is_none());
+    }
+
+    #[test]
+    fn strict_select_and_option_placement() {
+        assert!(is_tag_valid_with_ancestors_strict("p", false, &["select"]).is_some());
+        assert!(is_tag_valid_with_ancestors_strict("option", false, &["select"]).is_none());
+        assert!(is_tag_valid_with_ancestors_strict("option", false, &["div", "select"]).is_none());
+        assert!(is_tag_valid_with_ancestors_strict("option", false, &["ul"]).is_some());
+        assert!(is_tag_valid_with_ancestors_strict("option", false, &[]).is_none());
+        assert!(is_tag_valid_with_ancestors_strict("option", false, &["my-list"]).is_none());
+    }
 }
@@ -66,12 +66,8 @@ impl IdentGen {
         *count += 1;
 
         while self.conflicts.contains(name.as_str()) {
-            let c = self
-                .counters
-                .get_mut(prefix)
-                .expect("counter was inserted for this prefix above");
-            name = build_name(prefix, *c);
-            *c += 1;
+            name = build_name(prefix, *count);
+            *count += 1;
         }
 
         self.conflicts.insert(name.clone());
@@ -48,15 +48,9 @@ pub fn extract_script_info(
                 if let Some(decl) = &export.declaration {
                     if !runes
                         && props_declaration.is_none()
-                        && matches!(
-                            decl,
-                            oxc_ast::ast::Declaration::VariableDeclaration(var_decl)
-                                if var_decl.kind == oxc_ast::ast::VariableDeclarationKind::Let
-                        )
+                        && let oxc_ast::ast::Declaration::VariableDeclaration(var_decl) = decl
+                        && var_decl.kind == oxc_ast::ast::VariableDeclarationKind::Let
                     {
-                        let oxc_ast::ast::Declaration::VariableDeclaration(var_decl) = decl else {
-                            unreachable!()
-                        };
                         props_declaration = collect_legacy_export_props(var_decl, offset, source);
                         collect_declarations_from_declaration(
                             decl,
@@ -546,11 +540,10 @@ fn extract_prop_default(
             {
                 let sp = arg.span();
                 let text = &source[sp.start as usize..sp.end as usize];
-                let expr = arg.as_expression().expect("argument should be expression");
                 (
                     Some(Span::new(sp.start + offset, sp.end + offset)),
                     Some(text.to_string()),
-                    is_simple_expression(expr),
+                    arg.as_expression().is_some_and(is_simple_expression),
                 )
             } else {
                 (None, None, true)
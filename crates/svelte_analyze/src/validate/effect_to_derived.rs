@@ -0,0 +1,250 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{
+    Argument, AssignmentExpression, AssignmentOperator, AssignmentTarget, AwaitExpression,
+    BindingPattern, Expression, IdentifierReference, Program, Statement, UpdateExpression,
+};
+use oxc_ast_visit::Visit;
+use oxc_span::GetSpan;
+use svelte_component_semantics::SymbolId;
+use svelte_diagnostics::{Diagnostic, DiagnosticKind, TextEdit};
+use svelte_span::Span;
+
+use crate::types::data::{IgnoreData, ReferenceSemantics};
+use crate::types::script::RuneKind;
+use crate::utils::script_info::detect_rune_from_call;
+use crate::{AnalysisData, BindingSemantics, StateKind};
+
+const CODE: &str = "effect_can_be_derived";
+
+pub(super) fn validate(
+    data: &AnalysisData,
+    program: &Program<'_>,
+    offset: u32,
+    diags: &mut Vec<Diagnostic>,
+) {
+    let mut ignore = IgnoreData::new();
+    ignore.scan_program_comments(program, true);
+
+    for stmt in &program.body {
+        let Statement::ExpressionStatement(es) = stmt else {
+            continue;
+        };
+        if ignore.is_ignored_at_span(stmt.span().start, CODE) {
+            continue;
+        }
+        let Expression::CallExpression(call) = &es.expression else {
+            continue;
+        };
+        if !matches!(
+            detect_rune_from_call(call),
+            Some(RuneKind::Effect | RuneKind::EffectPre)
+        ) {
+            continue;
+        }
+        let Some(body) = effect_body(call.arguments.first()) else {
+            continue;
+        };
+        let Some(assignments) = sync_assignments(data, body, call.span) else {
+            continue;
+        };
+        let names: Vec<String> = assignments
+            .iter()
+            .map(|(name, _, _)| format!("`{name}`"))
+            .collect();
+        let source = program.source_text;
+        let suggestion = assignments
+            .iter()
+            .map(|(name, value, _)| {
+                let value = &source[value.start as usize..value.end as usize];
+                format!("let {name} = $derived({value});")
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut fixes: Vec<TextEdit> = assignments
+            .iter()
+            .map(|(_, value, init)| {
+                let value = &source[value.start as usize..value.end as usize];
+                TextEdit::new(
+                    Span::new(init.start + offset, init.end + offset),
+                    format!("$derived({value})"),
+                )
+            })
+            .collect();
+        let stmt_start = source[..stmt.span().start as usize].trim_end().len() as u32;
+        fixes.push(TextEdit::delete(Span::new(
+            stmt_start + offset,
+            stmt.span().end + offset,
+        )));
+        diags.push(
+            Diagnostic::warning(
+                DiagnosticKind::EffectCanBeDerived {
+                    names: names.join(", "),
+                    suggestion,
+                },
+                Span::new(call.span.start + offset, call.span.end + offset),
+            )
+            .with_fixes(fixes),
+        );
+    }
+}
+
+fn effect_body<'b, 'a>(arg: Option<&'b Argument<'a>>) -> Option<&'b [Statement<'a>]> {
+    match arg? {
+        Argument::ArrowFunctionExpression(arrow)
+            if arrow.params.items.is_empty() && !arrow.r#async =>
+        {
+            Some(&arrow.body.statements)
+        }
+        Argument::FunctionExpression(func)
+            if func.params.items.is_empty() && !func.r#async && !func.generator =>
+        {
+            func.body.as_ref().map(|body| body.statements.as_slice())
+        }
+        _ => None,
+    }
+}
+
+fn sync_assignments<'b>(
+    data: &AnalysisData,
+    body: &'b [Statement<'_>],
+    effect_span: oxc_span::Span,
+) -> Option<Vec<(&'b str, oxc_span::Span, oxc_span::Span)>> {
+    if body.is_empty() {
+        return None;
+    }
+    let mut targets: Vec<SymbolId> = Vec::with_capacity(body.len());
+    let mut values = Vec::with_capacity(body.len());
+    for stmt in body {
+        let Statement::ExpressionStatement(es) = stmt else {
+            return None;
+        };
+        let Expression::AssignmentExpression(assign) = es.expression.without_parentheses() else {
+            return None;
+        };
+        if assign.operator != AssignmentOperator::Assign {
+            return None;
+        }
+        let AssignmentTarget::AssignmentTargetIdentifier(target) = &assign.left else {
+            return None;
+        };
+        let sym = data.scoping.symbol_for_identifier_reference(target)?;
+        if targets.contains(&sym) {
+            return None;
+        }
+        let init = plain_local_state_init(data, sym)?;
+        targets.push(sym);
+        values.push((target.name.as_str(), &assign.right, init));
+    }
+
+    for (_, value, _) in &values {
+        let mut reads = ValueReads {
+            data,
+            targets: &targets,
+            reactive: false,
+            rejected: false,
+        };
+        reads.visit_expression(value);
+        if reads.rejected || !reads.reactive {
+            return None;
+        }
+    }
+    if !targets
+        .iter()
+        .all(|&sym| only_written_inside(data, sym, effect_span))
+    {
+        return None;
+    }
+    Some(
+        values
+            .into_iter()
+            .map(|(name, value, init)| (name, value.span(), init))
+            .collect(),
+    )
+}
+
+fn plain_local_state_init(data: &AnalysisData, sym: SymbolId) -> Option<oxc_span::Span> {
+    let BindingSemantics::State(state) = data.binding_semantics(sym) else {
+        return None;
+    };
+    if !matches!(state.kind, StateKind::State | StateKind::StateRaw) || state.var_declared {
+        return None;
+    }
+    if data.scoping.is_member_mutated(sym) || !data.scoping.is_component_top_level_symbol(sym) {
+        return None;
+    }
+    let decl = data.scoping.symbol_declaration(sym);
+    let Some(AstKind::VariableDeclarator(declarator)) = data
+        .scoping
+        .js_parent_id(decl)
+        .and_then(|parent| data.scoping.js_kind(parent))
+    else {
+        return None;
+    };
+    if !matches!(declarator.id, BindingPattern::BindingIdentifier(_)) {
+        return None;
+    }
+    declarator.init.as_ref().map(GetSpan::span)
+}
+
+fn only_written_inside(data: &AnalysisData, sym: SymbolId, effect_span: oxc_span::Span) -> bool {
+    data.scoping
+        .get_resolved_reference_ids(sym)
+        .iter()
+        .all(|&ref_id| {
+            let reference = data.scoping.get_reference(ref_id);
+            if !reference.is_write() {
+                return true;
+            }
+            if data.scoping.is_template_reference(ref_id) {
+                return false;
+            }
+            matches!(
+                data.scoping.js_kind(reference.node_id()),
+                Some(AstKind::IdentifierReference(ident))
+                    if ident.span.start >= effect_span.start && ident.span.end <= effect_span.end
+            )
+        })
+}
+
+struct ValueReads<'d, 'a> {
+    data: &'d AnalysisData<'a>,
+    targets: &'d [SymbolId],
+    reactive: bool,
+    rejected: bool,
+}
+
+impl<'a> Visit<'a> for ValueReads<'_, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(ref_id) = ident.reference_id.get() else {
+            return;
+        };
+        if self
+            .data
+            .scoping
+            .symbol_for_reference(ref_id)
+            .is_some_and(|sym| self.targets.contains(&sym))
+        {
+            self.rejected = true;
+        }
+        if matches!(
+            self.data.reference_semantics(ref_id),
+            ReferenceSemantics::SignalRead { .. }
+                | ReferenceSemantics::PropRead(_)
+                | ReferenceSemantics::StoreRead { .. }
+        ) {
+            self.reactive = true;
+        }
+    }
+
+    fn visit_assignment_expression(&mut self, _: &AssignmentExpression<'a>) {
+        self.rejected = true;
+    }
+
+    fn visit_update_expression(&mut self, _: &UpdateExpression<'a>) {
+        self.rejected = true;
+    }
+
+    fn visit_await_expression(&mut self, _: &AwaitExpression<'a>) {
+        self.rejected = true;
+    }
+}
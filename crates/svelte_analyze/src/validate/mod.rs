@@ -1,8 +1,10 @@
+mod effect_to_derived;
 mod experimental_async;
 mod legacy;
 mod non_reactive_update;
 mod runes;
 mod stores;
+mod unused;
 
 use oxc_ast::ast::{
     ArrowFunctionExpression, BindingPattern, Declaration, Function, ImportDeclarationSpecifier,
@@ -19,13 +21,14 @@ use svelte_diagnostics::{Diagnostic, DiagnosticKind};
 use svelte_span::Span;
 
 use crate::types::script::RuneKind;
-use crate::{AnalysisData, types::data::JsAst};
+use crate::{AnalysisData, LintOptions, types::data::JsAst};
 
 pub fn validate(
     component: &Component,
     data: &AnalysisData,
     parsed: &JsAst,
     runes: bool,
+    lint: LintOptions,
     diags: &mut Vec<Diagnostic>,
 ) {
     if let Some(program) = &parsed.program {
@@ -33,6 +36,9 @@ pub fn validate(
         validate_program(data, program, offset, runes, diags);
         runes::validate_invalid_exports(data, program, true, None, offset, diags);
         validate_illegal_default_export(program, offset, diags);
+        if runes && lint.effect_to_derived {
+            effect_to_derived::validate(data, program, offset, diags);
+        }
     }
 
     validate_module_program(parsed, diags);
@@ -55,6 +61,9 @@ pub fn validate(
     validate_svelte_options_warnings(component, data, runes, diags);
     validate_custom_element_props(data, diags);
     validate_script_context(component, runes, diags);
+    if lint.unused_declarations {
+        unused::validate(component, data, parsed, diags);
+    }
 }
 
 fn validate_script_context(component: &Component, runes: bool, diags: &mut Vec<Diagnostic>) {
@@ -233,7 +242,7 @@ fn validate_snippet_exports(component: &Component, parsed: &JsAst, diags: &mut V
                 .store
                 .get(svelte_ast::NodeId(i))
                 .as_snippet_block()
-                .map(|s| s.name(&component.source))
+                .map(|s| s.name(component.source))
         })
         .collect();
 
@@ -384,21 +393,19 @@ fn validate_custom_element_props(data: &AnalysisData, diags: &mut Vec<Diagnostic
         return;
     }
 
-    let span = if let Some(rest_span) = props.rest_pattern_span {
-        rest_span
-    } else {
-        data.script
-            .info
-            .as_ref()
-            .and_then(|s| {
-                s.declarations
-                    .iter()
-                    .find(|d| d.is_rune == Some(RuneKind::Props))
-                    .map(|d| d.span)
-            })
-            .unwrap_or_else(|| {
-                panic!("data.props exists but no $props() declaration in script info")
-            })
+    let span = props.rest_pattern_span.or_else(|| {
+        data.script.info.as_ref().and_then(|s| {
+            s.declarations
+                .iter()
+                .find(|d| d.is_rune == Some(RuneKind::Props))
+                .map(|d| d.span)
+        })
+    });
+    let Some(span) = span else {
+        diags.push(Diagnostic::internal_error(
+            "$props() declaration missing from script info".to_string(),
+        ));
+        return;
     };
 
     diags.push(Diagnostic::warning(
@@ -417,7 +424,7 @@ fn validate_svelte_options_warnings(
         return;
     };
 
-    for attr in &options.attributes {
+    for attr in options.attributes {
         let kind = match attr.html_name() {
             "accessors" if runes => Some(DiagnosticKind::OptionsDeprecatedAccessors),
             "immutable" if runes => Some(DiagnosticKind::OptionsDeprecatedImmutable),
@@ -41,7 +41,7 @@ pub(super) fn validate(
 }
 
 struct TemplateValidator<'a, 'b> {
-    component: &'a Component,
+    component: &'a Component<'a>,
     data: &'a AnalysisData<'a>,
     parsed: &'a JsAst<'a>,
     diags: &'b mut Vec<Diagnostic>,
@@ -70,26 +70,26 @@ impl<'a> TemplateValidator<'a, '_> {
                 }
                 Node::Element(el) => {
                     let f = el.fragment;
-                    let attrs = el.attributes.clone();
-                    self.visit_attributes(&attrs, in_dynamic_block);
+                    let attrs = el.attributes;
+                    self.visit_attributes(attrs, in_dynamic_block);
                     self.visit_fragment(f, in_dynamic_block);
                 }
                 Node::SlotElementLegacy(el) => {
                     let f = el.fragment;
-                    let attrs = el.attributes.clone();
-                    self.visit_attributes(&attrs, in_dynamic_block);
+                    let attrs = el.attributes;
+                    self.visit_attributes(attrs, in_dynamic_block);
                     self.visit_fragment(f, in_dynamic_block);
                 }
                 Node::ComponentNode(node) => {
                     let f = node.fragment;
-                    let attrs = node.attributes.clone();
-                    self.visit_attributes(&attrs, in_dynamic_block);
+                    let attrs = node.attributes;
+                    self.visit_attributes(attrs, in_dynamic_block);
                     self.visit_fragment(f, in_dynamic_block);
                 }
                 Node::SvelteComponentLegacy(node) => {
                     let f = node.fragment;
-                    let attrs = node.attributes.clone();
-                    self.visit_attributes(&attrs, in_dynamic_block);
+                    let attrs = node.attributes;
+                    self.visit_attributes(attrs, in_dynamic_block);
                     self.visit_fragment(f, in_dynamic_block);
                 }
                 Node::IfBlock(block) => {
@@ -142,36 +142,36 @@ impl<'a> TemplateValidator<'a, '_> {
                 }
                 Node::SvelteFragmentLegacy(node) => {
                     let f = node.fragment;
-                    let attrs = node.attributes.clone();
-                    self.visit_attributes(&attrs, in_dynamic_block);
+                    let attrs = node.attributes;
+                    self.visit_attributes(attrs, in_dynamic_block);
                     self.visit_fragment(f, in_dynamic_block);
                 }
                 Node::SvelteElement(el) => {
                     let tag = el.this_expr().cloned();
                     let f = el.fragment;
-                    let attrs = el.attributes.clone();
+                    let attrs = el.attributes;
                     if let Some(tag_ref) = tag.as_ref() {
                         self.visit_expr_ref(tag_ref, false, in_dynamic_block);
                     }
-                    self.visit_attributes(&attrs, in_dynamic_block);
+                    self.visit_attributes(attrs, in_dynamic_block);
                     self.visit_fragment(f, in_dynamic_block);
                 }
                 Node::SvelteWindow(node) => {
-                    let attrs = node.attributes.clone();
-                    self.visit_attributes(&attrs, in_dynamic_block);
+                    let attrs = node.attributes;
+                    self.visit_attributes(attrs, in_dynamic_block);
                 }
                 Node::SvelteDocument(node) => {
-                    let attrs = node.attributes.clone();
-                    self.visit_attributes(&attrs, in_dynamic_block);
+                    let attrs = node.attributes;
+                    self.visit_attributes(attrs, in_dynamic_block);
                 }
                 Node::SvelteBody(node) => {
-                    let attrs = node.attributes.clone();
-                    self.visit_attributes(&attrs, in_dynamic_block);
+                    let attrs = node.attributes;
+                    self.visit_attributes(attrs, in_dynamic_block);
                 }
                 Node::SvelteBoundary(node) => {
                     let f = node.fragment;
-                    let attrs = node.attributes.clone();
-                    self.visit_attributes(&attrs, in_dynamic_block);
+                    let attrs = node.attributes;
+                    self.visit_attributes(attrs, in_dynamic_block);
                     self.visit_fragment(f, in_dynamic_block);
                 }
                 Node::AwaitBlock(node) => {
@@ -201,7 +201,7 @@ impl<'a> TemplateValidator<'a, '_> {
                     self.visit_expr_ref(&attr.expression, false, in_dynamic_block);
                 }
                 Attribute::ConcatenationAttribute(attr) => {
-                    for part in &attr.parts {
+                    for part in attr.parts {
                         if let ConcatPart::Dynamic { expr, .. } = part {
                             self.visit_expr_ref(expr, false, in_dynamic_block);
                         }
@@ -218,7 +218,7 @@ impl<'a> TemplateValidator<'a, '_> {
                         self.visit_expr_ref(&attr.expression, false, in_dynamic_block);
                     }
                     StyleDirectiveValue::Concatenation(parts) => {
-                        for part in parts {
+                        for part in *parts {
                             if let ConcatPart::Dynamic { expr, .. } = part {
                                 self.visit_expr_ref(expr, false, in_dynamic_block);
                             }
@@ -10,7 +10,7 @@ use oxc_ast_visit::walk::{
     walk_property_definition,
 };
 use oxc_span::GetSpan;
-use svelte_diagnostics::{Diagnostic, DiagnosticKind};
+use svelte_diagnostics::{Diagnostic, DiagnosticKind, TextEdit};
 use svelte_span::Span;
 
 use crate::utils::script_info::{detect_rune, detect_rune_from_call};
@@ -325,6 +325,7 @@ fn validate_state_referenced_locally_derived(
         in_state_rune_arg: false,
         call_depth_offset: 0,
         in_illegal_prop_member_object: false,
+        derivable_init: None,
         _phantom: std::marker::PhantomData,
     };
     v.visit_program(program);
@@ -340,6 +341,8 @@ struct StateRefLocallyValidator<'a, 'b> {
     call_depth_offset: u32,
 
     in_illegal_prop_member_object: bool,
+
+    derivable_init: Option<oxc_span::Span>,
     _phantom: std::marker::PhantomData<&'a ()>,
 }
 
@@ -396,13 +399,45 @@ impl<'a> Visit<'a> for StateRefLocallyValidator<'a, '_> {
         } else {
             "closure"
         };
-        self.diags.push(Diagnostic::warning(
-            DiagnosticKind::StateReferencedLocally {
-                name: name.to_string(),
-                type_: type_.into(),
-            },
-            Span::new(ident.span.start + self.offset, ident.span.end + self.offset),
-        ));
+        let fixes = match self.derivable_init {
+            Some(init) if !self.in_state_rune_arg && self.call_depth_offset == 0 => vec![
+                TextEdit::insert(init.start + self.offset, "$derived("),
+                TextEdit::insert(init.end + self.offset, ")"),
+            ],
+            _ => Vec::new(),
+        };
+        self.diags.push(
+            Diagnostic::warning(
+                DiagnosticKind::StateReferencedLocally {
+                    name: name.to_string(),
+                    type_: type_.into(),
+                },
+                Span::new(ident.span.start + self.offset, ident.span.end + self.offset),
+            )
+            .with_fixes(fixes),
+        );
+    }
+
+    fn visit_program(&mut self, program: &oxc_ast::ast::Program<'a>) {
+        for stmt in &program.body {
+            let Statement::VariableDeclaration(decl) = stmt else {
+                self.visit_statement(stmt);
+                continue;
+            };
+            if decl.kind.is_var() {
+                self.visit_statement(stmt);
+                continue;
+            }
+            for declarator in &decl.declarations {
+                self.derivable_init = declarator
+                    .init
+                    .as_ref()
+                    .filter(|init| detect_rune(init).is_none())
+                    .map(GetSpan::span);
+                self.visit_variable_declarator(declarator);
+                self.derivable_init = None;
+            }
+        }
     }
 
     fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
@@ -441,9 +476,11 @@ impl<'a> Visit<'a> for StateRefLocallyValidator<'a, '_> {
     ) {
         let prev_state_arg = std::mem::replace(&mut self.in_state_rune_arg, false);
         let prev_call_depth = std::mem::replace(&mut self.call_depth_offset, 0);
+        let prev_init = self.derivable_init.take();
         walk_arrow_function_expression(self, arrow);
         self.in_state_rune_arg = prev_state_arg;
         self.call_depth_offset = prev_call_depth;
+        self.derivable_init = prev_init;
     }
 
     fn visit_function(
@@ -453,9 +490,11 @@ impl<'a> Visit<'a> for StateRefLocallyValidator<'a, '_> {
     ) {
         let prev_state_arg = std::mem::replace(&mut self.in_state_rune_arg, false);
         let prev_call_depth = std::mem::replace(&mut self.call_depth_offset, 0);
+        let prev_init = self.derivable_init.take();
         walk_function(self, func, flags);
         self.in_state_rune_arg = prev_state_arg;
         self.call_depth_offset = prev_call_depth;
+        self.derivable_init = prev_init;
     }
 
     fn visit_static_member_expression(&mut self, expr: &oxc_ast::ast::StaticMemberExpression<'a>) {
@@ -0,0 +1,244 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{
+    BindingPattern, Expression, ImportDeclarationSpecifier, Program, Statement, VariableDeclarator,
+};
+use oxc_span::GetSpan;
+use rustc_hash::FxHashSet;
+use svelte_ast::{Component, Node};
+use svelte_component_semantics::SymbolId;
+use svelte_diagnostics::{Diagnostic, DiagnosticKind};
+use svelte_span::Span;
+
+use crate::types::data::{IgnoreData, ParentKind};
+use crate::types::script::RuneKind;
+use crate::utils::script_info::detect_rune_from_call;
+use crate::{AnalysisData, JsAst};
+
+pub(super) fn validate(
+    component: &Component,
+    data: &AnalysisData,
+    parsed: &JsAst,
+    diags: &mut Vec<Diagnostic>,
+) {
+    let component_tags = component_tag_symbols(component, data);
+    if let Some(program) = &parsed.module_program {
+        let offset = parsed.module_script_content_span.map_or(0, |s| s.start);
+        validate_program(data, program, offset, &component_tags, diags);
+    }
+    if let Some(program) = &parsed.program {
+        let offset = parsed.script_content_span.map_or(0, |s| s.start);
+        validate_program(data, program, offset, &component_tags, diags);
+    }
+    validate_snippets(component, data, diags);
+}
+
+fn validate_program(
+    data: &AnalysisData,
+    program: &Program<'_>,
+    offset: u32,
+    component_tags: &FxHashSet<SymbolId>,
+    diags: &mut Vec<Diagnostic>,
+) {
+    let mut ignore = IgnoreData::new();
+    ignore.scan_program_comments(program, true);
+    let mut emit = |kind: DiagnosticKind, span: oxc_span::Span, stmt: &Statement<'_>| {
+        if !ignore.is_ignored_at_span(stmt.span().start, kind.code()) {
+            diags.push(Diagnostic::warning(
+                kind,
+                Span::new(span.start + offset, span.end + offset),
+            ));
+        }
+    };
+
+    for stmt in &program.body {
+        match stmt {
+            Statement::ImportDeclaration(decl) if !decl.import_kind.is_type() => {
+                let Some(specifiers) = &decl.specifiers else {
+                    continue;
+                };
+                for spec in specifiers {
+                    if let ImportDeclarationSpecifier::ImportSpecifier(s) = spec
+                        && s.import_kind.is_type()
+                    {
+                        continue;
+                    }
+                    let local = spec.local();
+                    let Some(sym) = local.symbol_id.get() else {
+                        continue;
+                    };
+                    if component_tags.contains(&sym) {
+                        continue;
+                    }
+                    let name = local.name.to_string();
+                    let refs = data.scoping.get_resolved_reference_ids(sym);
+                    if refs.is_empty() {
+                        emit(DiagnosticKind::UnusedImport { name }, local.span, stmt);
+                    } else if refs.iter().all(|&ref_id| {
+                        is_type_position(data, data.scoping.get_reference(ref_id).node_id())
+                    }) {
+                        emit(
+                            DiagnosticKind::ImportOnlyUsedAsType { name },
+                            local.span,
+                            stmt,
+                        );
+                    }
+                }
+            }
+            Statement::VariableDeclaration(decl) => {
+                for declarator in &decl.declarations {
+                    match declarator_rune(declarator) {
+                        Some(RuneKind::Props) => {
+                            let BindingPattern::ObjectPattern(pattern) = &declarator.id else {
+                                continue;
+                            };
+                            for prop in &pattern.properties {
+                                let (ident, bindable) = match &prop.value {
+                                    BindingPattern::BindingIdentifier(ident) => (ident, false),
+                                    BindingPattern::AssignmentPattern(assign) => {
+                                        let BindingPattern::BindingIdentifier(ident) = &assign.left
+                                        else {
+                                            continue;
+                                        };
+                                        let bindable = matches!(
+                                            &assign.right,
+                                            Expression::CallExpression(call)
+                                                if detect_rune_from_call(call) == Some(RuneKind::Bindable)
+                                        );
+                                        (ident, bindable)
+                                    }
+                                    _ => continue,
+                                };
+                                let Some(sym) = ident.symbol_id.get() else {
+                                    continue;
+                                };
+                                if has_read(data, sym) || (bindable && has_write(data, sym)) {
+                                    continue;
+                                }
+                                let name = prop
+                                    .key
+                                    .static_name()
+                                    .map_or_else(|| ident.name.to_string(), |n| n.to_string());
+                                emit(DiagnosticKind::UnusedProp { name }, ident.span, stmt);
+                            }
+                        }
+                        Some(RuneKind::State | RuneKind::StateRaw) => {
+                            let BindingPattern::BindingIdentifier(ident) = &declarator.id else {
+                                continue;
+                            };
+                            let Some(sym) = ident.symbol_id.get() else {
+                                continue;
+                            };
+                            if !has_read(data, sym) {
+                                emit(
+                                    DiagnosticKind::UnusedState {
+                                        name: ident.name.to_string(),
+                                    },
+                                    ident.span,
+                                    stmt,
+                                );
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn validate_snippets(component: &Component, data: &AnalysisData, diags: &mut Vec<Diagnostic>) {
+    let mut snippets: Vec<(svelte_ast::NodeId, SymbolId)> = data
+        .template
+        .snippets
+        .snippet_name_symbols
+        .iter()
+        .map(|(&sym, &id)| (id, sym))
+        .collect();
+    snippets.sort_unstable_by_key(|(id, _)| id.0);
+
+    for (id, sym) in snippets {
+        let Node::SnippetBlock(block) = component.store.get(id) else {
+            continue;
+        };
+        if data.parent(id).is_some_and(|parent| {
+            matches!(
+                parent.kind,
+                ParentKind::ComponentNode
+                    | ParentKind::SvelteComponentLegacy
+                    | ParentKind::SvelteBoundary
+            )
+        }) {
+            continue;
+        }
+        if !data.scoping.get_resolved_reference_ids(sym).is_empty()
+            || data.output.ignore_data.is_ignored(id, "unused_snippet")
+        {
+            continue;
+        }
+        let name = block.name(component.source);
+        let start = block.decl.span.start;
+        diags.push(Diagnostic::warning(
+            DiagnosticKind::UnusedSnippet {
+                name: name.to_string(),
+            },
+            Span::new(start, start + name.len() as u32),
+        ));
+    }
+}
+
+fn component_tag_symbols(component: &Component, data: &AnalysisData) -> FxHashSet<SymbolId> {
+    component
+        .store
+        .iter_nodes()
+        .filter_map(|node| match node {
+            Node::ComponentNode(cn) => data.elements.flags.component_binding_sym(cn.id),
+            _ => None,
+        })
+        .collect()
+}
+
+fn declarator_rune(declarator: &VariableDeclarator<'_>) -> Option<RuneKind> {
+    match declarator.init.as_ref()? {
+        Expression::CallExpression(call) => detect_rune_from_call(call),
+        _ => None,
+    }
+}
+
+fn has_read(data: &AnalysisData, sym: SymbolId) -> bool {
+    data.scoping
+        .get_resolved_reference_ids(sym)
+        .iter()
+        .any(|&ref_id| data.scoping.get_reference(ref_id).is_read())
+}
+
+fn has_write(data: &AnalysisData, sym: SymbolId) -> bool {
+    data.scoping
+        .get_resolved_reference_ids(sym)
+        .iter()
+        .any(|&ref_id| data.scoping.get_reference(ref_id).is_write())
+}
+
+fn is_type_position(data: &AnalysisData, node_id: oxc_syntax::node::NodeId) -> bool {
+    let mut current = Some(node_id);
+    while let Some(id) = current {
+        if matches!(
+            data.scoping.js_kind(id),
+            Some(
+                AstKind::TSTypeAnnotation(_)
+                    | AstKind::TSTypeReference(_)
+                    | AstKind::TSTypeQuery(_)
+                    | AstKind::TSTypeAliasDeclaration(_)
+                    | AstKind::TSInterfaceDeclaration(_)
+                    | AstKind::TSInterfaceHeritage(_)
+                    | AstKind::TSClassImplements(_)
+                    | AstKind::TSTypeParameterInstantiation(_)
+                    | AstKind::TSImportType(_)
+            )
+        ) {
+            return true;
+        }
+        current = data.scoping.js_parent_id(id);
+    }
+    false
+}
@@ -5,7 +5,7 @@ pub(crate) struct VisitContext<'d, 'a> {
     pub scope: ScopeId,
     pub data: &'d mut AnalysisData<'a>,
     pub(crate) parsed: Option<&'d JsAst<'a>>,
-    pub store: &'d svelte_ast::AstStore,
+    pub store: &'d svelte_ast::AstStore<'a>,
     parents: Vec<ParentRef>,
     element_name: Option<String>,
     pub source: &'d str,
@@ -21,7 +21,7 @@ impl<'d, 'a> VisitContext<'d, 'a> {
     pub fn new(
         scope: ScopeId,
         data: &'d mut AnalysisData<'a>,
-        store: &'d svelte_ast::AstStore,
+        store: &'d svelte_ast::AstStore<'a>,
         source: &'d str,
         runes: bool,
         component_name: &'d str,
@@ -47,7 +47,7 @@ impl<'d, 'a> VisitContext<'d, 'a> {
     pub fn with_parsed(
         scope: ScopeId,
         data: &'d mut AnalysisData<'a>,
-        store: &'d svelte_ast::AstStore,
+        store: &'d svelte_ast::AstStore<'a>,
         parsed: &'d JsAst<'a>,
         source: &'d str,
         runes: bool,
@@ -37,12 +37,12 @@ pub(crate) fn walk_template(
                 for v in visitors.iter_mut() {
                     v.visit_element(el, ctx);
                 }
-                let prev_name = ctx.replace_element_name(el.name.clone());
+                let prev_name = ctx.replace_element_name(el.name.to_string());
                 ctx.push(ParentRef {
                     id: el.id,
                     kind: ParentKind::Element,
                 });
-                walk_attributes(&el.attributes, ctx, visitors);
+                walk_attributes(el.attributes, ctx, visitors);
                 walk_template(el.fragment, ctx, visitors);
                 ctx.pop();
                 ctx.set_element_name(prev_name);
@@ -58,7 +58,7 @@ pub(crate) fn walk_template(
                     id: el.id,
                     kind: ParentKind::SlotElementLegacy,
                 });
-                walk_attributes(&el.attributes, ctx, visitors);
+                walk_attributes(el.attributes, ctx, visitors);
                 walk_template(el.fragment, ctx, visitors);
                 ctx.pop();
                 for v in visitors.iter_mut() {
@@ -138,9 +138,9 @@ pub(crate) fn walk_template(
                 }
                 walk_component_like(
                     cn.id,
-                    &cn.attributes,
+                    cn.attributes,
                     cn.fragment,
-                    &cn.legacy_slots,
+                    cn.legacy_slots,
                     ParentKind::ComponentNode,
                     ctx,
                     visitors,
@@ -152,9 +152,9 @@ pub(crate) fn walk_template(
                 }
                 walk_component_like(
                     cn.id,
-                    &cn.attributes,
+                    cn.attributes,
                     cn.fragment,
-                    &cn.legacy_slots,
+                    cn.legacy_slots,
                     ParentKind::SvelteComponentLegacy,
                     ctx,
                     visitors,
@@ -223,7 +223,7 @@ pub(crate) fn walk_template(
                     id: el.id,
                     kind: ParentKind::SvelteFragmentLegacy,
                 });
-                walk_attributes(&el.attributes, ctx, visitors);
+                walk_attributes(el.attributes, ctx, visitors);
                 walk_template(el.fragment, ctx, visitors);
                 ctx.pop();
                 for v in visitors.iter_mut() {
@@ -241,7 +241,7 @@ pub(crate) fn walk_template(
                 if let Some(tag_ref) = el.this_expr() {
                     dispatch_expr(visitors, el.id, tag_ref, ctx);
                 }
-                walk_attributes(&el.attributes, ctx, visitors);
+                walk_attributes(el.attributes, ctx, visitors);
                 let saved = ctx.scope;
                 ctx.scope = ctx.child_scope_by_id(el.fragment, saved);
                 walk_template(el.fragment, ctx, visitors);
@@ -259,7 +259,7 @@ pub(crate) fn walk_template(
                     id: w.id,
                     kind: ParentKind::SvelteWindow,
                 });
-                walk_attributes(&w.attributes, ctx, visitors);
+                walk_attributes(w.attributes, ctx, visitors);
                 ctx.pop();
             }
             Node::SvelteDocument(d) => {
@@ -270,7 +270,7 @@ pub(crate) fn walk_template(
                     id: d.id,
                     kind: ParentKind::SvelteDocument,
                 });
-                walk_attributes(&d.attributes, ctx, visitors);
+                walk_attributes(d.attributes, ctx, visitors);
                 ctx.pop();
             }
             Node::SvelteBody(b) => {
@@ -281,7 +281,7 @@ pub(crate) fn walk_template(
                     id: b.id,
                     kind: ParentKind::SvelteBody,
                 });
-                walk_attributes(&b.attributes, ctx, visitors);
+                walk_attributes(b.attributes, ctx, visitors);
                 ctx.pop();
             }
             Node::SvelteBoundary(b) => {
@@ -292,7 +292,7 @@ pub(crate) fn walk_template(
                     id: b.id,
                     kind: ParentKind::SvelteBoundary,
                 });
-                walk_attributes(&b.attributes, ctx, visitors);
+                walk_attributes(b.attributes, ctx, visitors);
                 let saved = ctx.scope;
                 ctx.scope = ctx.child_scope_by_id(b.fragment, saved);
                 walk_template(b.fragment, ctx, visitors);
@@ -495,7 +495,7 @@ fn walk_attributes(
                     id: a.id,
                     kind: ParentKind::ConcatenationAttribute,
                 });
-                dispatch_concat_exprs(visitors, &a.parts, ctx);
+                dispatch_concat_exprs(visitors, a.parts, ctx);
                 for v in visitors.iter_mut() {
                     v.leave_concatenation_attribute(a, ctx);
                 }
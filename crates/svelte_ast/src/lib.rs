@@ -1,6 +1,9 @@
 use svelte_span::GetSpan;
 pub use svelte_span::Span;
 
+pub use oxc_allocator::{Allocator, Vec as ArenaVec};
+pub use oxc_span::Atom;
+
 mod expr_ref;
 pub use expr_ref::{ExprRef, OxcNodeId, StmtRef};
 
@@ -198,27 +201,27 @@ pub struct NodeId(pub u32);
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct FragmentId(pub u32);
 
-pub struct Component {
+pub struct Component<'a> {
     pub root: FragmentId,
-    pub store: AstStore,
+    pub store: AstStore<'a>,
 
-    pub instance_script: Option<Script>,
+    pub instance_script: Option<Script<'a>>,
 
-    pub module_script: Option<Script>,
-    pub css: Option<RawBlock>,
-    pub options: Option<SvelteOptions>,
+    pub module_script: Option<Script<'a>>,
+    pub css: Option<RawBlock<'a>>,
+    pub options: Option<SvelteOptions<'a>>,
 
-    pub source: String,
+    pub source: &'a str,
 }
 
-impl Component {
+impl<'a> Component<'a> {
     pub fn new(
-        source: String,
+        source: &'a str,
         root: FragmentId,
-        store: AstStore,
-        instance_script: Option<Script>,
-        module_script: Option<Script>,
-        css: Option<RawBlock>,
+        store: AstStore<'a>,
+        instance_script: Option<Script<'a>>,
+        module_script: Option<Script<'a>>,
+        css: Option<RawBlock<'a>>,
     ) -> Self {
         Self {
             root,
@@ -231,9 +234,9 @@ impl Component {
         }
     }
 
-    pub fn dummy_for_standalone_module(source: String) -> Self {
-        let mut store = AstStore::default();
-        let root = store.push_fragment(FragmentRole::Root, vec![]);
+    pub fn dummy_for_standalone_module(allocator: &'a Allocator, source: &'a str) -> Self {
+        let mut store = AstStore::new(allocator);
+        let root = store.reserve_fragment(FragmentRole::Root);
         Self {
             root,
             store,
@@ -245,7 +248,7 @@ impl Component {
         }
     }
 
-    pub fn root_fragment(&self) -> &Fragment {
+    pub fn root_fragment(&self) -> &Fragment<'a> {
         self.store.fragment(self.root)
     }
 
@@ -261,7 +264,7 @@ impl Component {
         self.store.fragments_len()
     }
 
-    pub fn source_text(&self, span: Span) -> &str {
+    pub fn source_text(&self, span: Span) -> &'a str {
         &self.source[span.start as usize..span.end as usize]
     }
 }
@@ -287,16 +290,16 @@ pub enum FragmentRole {
     AwaitCatch,
 }
 
-pub struct Fragment {
+pub struct Fragment<'a> {
     pub id: FragmentId,
     pub role: FragmentRole,
-    pub nodes: Vec<NodeId>,
+    pub nodes: ArenaVec<'a, NodeId>,
 
     pub owner: Option<NodeId>,
 }
 
-impl Fragment {
-    pub fn new(id: FragmentId, role: FragmentRole, nodes: Vec<NodeId>) -> Self {
+impl<'a> Fragment<'a> {
+    pub fn new(id: FragmentId, role: FragmentRole, nodes: ArenaVec<'a, NodeId>) -> Self {
         Self {
             id,
             role,
@@ -305,11 +308,11 @@ impl Fragment {
         }
     }
 
-    pub fn empty(id: FragmentId, role: FragmentRole) -> Self {
+    pub fn empty(id: FragmentId, role: FragmentRole, allocator: &'a Allocator) -> Self {
         Self {
             id,
             role,
-            nodes: vec![],
+            nodes: ArenaVec::new_in(allocator),
             owner: None,
         }
     }
@@ -324,12 +327,12 @@ impl Fragment {
 }
 
 macro_rules! impl_node_enum {
-    ( $( $Variant:ident($Type:ident) => $is:ident / $as:ident ),+ $(,)? ) => {
-        pub enum Node {
+    ( $( $Variant:ident($Type:ty) => $is:ident / $as:ident ),+ $(,)? ) => {
+        pub enum Node<'a> {
             $( $Variant($Type), )+
         }
 
-        impl Node {
+        impl<'a> Node<'a> {
             pub fn node_id(&self) -> NodeId {
                 match self { $( Node::$Variant(n) => n.id, )+ }
             }
@@ -357,10 +360,10 @@ macro_rules! impl_node_enum {
 }
 
 impl_node_enum! {
-    Text(Text)                   => is_text / as_text,
-    Element(Element)             => is_element / as_element,
-    SlotElementLegacy(SlotElementLegacy) => is_slot_element_legacy / as_slot_element_legacy,
-    ComponentNode(ComponentNode) => is_component_node / as_component_node,
+    Text(Text<'a>)               => is_text / as_text,
+    Element(Element<'a>)         => is_element / as_element,
+    SlotElementLegacy(SlotElementLegacy<'a>) => is_slot_element_legacy / as_slot_element_legacy,
+    ComponentNode(ComponentNode<'a>) => is_component_node / as_component_node,
     Comment(Comment)             => is_comment / as_comment,
     ExpressionTag(ExpressionTag) => is_expression_tag / as_expression_tag,
     IfBlock(IfBlock)             => is_if_block / as_if_block,
@@ -369,16 +372,16 @@ impl_node_enum! {
     RenderTag(RenderTag)         => is_render_tag / as_render_tag,
     HtmlTag(HtmlTag)             => is_html_tag / as_html_tag,
     ConstTag(ConstTag)           => is_const_tag / as_const_tag,
-    DebugTag(DebugTag)           => is_debug_tag / as_debug_tag,
+    DebugTag(DebugTag<'a>)       => is_debug_tag / as_debug_tag,
     KeyBlock(KeyBlock)           => is_key_block / as_key_block,
-    SvelteHead(SvelteHead)       => is_svelte_head / as_svelte_head,
-    SvelteFragmentLegacy(SvelteFragmentLegacy) => is_svelte_fragment_legacy / as_svelte_fragment_legacy,
-    SvelteComponentLegacy(SvelteComponentLegacy) => is_svelte_component_legacy / as_svelte_component_legacy,
-    SvelteElement(SvelteElement) => is_svelte_element / as_svelte_element,
-    SvelteWindow(SvelteWindow)       => is_svelte_window / as_svelte_window,
-    SvelteDocument(SvelteDocument)   => is_svelte_document / as_svelte_document,
-    SvelteBody(SvelteBody)           => is_svelte_body / as_svelte_body,
-    SvelteBoundary(SvelteBoundary)   => is_svelte_boundary / as_svelte_boundary,
+    SvelteHead(SvelteHead<'a>)   => is_svelte_head / as_svelte_head,
+    SvelteFragmentLegacy(SvelteFragmentLegacy<'a>) => is_svelte_fragment_legacy / as_svelte_fragment_legacy,
+    SvelteComponentLegacy(SvelteComponentLegacy<'a>) => is_svelte_component_legacy / as_svelte_component_legacy,
+    SvelteElement(SvelteElement<'a>) => is_svelte_element / as_svelte_element,
+    SvelteWindow(SvelteWindow<'a>)   => is_svelte_window / as_svelte_window,
+    SvelteDocument(SvelteDocument<'a>) => is_svelte_document / as_svelte_document,
+    SvelteBody(SvelteBody<'a>)       => is_svelte_body / as_svelte_body,
+    SvelteBoundary(SvelteBoundary<'a>) => is_svelte_boundary / as_svelte_boundary,
     AwaitBlock(AwaitBlock)           => is_await_block / as_await_block,
     Error(ErrorNode)                 => is_error / as_error,
 }
@@ -388,52 +391,51 @@ pub struct ErrorNode {
     pub span: Span,
 }
 
-pub struct Text {
+pub struct Text<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub decoded: Option<String>,
+    pub decoded: Option<&'a str>,
 }
 
-impl Text {
-    pub fn raw_value<'a>(&self, source: &'a str) -> &'a str {
+impl<'a> Text<'a> {
+    pub fn raw_value<'s>(&self, source: &'s str) -> &'s str {
         &source[self.span.start as usize..self.span.end as usize]
     }
 
-    pub fn value<'a>(&'a self, source: &'a str) -> &'a str {
-        self.decoded
-            .as_deref()
-            .unwrap_or_else(|| self.raw_value(source))
+    pub fn value<'s>(&'s self, source: &'s str) -> &'s str {
+        self.decoded.unwrap_or_else(|| self.raw_value(source))
     }
 }
 
-pub struct Element {
+pub struct Element<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub name: String,
+    pub name: Atom<'a>,
     pub self_closing: bool,
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
     pub fragment: FragmentId,
 }
 
-pub struct SlotElementLegacy {
+pub struct SlotElementLegacy<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
     pub fragment: FragmentId,
 }
 
-pub struct ComponentNode {
+pub struct ComponentNode<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub name: String,
+    pub name: Atom<'a>,
     pub self_closing: bool,
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
     pub fragment: FragmentId,
-    pub legacy_slots: Vec<LegacySlot>,
+    pub legacy_slots: &'a [LegacySlot<'a>],
 }
 
-pub struct LegacySlot {
-    pub name: String,
+#[derive(Clone, Copy)]
+pub struct LegacySlot<'a> {
+    pub name: Atom<'a>,
     pub fragment: FragmentId,
 }
 
@@ -512,10 +514,10 @@ pub struct ConstTag {
     pub decl: StmtRef,
 }
 
-pub struct DebugTag {
+pub struct DebugTag<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub identifier_refs: Vec<ExprRef>,
+    pub identifier_refs: &'a [ExprRef],
 }
 
 pub struct KeyBlock {
@@ -525,68 +527,85 @@ pub struct KeyBlock {
     pub fragment: FragmentId,
 }
 
-pub struct SvelteHead {
+pub struct SvelteHead<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
     pub fragment: FragmentId,
 }
 
-pub struct SvelteFragmentLegacy {
+pub struct SvelteFragmentLegacy<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
     pub fragment: FragmentId,
 }
 
-pub struct SvelteElement {
+pub struct SvelteElement<'a> {
     pub id: NodeId,
     pub span: Span,
 
     pub tag_span: Span,
 
     pub static_tag: bool,
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
     pub fragment: FragmentId,
 }
 
-pub struct SvelteComponentLegacy {
+pub struct SvelteComponentLegacy<'a> {
     pub id: NodeId,
     pub span: Span,
     pub self_closing: bool,
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
     pub fragment: FragmentId,
-    pub legacy_slots: Vec<LegacySlot>,
+    pub legacy_slots: &'a [LegacySlot<'a>],
 }
 
 pub struct ComponentLikeView<'a> {
     pub id: NodeId,
     pub name: &'a str,
-    pub attributes: &'a [Attribute],
+    pub attributes: &'a [Attribute<'a>],
     pub fragment: FragmentId,
-    pub legacy_slots: &'a [LegacySlot],
+    pub legacy_slots: &'a [LegacySlot<'a>],
     pub span: Span,
     pub self_closing: bool,
 }
 
-impl Node {
-    pub fn as_component_like(&self) -> Option<ComponentLikeView<'_>> {
+impl<'a> Node<'a> {
+    pub fn attributes(&self) -> &'a [Attribute<'a>] {
+        match self {
+            Node::Element(n) => n.attributes,
+            Node::SlotElementLegacy(n) => n.attributes,
+            Node::ComponentNode(n) => n.attributes,
+            Node::SvelteHead(n) => n.attributes,
+            Node::SvelteFragmentLegacy(n) => n.attributes,
+            Node::SvelteComponentLegacy(n) => n.attributes,
+            Node::SvelteElement(n) => n.attributes,
+            Node::SvelteWindow(n) => n.attributes,
+            Node::SvelteDocument(n) => n.attributes,
+            Node::SvelteBody(n) => n.attributes,
+            Node::SvelteBoundary(n) => n.attributes,
+            _ => &[],
+        }
+    }
+
+    pub fn as_component_like(&self) -> Option<ComponentLikeView<'a>> {
         match self {
             Node::ComponentNode(cn) => Some(ComponentLikeView {
                 id: cn.id,
-                name: &cn.name,
-                attributes: &cn.attributes,
+                name: cn.name.as_str(),
+                attributes: cn.attributes,
                 fragment: cn.fragment,
-                legacy_slots: &cn.legacy_slots,
+                legacy_slots: cn.legacy_slots,
                 span: cn.span,
                 self_closing: cn.self_closing,
             }),
             Node::SvelteComponentLegacy(cn) => Some(ComponentLikeView {
                 id: cn.id,
                 name: SVELTE_COMPONENT,
-                attributes: &cn.attributes,
+                attributes: cn.attributes,
                 fragment: cn.fragment,
-                legacy_slots: &cn.legacy_slots,
+                legacy_slots: cn.legacy_slots,
                 span: cn.span,
                 self_closing: cn.self_closing,
             }),
@@ -595,8 +614,8 @@ impl Node {
     }
 }
 
-impl SvelteComponentLegacy {
-    pub fn this_attribute(&self) -> Option<&Attribute> {
+impl<'a> SvelteComponentLegacy<'a> {
+    pub fn this_attribute(&self) -> Option<&'a Attribute<'a>> {
         self.attributes.iter().find(|a| match a {
             Attribute::ExpressionAttribute(x) => x.name == "this",
             Attribute::StringAttribute(x) => x.name == "this",
@@ -606,7 +625,7 @@ impl SvelteComponentLegacy {
         })
     }
 
-    pub fn this_expr(&self) -> Option<&ExprRef> {
+    pub fn this_expr(&self) -> Option<&'a ExprRef> {
         self.attributes.iter().find_map(|a| match a {
             Attribute::ExpressionAttribute(x) if x.name == "this" => Some(&x.expression),
             _ => None,
@@ -614,8 +633,8 @@ impl SvelteComponentLegacy {
     }
 }
 
-impl SvelteElement {
-    pub fn this_expr(&self) -> Option<&ExprRef> {
+impl<'a> SvelteElement<'a> {
+    pub fn this_expr(&self) -> Option<&'a ExprRef> {
         self.attributes.iter().find_map(|a| match a {
             Attribute::ExpressionAttribute(x) if x.name == "this" => Some(&x.expression),
             _ => None,
@@ -623,7 +642,7 @@ impl SvelteElement {
     }
 }
 
-impl Attribute {
+impl Attribute<'_> {
     pub fn is_svelte_element_this(&self) -> bool {
         match self {
             Attribute::ExpressionAttribute(a) => a.name == "this",
@@ -634,31 +653,31 @@ impl Attribute {
     }
 }
 
-pub struct SvelteWindow {
+pub struct SvelteWindow<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
     pub fragment: FragmentId,
 }
 
-pub struct SvelteDocument {
+pub struct SvelteDocument<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
     pub fragment: FragmentId,
 }
 
-pub struct SvelteBody {
+pub struct SvelteBody<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
     pub fragment: FragmentId,
 }
 
-pub struct SvelteBoundary {
+pub struct SvelteBoundary<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
     pub fragment: FragmentId,
 }
 
@@ -674,18 +693,18 @@ pub struct AwaitBlock {
 }
 
 macro_rules! impl_attr_enum {
-    ( $( $(#[doc = $doc:expr])* $Variant:ident($Type:ident) ),+ $(,)? ) => {
-        pub enum Attribute {
+    ( $( $(#[doc = $doc:expr])* $Variant:ident($Type:ty) ),+ $(,)? ) => {
+        pub enum Attribute<'a> {
             $( $(#[doc = $doc])* $Variant($Type), )+
         }
 
-        impl Clone for Attribute {
+        impl Clone for Attribute<'_> {
             fn clone(&self) -> Self {
                 match self { $( Attribute::$Variant(a) => Attribute::$Variant(a.clone()), )+ }
             }
         }
 
-        impl Attribute {
+        impl Attribute<'_> {
             pub fn id(&self) -> NodeId {
                 match self { $( Attribute::$Variant(a) => a.id, )+ }
             }
@@ -699,50 +718,50 @@ macro_rules! impl_attr_enum {
 
 impl_attr_enum! {
 
-    StringAttribute(StringAttribute),
+    StringAttribute(StringAttribute<'a>),
 
 
 
-    ExpressionAttribute(ExpressionAttribute),
+    ExpressionAttribute(ExpressionAttribute<'a>),
 
-    BooleanAttribute(BooleanAttribute),
+    BooleanAttribute(BooleanAttribute<'a>),
 
-    ConcatenationAttribute(ConcatenationAttribute),
+    ConcatenationAttribute(ConcatenationAttribute<'a>),
 
     SpreadAttribute(SpreadAttribute),
 
-    ClassDirective(ClassDirective),
+    ClassDirective(ClassDirective<'a>),
 
-    StyleDirective(StyleDirective),
+    StyleDirective(StyleDirective<'a>),
 
-    BindDirective(BindDirective),
+    BindDirective(BindDirective<'a>),
 
-    LetDirectiveLegacy(LetDirectiveLegacy),
+    LetDirectiveLegacy(LetDirectiveLegacy<'a>),
 
     UseDirective(UseDirective),
 
 
-    OnDirectiveLegacy(OnDirectiveLegacy),
+    OnDirectiveLegacy(OnDirectiveLegacy<'a>),
 
-    TransitionDirective(TransitionDirective),
+    TransitionDirective(TransitionDirective<'a>),
 
     AnimateDirective(AnimateDirective),
 
     AttachTag(AttachTag),
 }
 
-impl Attribute {
-    pub fn name(&self) -> Option<&str> {
+impl<'a> Attribute<'a> {
+    pub fn name(&self) -> Option<&'a str> {
         match self {
-            Attribute::StringAttribute(a) => Some(&a.name),
-            Attribute::ExpressionAttribute(a) => Some(&a.name),
-            Attribute::BooleanAttribute(a) => Some(&a.name),
-            Attribute::ConcatenationAttribute(a) => Some(&a.name),
-            Attribute::ClassDirective(a) => Some(&a.name),
-            Attribute::StyleDirective(a) => Some(&a.name),
-            Attribute::BindDirective(a) => Some(&a.name),
-            Attribute::LetDirectiveLegacy(a) => Some(&a.name),
-            Attribute::OnDirectiveLegacy(a) => Some(&a.name),
+            Attribute::StringAttribute(a) => Some(a.name.as_str()),
+            Attribute::ExpressionAttribute(a) => Some(a.name.as_str()),
+            Attribute::BooleanAttribute(a) => Some(a.name.as_str()),
+            Attribute::ConcatenationAttribute(a) => Some(a.name.as_str()),
+            Attribute::ClassDirective(a) => Some(a.name.as_str()),
+            Attribute::StyleDirective(a) => Some(a.name.as_str()),
+            Attribute::BindDirective(a) => Some(a.name.as_str()),
+            Attribute::LetDirectiveLegacy(a) => Some(a.name.as_str()),
+            Attribute::OnDirectiveLegacy(a) => Some(a.name.as_str()),
             Attribute::UseDirective(_)
             | Attribute::TransitionDirective(_)
             | Attribute::AnimateDirective(_)
@@ -751,62 +770,62 @@ impl Attribute {
         }
     }
 
-    pub fn html_name(&self) -> &str {
+    pub fn html_name(&self) -> &'a str {
         match self {
-            Attribute::StringAttribute(a) => &a.name,
-            Attribute::ExpressionAttribute(a) => &a.name,
-            Attribute::BooleanAttribute(a) => &a.name,
-            Attribute::ConcatenationAttribute(a) => &a.name,
+            Attribute::StringAttribute(a) => a.name.as_str(),
+            Attribute::ExpressionAttribute(a) => a.name.as_str(),
+            Attribute::BooleanAttribute(a) => a.name.as_str(),
+            Attribute::ConcatenationAttribute(a) => a.name.as_str(),
             _ => "",
         }
     }
 }
 
-impl GetSpan for Attribute {
+impl GetSpan for Attribute<'_> {
     fn span(&self) -> Span {
         self.span()
     }
 }
 
 #[derive(Clone)]
-pub struct StringAttribute {
+pub struct StringAttribute<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub name: String,
+    pub name: Atom<'a>,
     pub value_span: Span,
 }
 
 #[derive(Clone)]
-pub struct ExpressionAttribute {
+pub struct ExpressionAttribute<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub name: String,
+    pub name: Atom<'a>,
     pub expression: ExprRef,
     pub shorthand: bool,
 
-    pub event_name: Option<String>,
+    pub event_name: Option<Atom<'a>>,
 }
 
 #[derive(Clone)]
-pub struct BooleanAttribute {
+pub struct BooleanAttribute<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub name: String,
+    pub name: Atom<'a>,
 }
 
 #[derive(Clone)]
-pub struct ConcatenationAttribute {
+pub struct ConcatenationAttribute<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub name: String,
+    pub name: Atom<'a>,
 
     pub quoted: bool,
-    pub parts: Vec<ConcatPart>,
+    pub parts: &'a [ConcatPart<'a>],
 }
 
 #[derive(Clone)]
-pub enum ConcatPart {
-    Static(String),
+pub enum ConcatPart<'a> {
+    Static(&'a str),
 
     Dynamic { id: NodeId, expr: ExprRef },
 }
@@ -819,50 +838,50 @@ pub struct SpreadAttribute {
 }
 
 #[derive(Clone)]
-pub struct ClassDirective {
+pub struct ClassDirective<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub name: String,
+    pub name: Atom<'a>,
     pub expression: ExprRef,
     pub shorthand: bool,
 }
 
 #[derive(Clone)]
-pub struct StyleDirective {
+pub struct StyleDirective<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub name: String,
+    pub name: Atom<'a>,
     pub expression: ExprRef,
 
     pub shorthand: bool,
-    pub value: StyleDirectiveValue,
+    pub value: StyleDirectiveValue<'a>,
     pub important: bool,
 }
 
 #[derive(Clone)]
-pub enum StyleDirectiveValue {
+pub enum StyleDirectiveValue<'a> {
     Expression,
 
-    String(String),
+    String(&'a str),
 
-    Concatenation(Vec<ConcatPart>),
+    Concatenation(&'a [ConcatPart<'a>]),
 }
 
 #[derive(Clone)]
-pub struct BindDirective {
+pub struct BindDirective<'a> {
     pub id: NodeId,
     pub span: Span,
-    pub name: String,
+    pub name: Atom<'a>,
     pub expression: ExprRef,
     pub shorthand: bool,
 }
 
 #[derive(Clone)]
-pub struct LetDirectiveLegacy {
+pub struct LetDirectiveLegacy<'a> {
     pub id: NodeId,
     pub span: Span,
 
-    pub name: String,
+    pub name: Atom<'a>,
 
     pub name_span: Span,
     pub binding: Option<StmtRef>,
@@ -877,21 +896,21 @@ pub struct UseDirective {
 }
 
 #[derive(Clone)]
-pub struct OnDirectiveLegacy {
+pub struct OnDirectiveLegacy<'a> {
     pub id: NodeId,
     pub span: Span,
 
-    pub name: String,
+    pub name: Atom<'a>,
 
     pub name_span: Span,
     pub expression: Option<ExprRef>,
 
-    pub modifiers: Vec<String>,
+    pub modifiers: &'a [Atom<'a>],
 }
 
-impl OnDirectiveLegacy {
+impl OnDirectiveLegacy<'_> {
     pub fn parsed_modifiers(&self) -> OnDirectiveModifiers {
-        OnDirectiveModifiers::from_modifiers(&self.modifiers)
+        OnDirectiveModifiers::from_modifiers(self.modifiers)
     }
 }
 
@@ -908,7 +927,7 @@ pub struct OnDirectiveModifiers {
 }
 
 impl OnDirectiveModifiers {
-    pub fn from_modifiers(modifiers: &[String]) -> Self {
+    pub fn from_modifiers(modifiers: &[Atom<'_>]) -> Self {
         let mut result = Self {
             stop_propagation: false,
             stop_immediate_propagation: false,
@@ -960,13 +979,13 @@ pub enum TransitionDirection {
 }
 
 #[derive(Clone)]
-pub struct TransitionDirective {
+pub struct TransitionDirective<'a> {
     pub id: NodeId,
     pub span: Span,
     pub name_ref: ExprRef,
     pub expression: Option<ExprRef>,
 
-    pub modifiers: Vec<String>,
+    pub modifiers: &'a [Atom<'a>],
 
     pub direction: TransitionDirection,
 }
@@ -986,17 +1005,33 @@ pub struct AttachTag {
     pub expression: ExprRef,
 }
 
-pub struct Script {
+pub struct Script<'a> {
     pub id: NodeId,
     pub span: Span,
 
     pub content_span: Span,
     pub context: ScriptContext,
-    pub language: ScriptLanguage,
+    /// `None` when the `lang` attribute names an unsupported language.
+    pub language: Option<ScriptLanguage>,
+    pub attributes: &'a [Attribute<'a>],
 
     pub context_deprecated: bool,
 }
 
+impl Script<'_> {
+    pub fn lang<'s>(&self, source: &'s str) -> Option<&'s str> {
+        static_attribute_value(self.attributes, "lang", source)
+    }
+
+    pub fn has_supported_lang(&self) -> bool {
+        self.language.is_some()
+    }
+
+    pub fn is_typescript(&self) -> bool {
+        self.language == Some(ScriptLanguage::TypeScript)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScriptContext {
     Default,
@@ -1009,12 +1044,44 @@ pub enum ScriptLanguage {
     TypeScript,
 }
 
-pub struct RawBlock {
+impl ScriptLanguage {
+    pub fn from_lang(lang: Option<&str>) -> Option<Self> {
+        match lang {
+            None | Some("js" | "javascript") => Some(Self::JavaScript),
+            Some("ts" | "typescript") => Some(Self::TypeScript),
+            Some(_) => None,
+        }
+    }
+}
+
+pub struct RawBlock<'a> {
     pub span: Span,
     pub content_span: Span,
+    pub attributes: &'a [Attribute<'a>],
 }
 
-pub struct SvelteOptions {
+impl RawBlock<'_> {
+    pub fn lang<'s>(&self, source: &'s str) -> Option<&'s str> {
+        static_attribute_value(self.attributes, "lang", source)
+    }
+
+    pub fn has_supported_lang(&self, source: &str) -> bool {
+        matches!(self.lang(source), None | Some("css" | "postcss"))
+    }
+}
+
+pub fn static_attribute_value<'s>(
+    attributes: &[Attribute<'_>],
+    name: &str,
+    source: &'s str,
+) -> Option<&'s str> {
+    attributes.iter().find_map(|attr| match attr {
+        Attribute::StringAttribute(a) if a.name == name => Some(a.value_span.source_text(source)),
+        _ => None,
+    })
+}
+
+pub struct SvelteOptions<'a> {
     pub span: Span,
 
     pub runes: Option<bool>,
@@ -1023,7 +1090,7 @@ pub struct SvelteOptions {
 
     pub css: Option<CssMode>,
 
-    pub custom_element: Option<CustomElementConfig>,
+    pub custom_element: Option<CustomElementConfig<'a>>,
 
     pub immutable: Option<bool>,
 
@@ -1031,7 +1098,7 @@ pub struct SvelteOptions {
 
     pub preserve_whitespace: Option<bool>,
 
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -1046,36 +1113,43 @@ pub enum CssMode {
     Injected,
 }
 
-pub enum CustomElementConfig {
-    Tag(String),
+pub enum CustomElementConfig<'a> {
+    Tag(&'a str),
 
     Expression(Span),
 }
 
-pub struct AstStore {
-    nodes: Vec<Node>,
-    fragments: Vec<Fragment>,
+pub struct AstStore<'a> {
+    allocator: &'a Allocator,
+    nodes: Vec<Node<'a>>,
+    fragments: Vec<Fragment<'a>>,
 
     node_to_fragment: Vec<Option<FragmentId>>,
 }
 
-impl AstStore {
-    pub fn new() -> Self {
+impl<'a> AstStore<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
         Self {
+            allocator,
             nodes: Vec::new(),
             fragments: Vec::new(),
             node_to_fragment: Vec::new(),
         }
     }
 
-    pub fn with_capacity(cap: usize) -> Self {
+    pub fn with_capacity(allocator: &'a Allocator, cap: usize) -> Self {
         Self {
+            allocator,
             nodes: Vec::with_capacity(cap),
             fragments: Vec::new(),
             node_to_fragment: Vec::new(),
         }
     }
 
+    pub fn allocator(&self) -> &'a Allocator {
+        self.allocator
+    }
+
     pub fn freeze_node_fragments(&mut self) {
         self.node_to_fragment = vec![None; self.nodes.len()];
         for fragment in &self.fragments {
@@ -1092,25 +1166,16 @@ impl AstStore {
             .flatten()
     }
 
-    pub fn push_fragment(&mut self, role: FragmentRole, nodes: Vec<NodeId>) -> FragmentId {
+    pub fn push_fragment(&mut self, role: FragmentRole, nodes: ArenaVec<'a, NodeId>) -> FragmentId {
         let id = FragmentId(self.fragments.len() as u32);
-        self.fragments.push(Fragment {
-            id,
-            role,
-            nodes,
-            owner: None,
-        });
+        self.fragments.push(Fragment::new(id, role, nodes));
         id
     }
 
     pub fn reserve_fragment(&mut self, role: FragmentRole) -> FragmentId {
         let id = FragmentId(self.fragments.len() as u32);
-        self.fragments.push(Fragment {
-            id,
-            role,
-            nodes: Vec::new(),
-            owner: None,
-        });
+        self.fragments
+            .push(Fragment::empty(id, role, self.allocator));
         id
     }
 
@@ -1118,11 +1183,11 @@ impl AstStore {
         self.fragments[id.0 as usize].owner = Some(owner);
     }
 
-    pub fn fragment(&self, id: FragmentId) -> &Fragment {
+    pub fn fragment(&self, id: FragmentId) -> &Fragment<'a> {
         &self.fragments[id.0 as usize]
     }
 
-    pub fn fragment_mut(&mut self, id: FragmentId) -> &mut Fragment {
+    pub fn fragment_mut(&mut self, id: FragmentId) -> &mut Fragment<'a> {
         &mut self.fragments[id.0 as usize]
     }
 
@@ -1134,35 +1199,36 @@ impl AstStore {
         self.fragments.len() as u32
     }
 
-    pub fn iter_fragments(&self) -> impl Iterator<Item = &Fragment> {
+    pub fn iter_fragments(&self) -> impl Iterator<Item = &Fragment<'a>> {
         self.fragments.iter()
     }
 
-    pub fn push(&mut self, mut node: Node) -> NodeId {
+    pub fn push(&mut self, mut node: Node<'a>) -> NodeId {
         let id = NodeId(self.nodes.len() as u32);
         node.set_id(id);
         self.nodes.push(node);
         id
     }
 
-    pub fn reserve(&mut self) -> NodeId {
+    pub fn reserve(&mut self, span: Span) -> NodeId {
         let id = NodeId(self.nodes.len() as u32);
-        self.nodes.push(Node::Error(ErrorNode {
-            id,
-            span: Span::new(0, 0),
-        }));
+        self.nodes.push(Node::Error(ErrorNode { id, span }));
         id
     }
 
-    pub fn get(&self, id: NodeId) -> &Node {
+    pub fn get(&self, id: NodeId) -> &Node<'a> {
         &self.nodes[id.0 as usize]
     }
 
-    pub fn get_mut(&mut self, id: NodeId) -> &mut Node {
+    pub fn node_span(&self, id: NodeId) -> Option<Span> {
+        self.nodes.get(id.0 as usize).map(Node::span)
+    }
+
+    pub fn get_mut(&mut self, id: NodeId) -> &mut Node<'a> {
         &mut self.nodes[id.0 as usize]
     }
 
-    pub fn take(&mut self, id: NodeId) -> Node {
+    pub fn take(&mut self, id: NodeId) -> Node<'a> {
         std::mem::replace(
             &mut self.nodes[id.0 as usize],
             Node::Error(ErrorNode {
@@ -1172,7 +1238,7 @@ impl AstStore {
         )
     }
 
-    pub fn replace(&mut self, id: NodeId, mut node: Node) {
+    pub fn replace(&mut self, id: NodeId, mut node: Node<'a>) {
         node.set_id(id);
         self.nodes[id.0 as usize] = node;
     }
@@ -1185,24 +1251,17 @@ impl AstStore {
         self.nodes.is_empty()
     }
 
-    pub fn iter_nodes(&self) -> impl Iterator<Item = &Node> {
+    pub fn iter_nodes(&self) -> impl Iterator<Item = &Node<'a>> {
         self.nodes.iter()
     }
 }
 
-impl Default for AstStore {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 macro_rules! impl_store_accessors {
-    ( $( $method:ident -> $Type:ident / $as_method:ident ),+ $(,)? ) => {
-        impl AstStore {
+    ( $( $method:ident -> $Type:ty => $as_method:ident ),+ $(,)? ) => {
+        impl<'a> AstStore<'a> {
             $(
-                pub fn $method(&self, id: NodeId) -> &$Type {
+                pub fn $method(&self, id: NodeId) -> Option<&$Type> {
                     self.get(id).$as_method()
-                        .unwrap_or_else(|| panic!("{:?} is not a {}", id, stringify!($Type)))
                 }
             )+
         }
@@ -1210,26 +1269,26 @@ macro_rules! impl_store_accessors {
 }
 
 impl_store_accessors! {
-    text -> Text / as_text,
-    element -> Element / as_element,
-    component_node -> ComponentNode / as_component_node,
-    comment -> Comment / as_comment,
-    expression_tag -> ExpressionTag / as_expression_tag,
-    if_block -> IfBlock / as_if_block,
-    each_block -> EachBlock / as_each_block,
-    snippet_block -> SnippetBlock / as_snippet_block,
-    render_tag -> RenderTag / as_render_tag,
-    html_tag -> HtmlTag / as_html_tag,
-    const_tag -> ConstTag / as_const_tag,
-    debug_tag -> DebugTag / as_debug_tag,
-    key_block -> KeyBlock / as_key_block,
-    svelte_head -> SvelteHead / as_svelte_head,
-    svelte_element -> SvelteElement / as_svelte_element,
-    svelte_component_legacy -> SvelteComponentLegacy / as_svelte_component_legacy,
-    svelte_window -> SvelteWindow / as_svelte_window,
-    svelte_document -> SvelteDocument / as_svelte_document,
-    svelte_body -> SvelteBody / as_svelte_body,
-    svelte_boundary -> SvelteBoundary / as_svelte_boundary,
-    await_block -> AwaitBlock / as_await_block,
-    error_node -> ErrorNode / as_error,
+    text -> Text<'a> => as_text,
+    element -> Element<'a> => as_element,
+    component_node -> ComponentNode<'a> => as_component_node,
+    comment -> Comment => as_comment,
+    expression_tag -> ExpressionTag => as_expression_tag,
+    if_block -> IfBlock => as_if_block,
+    each_block -> EachBlock => as_each_block,
+    snippet_block -> SnippetBlock => as_snippet_block,
+    render_tag -> RenderTag => as_render_tag,
+    html_tag -> HtmlTag => as_html_tag,
+    const_tag -> ConstTag => as_const_tag,
+    debug_tag -> DebugTag<'a> => as_debug_tag,
+    key_block -> KeyBlock => as_key_block,
+    svelte_head -> SvelteHead<'a> => as_svelte_head,
+    svelte_element -> SvelteElement<'a> => as_svelte_element,
+    svelte_component_legacy -> SvelteComponentLegacy<'a> => as_svelte_component_legacy,
+    svelte_window -> SvelteWindow<'a> => as_svelte_window,
+    svelte_document -> SvelteDocument<'a> => as_svelte_document,
+    svelte_body -> SvelteBody<'a> => as_svelte_body,
+    svelte_boundary -> SvelteBoundary<'a> => as_svelte_boundary,
+    await_block -> AwaitBlock => as_await_block,
+    error_node -> ErrorNode => as_error,
 }
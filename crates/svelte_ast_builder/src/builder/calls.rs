@@ -6,12 +6,12 @@ impl<'a> Builder<'a> {
         args: impl IntoIterator<Item = Arg<'a, 'short>>,
     ) -> Expression<'a> {
         let elements = args.into_iter().map(|a| {
-            if let Arg::Spread(e) = a {
-                return ast::ArrayExpressionElement::SpreadElement(
-                    self.alloc(self.ast.spread_element(SPAN, e)),
-                );
-            }
             let expr = match a {
+                Arg::Spread(e) => {
+                    return ast::ArrayExpressionElement::SpreadElement(
+                        self.alloc(self.ast.spread_element(SPAN, e)),
+                    );
+                }
                 Arg::Str(v) => self.str_expr(&v),
                 Arg::StrRef(v) => self.str_expr(v),
                 Arg::Num(v) => self.num_expr(v),
@@ -19,7 +19,6 @@ impl<'a> Builder<'a> {
                 Arg::Expr(e) => e,
                 Arg::Arrow(a) => Expression::ArrowFunctionExpression(self.alloc(a)),
                 Arg::Bool(v) => self.bool_expr(v),
-                Arg::Spread(_) => unreachable!(),
             };
             ast::ArrayExpressionElement::from(expr)
         });
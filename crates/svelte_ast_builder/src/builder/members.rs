@@ -32,6 +32,12 @@ impl<'a> Builder<'a> {
         Expression::StaticMemberExpression(self.alloc(self.static_member(object, prop)))
     }
 
+    pub fn dotted_path_expr(&self, path: &str) -> Expression<'a> {
+        let mut segments = path.split('.');
+        let head = self.rid_expr(segments.next().unwrap_or(path));
+        segments.fold(head, |object, prop| self.static_member_expr(object, prop))
+    }
+
     pub fn computed_member_expr(
         &self,
         object: Expression<'a>,
@@ -358,14 +358,16 @@ impl<'a> Builder<'a> {
         Expression::AssignmentExpression(self.alloc(assign))
     }
 
-    pub fn expr_to_assignment_target(&self, expr: Expression<'a>) -> AssignmentTarget<'a> {
+    pub fn expr_to_assignment_target(&self, expr: Expression<'a>) -> Option<AssignmentTarget<'a>> {
         match expr {
-            Expression::Identifier(id) => AssignmentTarget::AssignmentTargetIdentifier(id),
-            Expression::StaticMemberExpression(m) => AssignmentTarget::StaticMemberExpression(m),
+            Expression::Identifier(id) => Some(AssignmentTarget::AssignmentTargetIdentifier(id)),
+            Expression::StaticMemberExpression(m) => {
+                Some(AssignmentTarget::StaticMemberExpression(m))
+            }
             Expression::ComputedMemberExpression(m) => {
-                AssignmentTarget::ComputedMemberExpression(m)
+                Some(AssignmentTarget::ComputedMemberExpression(m))
             }
-            _ => panic!("cannot convert expression to assignment target"),
+            _ => None,
         }
     }
 }
@@ -42,8 +42,8 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                 let mut found_id: Option<oxc_syntax::node::NodeId> = None;
                 for n in store.iter_nodes() {
                     let attrs: &[svelte_ast::Attribute] = match n {
-                        svelte_ast::Node::Element(el) => &el.attributes,
-                        svelte_ast::Node::SvelteElement(el) => &el.attributes,
+                        svelte_ast::Node::Element(el) => el.attributes,
+                        svelte_ast::Node::SvelteElement(el) => el.attributes,
                         _ => match n.as_component_like() {
                             Some(view) => view.attributes,
                             None => continue,
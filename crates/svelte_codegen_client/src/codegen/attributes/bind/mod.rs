@@ -118,7 +118,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         bind_blockers: &[u32],
     ) -> Result<Option<BindPlacement<'a>>> {
         let var_name = if bind.shorthand {
-            bind.name.clone()
+            bind.name.to_string()
         } else {
             self.ctx
                 .query
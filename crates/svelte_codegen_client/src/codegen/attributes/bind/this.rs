@@ -22,7 +22,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                 | ReferenceSemantics::SignalRead { .. }
         );
         let var_name = if bind.shorthand {
-            bind.name.clone()
+            bind.name.to_string()
         } else {
             self.ctx
                 .query
@@ -78,13 +78,13 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         owner_id: NodeId,
         class_attr_id: NodeId,
     ) -> Result<Expression<'a>> {
-        let el = self.ctx.element(owner_id);
-        let attributes = el.attributes.clone();
+        let el = self.ctx.element(owner_id)?;
+        let attributes = el.attributes;
 
         let Some(attr) = self
             .ctx
             .attr_index(owner_id)
-            .and_then(|index| index.find_by_id(&attributes, class_attr_id))
+            .and_then(|index| index.find_by_id(attributes, class_attr_id))
         else {
             return CodegenError::unexpected_node(
                 class_attr_id,
@@ -102,8 +102,8 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                 Ok(expr)
             }
             Attribute::ConcatenationAttribute(a) => {
-                let parts = a.parts.clone();
-                self.build_concat_expr_collapse_single(class_attr_id, &parts)
+                let parts = a.parts;
+                self.build_concat_expr_collapse_single(class_attr_id, parts)
             }
             _ => CodegenError::unexpected_node(
                 class_attr_id,
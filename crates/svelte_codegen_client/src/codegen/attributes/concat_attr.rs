@@ -18,7 +18,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         }
 
         let attr_id = attr.id;
-        let val = self.build_concat_expr_collapse_single(attr_id, &attr.parts)?;
+        let val = self.build_concat_expr_collapse_single(attr_id, attr.parts)?;
 
         if attr.name == "value" && owner_tag == "option" {
             self.emit_option_concat_value(state, owner_var, val);
@@ -27,7 +27,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
 
         let html_attr_namespace = self.is_html_attr_namespace(owner_id);
         let attr_name = normalize_regular_attribute_name(&attr.name, html_attr_namespace);
-        let attr_update = self.regular_attr_update(owner_id, owner_tag, &attr_name);
+        let attr_update = self.regular_attr_update(owner_id, owner_tag, &attr_name)?;
 
         let is_dyn = self.ctx.is_dynamic_attr(attr_id);
         let target = if is_dyn {
@@ -125,6 +125,13 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                         }
                         continue;
                     }
+                    if let Some(value) = self.ctx.query.view.inline_attribute_constant(owner_id, a)
+                    {
+                        state
+                            .template
+                            .set_attribute(&a.name, Some(value.to_string()));
+                        continue;
+                    }
                     self.emit_attr_expression(state, owner_id, owner_tag, owner_var, a)?;
                 }
                 Attribute::ConcatenationAttribute(a) => {
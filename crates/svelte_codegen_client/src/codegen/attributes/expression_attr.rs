@@ -56,7 +56,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         let expr = self.take_attr_expr(attr_id, &attr.expression)?;
         let html_attr_namespace = self.is_html_attr_namespace(owner_id);
         let attr_name = normalize_regular_attribute_name(&attr.name, html_attr_namespace);
-        let attr_update = self.regular_attr_update(owner_id, owner_tag, &attr_name);
+        let attr_update = self.regular_attr_update(owner_id, owner_tag, &attr_name)?;
 
         if needs_memo {
             self.memoize_regular_attr_update(
@@ -38,28 +38,28 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         el_id: NodeId,
         tag_name: &str,
         attr_name: &str,
-    ) -> RegularAttrUpdate {
-        let el = self.ctx.element(el_id);
+    ) -> Result<RegularAttrUpdate> {
+        let el = self.ctx.element(el_id)?;
 
         if attr_name == "value" {
-            return RegularAttrUpdate::Call {
+            return Ok(RegularAttrUpdate::Call {
                 setter_fn: "$.set_value",
                 attr_name: None,
-            };
+            });
         }
 
         if attr_name == "checked" {
-            return RegularAttrUpdate::Call {
+            return Ok(RegularAttrUpdate::Call {
                 setter_fn: "$.set_checked",
                 attr_name: None,
-            };
+            });
         }
 
         if attr_name == "selected" {
-            return RegularAttrUpdate::Call {
+            return Ok(RegularAttrUpdate::Call {
                 setter_fn: "$.set_selected",
                 attr_name: None,
-            };
+            });
         }
 
         if attr_name == "defaultValue"
@@ -74,40 +74,40 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                         .nodes
                         .is_empty()))
         {
-            return RegularAttrUpdate::Call {
+            return Ok(RegularAttrUpdate::Call {
                 setter_fn: "$.set_default_value",
                 attr_name: None,
-            };
+            });
         }
 
         if attr_name == "defaultChecked" && has_static_true_boolean_attribute(el, "checked") {
-            return RegularAttrUpdate::Call {
+            return Ok(RegularAttrUpdate::Call {
                 setter_fn: "$.set_default_checked",
                 attr_name: None,
-            };
+            });
         }
 
         if attr_name == "style" {
-            return RegularAttrUpdate::Call {
+            return Ok(RegularAttrUpdate::Call {
                 setter_fn: "$.set_style",
                 attr_name: None,
-            };
+            });
         }
 
         if svelte_analyze::is_regular_dom_property(attr_name) {
-            return RegularAttrUpdate::Assignment {
+            return Ok(RegularAttrUpdate::Assignment {
                 property: attr_name.to_string(),
-            };
+            });
         }
 
-        RegularAttrUpdate::Call {
+        Ok(RegularAttrUpdate::Call {
             setter_fn: if attr_name.starts_with("xlink") {
                 "$.set_xlink_attribute"
             } else {
                 "$.set_attribute"
             },
             attr_name: Some(attr_name.to_string()),
-        }
+        })
     }
 
     pub(super) fn push_regular_attr_update(
@@ -219,7 +219,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         let mut tpl_parts: Vec<TemplatePart<'a>> = Vec::new();
         for part in parts {
             match part {
-                svelte_ast::ConcatPart::Static(s) => push_tpl_str(&mut tpl_parts, s.clone()),
+                svelte_ast::ConcatPart::Static(s) => push_tpl_str(&mut tpl_parts, s.to_string()),
                 svelte_ast::ConcatPart::Dynamic {
                     id: part_id,
                     expr: expr_ref,
@@ -86,7 +86,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                     props.push(ObjProp::KeyValue(name_alloc, expr));
                 }
                 Attribute::ConcatenationAttribute(a) => {
-                    let val = self.build_concat_expr_collapse_single(attr_id, &a.parts)?;
+                    let val = self.build_concat_expr_collapse_single(attr_id, a.parts)?;
                     let name_alloc = self.ctx.b.alloc_str(&a.name);
                     props.push(ObjProp::KeyValue(name_alloc, val));
                 }
@@ -31,9 +31,9 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                     ObjProp::KeyValue(name_alloc, self.ctx.b.str_expr(s))
                 }
                 StyleDirectiveValue::Concatenation(parts) => {
-                    let parts = parts.clone();
+                    let parts = *parts;
                     let name_alloc = self.ctx.b.alloc_str(name);
-                    let expr = self.build_concat_expr_template(sd.id, &parts)?;
+                    let expr = self.build_concat_expr_template(sd.id, parts)?;
                     ObjProp::KeyValue(name_alloc, expr)
                 }
             };
@@ -19,7 +19,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         sem: AwaitBlockSemantics,
     ) -> Result<()> {
         let anchor_node = self.comment_anchor_node_name(state, ctx)?;
-        let span_start = self.ctx.query.await_block(id).span.start;
+        let span_start = self.ctx.query.await_block(id)?.span.start;
 
         let expression = self.build_await_expression(id, sem.expression_has_await)?;
         let then_fn = self.build_await_then_fn(ctx, id, &sem.then, &sem.catch)?;
@@ -76,7 +76,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
             Some(name) => name,
             None => self.comment_anchor_node_name(state, ctx)?,
         };
-        let span_start = self.ctx.query.each_block(id).span.start;
+        let span_start = self.ctx.query.each_block(id)?.span.start;
 
         let plan = self.build_each_plan(id, &sem, is_controlled)?;
         let context_pattern = self.take_each_context_pattern(id)?;
@@ -144,7 +144,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         sem: &EachBlockSemantics,
         is_controlled: bool,
     ) -> Result<EachPlan> {
-        let block = self.ctx.query.each_block(block_id);
+        let block = self.ctx.query.each_block(block_id)?;
 
         let (body_uses_index, key_uses_index) = match sem.index {
             EachIndexKind::Declared {
@@ -234,7 +234,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         &mut self,
         block_id: NodeId,
     ) -> Result<Option<BindingPattern<'a>>> {
-        let block = self.ctx.query.each_block(block_id);
+        let block = self.ctx.query.each_block(block_id)?;
         let Some(context_ref) = block.context.as_ref() else {
             return Ok(None);
         };
@@ -265,7 +265,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                 .thunk(self.ctx.b.call_expr("$.get", [Arg::Ident("$$collection")])));
         }
         if plan.is_prop_source {
-            let block = self.ctx.query.each_block(block_id);
+            let block = self.ctx.query.each_block(block_id)?;
             let src = self
                 .ctx
                 .query
@@ -287,7 +287,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         if plan.key_is_index {
             return Ok(self.ctx.b.rid_expr("$.index"));
         }
-        let block = self.ctx.query.each_block(block_id);
+        let block = self.ctx.query.each_block(block_id)?;
         let Some(key_ref) = block.key.as_ref() else {
             return Ok(self.ctx.b.rid_expr("$.index"));
         };
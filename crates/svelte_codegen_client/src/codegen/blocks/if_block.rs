@@ -28,7 +28,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
             } => (true, *root_has_await, blockers.to_vec()),
         };
 
-        let span_start = self.ctx.query.if_block(id).span.start;
+        let span_start = self.ctx.query.if_block(id)?.span.start;
         let is_elseif_root = sem.is_elseif_root;
 
         let pre_anchor = self.reserve_comment_anchor_pre(state, ctx);
@@ -75,7 +75,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         let callee_expr = unboxed.callee;
         let arguments = unboxed.arguments;
 
-        let tag = self.ctx.render_tag(id);
+        let tag = self.ctx.render_tag(id)?;
         let full_source = self.ctx.query.component.source_text(tag.expression.span);
         let callee_text: &'a str =
             &full_source[callee_span.start as usize..callee_span.end as usize];
@@ -32,7 +32,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         };
 
         let var_name = if bind.shorthand {
-            bind.name.clone()
+            bind.name.to_string()
         } else {
             self.ctx
                 .query
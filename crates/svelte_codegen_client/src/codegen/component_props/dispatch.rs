@@ -104,7 +104,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                     self.emit_component_prop_concat(
                         &name,
                         attr_id,
-                        &parts,
+                        parts,
                         is_dynamic,
                         &mut out.items,
                     )?;
@@ -31,7 +31,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                 .iter()
                 .map(|slot| {
                     (
-                        slot.name.clone(),
+                        slot.name.to_string(),
                         self.ctx.query.component.fragment_nodes(slot.fragment)[0],
                     )
                 })
@@ -84,8 +84,8 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
             _ => return CodegenError::unexpected_node(el_id, "Element"),
         };
 
-        let el_name_hint = el.name.clone();
-        let attributes = el.attributes.clone();
+        let el_name_hint = el.name;
+        let attributes = el.attributes;
         let el_ns = self.element_namespace(el_id, ctx.namespace);
 
         let is_html = matches!(el_ns, Namespace::Html) && el_name_hint != "svg";
@@ -353,7 +353,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         el_id: NodeId,
         el_name: &str,
     ) -> Result<()> {
-        let el = self.ctx.element(el_id);
+        let el = self.ctx.element(el_id)?;
         let child_ids: Vec<NodeId> = self
             .ctx
             .query
@@ -361,7 +361,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
             .store
             .fragment(el.fragment)
             .nodes
-            .clone();
+            .to_vec();
 
         let mut expr_id: Option<NodeId> = None;
         for child_id in &child_ids {
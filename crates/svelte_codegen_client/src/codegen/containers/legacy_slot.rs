@@ -31,11 +31,11 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         el_id: NodeId,
     ) -> Result<String> {
         let attrs = match self.ctx.query.component.store.get(el_id) {
-            Node::SlotElementLegacy(el) => el.attributes.clone(),
+            Node::SlotElementLegacy(el) => el.attributes,
             _ => return CodegenError::unexpected_node(el_id, "SlotElementLegacy"),
         };
 
-        let slot_name = self.legacy_slot_name(&attrs);
+        let slot_name = self.legacy_slot_name(attrs);
         let slot_name_alloc: &str = self.ctx.b.alloc_str(&slot_name);
 
         let mut props: Vec<ObjProp<'a>> = Vec::new();
@@ -43,7 +43,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         let mut memo_stmts: Vec<Statement<'a>> = Vec::new();
         let runes = self.ctx.query.runes();
 
-        for attr in &attrs {
+        for attr in attrs {
             let attr_id = attr.id();
             match attr {
                 Attribute::StringAttribute(a) => {
@@ -111,7 +111,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                         continue;
                     }
                     let key = self.ctx.b.alloc_str(&a.name);
-                    let val = self.build_concat_expr_collapse_single(attr_id, &a.parts)?;
+                    let val = self.build_concat_expr_collapse_single(attr_id, a.parts)?;
                     let is_dyn = self.ctx.is_dynamic_attr(attr_id);
                     if is_dyn {
                         props.push(ObjProp::Getter(key, val));
@@ -19,20 +19,14 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         match node {
             Node::SvelteHead(_) => self.emit_svelte_head(state, ctx, el_id),
             Node::SvelteWindow(w) => {
-                self.emit_special_target_with_owner(state, el_id, "$.window", &w.attributes.clone())
+                self.emit_special_target_with_owner(state, el_id, "$.window", w.attributes)
+            }
+            Node::SvelteDocument(d) => {
+                self.emit_special_target_with_owner(state, el_id, "$.document", d.attributes)
+            }
+            Node::SvelteBody(b) => {
+                self.emit_special_target_with_owner(state, el_id, "$.document.body", b.attributes)
             }
-            Node::SvelteDocument(d) => self.emit_special_target_with_owner(
-                state,
-                el_id,
-                "$.document",
-                &d.attributes.clone(),
-            ),
-            Node::SvelteBody(b) => self.emit_special_target_with_owner(
-                state,
-                el_id,
-                "$.document.body",
-                &b.attributes.clone(),
-            ),
             _ => CodegenError::unexpected_node(el_id, "special target"),
         }
     }
@@ -104,7 +98,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         );
 
         let var_name = if bind.shorthand {
-            bind.name.clone()
+            bind.name.to_string()
         } else {
             self.ctx
                 .query
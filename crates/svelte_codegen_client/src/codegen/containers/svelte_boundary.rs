@@ -52,7 +52,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         el_id: NodeId,
         _existing_var: Option<&str>,
     ) -> Result<String> {
-        let boundary = self.ctx.query.svelte_boundary(el_id);
+        let boundary = self.ctx.query.svelte_boundary(el_id)?;
 
         let snippet_children: Vec<(NodeId, String)> = self
             .ctx
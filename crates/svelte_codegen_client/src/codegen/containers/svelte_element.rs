@@ -16,7 +16,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         el_id: NodeId,
         _existing_var: Option<&str>,
     ) -> Result<String> {
-        let el = self.ctx.query.svelte_element(el_id);
+        let el = self.ctx.query.svelte_element(el_id)?;
         let static_tag = el.static_tag;
         let tag_span = el.tag_span;
         let attributes: Vec<Attribute> = el
@@ -1,7 +1,9 @@
 use svelte_ast::NodeId;
+use svelte_span::Span;
+use svelte_transform::TransformError;
 
 #[derive(Debug)]
-pub(crate) enum CodegenError {
+pub enum CodegenError {
     UnexpectedChild {
         expected: &'static str,
         got: &'static str,
@@ -25,9 +27,29 @@ pub(crate) enum CodegenError {
         node_id: NodeId,
         feature: &'static str,
     },
+
+    Transform(TransformError),
 }
 
 impl CodegenError {
+    pub fn node_id(&self) -> Option<NodeId> {
+        match self {
+            Self::UnexpectedChild { .. } => None,
+            Self::UnexpectedNode { node_id, .. }
+            | Self::UnexpectedBlockSemantics { node_id, .. }
+            | Self::NotImplemented { node_id, .. } => Some(*node_id),
+            Self::MissingExpression(id) | Self::MissingExpressionDeps(id) => Some(*id),
+            Self::Transform(err) => err.node_id,
+        }
+    }
+
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Self::Transform(err) => err.span,
+            _ => None,
+        }
+    }
+
     pub(crate) fn unexpected_child<T>(expected: &'static str, got: &'static str) -> Result<T> {
         Err(Self::UnexpectedChild { expected, got })
     }
@@ -80,10 +102,17 @@ impl std::fmt::Display for CodegenError {
                     "codegen: {feature} not implemented yet (node {node_id:?})"
                 )
             }
+            CodegenError::Transform(err) => err.fmt(f),
         }
     }
 }
 
 impl std::error::Error for CodegenError {}
 
+impl From<TransformError> for CodegenError {
+    fn from(err: TransformError) -> Self {
+        Self::Transform(err)
+    }
+}
+
 pub(crate) type Result<T> = std::result::Result<T, CodegenError>;
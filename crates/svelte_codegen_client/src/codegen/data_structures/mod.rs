@@ -10,7 +10,8 @@ mod pre_anchor;
 mod template;
 
 pub(crate) use async_emission_plan::AsyncEmissionPlan;
-pub(crate) use codegen_error::{CodegenError, Result};
+pub use codegen_error::CodegenError;
+pub(crate) use codegen_error::Result;
 pub(crate) use codegen_result::CodegenResult;
 pub(crate) use concat::ConcatPart;
 pub(crate) use emit_state::EmitState;
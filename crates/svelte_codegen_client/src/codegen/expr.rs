@@ -3,7 +3,7 @@ use svelte_ast::{ExprRef, Node, NodeId};
 
 use super::{Codegen, CodegenError, Result};
 
-fn expr_ref_for_node(node: &Node) -> Option<&ExprRef> {
+fn expr_ref_for_node<'n>(node: &'n Node<'_>) -> Option<&'n ExprRef> {
     match node {
         Node::ExpressionTag(t) => Some(&t.expression),
         Node::HtmlTag(t) => Some(&t.expression),
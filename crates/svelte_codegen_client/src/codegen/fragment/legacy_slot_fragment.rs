@@ -80,6 +80,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         let (_children, strategy) = prepare(
             self.ctx.query.component.fragment_nodes(fragment_id),
             &self.ctx.query.component.store,
+            &self.ctx.query.view,
             ctx,
             &mut bucket,
         );
@@ -89,8 +90,8 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
     fn has_let_directives(&self, owner_id: NodeId) -> bool {
         let node = self.ctx.query.component.store.get(owner_id);
         let attrs = match node {
-            Node::Element(el) => &el.attributes[..],
-            Node::SvelteFragmentLegacy(el) => &el.attributes[..],
+            Node::Element(el) => el.attributes,
+            Node::SvelteFragmentLegacy(el) => el.attributes,
             _ => match node.as_component_like() {
                 Some(view) => view.attributes,
                 None => return false,
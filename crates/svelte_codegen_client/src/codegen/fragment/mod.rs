@@ -48,10 +48,11 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
             .store
             .fragment(fragment_id)
             .nodes
-            .clone();
+            .to_vec();
         let (children, raw_strategy) = prepare(
             &fragment_nodes,
             &self.ctx.query.component.store,
+            &self.ctx.query.view,
             ctx,
             &mut bucket,
         );
@@ -341,7 +342,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
             .store
             .fragment(fragment_id)
             .nodes
-            .clone();
+            .to_vec();
         for id in nodes {
             self.push_node_locations(id, &mut locs);
         }
@@ -361,7 +362,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                     .store
                     .fragment(el.fragment)
                     .nodes
-                    .clone();
+                    .to_vec();
                 for id in nodes {
                     self.push_node_locations(id, out);
                 }
@@ -387,7 +388,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
             .store
             .fragment(fragment_id)
             .nodes
-            .clone();
+            .to_vec();
         for id in nodes {
             self.push_node_locations(id, &mut child_locs);
         }
@@ -1081,7 +1082,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                     if !self.ctx.needs_var(el_id) {
                         self.emit_element(state, ctx, el_id, None)?;
                     } else {
-                        let el_name_hint = el.name.clone();
+                        let el_name_hint = el.name;
                         let prefix = self.element_ident_prefix(&el_name_hint);
                         let el_name = self.ctx.state.gen_ident(&prefix);
                         let b = &self.ctx.state.b;
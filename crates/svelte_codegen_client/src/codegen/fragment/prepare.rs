@@ -1,17 +1,21 @@
 use std::borrow::Cow;
 
 use smallvec::SmallVec;
+use svelte_analyze::CodegenView;
 use svelte_ast::{AstStore, Node, NodeId};
 use svelte_span::Span;
 
 use crate::codegen::{
     data_structures::{ConcatPart, FragmentCtx},
-    fragment::types::{BufItem, Child, ChildrenFlags, ContentStrategy, HoistedBucket, HoistedKind},
+    fragment::types::{
+        BufItem, BufText, Child, ChildrenFlags, ContentStrategy, HoistedBucket, HoistedKind,
+    },
 };
 
 pub(super) fn prepare<'a>(
     raw: &[NodeId],
-    store: &'a AstStore,
+    store: &'a AstStore<'a>,
+    view: &CodegenView<'_, '_>,
     ctx: &FragmentCtx<'a>,
     bucket: &mut HoistedBucket,
 ) -> (Vec<Child>, ContentStrategy) {
@@ -147,16 +151,21 @@ pub(super) fn prepare<'a>(
                     Cow::Borrowed(s) if text.decoded.is_none() && is_slice_of(ctx.source, s) => {
                         let source_ptr = ctx.source.as_ptr() as usize;
                         let offset = s.as_ptr() as usize - source_ptr;
-                        ConcatPart::Static(Span::new(offset as u32, (offset + s.len()) as u32))
+                        BufText::Source(Span::new(offset as u32, (offset + s.len()) as u32))
                     }
-                    Cow::Borrowed(s) => ConcatPart::StaticOwned(s.to_string()),
-                    Cow::Owned(s) => ConcatPart::StaticOwned(s),
+                    Cow::Borrowed(s) => BufText::Owned(s.to_string()),
+                    Cow::Owned(s) => BufText::Owned(s),
                 };
-                buf.push(BufItem::Text(part));
+                push_text(&mut buf, part, ctx.source);
             }
             Node::ExpressionTag(tag) => {
                 prev_text_ends_ws = false;
-                buf.push(BufItem::Expr(tag.id));
+                match view.inline_text_constant(tag.id) {
+                    Some(value) => {
+                        push_text(&mut buf, BufText::Owned(value.to_string()), ctx.source);
+                    }
+                    _ => buf.push(BufItem::Expr(tag.id)),
+                }
             }
             _ => {
                 prev_text_ends_ws = false;
@@ -213,8 +222,8 @@ fn classify(flags: ChildrenFlags, children: &[Child], store: &AstStore) -> Conte
 fn node_has_slot_attribute(node: &Node) -> bool {
     use svelte_ast::Attribute;
     let attrs: &[Attribute] = match node {
-        Node::Element(el) => &el.attributes,
-        Node::SvelteFragmentLegacy(el) => &el.attributes,
+        Node::Element(el) => el.attributes,
+        Node::SvelteFragmentLegacy(el) => el.attributes,
         _ => match node.as_component_like() {
             Some(view) => view.attributes,
             None => return false,
@@ -254,8 +263,8 @@ fn flush_buf(
             return;
         };
         match only {
-            BufItem::Text(part) => {
-                children.push(Child::Text(part));
+            BufItem::Text(text) => {
+                children.push(Child::Text(text.into_part()));
                 flags.insert(ChildrenFlags::HAS_TEXT);
             }
             BufItem::Expr(id) => {
@@ -267,7 +276,7 @@ fn flush_buf(
         let parts: SmallVec<[ConcatPart; 4]> = buf
             .drain(..)
             .map(|item| match item {
-                BufItem::Text(part) => part,
+                BufItem::Text(text) => text.into_part(),
                 BufItem::Expr(id) => ConcatPart::Expr(id),
             })
             .collect();
@@ -287,6 +296,19 @@ fn mark_node_flag(flags: &mut ChildrenFlags, node: &Node) {
     }
 }
 
+fn push_text(buf: &mut SmallVec<[BufItem; 4]>, text: BufText, source: &str) {
+    let Some(BufItem::Text(prev)) = buf.last_mut() else {
+        buf.push(BufItem::Text(text));
+        return;
+    };
+    let mut merged = match std::mem::replace(prev, BufText::Owned(String::new())) {
+        BufText::Source(span) => source[span.start as usize..span.end as usize].to_string(),
+        BufText::Owned(s) => s,
+    };
+    merged.push_str(text.as_str(source));
+    *prev = BufText::Owned(merged);
+}
+
 fn is_slice_of(haystack: &str, needle: &str) -> bool {
     let h_start = haystack.as_ptr() as usize;
     let h_end = h_start + haystack.len();
@@ -1,6 +1,7 @@
 use bitflags::bitflags;
 use smallvec::SmallVec;
 use svelte_ast::NodeId;
+use svelte_span::Span;
 
 use crate::codegen::data_structures::ConcatPart;
 
@@ -96,10 +97,32 @@ impl HoistedBucket {
 }
 
 pub(super) enum BufItem {
-    Text(ConcatPart),
+    Text(BufText),
     Expr(NodeId),
 }
 
+/// Static text pending in the buffer; adjacent pieces merge into one.
+pub(super) enum BufText {
+    Source(Span),
+    Owned(String),
+}
+
+impl BufText {
+    pub(super) fn as_str<'s>(&'s self, source: &'s str) -> &'s str {
+        match self {
+            Self::Source(span) => &source[span.start as usize..span.end as usize],
+            Self::Owned(s) => s,
+        }
+    }
+
+    pub(super) fn into_part(self) -> ConcatPart {
+        match self {
+            Self::Source(span) => ConcatPart::Static(span),
+            Self::Owned(s) => ConcatPart::StaticOwned(s),
+        }
+    }
+}
+
 pub(super) enum HoistedKind {
     NotHoisted,
     Snippet,
@@ -11,8 +11,8 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
         _ctx: &FragmentCtx<'a>,
         id: NodeId,
     ) -> Result<()> {
-        let tag = self.ctx.debug_tag(id);
-        let identifier_refs = tag.identifier_refs.clone();
+        let tag = self.ctx.debug_tag(id)?;
+        let identifier_refs = tag.identifier_refs;
         let runes = self.ctx.query.runes();
 
         let mut props: Vec<ObjProp<'a>> = Vec::with_capacity(identifier_refs.len());
@@ -24,13 +24,13 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
             .store
             .fragment(el.fragment)
             .nodes
-            .clone();
+            .to_vec();
 
         let mut parts: Vec<ConcatPart> = Vec::new();
         for child_id in &fragment_nodes {
             match self.ctx.query.component.store.get(*child_id) {
                 svelte_ast::Node::Text(t) => {
-                    let text = t.value(&self.ctx.query.component.source).to_string();
+                    let text = t.value(self.ctx.query.component.source).to_string();
                     parts.push(ConcatPart::StaticOwned(text));
                 }
                 svelte_ast::Node::ExpressionTag(ex) => {
@@ -10,8 +10,8 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
     ) -> Vec<oxc_ast::ast::Statement<'a>> {
         let node = self.ctx.query.component.store.get(owner_id);
         let attrs: &[Attribute] = match node {
-            Node::Element(el) => &el.attributes,
-            Node::SvelteFragmentLegacy(el) => &el.attributes,
+            Node::Element(el) => el.attributes,
+            Node::SvelteFragmentLegacy(el) => el.attributes,
             _ => match node.as_component_like() {
                 Some(view) => view.attributes,
                 None => return Vec::new(),
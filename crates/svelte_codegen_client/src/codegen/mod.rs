@@ -19,7 +19,8 @@ use svelte_ast_builder::Arg;
 
 use crate::context::Ctx;
 
-pub(crate) use data_structures::{CodegenError, CodegenResult, Result};
+pub use data_structures::CodegenError;
+pub(crate) use data_structures::{CodegenResult, Result};
 pub(crate) use fragment::FragmentEmitKind;
 
 use data_structures::{EmitState, FragmentCtx};
@@ -16,14 +16,16 @@ use svelte_transform::TransformData;
 
 use svelte_ast_builder::Builder;
 
+use crate::codegen::{CodegenError, Result};
+
 pub struct CodegenQuery<'a> {
-    pub component: &'a Component,
+    pub component: &'a Component<'a>,
     pub view: CodegenView<'a, 'a>,
     pub analysis: &'a AnalysisData<'a>,
 }
 
 impl<'a> CodegenQuery<'a> {
-    pub fn new(component: &'a Component, analysis: &'a AnalysisData<'a>) -> Self {
+    pub fn new(component: &'a Component<'a>, analysis: &'a AnalysisData<'a>) -> Self {
         Self {
             component,
             view: CodegenView::new(analysis),
@@ -31,32 +33,36 @@ impl<'a> CodegenQuery<'a> {
         }
     }
 
-    pub fn element(&self, id: NodeId) -> &'a Element {
-        self.component.store.element(id)
+    pub fn element(&self, id: NodeId) -> Result<&'a Element<'a>> {
+        node_of(self.component.store.element(id), id, "Element")
     }
-    pub fn if_block(&self, id: NodeId) -> &'a IfBlock {
-        self.component.store.if_block(id)
+    pub fn if_block(&self, id: NodeId) -> Result<&'a IfBlock> {
+        node_of(self.component.store.if_block(id), id, "IfBlock")
     }
-    pub fn each_block(&self, id: NodeId) -> &'a EachBlock {
-        self.component.store.each_block(id)
+    pub fn each_block(&self, id: NodeId) -> Result<&'a EachBlock> {
+        node_of(self.component.store.each_block(id), id, "EachBlock")
     }
-    pub fn render_tag(&self, id: NodeId) -> &'a RenderTag {
-        self.component.store.render_tag(id)
+    pub fn render_tag(&self, id: NodeId) -> Result<&'a RenderTag> {
+        node_of(self.component.store.render_tag(id), id, "RenderTag")
     }
-    pub fn key_block(&self, id: NodeId) -> &'a KeyBlock {
-        self.component.store.key_block(id)
+    pub fn key_block(&self, id: NodeId) -> Result<&'a KeyBlock> {
+        node_of(self.component.store.key_block(id), id, "KeyBlock")
     }
-    pub fn svelte_element(&self, id: NodeId) -> &'a SvelteElement {
-        self.component.store.svelte_element(id)
+    pub fn svelte_element(&self, id: NodeId) -> Result<&'a SvelteElement<'a>> {
+        node_of(self.component.store.svelte_element(id), id, "SvelteElement")
     }
-    pub fn svelte_boundary(&self, id: NodeId) -> &'a SvelteBoundary {
-        self.component.store.svelte_boundary(id)
+    pub fn svelte_boundary(&self, id: NodeId) -> Result<&'a SvelteBoundary<'a>> {
+        node_of(
+            self.component.store.svelte_boundary(id),
+            id,
+            "SvelteBoundary",
+        )
     }
-    pub fn await_block(&self, id: NodeId) -> &'a AwaitBlock {
-        self.component.store.await_block(id)
+    pub fn await_block(&self, id: NodeId) -> Result<&'a AwaitBlock> {
+        node_of(self.component.store.await_block(id), id, "AwaitBlock")
     }
-    pub fn debug_tag(&self, id: NodeId) -> &'a DebugTag {
-        self.component.store.debug_tag(id)
+    pub fn debug_tag(&self, id: NodeId) -> Result<&'a DebugTag<'a>> {
+        node_of(self.component.store.debug_tag(id), id, "DebugTag")
     }
 
     pub fn expression(&self, id: NodeId) -> Option<&ExpressionInfo> {
@@ -71,6 +77,10 @@ impl<'a> CodegenQuery<'a> {
     }
 }
 
+fn node_of<T>(node: Option<T>, node_id: NodeId, expected: &'static str) -> Result<T> {
+    node.ok_or(CodegenError::UnexpectedNode { node_id, expected })
+}
+
 impl<'a> Deref for CodegenQuery<'a> {
     type Target = CodegenView<'a, 'a>;
 
@@ -187,7 +197,7 @@ impl<'a> Ctx<'a> {
         let ident_gen = compile_ctx.ident_gen;
 
         let name = allocator.alloc_str(analysis.component_name());
-        let source = component.source.as_str();
+        let source = component.source;
         let source = allocator.alloc_str(source);
         let filename = allocator.alloc_str(&options.filename);
         let css_text = css_text.map(|t| allocator.alloc_str(t) as &str);
@@ -209,10 +219,10 @@ impl<'a> Ctx<'a> {
         }
     }
 
-    pub fn element(&self, id: NodeId) -> &'a Element {
+    pub fn element(&self, id: NodeId) -> Result<&'a Element<'a>> {
         self.query.element(id)
     }
-    pub fn render_tag(&self, id: NodeId) -> &'a RenderTag {
+    pub fn render_tag(&self, id: NodeId) -> Result<&'a RenderTag> {
         self.query.render_tag(id)
     }
 
@@ -345,7 +355,7 @@ impl<'a> Ctx<'a> {
     pub fn has_style_directives(&self, id: NodeId) -> bool {
         self.query.view.has_style_directives(id)
     }
-    pub fn style_directives(&self, id: NodeId) -> &[svelte_ast::StyleDirective] {
+    pub fn style_directives(&self, id: NodeId) -> &'a [svelte_ast::StyleDirective<'a>] {
         self.query.view.style_directives(id)
     }
     pub fn needs_input_defaults(&self, id: NodeId) -> bool {
@@ -387,7 +397,7 @@ impl<'a> Ctx<'a> {
     pub fn is_expression_shorthand(&self, id: NodeId) -> bool {
         self.query.view.is_expression_shorthand(id)
     }
-    pub fn component_props(&self, id: NodeId) -> &[ComponentPropInfo] {
+    pub fn component_props(&self, id: NodeId) -> &'a [ComponentPropInfo<'a>] {
         self.query.view.component_props(id)
     }
     pub fn component_binding_sym(&self, id: NodeId) -> Option<SymbolId> {
@@ -443,7 +453,7 @@ impl<'a> Ctx<'a> {
         self.query.view.symbol_blocker(sym)
     }
 
-    pub fn debug_tag(&self, id: NodeId) -> &'a DebugTag {
+    pub fn debug_tag(&self, id: NodeId) -> Result<&'a DebugTag<'a>> {
         self.query.debug_tag(id)
     }
 
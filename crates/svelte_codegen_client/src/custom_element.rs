@@ -12,7 +12,7 @@ pub fn gen_custom_element<'a>(
     let parsed_config = ctx.ce_config().cloned();
 
     let (simple_tag, parsed) = match ce_config {
-        Some(CustomElementConfig::Tag(tag)) => (Some(tag.as_str()), None),
+        Some(CustomElementConfig::Tag(tag)) => (Some(*tag), None),
         Some(CustomElementConfig::Expression(_)) => (None, parsed_config.as_ref()),
         None => (None, None),
     };
@@ -1,10 +1,12 @@
 pub(crate) mod codegen;
 mod context;
 mod custom_element;
+mod minify;
 mod script;
 
 use oxc_allocator::Allocator;
-use oxc_ast::ast::{ExportDefaultDeclarationKind, Statement};
+use oxc_ast::ast::{CallExpression, ExportDefaultDeclarationKind, Expression, Program, Statement};
+use oxc_ast_visit::{Visit, walk};
 use oxc_codegen::Codegen;
 use oxc_span::{GetSpanMut, Span};
 
@@ -15,19 +17,26 @@ use svelte_transform::TransformData;
 
 use context::Ctx;
 
+pub use codegen::CodegenError;
+
+pub struct CodegenOutput {
+    pub js: String,
+    pub template_effects: u32,
+}
+
 pub fn generate<'a>(
     compile_ctx: svelte_types::CompileContext<'a, 'a>,
     options: &svelte_types::CodegenOptions,
     transform_data: TransformData,
     css_text: Option<&str>,
-) -> String {
+) -> Result<CodegenOutput, CodegenError> {
     let alloc = compile_ctx.alloc;
     let component = compile_ctx.component;
     let analysis = compile_ctx.analysis;
     let dev = options.dev;
     let mut ctx = Ctx::new(compile_ctx, options, transform_data, css_text);
 
-    let script_output = script::gen_script(&mut ctx, dev);
+    let script_output = script::gen_script(&mut ctx, dev)?;
     let script_imports = script_output.imports;
     let script_body = script_output.body;
     let has_tracing = script_output.has_tracing;
@@ -50,9 +59,10 @@ pub fn generate<'a>(
                 Some(analysis.script_rune_calls()),
             )
         } else {
-            let is_ts = module_script.language == svelte_ast::ScriptLanguage::TypeScript;
+            let is_ts = module_script.is_typescript();
             script::transform_component_module_script(alloc, module_source, is_ts)
-        };
+        }
+        .map_err(|err| err.offset(module_script.content_span.start))?;
 
         if script_source_text.is_empty() {
             script_comments = module_output.comments;
@@ -76,7 +86,7 @@ pub fn generate<'a>(
         }
     }
 
-    let codegen_result = codegen::codegen_root_fragment(&mut ctx).expect("codegen failed");
+    let codegen_result = codegen::codegen_root_fragment(&mut ctx)?;
     let hoisted = codegen_result.hoisted;
     let template_body = codegen_result.body;
     let instance_snippets = codegen_result.instance_snippets;
@@ -467,6 +477,10 @@ pub fn generate<'a>(
         program_body.extend(ce_stmts);
     }
 
+    if let Some(runtime_path) = options.runtime_path.as_deref() {
+        rewrite_runtime_imports(&ctx.b, &mut program_body, runtime_path);
+    }
+
     let program = ctx.b.program(
         program_body,
         script_comments,
@@ -474,7 +488,73 @@ pub fn generate<'a>(
         script_span_end,
     );
 
-    Codegen::default().build(&program).code
+    let template_effects = if options.stats {
+        let mut counter = TemplateEffectCounter::default();
+        counter.visit_program(&program);
+        counter.count
+    } else {
+        0
+    };
+
+    Ok(CodegenOutput {
+        js: print_program(alloc, program, options.minify),
+        template_effects,
+    })
+}
+
+#[derive(Default)]
+struct TemplateEffectCounter {
+    count: u32,
+}
+
+impl<'a> Visit<'a> for TemplateEffectCounter {
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        if let Expression::Identifier(callee) = &it.callee
+            && matches!(
+                callee.name.as_str(),
+                "$.template_effect" | "$.deferred_template_effect"
+            )
+        {
+            self.count += 1;
+        }
+        walk::walk_call_expression(self, it);
+    }
+}
+
+fn rewrite_runtime_imports<'a>(
+    b: &Builder<'a>,
+    program_body: &mut [Statement<'a>],
+    runtime_path: &str,
+) {
+    let runtime_path = runtime_path.trim_end_matches('/');
+    for stmt in program_body {
+        let source = match stmt {
+            Statement::ImportDeclaration(decl) => &mut decl.source,
+            Statement::ExportAllDeclaration(decl) => &mut decl.source,
+            Statement::ExportNamedDeclaration(decl) => match decl.source.as_mut() {
+                Some(source) => source,
+                None => continue,
+            },
+            _ => continue,
+        };
+        let rewritten = if source.value == "svelte" {
+            runtime_path.to_string()
+        } else if let Some(rest) = source.value.strip_prefix("svelte/") {
+            format!("{runtime_path}/{rest}")
+        } else {
+            continue;
+        };
+        source.value = b.ast.atom(&rewritten);
+        source.raw = None;
+    }
+}
+
+fn print_program<'a>(alloc: &'a Allocator, program: Program<'a>, minify: bool) -> String {
+    if minify {
+        minify::minify_program(alloc, program)
+    } else {
+        Codegen::default().build(&program).code
+    }
 }
 
 fn shift_statement_spans(stmts: &mut [Statement<'_>], offset: u32) {
@@ -513,7 +593,10 @@ fn split_async_instance_body<'a>(
             continue;
         }
 
-        let meta = blocker_data.stmt_meta(i).expect("stmt_meta out of range");
+        let Some(meta) = blocker_data.stmt_meta(i) else {
+            result.push(stmt);
+            continue;
+        };
         let has_await = meta.has_await();
 
         for name in meta.hoist_names() {
@@ -618,10 +701,15 @@ pub fn generate_module<'a>(
     alloc: &'a Allocator,
     program: oxc_ast::ast::Program<'a>,
     analysis: &AnalysisData<'a>,
-    dev: bool,
-) -> String {
-    let script_output =
-        script::transform_module_program(alloc, program, Some(analysis), &analysis.scoping, dev);
+    options: &svelte_types::CodegenOptions,
+) -> Result<String, CodegenError> {
+    let script_output = script::transform_module_program(
+        alloc,
+        program,
+        Some(analysis),
+        &analysis.scoping,
+        options.dev,
+    )?;
 
     let b = Builder::new(alloc);
     let import_svelte = b.import_all("$", "svelte/internal/client");
@@ -631,11 +719,15 @@ pub fn generate_module<'a>(
     program_body.extend(script_output.imports);
     program_body.extend(script_output.body);
 
+    if let Some(runtime_path) = options.runtime_path.as_deref() {
+        rewrite_runtime_imports(&b, &mut program_body, runtime_path);
+    }
+
     let program = b.program(
         program_body,
         script_output.comments,
         script_output.source_text,
         script_output.program_span_end,
     );
-    Codegen::default().build(&program).code
+    Ok(print_program(alloc, program, options.minify))
 }
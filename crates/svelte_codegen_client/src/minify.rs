@@ -0,0 +1,65 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, Program, SimpleAssignmentTarget};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_mangler::{MangleOptions, Mangler};
+use oxc_minifier::{CompressOptions, Compressor};
+use oxc_semantic::SemanticBuilder;
+use svelte_ast_builder::Builder;
+
+pub(crate) fn minify_program<'a>(alloc: &'a Allocator, mut program: Program<'a>) -> String {
+    ExpandDottedIdentifiers {
+        b: Builder::new(alloc),
+    }
+    .visit_program(&mut program);
+    Compressor::new(alloc).build(&mut program, CompressOptions::smallest());
+    let mut semantic = SemanticBuilder::new().build(&program).semantic;
+    Mangler::default()
+        .with_options(MangleOptions {
+            top_level: Some(false),
+            ..MangleOptions::default()
+        })
+        .build_with_semantic(&mut semantic, &program);
+    Codegen::new()
+        .with_options(CodegenOptions::minify())
+        .with_scoping(Some(semantic.into_scoping()))
+        .build(&program)
+        .code
+}
+
+struct ExpandDottedIdentifiers<'a> {
+    b: Builder<'a>,
+}
+
+impl<'a> ExpandDottedIdentifiers<'a> {
+    fn expand(&self, name: &str) -> Option<Expression<'a>> {
+        let is_path = name.contains('.')
+            && name.split('.').all(|segment| {
+                segment.starts_with(|c: char| c.is_ascii_alphabetic() || matches!(c, '_' | '$'))
+                    && segment
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$'))
+            });
+        is_path.then(|| self.b.dotted_path_expr(name))
+    }
+}
+
+impl<'a> VisitMut<'a> for ExpandDottedIdentifiers<'a> {
+    fn visit_expression(&mut self, it: &mut Expression<'a>) {
+        if let Expression::Identifier(id) = it
+            && let Some(expanded) = self.expand(&id.name)
+        {
+            *it = expanded;
+        }
+        walk_mut::walk_expression(self, it);
+    }
+
+    fn visit_simple_assignment_target(&mut self, it: &mut SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(id) = it
+            && let Some(Expression::StaticMemberExpression(member)) = self.expand(&id.name)
+        {
+            *it = SimpleAssignmentTarget::StaticMemberExpression(member);
+        }
+        walk_mut::walk_simple_assignment_target(self, it);
+    }
+}
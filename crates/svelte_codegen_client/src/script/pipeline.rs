@@ -1,13 +1,14 @@
 use oxc_allocator::Allocator;
-use oxc_ast::Comment;
-use oxc_ast::ast::{Program, Statement};
+use oxc_ast::ast::{Argument, ClassElement, FunctionBody, ObjectExpression, Program, Statement};
+use oxc_ast::{Comment, CommentPosition};
+use oxc_ast_visit::{Visit, walk};
 use oxc_parser::Parser as OxcParser;
-use oxc_span::{GetSpan, SourceType};
+use oxc_span::{GetSpan, SourceType, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
 use svelte_analyze::{AnalysisData, ComponentScoping, ScriptRuneCalls};
-use svelte_ast::ScriptLanguage;
 
 use svelte_ast_builder::Builder;
-use svelte_transform::{IgnoreQuery, transform_script};
+use svelte_transform::{IgnoreQuery, TransformError, transform_script};
 
 use crate::context::Ctx;
 
@@ -21,9 +22,9 @@ pub struct ScriptOutput<'a> {
     pub program_span_end: u32,
 }
 
-pub fn gen_script<'a>(ctx: &mut Ctx<'a>, dev: bool) -> ScriptOutput<'a> {
-    if ctx.query.component.instance_script.is_none() {
-        return ScriptOutput {
+pub fn gen_script<'a>(ctx: &mut Ctx<'a>, dev: bool) -> Result<ScriptOutput<'a>, TransformError> {
+    let Some(script) = ctx.query.component.instance_script.as_ref() else {
+        return Ok(ScriptOutput {
             imports: vec![],
             body: vec![],
             has_tracing: false,
@@ -31,19 +32,12 @@ pub fn gen_script<'a>(ctx: &mut Ctx<'a>, dev: bool) -> ScriptOutput<'a> {
             comments: vec![],
             source_text: "",
             program_span_end: 0,
-        };
+        });
     };
 
     let allocator = ctx.b.ast.allocator;
     let component_source = &ctx.query.component.source;
-    let script_content_start = ctx
-        .query
-        .component
-        .instance_script
-        .as_ref()
-        .expect("early return above when instance_script is None")
-        .content_span
-        .start;
+    let script_content_start = script.content_span.start;
     let filename = ctx.state.filename;
     let ignore_query = IgnoreQuery::new(ctx.query.analysis);
 
@@ -72,13 +66,7 @@ pub fn gen_script<'a>(ctx: &mut Ctx<'a>, dev: bool) -> ScriptOutput<'a> {
     }
 
     let component_scoping = ctx.query.scoping();
-    let script = ctx
-        .query
-        .component
-        .instance_script
-        .as_ref()
-        .expect("early return above when instance_script is None");
-    let is_ts = script.language == ScriptLanguage::TypeScript;
+    let is_ts = script.is_typescript();
     let script_text = ctx.query.component.source_text(script.content_span);
     transform_script_text(
         allocator,
@@ -108,7 +96,7 @@ pub fn transform_module_program<'a, 'b>(
     analysis: Option<&'b AnalysisData<'a>>,
     component_scoping: &'b ComponentScoping<'a>,
     dev: bool,
-) -> ScriptOutput<'a> {
+) -> Result<ScriptOutput<'a>, TransformError> {
     run_transform(
         allocator,
         program,
@@ -134,7 +122,7 @@ pub fn transform_component_module_script<'a>(
     allocator: &'a Allocator,
     source: &'a str,
     is_ts: bool,
-) -> ScriptOutput<'a> {
+) -> Result<ScriptOutput<'a>, TransformError> {
     let empty_scoping = ComponentScoping::new_empty();
     transform_script_text(
         allocator,
@@ -164,7 +152,7 @@ pub fn transform_component_module_program<'a, 'b>(
     analysis: Option<&'b AnalysisData<'a>>,
     component_scoping: &'b ComponentScoping<'a>,
     script_rune_calls: Option<&ScriptRuneCalls>,
-) -> ScriptOutput<'a> {
+) -> Result<ScriptOutput<'a>, TransformError> {
     run_transform(
         allocator,
         program,
@@ -205,7 +193,7 @@ fn transform_script_text<'a>(
     experimental_async: bool,
     ignore_query: IgnoreQuery<'_, 'a>,
     prepare_semantic: bool,
-) -> ScriptOutput<'a> {
+) -> Result<ScriptOutput<'a>, TransformError> {
     let src_type = if is_ts {
         SourceType::default()
             .with_typescript(true)
@@ -255,9 +243,10 @@ fn run_transform<'a>(
     experimental_async: bool,
     ignore_query: IgnoreQuery<'_, 'a>,
     prepare_semantic: bool,
-) -> ScriptOutput<'a> {
+) -> Result<ScriptOutput<'a>, TransformError> {
     let b = Builder::new(allocator);
-    let is_ts = program.source_type.is_typescript();
+    let mut original = CommentAnchors::default();
+    original.visit_program(&program);
 
     let out = transform_script(
         allocator,
@@ -278,11 +267,9 @@ fn run_transform<'a>(
         experimental_async,
         ignore_query,
         prepare_semantic,
-    );
+    )?;
 
-    if is_ts {
-        reattach_orphaned_comments(&mut program);
-    }
+    anchor_script_comments(&mut program, &original.declarations);
 
     let source_text = program.source_text;
     let program_span_end = program.span.end;
@@ -302,7 +289,7 @@ fn run_transform<'a>(
         program.comments.iter().copied().collect()
     };
 
-    ScriptOutput {
+    Ok(ScriptOutput {
         imports,
         body,
         has_tracing: out.has_tracing,
@@ -310,21 +297,111 @@ fn run_transform<'a>(
         comments,
         source_text,
         program_span_end,
-    }
+    })
 }
 
-fn reattach_orphaned_comments(program: &mut Program<'_>) {
-    let mut stmt_starts: Vec<u32> = program.body.iter().map(|s| s.span().start).collect();
-    stmt_starts.sort_unstable();
+/// Re-points comments whose anchor did not survive lowering. Leading comments
+/// of a rewritten declaration move to the first statement lowered from it, or
+/// to the next surviving statement when nothing was lowered from it. Trailing
+/// comments, which the printer does not emit, become leading comments of the
+/// next statement or of the enclosing body's end.
+fn anchor_script_comments(program: &mut Program<'_>, original_declarations: &FxHashMap<u32, Span>) {
+    let mut anchors = CommentAnchors::default();
+    anchors.visit_program(program);
+    anchors.anchors.insert(program.span.end);
+    anchors.statements.sort_unstable_by_key(|span| span.start);
 
+    let program_end = program.span.end;
     for comment in program.comments.iter_mut() {
-        if stmt_starts.binary_search(&comment.attached_to).is_ok() {
+        if comment.is_trailing() {
+            let body_end = anchors
+                .function_bodies
+                .iter()
+                .filter(|body| body.start < comment.span.start && comment.span.end < body.end)
+                .min_by_key(|body| body.size())
+                .map_or(program_end, |body| body.end - 1);
+            comment.attached_to = anchors
+                .statements
+                .iter()
+                .find(|span| span.start >= comment.span.end && span.end <= body_end + 1)
+                .map_or(body_end, |span| span.start);
+            comment.position = CommentPosition::Leading;
+            continue;
+        }
+        if anchors.anchors.contains(&comment.attached_to) {
+            continue;
+        }
+        let lowered = original_declarations
+            .get(&comment.attached_to)
+            .and_then(|owner| {
+                anchors
+                    .statements
+                    .iter()
+                    .find(|span| owner.start <= span.start && span.end <= owner.end)
+            });
+        if let Some(lowered) = lowered {
+            comment.attached_to = lowered.start;
             continue;
         }
-        let pos = comment.span.end;
-        let next = stmt_starts.iter().find(|&&s| s >= pos).copied();
-        if let Some(next_start) = next {
-            comment.attached_to = next_start;
+        let container = anchors
+            .statements
+            .iter()
+            .filter(|span| span.start <= comment.span.start && comment.span.end <= span.end)
+            .min_by_key(|span| span.size());
+        let next = anchors
+            .statements
+            .iter()
+            .find(|span| {
+                span.start >= comment.span.end
+                    && container.is_none_or(|container| span.end <= container.end)
+            })
+            .or(container);
+        if let Some(target) = next {
+            comment.attached_to = target.start;
+        }
+    }
+}
+
+#[derive(Default)]
+struct CommentAnchors {
+    anchors: FxHashSet<u32>,
+    statements: Vec<Span>,
+    declarations: FxHashMap<u32, Span>,
+    function_bodies: Vec<Span>,
+}
+
+impl<'a> Visit<'a> for CommentAnchors {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        let span = stmt.span();
+        self.anchors.insert(span.start);
+        if !span.is_unspanned() {
+            self.statements.push(span);
+            if matches!(stmt, Statement::VariableDeclaration(_)) {
+                self.declarations.insert(span.start, span);
+            }
+        }
+        walk::walk_statement(self, stmt);
+    }
+
+    fn visit_class_element(&mut self, elem: &ClassElement<'a>) {
+        self.anchors.insert(elem.span().start);
+        walk::walk_class_element(self, elem);
+    }
+
+    fn visit_object_expression(&mut self, obj: &ObjectExpression<'a>) {
+        self.anchors.insert(obj.span.start);
+        walk::walk_object_expression(self, obj);
+    }
+
+    fn visit_argument(&mut self, arg: &Argument<'a>) {
+        self.anchors.insert(arg.span().start);
+        walk::walk_argument(self, arg);
+    }
+
+    fn visit_function_body(&mut self, body: &FunctionBody<'a>) {
+        if !body.span.is_unspanned() {
+            self.function_bodies.push(body.span);
         }
+        walk::walk_function_body(self, body);
     }
 }
@@ -0,0 +1,171 @@
+use rustc_hash::FxHashMap;
+
+use svelte_ast::{Attribute, Component, Node, Span};
+use svelte_diagnostics::{Diagnostic, LineIndex, Severity};
+
+const TRANSLATABLE_ATTRIBUTES: [&str; 4] = ["title", "alt", "placeholder", "aria-label"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtractedStringKind {
+    Text,
+    Title,
+    Attribute,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ExtractedString {
+    pub text: String,
+    pub kind: ExtractedStringKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribute: Option<String>,
+    pub span: Span,
+    pub line: usize,
+}
+
+pub fn extract_strings(component: &Component) -> Vec<ExtractedString> {
+    let source = component.source;
+    let line_index = LineIndex::new(source);
+    let mut strings = Vec::new();
+    let mut push = |text: &str, kind, attribute: Option<&str>, span: Span| {
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            return;
+        }
+        strings.push(ExtractedString {
+            text,
+            kind,
+            attribute: attribute.map(str::to_string),
+            span,
+            line: line_index.line_col(span.start as usize).0 + 1,
+        });
+    };
+
+    for node in component.store.iter_nodes() {
+        match node {
+            Node::Text(text) => {
+                let kind = match parent_element_name(component, text.id) {
+                    Some("script" | "style") => continue,
+                    Some("title") => ExtractedStringKind::Title,
+                    _ => ExtractedStringKind::Text,
+                };
+                push(text.value(source), kind, None, text.span);
+            }
+            Node::Element(el) => {
+                for attr in el.attributes {
+                    if let Attribute::StringAttribute(attr) = attr
+                        && TRANSLATABLE_ATTRIBUTES.contains(&attr.name.as_str())
+                    {
+                        push(
+                            &svelte_parser::decode_html_entities(
+                                attr.value_span.source_text(source),
+                            ),
+                            ExtractedStringKind::Attribute,
+                            Some(&attr.name),
+                            attr.span,
+                        );
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    strings.sort_by_key(|s| s.span.start);
+    strings
+}
+
+pub fn extract_strings_from_source(source: &str) -> Result<Vec<ExtractedString>, Vec<Diagnostic>> {
+    let allocator = svelte_ast::Allocator::default();
+    let (component, diagnostics) = svelte_parser::Parser::new(&allocator, source).parse();
+    if diagnostics.iter().any(|d| d.severity == Severity::Error) {
+        return Err(diagnostics);
+    }
+    Ok(extract_strings(&component))
+}
+
+fn parent_element_name<'a>(component: &Component<'a>, id: svelte_ast::NodeId) -> Option<&'a str> {
+    let fragment = component.store.node_fragment(id)?;
+    let owner = component.store.fragment(fragment).owner?;
+    match component.store.get(owner) {
+        Node::Element(el) => Some(el.name.as_str()),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CatalogReference {
+    pub file: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CatalogMessage {
+    pub msgid: String,
+    pub references: Vec<CatalogReference>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct MessageCatalog {
+    pub messages: Vec<CatalogMessage>,
+    #[serde(skip)]
+    index: FxHashMap<String, usize>,
+}
+
+impl MessageCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, filename: &str, strings: &[ExtractedString]) {
+        for s in strings {
+            let idx = *self.index.entry(s.text.clone()).or_insert_with(|| {
+                self.messages.push(CatalogMessage {
+                    msgid: s.text.clone(),
+                    references: Vec::new(),
+                });
+                self.messages.len() - 1
+            });
+            let references = &mut self.messages[idx].references;
+            if !references
+                .iter()
+                .any(|r| r.file == filename && r.line == s.line)
+            {
+                references.push(CatalogReference {
+                    file: filename.to_string(),
+                    line: s.line,
+                });
+            }
+        }
+    }
+
+    pub fn to_pot(&self) -> String {
+        let mut out = String::from(
+            "msgid \"\"\nmsgstr \"\"\n\"Content-Type: text/plain; charset=UTF-8\\n\"\n",
+        );
+        for message in &self.messages {
+            out.push('\n');
+            for reference in &message.references {
+                out.push_str(&format!("#: {}:{}\n", reference.file, reference.line));
+            }
+            out.push_str(&format!(
+                "msgid \"{}\"\nmsgstr \"\"\n",
+                escape_po(&message.msgid)
+            ));
+        }
+        out
+    }
+}
+
+fn escape_po(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
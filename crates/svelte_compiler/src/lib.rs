@@ -1,9 +1,29 @@
+mod i18n;
 mod options;
+mod project;
+mod session;
+mod stats;
+mod watch;
 
+pub use i18n::{
+    CatalogMessage, CatalogReference, ExtractedString, ExtractedStringKind, MessageCatalog,
+    extract_strings, extract_strings_from_source,
+};
 pub use options::{
-    CompileOptions, CssMode, ExperimentalOptions, GenerateMode, ModuleCompileOptions, Namespace,
+    CompileOptions, CssHash, CssHashGetter, CssHashInput, CssMode, CssTarget, ExperimentalOptions,
+    GenerateMode, LintOptions, ModuleCompileOptions, Namespace,
+};
+pub use project::{ProjectFile, ProjectFileDiagnostics, check_project};
+pub use session::Compiler;
+use stats::StatsCollector;
+pub use stats::{CompileStats, PassTiming, PhaseTiming, StatsClock};
+pub use svelte_analyze::css_hash_digest;
+pub use svelte_analyze::{
+    Dependency, DependencyKind, ReactivityEdge, ReactivityEdgeKind, ReactivityGraph,
+    ReactivityNode, ReactivityNodeKind,
 };
 use svelte_diagnostics::Diagnostic;
+pub use watch::{WatchDriver, WatchUpdate};
 
 #[derive(serde::Serialize)]
 pub struct CompileResult {
@@ -11,6 +31,8 @@ pub struct CompileResult {
 
     pub css: Option<String>,
     pub diagnostics: Vec<Diagnostic>,
+    pub dependencies: Vec<Dependency>,
+    pub stats: Option<CompileStats>,
 }
 
 fn apply_compile_options_to_component(
@@ -36,7 +58,7 @@ fn apply_compile_options_to_component(
             immutable: None,
             accessors: None,
             preserve_whitespace: None,
-            attributes: Vec::new(),
+            attributes: &[],
         });
     if opts.namespace.is_none() {
         opts.namespace = Some(ast_namespace);
@@ -87,72 +109,198 @@ fn resolved_css_mode(component: &svelte_ast::Component, options: &CompileOptions
     }
 }
 
+fn analyze_options(
+    component: &svelte_ast::Component,
+    options: &CompileOptions,
+    component_name: String,
+) -> svelte_analyze::AnalyzeOptions {
+    svelte_analyze::AnalyzeOptions {
+        custom_element: options.custom_element,
+        experimental_async: options.experimental.async_,
+        runes: resolved_runes_option(component, options),
+        accessors: resolved_accessors_option(component, options),
+        immutable: resolved_immutable_option(component, options),
+        preserve_whitespace: resolved_preserve_whitespace_option(component, options),
+        dev: options.dev,
+        inline_constants: options.inline_constants,
+        component_name,
+        filename_basename: options
+            .filename
+            .rsplit_once('/')
+            .or_else(|| options.filename.rsplit_once('\\'))
+            .map_or(options.filename.as_str(), |(_, basename)| basename)
+            .to_string(),
+        lint: options.lint,
+        warning_filter: None,
+        pass_clock: None,
+    }
+}
+
+pub(crate) fn unsupported_lang_diagnostics(component: &svelte_ast::Component) -> Vec<Diagnostic> {
+    [&component.instance_script, &component.module_script]
+        .into_iter()
+        .flatten()
+        .filter(|script| !script.has_supported_lang())
+        .map(|script| {
+            Diagnostic::error(
+                svelte_diagnostics::DiagnosticKind::ScriptUnsupportedLang {
+                    lang: script
+                        .lang(component.source)
+                        .unwrap_or_default()
+                        .to_string(),
+                },
+                lang_attribute_span(script.attributes, script.span),
+            )
+        })
+        .chain(
+            component
+                .css
+                .as_ref()
+                .filter(|css| !css.has_supported_lang(component.source))
+                .map(|css| {
+                    Diagnostic::error(
+                        svelte_diagnostics::DiagnosticKind::StyleUnsupportedLang {
+                            lang: css.lang(component.source).unwrap_or_default().to_string(),
+                        },
+                        lang_attribute_span(css.attributes, css.span),
+                    )
+                }),
+        )
+        .collect()
+}
+
+fn lang_attribute_span(
+    attributes: &[svelte_ast::Attribute],
+    fallback: svelte_ast::Span,
+) -> svelte_ast::Span {
+    attributes
+        .iter()
+        .find_map(|attr| match attr {
+            svelte_ast::Attribute::StringAttribute(a) if a.name == "lang" => Some(a.span),
+            _ => None,
+        })
+        .unwrap_or(fallback)
+}
+
 pub fn compile(source: &str, options: &CompileOptions) -> CompileResult {
+    compile_in(&oxc_allocator::Allocator::default(), source, options, None)
+}
+
+pub fn compile_with_css_hash_getter(
+    source: &str,
+    options: &CompileOptions,
+    css_hash: CssHashGetter<'_>,
+) -> CompileResult {
+    compile_in(
+        &oxc_allocator::Allocator::default(),
+        source,
+        options,
+        Some(css_hash),
+    )
+}
+
+pub(crate) fn compile_in(
+    js_alloc: &oxc_allocator::Allocator,
+    source: &str,
+    options: &CompileOptions,
+    css_hash_getter: Option<CssHashGetter<'_>>,
+) -> CompileResult {
     let candidate_name = options.component_name();
+    let mut collector = StatsCollector::new(options.stats, options.stats_clock);
 
-    let js_alloc = oxc_allocator::Allocator::default();
+    let started = collector.start();
     let (mut component, js_result, mut diagnostics) =
-        svelte_parser::parse_with_js(&js_alloc, source);
+        svelte_parser::parse_with_js(js_alloc, source);
     apply_compile_options_to_component(&mut component, options);
     let css_parsed = svelte_parser::parse_css_block(&component);
+    collector.finish("parse", started);
+    if let Some(stats) = collector.counts() {
+        stats.nodes = component.node_count();
+        stats.fragments = component.fragment_count();
+    }
+
+    let lang_diagnostics = unsupported_lang_diagnostics(&component);
+    if !lang_diagnostics.is_empty() {
+        diagnostics.extend(lang_diagnostics);
+        return CompileResult {
+            js: None,
+            css: None,
+            diagnostics,
+            dependencies: Vec::new(),
+            stats: collector.into_stats(),
+        };
+    }
 
     let has_parse_errors = diagnostics
         .iter()
         .any(|d| d.severity == svelte_diagnostics::Severity::Error);
 
-    let analyze_opts = svelte_analyze::AnalyzeOptions {
-        custom_element: options.custom_element,
-        experimental_async: options.experimental.async_,
-        runes: resolved_runes_option(&component, options),
-        accessors: resolved_accessors_option(&component, options),
-        immutable: resolved_immutable_option(&component, options),
-        preserve_whitespace: resolved_preserve_whitespace_option(&component, options),
-        dev: options.dev,
-        component_name: candidate_name,
-        filename_basename: options
-            .filename
-            .rsplit_once('/')
-            .or_else(|| options.filename.rsplit_once('\\'))
-            .map_or(options.filename.as_str(), |(_, basename)| basename)
-            .to_string(),
-        warning_filter: None,
-    };
+    let mut analyze_opts = analyze_options(&component, options, candidate_name);
+    analyze_opts.pass_clock = collector.clock();
 
-    let codegen_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        let (mut analysis, mut parsed, mut analyze_diags) =
-            svelte_analyze::analyze_with_options(&component, js_result, &analyze_opts);
+    let mut dependencies = Vec::new();
+    let (js, css, analyze_diags) = 'compile: {
+        let started = collector.start();
+        let analyzed = svelte_analyze::analyze_with_options(&component, js_result, &analyze_opts);
+        collector.finish("analyze", started);
+        let (mut analysis, mut parsed, mut analyze_diags) = match analyzed {
+            Ok(analyzed) => analyzed,
+            Err(err) => break 'compile (None, None, vec![analyze_error_diagnostic(&err)]),
+        };
+        if let Some(stats) = collector.counts() {
+            stats.analyze_passes = std::mem::take(&mut analysis.pass_timings);
+            stats.symbols = analysis.scoping.symbol_ids().count() as u32;
+        }
+        dependencies = svelte_analyze::component_dependencies(
+            &component,
+            &parsed,
+            &analysis,
+            css_parsed.as_ref().map(|(ss, _)| ss),
+        );
 
         let mut css_text: Option<String> = None;
-        if let Some((ss, css_diags)) = css_parsed {
+        if let Some((ss, css_diags)) = css_parsed
+            && let Some(css_block) = component.css.as_ref()
+        {
             analyze_diags.extend(css_diags);
             let inject_styles = resolved_css_mode(&component, options) == CssMode::Injected
                 || analysis.output.is_custom_element_target;
+            let started = collector.start();
             svelte_analyze::analyze_css_pass(
                 &component,
                 &ss,
                 &parsed,
                 inject_styles,
+                css_hash_getter.unwrap_or(&|input| options.css_hash.resolve(input)),
+                &options.filename,
                 &mut analysis,
                 &mut analyze_diags,
             );
-            let css_block = component
-                .css
-                .as_ref()
-                .unwrap_or_else(|| panic!("css block must exist when css_parsed is Some"));
+            collector.finish("css_prune", started);
+            if let Some(stats) = collector.counts() {
+                stats.css_rules_pruned = svelte_css::Printer::count_unused_rules(
+                    &ss,
+                    &analysis.output.css.used_selectors,
+                );
+            }
+            let started = collector.start();
             let css_source = component.source_text(css_block.content_span);
             let raw_css = svelte_transform_css::transform_css_with_usage(
                 &analysis.output.css.hash,
                 &analysis.output.css.keyframes,
                 Some(&analysis.output.css.used_selectors),
                 true,
+                &options.css_target,
+                options.css_minify,
                 ss,
                 css_source,
             );
-            css_text = if inject_styles {
+            css_text = if inject_styles && !options.css_minify {
                 Some(svelte_transform_css::compact_css_for_injection(&raw_css))
             } else {
                 Some(raw_css)
             };
+            collector.finish("css_transform", started);
         }
 
         let (css, injected_css_text) = if analysis.output.css.inject_styles {
@@ -167,16 +315,17 @@ pub fn compile(source: &str, options: &CompileOptions) -> CompileResult {
                 .any(|d| d.severity == svelte_diagnostics::Severity::Error);
 
         if has_errors {
-            return (None, css, analyze_diags);
+            break 'compile (None, css, analyze_diags);
         }
 
+        let started = collector.start();
         let mut ident_gen =
             svelte_analyze::IdentGen::with_conflicts(analysis.scoping.collect_all_symbol_names());
         let name = analysis.component_name().to_string();
         let _ = ident_gen.generate(&name);
-        let transform_data = {
+        let transform_result = {
             let mut compile_ctx = svelte_types::CompileContext {
-                alloc: &js_alloc,
+                alloc: js_alloc,
                 component: &component,
                 analysis: &analysis,
                 js_arena: &mut parsed,
@@ -187,62 +336,129 @@ pub fn compile(source: &str, options: &CompileOptions) -> CompileResult {
                 &svelte_types::TransformOptions { dev: options.dev },
             )
         };
+        collector.finish("transform", started);
+        let transform_data = match transform_result {
+            Ok(transform_data) => transform_data,
+            Err(err) => {
+                analyze_diags.push(internal_error_diagnostic(&component, &err.into()));
+                break 'compile (None, css, analyze_diags);
+            }
+        };
         let codegen_options = svelte_types::CodegenOptions {
             dev: options.dev,
             experimental_async: options.experimental.async_,
             filename: options.filename.clone(),
+            minify: options.minify,
+            runtime_path: options.runtime_path.clone(),
+            stats: options.stats,
         };
         let compile_ctx = svelte_types::CompileContext {
-            alloc: &js_alloc,
+            alloc: js_alloc,
             component: &component,
             analysis: &analysis,
             js_arena: &mut parsed,
             ident_gen: &mut ident_gen,
         };
-        let js = svelte_codegen_client::generate(
+        let started = collector.start();
+        let output = svelte_codegen_client::generate(
             compile_ctx,
             &codegen_options,
             transform_data,
             injected_css_text.as_deref(),
         );
-        (Some(js), css, analyze_diags)
-    }));
-
-    match codegen_result {
-        Ok((js, css, analyze_diags)) => {
-            diagnostics.extend(analyze_diags);
-            CompileResult {
-                js,
-                css,
-                diagnostics,
+        collector.finish("codegen", started);
+        match output {
+            Ok(output) => {
+                if let Some(stats) = collector.counts() {
+                    stats.template_effects = output.template_effects;
+                }
+                (Some(output.js), css, analyze_diags)
             }
-        }
-        Err(panic_payload) => {
-            let message = if let Some(s) = panic_payload.downcast_ref::<String>() {
-                s.clone()
-            } else if let Some(s) = panic_payload.downcast_ref::<&str>() {
-                s.to_string()
-            } else {
-                "unknown internal error".to_string()
-            };
-            diagnostics.push(Diagnostic::internal_error(message));
-            CompileResult {
-                js: None,
-                css: None,
-                diagnostics,
+            Err(err) => {
+                analyze_diags.push(internal_error_diagnostic(&component, &err));
+                (None, css, analyze_diags)
             }
         }
+    };
+
+    diagnostics.extend(analyze_diags);
+    CompileResult {
+        js,
+        css,
+        diagnostics,
+        dependencies,
+        stats: collector.into_stats(),
+    }
+}
+
+fn analyze_error_diagnostic(err: &svelte_analyze::AnalyzeError) -> Diagnostic {
+    Diagnostic::internal_error_at(err.to_string(), err.span.unwrap_or_default())
+}
+
+fn internal_error_diagnostic(
+    component: &svelte_ast::Component,
+    err: &svelte_codegen_client::CodegenError,
+) -> Diagnostic {
+    let span = err
+        .span()
+        .or_else(|| err.node_id().and_then(|id| component.store.node_span(id)))
+        .unwrap_or_default();
+    Diagnostic::internal_error_at(err.to_string(), span)
+}
+
+pub fn reactivity_graph(
+    source: &str,
+    options: &CompileOptions,
+) -> Result<ReactivityGraph, Vec<Diagnostic>> {
+    let js_alloc = oxc_allocator::Allocator::default();
+    let (mut component, js_result, mut diagnostics) =
+        svelte_parser::parse_with_js(&js_alloc, source);
+    apply_compile_options_to_component(&mut component, options);
+    diagnostics.extend(unsupported_lang_diagnostics(&component));
+    if diagnostics
+        .iter()
+        .any(|d| d.severity == svelte_diagnostics::Severity::Error)
+    {
+        return Err(diagnostics);
+    }
+
+    let analyze_opts = analyze_options(&component, options, options.component_name());
+    let (analysis, parsed, analyze_diags) =
+        match svelte_analyze::analyze_with_options(&component, js_result, &analyze_opts) {
+            Ok(result) => result,
+            Err(err) => {
+                diagnostics.push(analyze_error_diagnostic(&err));
+                return Err(diagnostics);
+            }
+        };
+    diagnostics.extend(analyze_diags);
+    if diagnostics
+        .iter()
+        .any(|d| d.severity == svelte_diagnostics::Severity::Error)
+    {
+        return Err(diagnostics);
     }
+    Ok(svelte_analyze::build_reactivity_graph(
+        &component, &parsed, &analysis,
+    ))
 }
 
 pub fn compile_module(source: &str, options: &ModuleCompileOptions) -> CompileResult {
+    compile_module_in(&oxc_allocator::Allocator::default(), source, options)
+}
+
+pub(crate) fn compile_module_in(
+    js_alloc: &oxc_allocator::Allocator,
+    source: &str,
+    options: &ModuleCompileOptions,
+) -> CompileResult {
     let is_ts = options.filename.ends_with(".ts");
     let dev = options.dev;
 
-    let js_alloc = oxc_allocator::Allocator::default();
-
     let (analysis, mut parsed, mut diagnostics) =
-        svelte_analyze::analyze_module(&js_alloc, source, is_ts, dev);
+        svelte_analyze::analyze_module(js_alloc, source, is_ts, dev);
+
+    let dependencies = svelte_analyze::module_dependencies(&parsed);
 
     if options.generate == GenerateMode::False
         || diagnostics
@@ -253,36 +469,50 @@ pub fn compile_module(source: &str, options: &ModuleCompileOptions) -> CompileRe
             js: None,
             css: None,
             diagnostics,
+            dependencies,
+            stats: None,
         };
     }
 
-    let program = parsed
-        .program
-        .take()
-        .expect("analyze_module produced no program");
-    let codegen_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        svelte_codegen_client::generate_module(&js_alloc, program, &analysis, dev)
-    }));
-
-    match codegen_result {
+    let Some(program) = parsed.program.take() else {
+        diagnostics.push(Diagnostic::internal_error(
+            "analyze_module produced no program".to_string(),
+        ));
+        return CompileResult {
+            js: None,
+            css: None,
+            diagnostics,
+            dependencies,
+            stats: None,
+        };
+    };
+    let codegen_options = svelte_types::CodegenOptions {
+        dev,
+        experimental_async: false,
+        filename: options.filename.clone(),
+        minify: options.minify,
+        runtime_path: options.runtime_path.clone(),
+        stats: false,
+    };
+    match svelte_codegen_client::generate_module(js_alloc, program, &analysis, &codegen_options) {
         Ok(js) => CompileResult {
             js: Some(js),
             css: None,
             diagnostics,
+            dependencies,
+            stats: None,
         },
-        Err(panic_payload) => {
-            let message = if let Some(s) = panic_payload.downcast_ref::<String>() {
-                s.clone()
-            } else if let Some(s) = panic_payload.downcast_ref::<&str>() {
-                s.to_string()
-            } else {
-                "unknown internal error".to_string()
-            };
-            diagnostics.push(Diagnostic::internal_error(message));
+        Err(err) => {
+            diagnostics.push(Diagnostic::internal_error_at(
+                err.to_string(),
+                err.span().unwrap_or_default(),
+            ));
             CompileResult {
                 js: None,
                 css: None,
                 diagnostics,
+                dependencies,
+                stats: None,
             }
         }
     }
@@ -1,3 +1,8 @@
+pub use svelte_analyze::{CssHash, CssHashGetter, CssHashInput, LintOptions};
+pub use svelte_transform_css::CssTarget;
+
+use crate::StatsClock;
+
 #[derive(Debug, Clone, Default, serde::Deserialize)]
 #[serde(default)]
 pub struct ExperimentalOptions {
@@ -17,12 +22,20 @@ pub struct CompileOptions {
     pub custom_element: bool,
     pub namespace: Namespace,
     pub css: CssMode,
+    #[serde(deserialize_with = "deserialize_css_hash")]
+    pub css_hash: CssHash,
+    #[serde(deserialize_with = "deserialize_css_target")]
+    pub css_target: CssTarget,
+    pub css_minify: bool,
 
     pub runes: Option<bool>,
     pub preserve_comments: bool,
     pub preserve_whitespace: bool,
     pub disclose_version: bool,
     pub hmr: bool,
+    pub minify: bool,
+    pub inline_constants: bool,
+    pub runtime_path: Option<String>,
 
     pub accessors: bool,
 
@@ -30,6 +43,10 @@ pub struct CompileOptions {
 
     pub compatibility_component_api: u8,
     pub experimental: ExperimentalOptions,
+    pub lint: LintOptions,
+    pub stats: bool,
+    #[serde(skip)]
+    pub stats_clock: Option<StatsClock>,
 }
 
 impl Default for CompileOptions {
@@ -43,20 +60,52 @@ impl Default for CompileOptions {
             custom_element: false,
             namespace: Namespace::default(),
             css: CssMode::default(),
+            css_hash: CssHash::default(),
+            css_target: CssTarget::default(),
+            css_minify: false,
             runes: None,
             preserve_comments: false,
             preserve_whitespace: false,
             disclose_version: true,
             hmr: false,
+            minify: false,
+            inline_constants: false,
+            runtime_path: None,
             accessors: false,
             immutable: false,
             compatibility_component_api: 5,
             experimental: ExperimentalOptions::default(),
+            lint: LintOptions::default(),
+            stats: false,
+            stats_clock: None,
         }
     }
 }
 
 impl CompileOptions {
+    /// Rejects option values that parse but cannot produce valid output.
+    pub fn validate(&self) -> Result<(), String> {
+        if !matches!(self.compatibility_component_api, 4 | 5) {
+            return Err(format!(
+                "compatibility.componentApi must be 4 or 5, got {}",
+                self.compatibility_component_api
+            ));
+        }
+        if let CssHash::Template(template) = &self.css_hash
+            && template.trim().is_empty()
+        {
+            return Err("cssHash template must not be empty".to_string());
+        }
+        if self
+            .runtime_path
+            .as_deref()
+            .is_some_and(|path| path.trim().is_empty())
+        {
+            return Err("runtimePath must not be empty".to_string());
+        }
+        Ok(())
+    }
+
     pub fn component_name(&self) -> String {
         let candidate = if let Some(ref name) = self.name {
             name.clone()
@@ -82,15 +131,15 @@ impl CompileOptions {
                 name = dir.to_string();
             }
 
-            if name.is_empty() {
-                "Component".to_string()
-            } else {
-                let mut chars = name.chars();
-                let first = chars.next().expect("name is non-empty (checked above)");
-                let mut capitalized = String::new();
-                capitalized.extend(first.to_uppercase());
-                capitalized.extend(chars);
-                capitalized
+            let mut chars = name.chars();
+            match chars.next() {
+                Some(first) => {
+                    let mut capitalized = String::new();
+                    capitalized.extend(first.to_uppercase());
+                    capitalized.extend(chars);
+                    capitalized
+                }
+                None => "Component".to_string(),
             }
         };
 
@@ -121,6 +170,24 @@ impl CompileOptions {
     }
 }
 
+fn deserialize_css_hash<'de, D>(deserializer: D) -> Result<CssHash, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let template: Option<String> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(template.map_or(CssHash::Default, CssHash::Template))
+}
+
+fn deserialize_css_target<'de, D>(deserializer: D) -> Result<CssTarget, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let query: Option<String> = serde::Deserialize::deserialize(deserializer)?;
+    query.map_or(Ok(CssTarget::default()), |query| {
+        CssTarget::parse(&query).map_err(serde::de::Error::custom)
+    })
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 #[serde(default, rename_all = "camelCase")]
 pub struct ModuleCompileOptions {
@@ -128,6 +195,8 @@ pub struct ModuleCompileOptions {
     pub generate: GenerateMode,
     pub filename: String,
     pub root_dir: Option<String>,
+    pub minify: bool,
+    pub runtime_path: Option<String>,
 }
 
 impl Default for ModuleCompileOptions {
@@ -137,6 +206,8 @@ impl Default for ModuleCompileOptions {
             generate: GenerateMode::default(),
             filename: "(unknown)".to_string(),
             root_dir: None,
+            minify: false,
+            runtime_path: None,
         }
     }
 }
@@ -176,6 +247,27 @@ pub enum GenerateMode {
 mod tests {
     use super::*;
 
+    #[test]
+    fn validate_rejects_unusable_values() {
+        assert!(CompileOptions::default().validate().is_ok());
+        for options in [
+            CompileOptions {
+                compatibility_component_api: 3,
+                ..Default::default()
+            },
+            CompileOptions {
+                css_hash: CssHash::Template(" ".to_string()),
+                ..Default::default()
+            },
+            CompileOptions {
+                runtime_path: Some(String::new()),
+                ..Default::default()
+            },
+        ] {
+            assert!(options.validate().is_err());
+        }
+    }
+
     #[test]
     fn component_name_from_filename() {
         let opts = CompileOptions {
@@ -275,6 +367,36 @@ mod tests {
         assert!(opts.custom_element);
     }
 
+    #[test]
+    fn serde_lint() {
+        let opts: CompileOptions = serde_json::from_str("{}").expect("test invariant");
+        assert!(!opts.lint.effect_to_derived);
+
+        let json = r#"{"lint": {"effectToDerived": true}}"#;
+        let opts: CompileOptions = serde_json::from_str(json).expect("test invariant");
+        assert!(opts.lint.effect_to_derived);
+        assert!(!opts.lint.unused_declarations);
+
+        let json = r#"{"lint": {"unusedDeclarations": true}}"#;
+        let opts: CompileOptions = serde_json::from_str(json).expect("test invariant");
+        assert!(opts.lint.unused_declarations);
+        assert!(!opts.lint.strict_html_nesting);
+
+        let json = r#"{"lint": {"strictHtmlNesting": true}}"#;
+        let opts: CompileOptions = serde_json::from_str(json).expect("test invariant");
+        assert!(opts.lint.strict_html_nesting);
+    }
+
+    #[test]
+    fn serde_inline_constants() {
+        let opts: CompileOptions = serde_json::from_str("{}").expect("test invariant");
+        assert!(!opts.inline_constants);
+
+        let json = r#"{"inlineConstants": true}"#;
+        let opts: CompileOptions = serde_json::from_str(json).expect("test invariant");
+        assert!(opts.inline_constants);
+    }
+
     #[test]
     fn serde_namespace() {
         let json = r#"{"namespace": "svg"}"#;
@@ -289,6 +411,55 @@ mod tests {
         assert_eq!(opts.css, CssMode::Injected);
     }
 
+    #[test]
+    fn serde_css_hash_template() {
+        let json = r#"{"cssHash": "{name}-{hash}"}"#;
+        let opts: CompileOptions = serde_json::from_str(json).expect("test invariant");
+        assert!(matches!(opts.css_hash, CssHash::Template(ref t) if t == "{name}-{hash}"));
+
+        let opts: CompileOptions = serde_json::from_str("{}").expect("test invariant");
+        assert!(matches!(opts.css_hash, CssHash::Default));
+    }
+
+    #[test]
+    fn serde_css_target() {
+        let json = r#"{"cssTarget": "chrome >= 100, safari 15"}"#;
+        let opts: CompileOptions = serde_json::from_str(json).expect("test invariant");
+        assert!(opts.css_target.lowers_nesting());
+        assert!(!opts.css_target.lowers_is_selector());
+
+        let json = r#"{"cssTarget": "netscape 4"}"#;
+        assert!(serde_json::from_str::<CompileOptions>(json).is_err());
+    }
+
+    #[test]
+    fn serde_css_minify() {
+        let json = r#"{"cssMinify": true}"#;
+        let opts: CompileOptions = serde_json::from_str(json).expect("test invariant");
+        assert!(opts.css_minify);
+        assert!(!CompileOptions::default().css_minify);
+    }
+
+    #[test]
+    fn serde_minify() {
+        let opts: CompileOptions =
+            serde_json::from_str(r#"{"minify": true}"#).expect("test invariant");
+        assert!(opts.minify);
+        let opts: ModuleCompileOptions =
+            serde_json::from_str(r#"{"minify": true}"#).expect("test invariant");
+        assert!(opts.minify);
+    }
+
+    #[test]
+    fn serde_runtime_path() {
+        let json = r#"{"runtimePath": "/vendor/svelte"}"#;
+        let opts: CompileOptions = serde_json::from_str(json).expect("test invariant");
+        assert_eq!(opts.runtime_path.as_deref(), Some("/vendor/svelte"));
+        let opts: ModuleCompileOptions = serde_json::from_str(json).expect("test invariant");
+        assert_eq!(opts.runtime_path.as_deref(), Some("/vendor/svelte"));
+        assert!(CompileOptions::default().runtime_path.is_none());
+    }
+
     #[test]
     fn serde_generate_mode() {
         let json = r#"{"generate": "client"}"#;
@@ -0,0 +1,121 @@
+use rustc_hash::FxHashMap;
+use svelte_analyze::{ComponentInterface, ComponentUsage};
+use svelte_diagnostics::{Diagnostic, Severity};
+
+use crate::{
+    CompileOptions, analyze_options, apply_compile_options_to_component,
+    unsupported_lang_diagnostics,
+};
+
+#[derive(Debug, Clone)]
+pub struct ProjectFile {
+    pub filename: String,
+    pub source: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ProjectFileDiagnostics {
+    pub filename: String,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+struct AnalyzedFile {
+    interface: ComponentInterface,
+    usages: Vec<ComponentUsage>,
+}
+
+pub fn check_project(
+    files: &[ProjectFile],
+    options: &CompileOptions,
+) -> Vec<ProjectFileDiagnostics> {
+    let analyzed: Vec<Option<AnalyzedFile>> = files
+        .iter()
+        .map(|file| analyze_project_file(file, options))
+        .collect();
+    let by_path: FxHashMap<String, usize> = files
+        .iter()
+        .enumerate()
+        .map(|(idx, file)| (normalize_path(&file.filename), idx))
+        .collect();
+
+    let mut result = Vec::new();
+    for (file, analyzed_file) in files.iter().zip(&analyzed) {
+        let Some(analyzed_file) = analyzed_file else {
+            continue;
+        };
+        let mut diagnostics = Vec::new();
+        for usage in &analyzed_file.usages {
+            let Some(child) = resolve_specifier(&file.filename, &usage.source)
+                .and_then(|path| by_path.get(&path))
+                .and_then(|&idx| analyzed[idx].as_ref())
+            else {
+                continue;
+            };
+            diagnostics.extend(svelte_analyze::validate_component_usage(
+                usage,
+                &child.interface,
+            ));
+        }
+        if !diagnostics.is_empty() {
+            result.push(ProjectFileDiagnostics {
+                filename: file.filename.clone(),
+                diagnostics,
+            });
+        }
+    }
+    result
+}
+
+fn analyze_project_file(file: &ProjectFile, options: &CompileOptions) -> Option<AnalyzedFile> {
+    let options = CompileOptions {
+        filename: file.filename.clone(),
+        ..options.clone()
+    };
+    let js_alloc = oxc_allocator::Allocator::default();
+    let (mut component, js_result, diagnostics) =
+        svelte_parser::parse_with_js(&js_alloc, &file.source);
+    if diagnostics.iter().any(|d| d.severity == Severity::Error)
+        || !unsupported_lang_diagnostics(&component).is_empty()
+    {
+        return None;
+    }
+    apply_compile_options_to_component(&mut component, &options);
+    let analyze_opts = analyze_options(&component, &options, options.component_name());
+    let (analysis, parsed, _) =
+        svelte_analyze::analyze_with_options(&component, js_result, &analyze_opts).ok()?;
+    Some(AnalyzedFile {
+        interface: svelte_analyze::component_interface(&component, &parsed, &analysis),
+        usages: svelte_analyze::component_usages(&component, &parsed, &analysis),
+    })
+}
+
+pub(crate) fn resolve_specifier(importer: &str, specifier: &str) -> Option<String> {
+    if !specifier.starts_with("./") && !specifier.starts_with("../") {
+        return None;
+    }
+    let importer = importer.replace('\\', "/");
+    match importer.rsplit_once('/') {
+        Some((dir, _)) => Some(normalize_path(&format!("{dir}/{specifier}"))),
+        None => Some(normalize_path(specifier)),
+    }
+}
+
+pub(crate) fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." if parts.last().is_some_and(|last| *last != "..") => {
+                parts.pop();
+            }
+            _ => parts.push(part),
+        }
+    }
+    let joined = parts.join("/");
+    if path.starts_with('/') {
+        format!("/{joined}")
+    } else {
+        joined
+    }
+}
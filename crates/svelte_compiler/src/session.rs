@@ -0,0 +1,68 @@
+use oxc_allocator::Allocator;
+
+use crate::{CompileOptions, CompileResult, CssHashGetter, ModuleCompileOptions};
+
+/// A compile session for batch and watch builds. It checks the options once
+/// and keeps one `Allocator` that is reset, not dropped, between compiles.
+/// Everything else (scoping, analysis tables, line index) is still built per
+/// file.
+pub struct Compiler {
+    options: CompileOptions,
+    module_options: ModuleCompileOptions,
+    allocator: Allocator,
+}
+
+impl Compiler {
+    pub fn new(options: CompileOptions) -> Result<Self, String> {
+        options.validate()?;
+        let module_options = ModuleCompileOptions {
+            dev: options.dev,
+            generate: options.generate,
+            filename: options.filename.clone(),
+            root_dir: options.root_dir.clone(),
+            minify: options.minify,
+            runtime_path: options.runtime_path.clone(),
+        };
+        Ok(Self {
+            options,
+            module_options,
+            allocator: Allocator::default(),
+        })
+    }
+
+    pub fn options(&self) -> &CompileOptions {
+        &self.options
+    }
+
+    pub fn compile(&mut self, source: &str, filename: &str) -> CompileResult {
+        self.compile_inner(source, filename, None)
+    }
+
+    pub fn compile_with_css_hash_getter(
+        &mut self,
+        source: &str,
+        filename: &str,
+        css_hash: CssHashGetter<'_>,
+    ) -> CompileResult {
+        self.compile_inner(source, filename, Some(css_hash))
+    }
+
+    fn compile_inner(
+        &mut self,
+        source: &str,
+        filename: &str,
+        css_hash: Option<CssHashGetter<'_>>,
+    ) -> CompileResult {
+        self.allocator.reset();
+        self.options.filename.clear();
+        self.options.filename.push_str(filename);
+        crate::compile_in(&self.allocator, source, &self.options, css_hash)
+    }
+
+    pub fn compile_module(&mut self, source: &str, filename: &str) -> CompileResult {
+        self.allocator.reset();
+        self.module_options.filename.clear();
+        self.module_options.filename.push_str(filename);
+        crate::compile_module_in(&self.allocator, source, &self.module_options)
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+pub use svelte_analyze::PassTiming;
+
+pub type StatsClock = svelte_analyze::PassClock;
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PhaseTiming {
+    pub phase: &'static str,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct CompileStats {
+    pub phases: Vec<PhaseTiming>,
+    pub analyze_passes: Vec<PassTiming>,
+    pub nodes: u32,
+    pub fragments: u32,
+    pub symbols: u32,
+    pub template_effects: u32,
+    pub css_rules_pruned: u32,
+}
+
+impl CompileStats {
+    pub fn phase(&self, phase: &str) -> Option<Duration> {
+        self.phases
+            .iter()
+            .find(|timing| timing.phase == phase)
+            .map(|timing| timing.duration)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.phases.iter().map(|timing| timing.duration).sum()
+    }
+}
+
+fn monotonic_clock() -> Duration {
+    static ORIGIN: OnceLock<Instant> = OnceLock::new();
+    ORIGIN.get_or_init(Instant::now).elapsed()
+}
+
+pub(crate) struct StatsCollector {
+    clock: Option<StatsClock>,
+    stats: CompileStats,
+}
+
+impl StatsCollector {
+    pub(crate) fn new(enabled: bool, clock: Option<StatsClock>) -> Self {
+        Self {
+            clock: enabled.then(|| clock.unwrap_or(monotonic_clock)),
+            stats: CompileStats::default(),
+        }
+    }
+
+    pub(crate) fn clock(&self) -> Option<StatsClock> {
+        self.clock
+    }
+
+    pub(crate) fn start(&self) -> Option<Duration> {
+        self.clock.map(|clock| clock())
+    }
+
+    pub(crate) fn finish(&mut self, phase: &'static str, started: Option<Duration>) {
+        if let (Some(clock), Some(started)) = (self.clock, started) {
+            self.stats.phases.push(PhaseTiming {
+                phase,
+                duration: clock().saturating_sub(started),
+            });
+        }
+    }
+
+    pub(crate) fn counts(&mut self) -> Option<&mut CompileStats> {
+        self.clock.is_some().then_some(&mut self.stats)
+    }
+
+    pub(crate) fn into_stats(self) -> Option<CompileStats> {
+        self.clock.is_some().then_some(self.stats)
+    }
+}
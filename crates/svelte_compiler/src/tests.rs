@@ -739,3 +739,1127 @@ fn attribute_invalid_event_handler_string_value() {
         result.diagnostics
     );
 }
+
+#[test]
+fn css_hash_template_scopes_classes_and_keyframes() {
+    let opts = CompileOptions {
+        name: Some("Card".into()),
+        filename: "src/lib/Card.svelte".into(),
+        css_hash: CssHash::Template("{name}-{filename}-{hash}".into()),
+        ..Default::default()
+    };
+    let result = compile(
+        r#"<style>
+    @keyframes pulse { from { opacity: 0; } to { opacity: 1; } }
+    p { animation: pulse 1s; }
+</style>
+<p>hello</p>"#,
+        &opts,
+    );
+    let js = result
+        .js
+        .unwrap_or_else(|| panic!("compile produced no JS"));
+    let css = result
+        .css
+        .unwrap_or_else(|| panic!("compile produced no CSS"));
+    let class = css
+        .split_whitespace()
+        .find_map(|token| token.strip_prefix("p."))
+        .expect("scoped paragraph selector");
+    assert!(class.starts_with("Card-Card-"), "got: {css}");
+    assert!(
+        css.contains(&format!("@keyframes {class}-pulse")),
+        "got: {css}"
+    );
+    assert!(
+        css.contains(&format!("animation: {class}-pulse 1s")),
+        "got: {css}"
+    );
+    assert!(
+        js.contains(class),
+        "template must use the custom class: {js}"
+    );
+}
+
+#[test]
+fn css_hash_getter_receives_component_metadata() {
+    let opts = CompileOptions {
+        name: Some("Card".into()),
+        filename: "src/lib/Card.svelte".into(),
+        ..Default::default()
+    };
+    let result = compile_with_css_hash_getter(
+        "<style>p { color: red; }</style><p>hello</p>",
+        &opts,
+        &|input: &CssHashInput<'_>| {
+            format!(
+                "x-{}-{}",
+                input.name.to_lowercase(),
+                css_hash_digest(input.filename)
+            )
+        },
+    );
+    let css = result
+        .css
+        .unwrap_or_else(|| panic!("compile produced no CSS"));
+    let expected = format!("p.x-card-{}", css_hash_digest("src/lib/Card.svelte"));
+    assert!(css.contains(&expected), "got: {css}");
+}
+
+#[test]
+fn compile_options_are_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<CompileOptions>();
+}
+
+#[test]
+fn css_target_flattens_nested_rules() {
+    let opts = CompileOptions {
+        name: Some("App".into()),
+        css_target: CssTarget::parse("safari 15").expect("valid target"),
+        ..Default::default()
+    };
+    let result = compile(
+        "<style>div { color: red; & > span { color: blue; } }</style><div><span></span></div>",
+        &opts,
+    );
+    let css = result
+        .css
+        .unwrap_or_else(|| panic!("compile produced no CSS"));
+    let hash = css
+        .split_whitespace()
+        .find_map(|token| token.strip_prefix("div."))
+        .expect("scoped div selector");
+    assert!(
+        css.contains(&format!("div.{hash} > span:where(.{hash}) {{")),
+        "got: {css}"
+    );
+    assert!(!css.contains('&'), "got: {css}");
+}
+
+#[test]
+fn css_minify_prints_compact_stylesheet() {
+    let opts = CompileOptions {
+        name: Some("App".into()),
+        css_hash: CssHash::Template("s-{hash}".into()),
+        css_minify: true,
+        ..Default::default()
+    };
+    let result = compile(
+        "<style>\n  /* card */\n  p { color: #FFFFFF; margin: 0px; }\n  .unused { color: red; }\n  @media (min-width: 1px) { .unused { color: blue; } }\n</style>\n<p></p>",
+        &opts,
+    );
+    let css = result
+        .css
+        .unwrap_or_else(|| panic!("compile produced no CSS"));
+    let hash = css
+        .strip_prefix("p.")
+        .and_then(|rest| rest.split_once('{'))
+        .map(|(hash, _)| hash)
+        .expect("scoped p selector");
+    assert_eq!(css, format!("p.{hash}{{color:#fff;margin:0}}"));
+}
+
+#[test]
+fn minify_preserves_runtime_namespace_and_exports() {
+    let opts = CompileOptions {
+        name: Some("App".into()),
+        minify: true,
+        ..Default::default()
+    };
+    let result = compile(
+        "<script module>\n  export const VERSION = '1.0';\n</script>\n<script>\n  let count = $state(0);\n  let doubled = $derived(count * 2);\n  export function reset() { count = 0; }\n</script>\n<button onclick={() => count++}>{count} {doubled}</button>",
+        &opts,
+    );
+    assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+    let js = result.js.expect("compile produced no JS");
+    assert!(
+        js.starts_with("import*as $ from\"svelte/internal/client\";"),
+        "got: {js}"
+    );
+    assert!(js.contains("export default function App("), "got: {js}");
+    assert!(js.contains("export const VERSION="), "got: {js}");
+    assert!(js.contains("{reset:"), "got: {js}");
+    assert!(
+        !js.contains("doubled"),
+        "locals should be mangled, got: {js}"
+    );
+    assert!(!js.contains('\n'), "got: {js}");
+}
+
+#[test]
+fn minify_module_keeps_exports() {
+    let opts = ModuleCompileOptions {
+        minify: true,
+        ..Default::default()
+    };
+    let result = compile_module(
+        "export function counter(initial) {\n  let value = $state(initial);\n  return { get current() { return value; } };\n}",
+        &opts,
+    );
+    let js = result.js.expect("compile_module produced no JS");
+    assert!(
+        js.starts_with("import*as $ from\"svelte/internal/client\";"),
+        "got: {js}"
+    );
+    assert!(js.contains("export function counter("), "got: {js}");
+    assert!(!js.contains("initial"), "got: {js}");
+}
+
+#[test]
+fn runtime_path_rewrites_component_imports() {
+    let opts = CompileOptions {
+        name: Some("App".into()),
+        runes: Some(false),
+        runtime_path: Some("/vendor/svelte/".into()),
+        ..Default::default()
+    };
+    let result = compile(
+        "<script>\n  import { onMount } from 'svelte';\n  import { fade } from 'svelte/transition';\n  import Other from './Other.svelte';\n  onMount(() => {});\n</script>\n<div transition:fade><Other /></div>",
+        &opts,
+    );
+    let js = result.js.expect("compile produced no JS");
+    assert!(
+        js.contains("import \"/vendor/svelte/internal/flags/legacy\";"),
+        "got: {js}"
+    );
+    assert!(
+        js.contains("import * as $ from \"/vendor/svelte/internal/client\";"),
+        "got: {js}"
+    );
+    assert!(js.contains("from \"/vendor/svelte\";"), "got: {js}");
+    assert!(
+        js.contains("from \"/vendor/svelte/transition\";"),
+        "got: {js}"
+    );
+    assert!(js.contains("from \"./Other.svelte\";"), "got: {js}");
+    assert!(!js.contains("\"svelte/"), "got: {js}");
+}
+
+#[test]
+fn runtime_path_rewrites_module_imports() {
+    let opts = ModuleCompileOptions {
+        runtime_path: Some("https://esm.sh/svelte@5".into()),
+        ..Default::default()
+    };
+    let result = compile_module(
+        "export { tick } from 'svelte';\nexport * from 'svelte/store';\nexport let count = $state(0);",
+        &opts,
+    );
+    let js = result.js.expect("compile_module produced no JS");
+    assert!(
+        js.contains("import * as $ from \"https://esm.sh/svelte@5/internal/client\";"),
+        "got: {js}"
+    );
+    assert!(
+        js.contains("export { tick } from \"https://esm.sh/svelte@5\";"),
+        "got: {js}"
+    );
+    assert!(
+        js.contains("export * from \"https://esm.sh/svelte@5/store\";"),
+        "got: {js}"
+    );
+}
+
+#[test]
+fn internal_error_points_at_offending_node_span() {
+    let source = "<div class={x}></div>";
+    let alloc = oxc_allocator::Allocator::default();
+    let (component, _, _) = svelte_parser::parse_with_js(&alloc, source);
+    let element_id = component.fragment_nodes(component.root)[0];
+    let attr_id = component
+        .store
+        .element(element_id)
+        .expect("test invariant")
+        .attributes[0]
+        .id();
+    let err = svelte_codegen_client::CodegenError::UnexpectedNode {
+        node_id: attr_id,
+        expected: "element",
+    };
+    let diag = internal_error_diagnostic(&component, &err);
+    assert_eq!(
+        component.source_text(diag.span),
+        "class={x}",
+        "got: {diag:?}"
+    );
+    assert_eq!(diag.severity, svelte_diagnostics::Severity::Error);
+}
+
+#[test]
+fn internal_transform_error_keeps_its_span() {
+    let alloc = oxc_allocator::Allocator::default();
+    let (component, _, _) = svelte_parser::parse_with_js(&alloc, "<p>hi</p>");
+    let err = svelte_codegen_client::CodegenError::from(svelte_transform::TransformError {
+        node_id: None,
+        span: Some(svelte_ast::Span::new(3, 5)),
+        message: "lost expression".to_string(),
+    });
+    let diag = internal_error_diagnostic(&component, &err);
+    assert_eq!(diag.span, svelte_ast::Span::new(3, 5));
+    assert!(
+        matches!(&diag.kind, svelte_diagnostics::DiagnosticKind::InternalError(msg) if msg.contains("lost expression")),
+        "got: {diag:?}"
+    );
+}
+
+#[test]
+fn analyze_error_becomes_internal_error_diagnostic() {
+    let err = svelte_analyze::AnalyzeError {
+        span: Some(svelte_ast::Span::new(8, 12)),
+        message: "invalid analyze pass configuration".to_string(),
+    };
+    let diag = analyze_error_diagnostic(&err);
+    assert_eq!(diag.span, svelte_ast::Span::new(8, 12));
+    assert_eq!(diag.severity, svelte_diagnostics::Severity::Error);
+    assert!(
+        matches!(&diag.kind, svelte_diagnostics::DiagnosticKind::InternalError(msg) if msg.starts_with("analyze: invalid")),
+        "got: {diag:?}"
+    );
+}
+
+#[test]
+fn reactivity_graph_uses_component_name_and_serializes() {
+    let opts = CompileOptions {
+        filename: "src/Counter.svelte".into(),
+        ..Default::default()
+    };
+    let source =
+        "<script>let count = $state(0);</script>\n<button onclick={() => count++}>{count}</button>";
+    let graph = reactivity_graph(source, &opts).expect("graph builds");
+    assert_eq!(graph.component, "Counter");
+    let json = serde_json::to_value(&graph).expect("graph serializes");
+    assert_eq!(json["nodes"][0]["kind"], "state");
+    assert_eq!(json["nodes"][0]["name"], "count");
+    assert_eq!(json["edges"][0]["kind"], "write");
+    assert_eq!(json["edges"][1]["kind"], "read");
+}
+
+#[test]
+fn reactivity_graph_reports_errors_as_diagnostics() {
+    let diags = reactivity_graph("<div>{a +}</div>", &CompileOptions::default())
+        .expect_err("invalid template");
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.severity == svelte_diagnostics::Severity::Error),
+        "got: {diags:?}"
+    );
+}
+
+fn project_codes(files: &[(&str, &str)]) -> Vec<(String, String)> {
+    let files: Vec<ProjectFile> = files
+        .iter()
+        .map(|(filename, source)| ProjectFile {
+            filename: filename.to_string(),
+            source: source.to_string(),
+        })
+        .collect();
+    check_project(&files, &CompileOptions::default())
+        .into_iter()
+        .flat_map(|file| {
+            let filename = file.filename;
+            file.diagnostics
+                .into_iter()
+                .map(move |d| (filename.clone(), d.kind.code().to_string()))
+        })
+        .collect()
+}
+
+const TYPED_CHILD: &str = r#"<script lang="ts">
+    import type { Snippet } from 'svelte';
+    interface Props {
+        title: string;
+        count?: number;
+        header?: Snippet;
+    }
+    let { title, count = 0, value = $bindable(), header }: Props & {} = $props();
+</script>"#;
+
+#[test]
+fn check_project_reports_unknown_and_missing_props() {
+    let child = r#"<script lang="ts">
+    let { title, count = 0 }: { title: string; count?: number } = $props();
+</script>
+<h1>{title} {count}</h1>"#;
+    let parent = r#"<script>
+    import Child from './Child.svelte';
+</script>
+<Child count={1} color="red" />"#;
+    let codes = project_codes(&[
+        ("src/lib/Child.svelte", child),
+        ("src/lib/Parent.svelte", parent),
+    ]);
+    assert_eq!(
+        codes,
+        vec![
+            (
+                "src/lib/Parent.svelte".to_string(),
+                "component_unknown_prop".to_string()
+            ),
+            (
+                "src/lib/Parent.svelte".to_string(),
+                "component_missing_prop".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn check_project_resolves_parent_directory_specifiers() {
+    let child =
+        "<script>/** @type {{ title: string }} */ let { title } = $props();</script>{title}";
+    let parent = "<script>import Child from '../lib/Child.svelte';</script><Child />";
+    let codes = project_codes(&[
+        ("src/lib/Child.svelte", child),
+        ("src/routes/Page.svelte", parent),
+    ]);
+    assert_eq!(
+        codes,
+        vec![(
+            "src/routes/Page.svelte".to_string(),
+            "component_missing_prop".to_string()
+        )]
+    );
+}
+
+#[test]
+fn check_project_rest_props_and_spread_are_permissive() {
+    let child = r#"<script lang="ts">
+    let { title, ...rest }: { title: string } = $props();
+</script>
+<h1 {...rest}>{title}</h1>"#;
+    let parent = r#"<script>
+    import Child from './Child.svelte';
+    let props = { title: 'x' };
+</script>
+<Child class="big" />
+<Child {...props} />"#;
+    let codes = project_codes(&[("Child.svelte", child), ("Parent.svelte", parent)]);
+    assert_eq!(
+        codes,
+        vec![(
+            "Parent.svelte".to_string(),
+            "component_missing_prop".to_string()
+        )]
+    );
+}
+
+#[test]
+fn check_project_untyped_props_are_never_required() {
+    let child = r#"<script>
+    let { title, count = 0 } = $props();
+</script>
+<h1>{title} {count}</h1>"#;
+    let jsdoc_child = r#"<script>
+    /** @type {{ title?: string, count: number }} */
+    let { title, count } = $props();
+</script>
+<h1>{title} {count}</h1>"#;
+    let parent = r#"<script>
+    import Child from './Child.svelte';
+    import Doc from './Doc.svelte';
+</script>
+<Child />
+<Doc />"#;
+    let codes = project_codes(&[
+        ("Child.svelte", child),
+        ("Doc.svelte", jsdoc_child),
+        ("Parent.svelte", parent),
+    ]);
+    assert_eq!(
+        codes,
+        vec![(
+            "Parent.svelte".to_string(),
+            "component_missing_prop".to_string()
+        )]
+    );
+}
+
+#[test]
+fn check_project_bind_requires_bindable_prop() {
+    let child =
+        "<script>let { value = $bindable(), label = '' } = $props();</script>{label}{value}";
+    let parent = r#"<script>
+    import Input from './Input.svelte';
+    let value = $state('');
+    let label = $state('');
+</script>
+<Input bind:value bind:label />"#;
+    let codes = project_codes(&[("Input.svelte", child), ("Form.svelte", parent)]);
+    assert_eq!(
+        codes,
+        vec![(
+            "Form.svelte".to_string(),
+            "component_bind_non_bindable".to_string()
+        )]
+    );
+}
+
+#[test]
+fn check_project_snippets_match_rendered_and_typed_props() {
+    let parent = r#"<script>
+    import Card from './Card.svelte';
+</script>
+<Card title="x">
+    {#snippet header()}<b>h</b>{/snippet}
+</Card>
+<Card>
+    {#snippet title()}<b>t</b>{/snippet}
+</Card>"#;
+    let typed_child = TYPED_CHILD.replace("Props & {}", "Props");
+    let codes = project_codes(&[("Card.svelte", &typed_child), ("App.svelte", parent)]);
+    assert_eq!(
+        codes,
+        vec![(
+            "App.svelte".to_string(),
+            "component_snippet_prop_mismatch".to_string()
+        )]
+    );
+}
+
+#[test]
+fn check_project_unresolvable_prop_type_marks_props_optional() {
+    let parent = "<script>import Card from './Card.svelte';</script><Card />";
+    let codes = project_codes(&[("Card.svelte", TYPED_CHILD), ("App.svelte", parent)]);
+    assert!(codes.is_empty(), "got: {codes:?}");
+}
+
+#[test]
+fn check_project_children_and_legacy_slots() {
+    let runes_child = "<script>let { children, footer } = $props();</script>{@render children()}{@render footer?.()}";
+    let legacy_child = "<svelte:options runes={false} /><script>export let title;</script><h1>{title}</h1><slot /><slot name=\"footer\" />";
+    let parent = r#"<script>
+    import Runes from './Runes.svelte';
+    import Legacy from './Legacy.svelte';
+    let title = 'x';
+</script>
+<Runes>body{#snippet footer()}f{/snippet}</Runes>
+<Legacy bind:title>body<p slot="footer">f</p></Legacy>
+<Legacy {title} extra={1} />"#;
+    let codes = project_codes(&[
+        ("Runes.svelte", runes_child),
+        ("Legacy.svelte", legacy_child),
+        ("App.svelte", parent),
+    ]);
+    assert_eq!(
+        codes,
+        vec![(
+            "App.svelte".to_string(),
+            "component_unknown_prop".to_string()
+        )]
+    );
+}
+
+#[test]
+fn check_project_honors_svelte_ignore() {
+    let child = "<script>let { title } = $props();</script>{title}";
+    let parent = r#"<script>import Child from './Child.svelte';</script>
+<!-- svelte-ignore component_missing_prop -->
+<Child />"#;
+    let codes = project_codes(&[("Child.svelte", child), ("Parent.svelte", parent)]);
+    assert!(codes.is_empty(), "got: {codes:?}");
+}
+
+#[test]
+fn extract_strings_collects_text_title_and_attributes() {
+    let source = r#"<script>let name = 'x';</script>
+<svelte:head>
+    <title>My   page</title>
+    <script>console.log('skip');</script>
+</svelte:head>
+<h1 class="big" title="Greeting">Hello {name}!</h1>
+<img alt="A &quot;cat&quot;" src="cat.png" />
+<input placeholder="Search" aria-label={name} />
+<p>
+</p>
+<style>p { color: red; }</style>"#;
+    let strings = extract_strings_from_source(source).expect("parses");
+    let found: Vec<(&str, ExtractedStringKind, usize)> = strings
+        .iter()
+        .map(|s| (s.text.as_str(), s.kind, s.line))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            ("My page", ExtractedStringKind::Title, 3),
+            ("Greeting", ExtractedStringKind::Attribute, 6),
+            ("Hello", ExtractedStringKind::Text, 6),
+            ("!", ExtractedStringKind::Text, 6),
+            ("A \"cat\"", ExtractedStringKind::Attribute, 7),
+            ("Search", ExtractedStringKind::Attribute, 8),
+        ]
+    );
+    assert_eq!(strings[1].attribute.as_deref(), Some("title"));
+}
+
+#[test]
+fn message_catalog_merges_references_and_renders_pot() {
+    let mut catalog = MessageCatalog::new();
+    catalog.add(
+        "src/A.svelte",
+        &extract_strings_from_source("<p>Save</p>\n<p>Say \"hi\"</p>").expect("parses"),
+    );
+    catalog.add(
+        "src/B.svelte",
+        &extract_strings_from_source("<button title=\"Save\">Save</button>").expect("parses"),
+    );
+    assert_eq!(catalog.messages.len(), 2);
+    assert_eq!(catalog.messages[0].references.len(), 2);
+    assert_eq!(
+        catalog.to_pot(),
+        "msgid \"\"\nmsgstr \"\"\n\"Content-Type: text/plain; charset=UTF-8\\n\"\n\n\
+         #: src/A.svelte:1\n#: src/B.svelte:1\nmsgid \"Save\"\nmsgstr \"\"\n\n\
+         #: src/A.svelte:2\nmsgid \"Say \\\"hi\\\"\"\nmsgstr \"\"\n"
+    );
+    let json = serde_json::to_value(&catalog).expect("catalog serializes");
+    assert_eq!(json["messages"][0]["references"][1]["file"], "src/B.svelte");
+}
+
+fn compile_inline_constants(source: &str) -> String {
+    let opts = CompileOptions {
+        name: Some("App".into()),
+        inline_constants: true,
+        ..Default::default()
+    };
+    compile(source, &opts)
+        .js
+        .unwrap_or_else(|| panic!("compile produced no JS"))
+}
+
+#[test]
+fn inline_constants_folds_text_and_attributes_into_template() {
+    let source = r#"<script module>
+    const BRAND = "Acme";
+</script>
+<script>
+    const title = `Home`;
+    const count = 3;
+</script>
+<p>{BRAND} x{count}</p>
+<a href="/" title={title}>{title}</a>"#;
+    assert_eq!(
+        compile_inline_constants(source),
+        r#"import * as $ from "svelte/internal/client";
+const BRAND = "Acme";
+var root = $.from_html(`<p>Acme x3</p> <a href="/" title="Home">Home</a>`, 1);
+export default function App($$anchor) {
+	const title = `Home`;
+	const count = 3;
+	var fragment = root();
+	$.next(2);
+	$.append($$anchor, fragment);
+}
+"#
+    );
+}
+
+#[test]
+fn inline_constants_skips_unsafe_values() {
+    let source = r#"<script>
+    const off = false;
+    const markup = "<b>";
+    let label = "x";
+    const items = ["a"];
+    const item = "outer";
+</script>
+<button disabled={off}>{markup}{label}</button>
+{#each items as item}<i>{item}</i>{/each}"#;
+    let js = compile_inline_constants(source);
+    assert!(js.contains("button.disabled = off;"), "{js}");
+    assert!(js.contains("markup"), "{js}");
+    assert!(js.contains("label"), "{js}");
+    assert!(!js.contains("<i>outer"), "{js}");
+}
+
+#[test]
+fn stats_are_opt_in() {
+    let result = compile("<p>{1}</p>", &CompileOptions::default());
+    assert!(result.stats.is_none());
+}
+
+#[test]
+fn stats_report_phases_passes_and_counts() {
+    let source = r#"<script>
+    let count = $state(0);
+    let name = $state("x");
+</script>
+<button onclick={() => count++}>{count}</button>
+<p>{name}</p>
+{#if count}<span>more</span>{/if}
+<style>
+    p { color: red; }
+    .missing { color: blue; }
+    @keyframes fade { from { opacity: 0; } }
+</style>"#;
+    let options = CompileOptions {
+        stats: true,
+        ..Default::default()
+    };
+    let result = compile(source, &options);
+    assert!(result.js.is_some(), "{:?}", result.diagnostics);
+    let stats = result.stats.expect("stats were requested");
+
+    let phases: Vec<&str> = stats.phases.iter().map(|timing| timing.phase).collect();
+    assert_eq!(
+        phases,
+        [
+            "parse",
+            "analyze",
+            "css_prune",
+            "css_transform",
+            "transform",
+            "codegen"
+        ]
+    );
+    assert!(stats.phase("codegen").is_some());
+    assert!(stats.total() >= stats.phase("analyze").unwrap_or_default());
+    assert!(
+        stats
+            .analyze_passes
+            .iter()
+            .any(|timing| timing.pass == "ReactivityWalk")
+    );
+    assert!(stats.nodes > 0);
+    assert!(stats.fragments > 1);
+    assert!(stats.symbols >= 2);
+    assert_eq!(stats.template_effects, 1);
+    assert_eq!(stats.css_rules_pruned, 1);
+}
+
+#[test]
+fn stats_use_the_injected_clock_and_survive_errors() {
+    let options = CompileOptions {
+        stats: true,
+        stats_clock: Some(|| std::time::Duration::ZERO),
+        ..Default::default()
+    };
+    let result = compile("<div>", &options);
+    assert!(result.js.is_none());
+    let stats = result.stats.expect("stats were requested");
+    assert_eq!(stats.total(), std::time::Duration::ZERO);
+    assert!(stats.phase("parse").is_some());
+    assert!(stats.phase("codegen").is_none());
+}
+
+#[test]
+fn unsupported_script_lang_is_rejected() {
+    let source = r#"<script lang="coffee">x = 1</script>
+<p>x</p>"#;
+    let result = compile(source, &CompileOptions::default());
+    assert!(result.js.is_none());
+    let codes: Vec<&str> = result.diagnostics.iter().map(|d| d.kind.code()).collect();
+    assert_eq!(codes, ["script_unsupported_lang"]);
+}
+
+#[test]
+fn unsupported_style_lang_is_rejected_even_if_it_parses_as_css() {
+    for body in ["$c: red; p { color: $c; }", "p { color: red; }"] {
+        let source = format!("<p>x</p>\n<style lang=\"scss\">{body}</style>");
+        let result = compile(&source, &CompileOptions::default());
+        assert!(result.js.is_none());
+        assert!(result.css.is_none());
+        let codes: Vec<&str> = result.diagnostics.iter().map(|d| d.kind.code()).collect();
+        assert_eq!(codes, ["style_unsupported_lang"], "{body}");
+        let style_diag = &result.diagnostics[0];
+        assert_eq!(
+            &source[style_diag.span.start as usize..style_diag.span.end as usize],
+            r#"lang="scss""#
+        );
+        assert!(style_diag.kind.message().contains("scss"));
+    }
+
+    let source = r#"<p>x</p><style lang="postcss">p { color: red; }</style>"#;
+    let result = compile(source, &CompileOptions::default());
+    assert!(
+        result.diagnostics.is_empty(),
+        "postcss: {:?}",
+        result.diagnostics
+    );
+    assert!(result.css.is_some());
+}
+
+#[test]
+fn supported_langs_compile() {
+    let source = r#"<script lang="typescript">let n: number = 1;</script>
+<p>{n}</p>
+<style lang="css">p { color: red; }</style>"#;
+    let result = compile(source, &CompileOptions::default());
+    assert!(result.js.is_some(), "{:?}", result.diagnostics);
+    assert!(result.css.is_some());
+}
+
+#[test]
+fn compiler_session_matches_one_shot_compiles() {
+    let sources = [
+        (
+            "src/Counter.svelte",
+            "<script>let count = $state(0);</script>\n<button onclick={() => count++}>{count}</button>",
+        ),
+        ("src/Broken.svelte", "<div>"),
+        (
+            "src/Label.svelte",
+            "<script>let { text } = $props();</script>\n<p class=\"label\">{text}</p>\n<style>p { color: red; }</style>",
+        ),
+    ];
+    let mut session = Compiler::new(CompileOptions::default()).expect("default options are valid");
+    for _ in 0..2 {
+        for (filename, source) in sources {
+            let expected = compile(
+                source,
+                &CompileOptions {
+                    filename: filename.to_string(),
+                    ..Default::default()
+                },
+            );
+            let actual = session.compile(source, filename);
+            assert_eq!(actual.js, expected.js, "{filename}");
+            assert_eq!(actual.css, expected.css, "{filename}");
+            assert_eq!(
+                format!("{:?}", actual.diagnostics),
+                format!("{:?}", expected.diagnostics),
+                "{filename}"
+            );
+        }
+    }
+    assert_eq!(session.options().filename, "src/Label.svelte");
+}
+
+#[test]
+fn compiler_session_rejects_invalid_options() {
+    let options = CompileOptions {
+        compatibility_component_api: 6,
+        ..Default::default()
+    };
+    let err = Compiler::new(options)
+        .err()
+        .expect("componentApi 6 is rejected");
+    assert!(err.contains("componentApi"), "{err}");
+}
+
+#[test]
+fn compiler_session_compiles_modules() {
+    let source = "export const count = $state(0);";
+    let expected = compile_module(
+        source,
+        &ModuleCompileOptions {
+            filename: "store.svelte.js".to_string(),
+            ..Default::default()
+        },
+    );
+    let mut session = Compiler::new(CompileOptions::default()).expect("default options are valid");
+    session.compile("<p>hi</p>", "App.svelte");
+    let actual = session.compile_module(source, "store.svelte.js");
+    assert!(actual.js.is_some(), "{:?}", actual.diagnostics);
+    assert_eq!(actual.js, expected.js);
+}
+
+#[test]
+fn compile_reports_script_component_and_css_dependencies() {
+    let source = r#"<script module>
+    export { helper } from "./helpers.js";
+</script>
+<script lang="ts">
+    import Button from "./Button.svelte";
+    import { count } from "./store.svelte.js";
+    import type { Props } from "./types";
+    import Button2 from "./Button.svelte";
+</script>
+<Button /><Button2 />{count}
+<style>
+    @import url("./theme.css");
+    @import './reset.css' screen;
+</style>"#;
+    let result = compile(source, &CompileOptions::default());
+    let deps: Vec<(&str, DependencyKind)> = result
+        .dependencies
+        .iter()
+        .map(|dep| (dep.specifier.as_str(), dep.kind))
+        .collect();
+    assert_eq!(
+        deps,
+        [
+            ("./helpers.js", DependencyKind::Import),
+            ("./Button.svelte", DependencyKind::Import),
+            ("./store.svelte.js", DependencyKind::Import),
+            ("./Button.svelte", DependencyKind::Component),
+            ("./theme.css", DependencyKind::CssImport),
+            ("./reset.css", DependencyKind::CssImport),
+        ]
+    );
+
+    let module = compile_module(
+        "import { x } from './x.js';\nexport * from './y.js';\nexport const z = $state(x);",
+        &ModuleCompileOptions::default(),
+    );
+    let specifiers: Vec<&str> = module
+        .dependencies
+        .iter()
+        .map(|dep| dep.specifier.as_str())
+        .collect();
+    assert_eq!(specifiers, ["./x.js", "./y.js"]);
+}
+
+#[test]
+fn watch_driver_recompiles_changed_files_and_dependents() {
+    let file = |filename: &str, source: &str| ProjectFile {
+        filename: filename.to_string(),
+        source: source.to_string(),
+    };
+    let mut driver =
+        WatchDriver::new(CompileOptions::default()).expect("default options are valid");
+    let updates = driver.update(vec![
+        file(
+            "src/App.svelte",
+            "<script>import Card from './lib/Card.svelte';</script><Card />",
+        ),
+        file(
+            "src/lib/Card.svelte",
+            "<script>import { count } from './store.svelte';</script><p>{count}</p>",
+        ),
+        file("src/lib/store.svelte.js", "export const count = 1;"),
+        file("src/Other.svelte", "<p>other</p>"),
+    ]);
+    assert_eq!(updates.len(), 4);
+    assert!(updates.iter().all(|update| update.result.js.is_some()));
+    assert_eq!(
+        driver.dependencies("src/lib/Card.svelte"),
+        ["src/lib/store.svelte.js"]
+    );
+
+    let updates = driver.update(vec![file(
+        "src/lib/store.svelte.js",
+        "export const count = 2;",
+    )]);
+    let filenames: Vec<&str> = updates.iter().map(|u| u.filename.as_str()).collect();
+    assert_eq!(
+        filenames,
+        [
+            "src/App.svelte",
+            "src/lib/Card.svelte",
+            "src/lib/store.svelte.js"
+        ]
+    );
+
+    let updates = driver.update(vec![file("src/Other.svelte", "<p>other</p>")]);
+    assert!(updates.is_empty());
+
+    let updates = driver.remove("src/lib/Card.svelte");
+    let filenames: Vec<&str> = updates.iter().map(|u| u.filename.as_str()).collect();
+    assert_eq!(filenames, ["src/App.svelte"]);
+    assert!(driver.dependents("src/lib/store.svelte.js").is_empty());
+}
+
+#[test]
+fn watch_driver_links_imports_to_files_added_later() {
+    let file = |filename: &str, source: &str| ProjectFile {
+        filename: filename.to_string(),
+        source: source.to_string(),
+    };
+    let mut driver =
+        WatchDriver::new(CompileOptions::default()).expect("default options are valid");
+    driver.update(vec![file(
+        "src/App.svelte",
+        "<script>import { count } from './store.svelte';</script><p>{count}</p>",
+    )]);
+    assert_eq!(driver.dependencies("src/App.svelte"), ["src/store.svelte"]);
+
+    let updates = driver.update(vec![file("src/store.svelte.js", "export const count = 1;")]);
+    let filenames: Vec<&str> = updates.iter().map(|u| u.filename.as_str()).collect();
+    assert_eq!(filenames, ["src/App.svelte", "src/store.svelte.js"]);
+    assert_eq!(
+        driver.dependencies("src/App.svelte"),
+        ["src/store.svelte.js"]
+    );
+
+    let updates = driver.update(vec![file("src/store.svelte.js", "export const count = 2;")]);
+    let filenames: Vec<&str> = updates.iter().map(|u| u.filename.as_str()).collect();
+    assert_eq!(filenames, ["src/App.svelte", "src/store.svelte.js"]);
+}
+
+#[test]
+fn watch_driver_keeps_edges_of_files_that_fail_to_compile() {
+    let file = |filename: &str, source: &str| ProjectFile {
+        filename: filename.to_string(),
+        source: source.to_string(),
+    };
+    let mut driver =
+        WatchDriver::new(CompileOptions::default()).expect("default options are valid");
+    driver.update(vec![
+        file(
+            "src/App.svelte",
+            "<script>import { count } from './store.svelte.js';</script><p>{count}</p>",
+        ),
+        file("src/store.svelte.js", "export const count = 1;"),
+    ]);
+
+    let updates = driver.update(vec![file(
+        "src/App.svelte",
+        "<script>import { count } from './store.svelte.js';</script><p>{count}</p><style lang=\"scss\"></style>",
+    )]);
+    assert!(updates[0].result.js.is_none());
+    assert_eq!(
+        driver.dependencies("src/App.svelte"),
+        ["src/store.svelte.js"]
+    );
+
+    let updates = driver.update(vec![file("src/store.svelte.js", "export const count = 2;")]);
+    let filenames: Vec<&str> = updates.iter().map(|u| u.filename.as_str()).collect();
+    assert_eq!(filenames, ["src/App.svelte", "src/store.svelte.js"]);
+}
+
+#[test]
+fn instance_script_comments_survive_lowering() {
+    for lang in ["", r#" lang="ts""#] {
+        let source = format!(
+            r#"<script{lang}>
+	// counter state
+	let count = $state(0);
+
+	let {{
+		// the label
+		label = "x"
+	}} = $props();
+
+	/** @type {{Function | undefined}} */
+	let show;
+
+	let a = 1,
+		// second declarator
+		b = $state(2);
+
+	function bump() {{
+		// increment
+		count++;
+	}}
+
+	// destructured state
+	let {{ x, y }} = $state({{ x: 1, y: 2 }});
+
+	// watch
+	$effect(() => show?.(count));
+</script>
+
+<button onclick={{bump}}>{{count}} {{label}} {{a}} {{b}} {{x}} {{y}}</button>"#
+        );
+        let result = compile(&source, &CompileOptions::default());
+        let js = result
+            .js
+            .unwrap_or_else(|| panic!("{:?}", result.diagnostics));
+        for (comment, next) in [
+            ("// counter state", "let count = $.state(0);"),
+            ("// the label", "let label = $.prop("),
+            ("/** @type {Function | undefined} */", "let show;"),
+            ("// second declarator", "let a = 1, b = 2;"),
+            ("// increment", "$.update(count);"),
+            ("// destructured state", "let tmp"),
+            ("// watch", "$.user_effect("),
+        ] {
+            let at = js
+                .find(comment)
+                .unwrap_or_else(|| panic!("{lang}: missing `{comment}` in\n{js}"));
+            let rest = js[at + comment.len()..].trim_start();
+            assert!(
+                rest.starts_with(next),
+                "{lang}: `{comment}` is not followed by `{next}` in\n{js}"
+            );
+        }
+    }
+}
+
+#[test]
+fn instance_script_comments_of_removed_declarations_move_to_the_next_statement() {
+    let source = r#"<script>
+	/** the props */
+	let { x } = $props();
+
+	$effect(() => console.log(x));
+</script>
+<p>{x}</p>"#;
+    let result = compile(source, &CompileOptions::default());
+    let js = result
+        .js
+        .unwrap_or_else(|| panic!("{:?}", result.diagnostics));
+    let at = js
+        .find("/** the props */")
+        .unwrap_or_else(|| panic!("missing `/** the props */` in\n{js}"));
+    assert!(
+        js[at + "/** the props */".len()..]
+            .trim_start()
+            .starts_with("$.user_effect("),
+        "`/** the props */` is not followed by `$.user_effect(` in\n{js}"
+    );
+}
+
+#[test]
+fn instance_script_trailing_comments_survive_lowering() {
+    let source = r#"<script>
+	function get() {
+		let a = 1;
+		return a; // trailing
+	}
+	let q = $state(1); // after q
+
+	$effect(() => console.log(get(), q));
+</script>
+<p>{q}</p>"#;
+    let result = compile(source, &CompileOptions::default());
+    let js = result
+        .js
+        .unwrap_or_else(|| panic!("{:?}", result.diagnostics));
+    for (prev, comment, next) in [
+        ("return a;", "// trailing", "}"),
+        ("let q = 1;", "// after q", "$.user_effect("),
+    ] {
+        let at = js
+            .find(comment)
+            .unwrap_or_else(|| panic!("missing `{comment}` in\n{js}"));
+        assert!(
+            js[..at].trim_end().ends_with(prev),
+            "`{comment}` does not follow `{prev}` in\n{js}"
+        );
+        assert!(
+            js[at + comment.len()..].trim_start().starts_with(next),
+            "`{comment}` is not followed by `{next}` in\n{js}"
+        );
+    }
+}
+
+#[test]
+fn inline_constants_skip_values_unsafe_in_template_literals() {
+    let source = r#"<script>
+    const tick = "a`b";
+    const interp = "${x}";
+    const slash = "a\\b";
+    const quote = 'say "hi"';
+</script>
+<p title={tick}>{tick}</p>
+<p title={interp}>{interp}</p>
+<p title={slash}>{slash}</p>
+<p title={quote}>{quote}</p>"#;
+    let js = compile_inline_constants(source);
+    let template = js
+        .lines()
+        .find(|line| line.starts_with("var root"))
+        .unwrap_or_else(|| panic!("no template in {js}"));
+    assert_eq!(
+        template, r#"var root = $.from_html(`<p></p> <p></p> <p></p> <p>say "hi"</p>`, 1);"#,
+        "{js}"
+    );
+    for name in ["tick", "interp", "slash", "quote"] {
+        assert!(js.contains(&format!("\"title\", {name})")), "{name}: {js}");
+    }
+}
+
+#[test]
+fn inline_constants_keep_vars_for_lowered_textarea_and_option_values() {
+    let source = r#"<script>
+    const v = "x";
+</script>
+<textarea>{v}</textarea>
+<select><option>{v}</option></select>"#;
+    let js = compile_inline_constants(source);
+    assert!(js.contains("$.set_value(textarea, v);"), "{js}");
+    assert!(js.contains("option.__value = v;"), "{js}");
+    assert!(js.contains("var option = "), "{js}");
+}
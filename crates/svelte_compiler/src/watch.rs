@@ -0,0 +1,182 @@
+use std::collections::BTreeSet;
+
+use rustc_hash::FxHashMap;
+
+use crate::project::{normalize_path, resolve_specifier};
+use svelte_diagnostics::Severity;
+
+use crate::{CompileOptions, CompileResult, Compiler, ProjectFile};
+
+const RESOLVE_EXTENSIONS: [&str; 2] = [".js", ".ts"];
+
+#[derive(serde::Serialize)]
+pub struct WatchUpdate {
+    pub filename: String,
+    pub result: CompileResult,
+}
+
+struct WatchedFile {
+    filename: String,
+    source: String,
+    /// Relative imports joined to the importer's directory, before the
+    /// extension lookup; re-resolved whenever the set of watched files changes.
+    imports: Vec<String>,
+    dependencies: Vec<String>,
+}
+
+pub struct WatchDriver {
+    compiler: Compiler,
+    files: FxHashMap<String, WatchedFile>,
+}
+
+impl WatchDriver {
+    pub fn new(options: CompileOptions) -> Result<Self, String> {
+        Ok(Self {
+            compiler: Compiler::new(options)?,
+            files: FxHashMap::default(),
+        })
+    }
+
+    pub fn update(&mut self, changed: Vec<ProjectFile>) -> Vec<WatchUpdate> {
+        let mut affected = BTreeSet::new();
+        let mut added = false;
+        for file in changed {
+            let path = normalize_path(&file.filename);
+            let unchanged = self
+                .files
+                .get(&path)
+                .is_some_and(|watched| watched.source == file.source);
+            if unchanged {
+                continue;
+            }
+            let watched = self.files.entry(path.clone()).or_insert_with(|| {
+                added = true;
+                WatchedFile {
+                    filename: file.filename,
+                    source: String::new(),
+                    imports: Vec::new(),
+                    dependencies: Vec::new(),
+                }
+            });
+            watched.source = file.source;
+            affected.insert(path);
+        }
+        if added {
+            self.relink();
+        }
+        self.recompile(affected)
+    }
+
+    pub fn remove(&mut self, filename: &str) -> Vec<WatchUpdate> {
+        let path = normalize_path(filename);
+        let dependents = self.dependents(&path);
+        if self.files.remove(&path).is_none() {
+            return Vec::new();
+        }
+        self.relink();
+        self.recompile(dependents)
+    }
+
+    pub fn dependencies(&self, filename: &str) -> &[String] {
+        self.files
+            .get(&normalize_path(filename))
+            .map_or(&[], |watched| &watched.dependencies)
+    }
+
+    pub fn dependents(&self, filename: &str) -> BTreeSet<String> {
+        let mut found = BTreeSet::new();
+        let mut pending = vec![normalize_path(filename)];
+        while let Some(target) = pending.pop() {
+            for (path, watched) in &self.files {
+                if watched.dependencies.contains(&target) && found.insert(path.clone()) {
+                    pending.push(path.clone());
+                }
+            }
+        }
+        found.remove(&normalize_path(filename));
+        found
+    }
+
+    fn recompile(&mut self, changed: BTreeSet<String>) -> Vec<WatchUpdate> {
+        let mut affected = BTreeSet::new();
+        for path in changed {
+            affected.extend(self.dependents(&path));
+            affected.insert(path);
+        }
+
+        let mut updates = Vec::new();
+        for path in affected {
+            let Some(watched) = self.files.get(&path) else {
+                continue;
+            };
+            let result = if is_component(&path) {
+                self.compiler.compile(&watched.source, &watched.filename)
+            } else if is_svelte_module(&path) {
+                self.compiler
+                    .compile_module(&watched.source, &watched.filename)
+            } else {
+                continue;
+            };
+            let failed = result.dependencies.is_empty()
+                && result
+                    .diagnostics
+                    .iter()
+                    .any(|d| d.severity == Severity::Error);
+            // A compile that fails before analysis reports no dependencies;
+            // keep the previous edges so dependents are still tracked.
+            let imports: Vec<String> = if failed {
+                watched.imports.clone()
+            } else {
+                result
+                    .dependencies
+                    .iter()
+                    .filter_map(|dep| resolve_specifier(&path, &dep.specifier))
+                    .collect()
+            };
+            let dependencies = self.resolve_all(&imports);
+            let Some(watched) = self.files.get_mut(&path) else {
+                continue;
+            };
+            watched.imports = imports;
+            watched.dependencies = dependencies;
+            updates.push(WatchUpdate {
+                filename: watched.filename.clone(),
+                result,
+            });
+        }
+        updates
+    }
+
+    fn relink(&mut self) {
+        let paths: Vec<String> = self.files.keys().cloned().collect();
+        for path in paths {
+            let dependencies = self.resolve_all(&self.files[&path].imports);
+            if let Some(watched) = self.files.get_mut(&path) {
+                watched.dependencies = dependencies;
+            }
+        }
+    }
+
+    fn resolve_all(&self, imports: &[String]) -> Vec<String> {
+        imports.iter().map(|path| self.resolve(path)).collect()
+    }
+
+    fn resolve(&self, path: &str) -> String {
+        if self.files.contains_key(path) {
+            return path.to_string();
+        }
+        RESOLVE_EXTENSIONS
+            .iter()
+            .map(|ext| format!("{path}{ext}"))
+            .find(|candidate| self.files.contains_key(candidate))
+            .unwrap_or_else(|| path.to_string())
+    }
+}
+
+fn is_component(path: &str) -> bool {
+    path.ends_with(".svelte")
+}
+
+fn is_svelte_module(path: &str) -> bool {
+    path.ends_with(".svelte.js") || path.ends_with(".svelte.ts")
+}
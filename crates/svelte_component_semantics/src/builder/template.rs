@@ -12,6 +12,8 @@ use crate::symbol::SymbolOwner;
 
 pub struct TemplateBuildContext<'s, 'a> {
     semantics: &'s mut ComponentSemantics<'a>,
+    root_scope: ScopeId,
+    /// Scopes entered below `root_scope`.
     scope_stack: Vec<ScopeId>,
 
     node_id_offset: u32,
@@ -28,17 +30,15 @@ impl<'s, 'a> TemplateBuildContext<'s, 'a> {
         let safe_offset = node_id_offset.max(1);
         Self {
             semantics,
-            scope_stack: vec![root_scope],
+            root_scope,
+            scope_stack: Vec::new(),
             node_id_offset: safe_offset,
             next_synthetic_node_id: safe_offset,
         }
     }
 
     pub fn current_scope(&self) -> ScopeId {
-        *self
-            .scope_stack
-            .last()
-            .expect("scope stack must not be empty")
+        self.scope_stack.last().copied().unwrap_or(self.root_scope)
     }
 
     pub fn enter_child_scope(&mut self) -> ScopeId {
@@ -60,7 +60,7 @@ impl<'s, 'a> TemplateBuildContext<'s, 'a> {
     }
 
     pub fn leave_scope(&mut self) {
-        debug_assert!(self.scope_stack.len() > 1, "cannot leave root scope");
+        debug_assert!(!self.scope_stack.is_empty(), "cannot leave root scope");
         self.scope_stack.pop();
     }
 
@@ -1,4 +1,5 @@
 pub mod ast;
+mod minify;
 mod parser;
 pub mod printer;
 pub(crate) mod scanner;
@@ -0,0 +1,310 @@
+const LENGTH_UNITS: &[&str] = &[
+    "px", "em", "rem", "ex", "ch", "vw", "vh", "vmin", "vmax", "vi", "vb", "cm", "mm", "q", "in",
+    "pt", "pc", "lh", "rlh", "svw", "svh", "lvw", "lvh", "dvw", "dvh", "cqw", "cqh", "cqi", "cqb",
+    "cqmin", "cqmax",
+];
+
+const MATH_FUNCTIONS: &[&str] = &[
+    "calc", "min", "max", "clamp", "round", "mod", "rem", "sin", "cos", "tan", "asin", "acos",
+    "atan", "atan2", "pow", "sqrt", "hypot", "log", "exp", "abs", "sign",
+];
+
+pub(crate) fn declaration_key(property: &str) -> String {
+    if property.starts_with("--") {
+        property.to_string()
+    } else {
+        property.to_ascii_lowercase()
+    }
+}
+
+pub(crate) fn minify_value(property: &str, value: &str) -> String {
+    if property.starts_with("--") {
+        return value.trim().to_string();
+    }
+    let strip_zero_units = !property.eq_ignore_ascii_case("flex");
+    let mut out = String::with_capacity(value.len());
+    let mut functions: Vec<String> = Vec::new();
+    let mut pending_space = false;
+    let mut word = String::new();
+    let mut chars = value.char_indices().peekable();
+
+    while let Some((idx, ch)) = chars.next() {
+        match ch {
+            '"' | '\'' => {
+                flush_word(&mut out, &mut word, &functions, strip_zero_units);
+                push_pending_space(&mut out, &mut pending_space, ch);
+                out.push(ch);
+                while let Some((_, next)) = chars.next() {
+                    out.push(next);
+                    if next == '\\' {
+                        if let Some((_, escaped)) = chars.next() {
+                            out.push(escaped);
+                        }
+                    } else if next == ch {
+                        break;
+                    }
+                }
+            }
+            '/' if value[idx..].starts_with("/*") => {
+                flush_word(&mut out, &mut word, &functions, strip_zero_units);
+                chars.next();
+                let mut prev = '\0';
+                for (_, next) in chars.by_ref() {
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+                    prev = next;
+                }
+                pending_space = !out.is_empty();
+            }
+            '(' => {
+                let name = word.to_ascii_lowercase();
+                if name == "url" {
+                    push_pending_space(&mut out, &mut pending_space, ch);
+                    out.push_str(&word);
+                    word.clear();
+                    out.push('(');
+                    copy_url_body(&mut out, &mut chars);
+                    continue;
+                }
+                push_pending_space(&mut out, &mut pending_space, ch);
+                out.push_str(&word);
+                word.clear();
+                out.push('(');
+                functions.push(name.trim_start_matches("-webkit-").to_string());
+            }
+            ')' => {
+                flush_word(&mut out, &mut word, &functions, strip_zero_units);
+                pending_space = false;
+                out.push(')');
+                functions.pop();
+            }
+            c if c.is_whitespace() => {
+                flush_word(&mut out, &mut word, &functions, strip_zero_units);
+                pending_space = !out.is_empty();
+            }
+            ',' | '!' => {
+                flush_word(&mut out, &mut word, &functions, strip_zero_units);
+                pending_space = false;
+                out.push(ch);
+                while chars.peek().is_some_and(|(_, next)| next.is_whitespace()) {
+                    chars.next();
+                }
+            }
+            _ => {
+                if word.is_empty() {
+                    push_pending_space(&mut out, &mut pending_space, ch);
+                }
+                word.push(ch);
+            }
+        }
+    }
+    flush_word(&mut out, &mut word, &functions, strip_zero_units);
+    out
+}
+
+pub(crate) fn is_fallback_value(value: &str) -> bool {
+    value.contains('(')
+        || value.split_whitespace().any(|token| {
+            token
+                .strip_prefix('-')
+                .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_alphabetic()))
+        })
+}
+
+pub(crate) fn is_important(value: &str) -> bool {
+    value
+        .rsplit_once('!')
+        .is_some_and(|(_, flag)| flag.trim().eq_ignore_ascii_case("important"))
+}
+
+pub(crate) fn collapse_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut pending_space = false;
+    for ch in text.trim().chars() {
+        if ch.is_whitespace() {
+            pending_space = true;
+            continue;
+        }
+        if pending_space && !matches!(ch, ')' | ',' | ':') && !out.ends_with(['(', ',', ':']) {
+            out.push(' ');
+        }
+        pending_space = false;
+        out.push(ch);
+    }
+    out
+}
+
+fn push_pending_space(out: &mut String, pending_space: &mut bool, next: char) {
+    if *pending_space && next != ')' && !out.ends_with(['(', ',', '!']) {
+        out.push(' ');
+    }
+    *pending_space = false;
+}
+
+fn copy_url_body(out: &mut String, chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>) {
+    let mut quote: Option<char> = None;
+    while let Some((_, ch)) = chars.next() {
+        out.push(ch);
+        match quote {
+            Some(q) if ch == q => quote = None,
+            Some(_) if ch == '\\' => {
+                if let Some((_, escaped)) = chars.next() {
+                    out.push(escaped);
+                }
+            }
+            Some(_) => {}
+            None if ch == '"' || ch == '\'' => quote = Some(ch),
+            None if ch == ')' => return,
+            None => {}
+        }
+    }
+}
+
+fn flush_word(out: &mut String, word: &mut String, functions: &[String], strip_zero_units: bool) {
+    if word.is_empty() {
+        return;
+    }
+    let in_math = functions
+        .iter()
+        .any(|name| MATH_FUNCTIONS.contains(&name.as_str()));
+    if let Some(color) = shorten_hex_color(word) {
+        out.push_str(&color);
+    } else if let Some(number) = shorten_number(word, strip_zero_units && !in_math) {
+        out.push_str(&number);
+    } else {
+        out.push_str(word);
+    }
+    word.clear();
+}
+
+fn shorten_hex_color(word: &str) -> Option<String> {
+    let digits = word.strip_prefix('#')?;
+    if !matches!(digits.len(), 3 | 4 | 6 | 8) || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digits = digits.to_ascii_lowercase();
+    let bytes = digits.as_bytes();
+    if bytes.len() >= 6 && bytes.chunks(2).all(|pair| pair[0] == pair[1]) {
+        let short: String = bytes.chunks(2).map(|pair| pair[0] as char).collect();
+        return Some(format!("#{short}"));
+    }
+    Some(format!("#{digits}"))
+}
+
+fn shorten_number(word: &str, strip_zero_unit: bool) -> Option<String> {
+    let (sign, rest) = match word.as_bytes().first()? {
+        b'-' => ("-", &word[1..]),
+        b'+' => ("", &word[1..]),
+        _ => ("", word),
+    };
+    let numeric_len = rest
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(rest.len());
+    let (number, unit) = rest.split_at(numeric_len);
+    if number.is_empty()
+        || number == "."
+        || number.matches('.').count() > 1
+        || !(unit.is_empty() || unit == "%" || unit.bytes().all(|b| b.is_ascii_alphabetic()))
+    {
+        return None;
+    }
+
+    let (int_part, frac_part) = number.split_once('.').unwrap_or((number, ""));
+    let int_part = int_part.trim_start_matches('0');
+    let frac_part = frac_part.trim_end_matches('0');
+    if int_part.is_empty() && frac_part.is_empty() {
+        let drop_unit = unit.is_empty()
+            || (strip_zero_unit && LENGTH_UNITS.contains(&unit.to_ascii_lowercase().as_str()));
+        return Some(if drop_unit {
+            "0".to_string()
+        } else {
+            format!("0{unit}")
+        });
+    }
+
+    let mut out = String::with_capacity(word.len());
+    out.push_str(sign);
+    out.push_str(int_part);
+    if !frac_part.is_empty() {
+        out.push('.');
+        out.push_str(frac_part);
+    }
+    out.push_str(unit);
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortens_colors() {
+        assert_eq!(minify_value("color", "#FFFFFF"), "#fff");
+        assert_eq!(minify_value("color", "#aabbccdd"), "#abcd");
+        assert_eq!(minify_value("color", "#123456"), "#123456");
+        assert_eq!(
+            minify_value("border", "1px solid #CC0000"),
+            "1px solid #c00"
+        );
+    }
+
+    #[test]
+    fn shortens_numbers_and_zero_units() {
+        assert_eq!(
+            minify_value("margin", "0px 0.50em -0.5rem 10.0%"),
+            "0 .5em -.5rem 10%"
+        );
+        assert_eq!(minify_value("opacity", "0.80"), ".8");
+        assert_eq!(minify_value("transition", "opacity 0s"), "opacity 0s");
+        assert_eq!(minify_value("width", "0%"), "0%");
+    }
+
+    #[test]
+    fn keeps_units_where_zero_is_not_a_length() {
+        assert_eq!(
+            minify_value("width", "calc(100% - 0px)"),
+            "calc(100% - 0px)"
+        );
+        assert_eq!(minify_value("flex", "1 1 0px"), "1 1 0px");
+        assert_eq!(minify_value("--gap", " 0px "), "0px");
+    }
+
+    #[test]
+    fn collapses_whitespace_and_comments() {
+        assert_eq!(
+            minify_value(
+                "font-family",
+                "\"Helvetica Neue\" ,  Arial /* fallback */, sans-serif"
+            ),
+            "\"Helvetica Neue\",Arial,sans-serif"
+        );
+        assert_eq!(minify_value("color", "red ! important"), "red!important");
+        assert_eq!(
+            minify_value("transform", "translate( 10px , 0px )  rotate(45deg)"),
+            "translate(10px,0) rotate(45deg)"
+        );
+    }
+
+    #[test]
+    fn leaves_strings_and_urls_untouched() {
+        assert_eq!(
+            minify_value("content", "\"0.50px  #FFFFFF\""),
+            "\"0.50px  #FFFFFF\""
+        );
+        assert_eq!(
+            minify_value("background", "url(img/0.50px.png)  #FFFFFF"),
+            "url(img/0.50px.png) #fff"
+        );
+    }
+
+    #[test]
+    fn detects_importance_and_fallbacks() {
+        assert!(is_important("red !important"));
+        assert!(is_important("red!IMPORTANT"));
+        assert!(!is_important("red"));
+        assert!(is_fallback_value("-webkit-box"));
+        assert!(is_fallback_value("linear-gradient(red, blue)"));
+        assert!(!is_fallback_value("-1px solid red"));
+    }
+}
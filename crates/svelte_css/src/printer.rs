@@ -1,4 +1,5 @@
 use crate::ast::*;
+use crate::minify;
 use rustc_hash::FxHashSet;
 pub struct Printer<'a> {
     output: String,
@@ -6,6 +7,7 @@ pub struct Printer<'a> {
     minify: bool,
     used_selectors: Option<&'a FxHashSet<CssNodeId>>,
     remove_unused: bool,
+    in_keyframes: bool,
 }
 const INDENTS: [&str; 8] = [
     "",
@@ -26,6 +28,7 @@ impl Printer<'_> {
             minify: false,
             used_selectors: None,
             remove_unused: false,
+            in_keyframes: false,
         }
     }
 
@@ -36,6 +39,7 @@ impl Printer<'_> {
             minify: true,
             used_selectors: None,
             remove_unused: false,
+            in_keyframes: false,
         }
     }
     pub fn print(stylesheet: &StyleSheet, source: &str) -> String {
@@ -45,6 +49,7 @@ impl Printer<'_> {
             minify: false,
             used_selectors: None,
             remove_unused: false,
+            in_keyframes: false,
         };
         p.print_stylesheet(stylesheet, source);
         p.output
@@ -62,6 +67,46 @@ impl Printer<'_> {
             minify: false,
             used_selectors: Some(used_selectors),
             remove_unused,
+            in_keyframes: false,
+        };
+        p.print_stylesheet(stylesheet, source);
+        p.output
+    }
+
+    pub fn count_unused_rules(
+        stylesheet: &StyleSheet,
+        used_selectors: &'_ FxHashSet<CssNodeId>,
+    ) -> u32 {
+        let p = Printer {
+            output: String::new(),
+            indent: 0,
+            minify: false,
+            used_selectors: Some(used_selectors),
+            remove_unused: true,
+            in_keyframes: false,
+        };
+        stylesheet
+            .children
+            .iter()
+            .map(|child| match child {
+                StyleSheetChild::Rule(rule) => p.count_unused_in_rule(rule),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    pub fn print_minified(
+        stylesheet: &StyleSheet,
+        source: &str,
+        used_selectors: Option<&'_ FxHashSet<CssNodeId>>,
+    ) -> String {
+        let mut p = Printer {
+            output: String::with_capacity(source.len() / 2),
+            indent: 0,
+            minify: true,
+            used_selectors,
+            remove_unused: true,
+            in_keyframes: false,
         };
         p.print_stylesheet(stylesheet, source);
         p.output
@@ -75,6 +120,9 @@ impl Printer<'_> {
         for child in &node.children {
             match child {
                 StyleSheetChild::Comment(c) => {
+                    if self.minify && !is_preserved_comment(c, source) {
+                        continue;
+                    }
                     if !first && !self.minify {
                         self.output.push('\n');
                     }
@@ -100,6 +148,9 @@ impl Printer<'_> {
     }
 
     fn print_rule(&mut self, rule: &Rule, source: &str) -> bool {
+        if self.minify && !self.rule_has_output(rule, source) {
+            return false;
+        }
         match rule {
             Rule::Style(r) => self.print_style_rule(r.as_ref(), source),
             Rule::AtRule(r) => {
@@ -114,6 +165,13 @@ impl Printer<'_> {
         if self.remove_unused && !rule_used {
             return false;
         }
+        if self.minify {
+            self.print_selector_list(&rule.prelude, source);
+            self.output.push('{');
+            self.print_block_children(&rule.block, source);
+            self.output.push('}');
+            return true;
+        }
 
         self.write_indent();
         if rule_used {
@@ -147,19 +205,28 @@ impl Printer<'_> {
             None => rule.prelude.source_text(source).trim(),
         };
         if !prelude_text.is_empty() {
-            self.output.push(' ');
-            self.output.push_str(prelude_text);
+            if self.minify {
+                let prelude_text = minify::collapse_whitespace(prelude_text);
+                if !prelude_text.starts_with('(') {
+                    self.output.push(' ');
+                }
+                self.output.push_str(&prelude_text);
+            } else {
+                self.output.push(' ');
+                self.output.push_str(prelude_text);
+            }
         }
 
         if let Some(block) = &rule.block {
-            let is_keyframes = rule.name == "keyframes"
-                || rule
-                    .name
-                    .strip_prefix('-')
-                    .and_then(|s| s.split_once('-'))
-                    .is_some_and(|(_, rest)| rest == "keyframes");
-
-            if is_keyframes {
+            let is_keyframes = is_keyframes_rule(rule);
+
+            if is_keyframes && self.minify {
+                let in_keyframes = std::mem::replace(&mut self.in_keyframes, true);
+                self.output.push('{');
+                self.print_block_children(block, source);
+                self.output.push('}');
+                self.in_keyframes = in_keyframes;
+            } else if is_keyframes {
                 self.output.push(' ');
                 self.push_span(block.span, source);
                 self.output.push('\n');
@@ -175,12 +242,18 @@ impl Printer<'_> {
                 self.write_indent();
                 self.output.push_str("}\n");
             }
+        } else if self.minify {
+            self.output.push(';');
         } else {
             self.output.push_str(";\n");
         }
     }
 
     fn print_block_children(&mut self, block: &Block, source: &str) {
+        if self.minify {
+            self.print_minified_block_children(block, source);
+            return;
+        }
         for child in &block.children {
             match child {
                 BlockChild::Declaration(d) => self.print_declaration(d, source),
@@ -199,6 +272,89 @@ impl Printer<'_> {
         }
     }
 
+    fn print_minified_block_children(&mut self, block: &Block, source: &str) {
+        let declarations: Vec<(usize, String, String)> = block
+            .children
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, child)| match child {
+                BlockChild::Declaration(d) => {
+                    let property = d.property.source_text(source).trim();
+                    let value = match &d.value_override {
+                        Some(ov) => ov.as_str(),
+                        None => d.value.source_text(source),
+                    };
+                    Some((
+                        idx,
+                        minify::declaration_key(property),
+                        minify::minify_value(property, value),
+                    ))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut pending_semicolon = false;
+        for (idx, child) in block.children.iter().enumerate() {
+            match child {
+                BlockChild::Declaration(d) => {
+                    let Some(pos) = declarations.iter().position(|(i, ..)| *i == idx) else {
+                        continue;
+                    };
+                    let (_, key, value) = &declarations[pos];
+                    if is_overridden(key, value, &declarations[pos + 1..]) {
+                        continue;
+                    }
+                    if pending_semicolon {
+                        self.output.push(';');
+                    }
+                    self.output.push_str(d.property.source_text(source).trim());
+                    self.output.push(':');
+                    self.output.push_str(value);
+                    pending_semicolon = true;
+                }
+                BlockChild::Rule(r) => {
+                    if pending_semicolon && self.rule_has_output(r, source) {
+                        self.output.push(';');
+                        pending_semicolon = false;
+                    }
+                    self.print_rule(r, source);
+                }
+                BlockChild::Comment(c) => {
+                    if is_preserved_comment(c, source) {
+                        if pending_semicolon {
+                            self.output.push(';');
+                            pending_semicolon = false;
+                        }
+                        self.push_span(c.span, source);
+                    }
+                }
+                BlockChild::Error(_) => {}
+            }
+        }
+    }
+
+    fn rule_has_output(&self, rule: &Rule, source: &str) -> bool {
+        match rule {
+            Rule::Style(r) => self.rule_is_used(r) && self.block_has_output(&r.block, source),
+            Rule::AtRule(r) => match &r.block {
+                None => true,
+                Some(_) if r.name.eq_ignore_ascii_case("layer") => true,
+                Some(block) if is_keyframes_rule(r) => !block.children.is_empty(),
+                Some(block) => self.block_has_output(block, source),
+            },
+        }
+    }
+
+    fn block_has_output(&self, block: &Block, source: &str) -> bool {
+        block.children.iter().any(|child| match child {
+            BlockChild::Declaration(_) => true,
+            BlockChild::Rule(r) => self.rule_has_output(r, source),
+            BlockChild::Comment(c) => is_preserved_comment(c, source),
+            BlockChild::Error(_) => false,
+        })
+    }
+
     fn print_declaration(&mut self, decl: &Declaration, source: &str) {
         self.write_indent();
         self.push_span(decl.property, source);
@@ -261,26 +417,47 @@ impl Printer<'_> {
 
     fn render_complex_selector(&self, sel: &ComplexSelector, source: &str) -> String {
         let mut output = String::new();
+        Self::render_relative_selectors(&mut output, sel, source, self.minify);
+        output
+    }
+
+    fn render_relative_selectors(
+        output: &mut String,
+        sel: &ComplexSelector,
+        source: &str,
+        minify: bool,
+    ) {
         for (i, rel) in sel.children.iter().enumerate() {
             if let Some(combinator) = &rel.combinator
                 && (i > 0 || combinator.kind != CombinatorKind::Descendant)
             {
-                match combinator.kind {
-                    CombinatorKind::Descendant => output.push(' '),
-                    CombinatorKind::Child => output.push_str(" > "),
-                    CombinatorKind::NextSibling => output.push_str(" + "),
-                    CombinatorKind::SubsequentSibling => output.push_str(" ~ "),
-                    CombinatorKind::Column => output.push_str(" || "),
+                let text = match combinator.kind {
+                    CombinatorKind::Descendant => " ",
+                    CombinatorKind::Child => ">",
+                    CombinatorKind::NextSibling => "+",
+                    CombinatorKind::SubsequentSibling => "~",
+                    CombinatorKind::Column => "||",
+                };
+                if minify || combinator.kind == CombinatorKind::Descendant {
+                    output.push_str(text);
+                } else {
+                    output.push(' ');
+                    output.push_str(text);
+                    output.push(' ');
                 }
             }
             for simple in &rel.selectors {
-                Self::render_simple_selector(&mut output, simple, source);
+                Self::render_simple_selector(output, simple, source, minify);
             }
         }
-        output
     }
 
-    fn render_simple_selector(output: &mut String, sel: &SimpleSelector, source: &str) {
+    fn render_simple_selector(
+        output: &mut String,
+        sel: &SimpleSelector,
+        source: &str,
+        minify: bool,
+    ) {
         match sel {
             SimpleSelector::Type { name, .. } => {
                 output.push_str(name);
@@ -299,26 +476,9 @@ impl Printer<'_> {
                     output.push('(');
                     for (idx, complex) in args.children.iter().enumerate() {
                         if idx > 0 {
-                            output.push_str(", ");
+                            output.push_str(if minify { "," } else { ", " });
                         }
-                        let mut nested = String::new();
-                        for (rel_idx, rel) in complex.children.iter().enumerate() {
-                            if let Some(combinator) = &rel.combinator
-                                && (rel_idx > 0 || combinator.kind != CombinatorKind::Descendant)
-                            {
-                                match combinator.kind {
-                                    CombinatorKind::Descendant => nested.push(' '),
-                                    CombinatorKind::Child => nested.push_str(" > "),
-                                    CombinatorKind::NextSibling => nested.push_str(" + "),
-                                    CombinatorKind::SubsequentSibling => nested.push_str(" ~ "),
-                                    CombinatorKind::Column => nested.push_str(" || "),
-                                }
-                            }
-                            for simple in &rel.selectors {
-                                Self::render_simple_selector(&mut nested, simple, source);
-                            }
-                        }
-                        output.push_str(&nested);
+                        Self::render_relative_selectors(output, complex, source, minify);
                     }
                     output.push(')');
                 }
@@ -335,26 +495,9 @@ impl Printer<'_> {
                     output.push('(');
                     for (idx, complex) in args.children.iter().enumerate() {
                         if idx > 0 {
-                            output.push_str(", ");
-                        }
-                        let mut nested = String::new();
-                        for (rel_idx, rel) in complex.children.iter().enumerate() {
-                            if let Some(combinator) = &rel.combinator
-                                && (rel_idx > 0 || combinator.kind != CombinatorKind::Descendant)
-                            {
-                                match combinator.kind {
-                                    CombinatorKind::Descendant => nested.push(' '),
-                                    CombinatorKind::Child => nested.push_str(" > "),
-                                    CombinatorKind::NextSibling => nested.push_str(" + "),
-                                    CombinatorKind::SubsequentSibling => nested.push_str(" ~ "),
-                                    CombinatorKind::Column => nested.push_str(" || "),
-                                }
-                            }
-                            for simple in &rel.selectors {
-                                Self::render_simple_selector(&mut nested, simple, source);
-                            }
+                            output.push_str(if minify { "," } else { ", " });
                         }
-                        output.push_str(&nested);
+                        Self::render_relative_selectors(output, complex, source, minify);
                     }
                     output.push(')');
                 }
@@ -366,26 +509,9 @@ impl Printer<'_> {
                     output.push('(');
                     for (idx, complex) in args.children.iter().enumerate() {
                         if idx > 0 {
-                            output.push_str(", ");
+                            output.push_str(if minify { "," } else { ", " });
                         }
-                        let mut nested = String::new();
-                        for (rel_idx, rel) in complex.children.iter().enumerate() {
-                            if let Some(combinator) = &rel.combinator
-                                && (rel_idx > 0 || combinator.kind != CombinatorKind::Descendant)
-                            {
-                                match combinator.kind {
-                                    CombinatorKind::Descendant => nested.push(' '),
-                                    CombinatorKind::Child => nested.push_str(" > "),
-                                    CombinatorKind::NextSibling => nested.push_str(" + "),
-                                    CombinatorKind::SubsequentSibling => nested.push_str(" ~ "),
-                                    CombinatorKind::Column => nested.push_str(" || "),
-                                }
-                            }
-                            for simple in &rel.selectors {
-                                Self::render_simple_selector(&mut nested, simple, source);
-                            }
-                        }
-                        output.push_str(&nested);
+                        Self::render_relative_selectors(output, complex, source, minify);
                     }
                     output.push(')');
                 }
@@ -411,7 +537,27 @@ impl Printer<'_> {
     }
 
     fn selector_is_used(&self, id: CssNodeId) -> bool {
-        self.used_selectors.is_none_or(|used| used.contains(&id))
+        self.in_keyframes || self.used_selectors.is_none_or(|used| used.contains(&id))
+    }
+
+    fn count_unused_in_rule(&self, rule: &Rule) -> u32 {
+        let block = match rule {
+            Rule::Style(style) if !self.rule_is_used(style) => return 1,
+            Rule::Style(style) => &style.block,
+            Rule::AtRule(at_rule) if is_keyframes_rule(at_rule) => return 0,
+            Rule::AtRule(at_rule) => match &at_rule.block {
+                Some(block) => block,
+                None => return 0,
+            },
+        };
+        block
+            .children
+            .iter()
+            .map(|child| match child {
+                BlockChild::Rule(rule) => self.count_unused_in_rule(rule),
+                _ => 0,
+            })
+            .sum()
     }
 
     fn rule_is_used(&self, rule: &StyleRule) -> bool {
@@ -442,6 +588,29 @@ impl Printer<'_> {
     }
 }
 
+fn is_keyframes_rule(rule: &AtRule) -> bool {
+    rule.name == "keyframes"
+        || rule
+            .name
+            .strip_prefix('-')
+            .and_then(|s| s.split_once('-'))
+            .is_some_and(|(_, rest)| rest == "keyframes")
+}
+
+fn is_preserved_comment(comment: &Comment, source: &str) -> bool {
+    comment.span.source_text(source).starts_with("/*!")
+}
+
+fn is_overridden(key: &str, value: &str, later: &[(usize, String, String)]) -> bool {
+    let important = minify::is_important(value);
+    later.iter().any(|(_, later_key, later_value)| {
+        later_key == key
+            && (!important || minify::is_important(later_value))
+            && (later_value == value
+                || (!minify::is_fallback_value(value) && !minify::is_fallback_value(later_value)))
+    })
+}
+
 impl Default for Printer<'_> {
     fn default() -> Self {
         Self::new()
@@ -133,15 +133,12 @@ impl<'src> Scanner<'src> {
 
     #[inline(always)]
     pub fn peek_n(&self, offset: usize) -> Token {
-        let idx = self.pos + offset;
-        if idx < self.tokens.len() {
-            self.tokens[idx]
-        } else {
-            *self
-                .tokens
-                .last()
-                .expect("scanner always emits an Eof token at the end of tokens")
-        }
+        let end = self.src.len() as u32;
+        self.tokens
+            .get(self.pos + offset)
+            .or(self.tokens.last())
+            .copied()
+            .unwrap_or(Token::new(TokenKind::Eof, end, end))
     }
 
     #[inline(always)]
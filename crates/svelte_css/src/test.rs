@@ -1175,6 +1175,98 @@ fn printer_deeply_nested() {
     );
 }
 
+#[test]
+fn printer_minified_basic() {
+    let src = "/* header */\nh1 > span, h2 ~ p {\n  color: #FFFFFF;\n  margin: 0px 0.50em;\n}\n";
+    let ss = p(src);
+    let output = Printer::print_minified(&ss, src, None);
+    assert_eq!(output, "h1>span,h2~p{color:#fff;margin:0 .5em}");
+}
+
+#[test]
+fn printer_minified_merges_duplicate_declarations() {
+    let src = "p { color: red; margin: 0; color: blue; display: -webkit-box; display: flex; }";
+    let ss = p(src);
+    let output = Printer::print_minified(&ss, src, None);
+    assert_eq!(
+        output,
+        "p{margin:0;color:blue;display:-webkit-box;display:flex}"
+    );
+}
+
+#[test]
+fn printer_minified_keeps_important_declaration() {
+    let src = "p { color: red !important; color: blue; }";
+    let ss = p(src);
+    let output = Printer::print_minified(&ss, src, None);
+    assert_eq!(output, "p{color:red!important;color:blue}");
+}
+
+#[test]
+fn printer_minified_drops_empty_rules() {
+    let src = "p {} @media (min-width: 768px) { div { } } span { /* nothing */ } @layer base {}";
+    let ss = p(src);
+    let output = Printer::print_minified(&ss, src, None);
+    assert_eq!(output, "@layer base{}");
+}
+
+#[test]
+fn printer_minified_drops_unused_rules() {
+    let src = "p { color: red; } div { color: blue; } @media print { div { color: green; } }";
+    let ss = p(src);
+    let used: rustc_hash::FxHashSet<CssNodeId> = ss
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            StyleSheetChild::Rule(Rule::Style(rule)) if text(rule.span, src).starts_with('p') => {
+                Some(rule.prelude.children[0].id)
+            }
+            _ => None,
+        })
+        .collect();
+    let output = Printer::print_minified(&ss, src, Some(&used));
+    assert_eq!(output, "p{color:red}");
+}
+
+#[test]
+fn count_unused_rules_skips_keyframes_and_nested_rules_of_unused_parents() {
+    let src = "p { color: red; } div { color: blue; .x { color: red; } } @media print { div { color: green; } } @keyframes fade { from { opacity: 0; } }";
+    let ss = p(src);
+    let used: rustc_hash::FxHashSet<CssNodeId> = ss
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            StyleSheetChild::Rule(Rule::Style(rule)) if text(rule.span, src).starts_with('p') => {
+                Some(rule.prelude.children[0].id)
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(Printer::count_unused_rules(&ss, &used), 2);
+}
+
+#[test]
+fn printer_minified_nested_and_keyframes() {
+    let src = "@keyframes fade { from { opacity: 0.0; } to { opacity: 1.0; } }\n.a { color: red; .b { color: blue; } }";
+    let ss = p(src);
+    let output = Printer::print_minified(&ss, src, None);
+    assert_eq!(
+        output,
+        "@keyframes fade{from{opacity:0}to{opacity:1}}.a{color:red;.b{color:blue}}"
+    );
+}
+
+#[test]
+fn printer_minified_keeps_legal_comments_and_strings() {
+    let src = "/*! license */\np::before { content: \"a  /* b */  #FFFFFF\"; }";
+    let ss = p(src);
+    let output = Printer::print_minified(&ss, src, None);
+    assert_eq!(
+        output,
+        "/*! license */p::before{content:\"a  /* b */  #FFFFFF\"}"
+    );
+}
+
 #[test]
 fn keyframes_percentages() {
     let src = "@keyframes slide { 0% { left: 0; } 100% { left: 100px; } }";
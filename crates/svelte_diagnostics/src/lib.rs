@@ -4,6 +4,7 @@ pub mod extract_svelte_ignore;
 use std::fmt;
 
 use svelte_span::Span;
+use unicode_width::UnicodeWidthChar;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
 pub enum Severity {
@@ -271,6 +272,9 @@ pub enum DiagnosticKind {
     ScriptReservedAttribute {
         name: String,
     },
+    ScriptUnsupportedLang {
+        lang: String,
+    },
     SlotAttributeDuplicate {
         name: String,
         component: String,
@@ -289,6 +293,9 @@ pub enum DiagnosticKind {
     },
     StyleDirectiveInvalidModifier,
     StyleDuplicate,
+    StyleUnsupportedLang {
+        lang: String,
+    },
     SvelteBodyIllegalAttribute,
     SvelteBoundaryInvalidAttribute,
     SvelteBoundaryInvalidAttributeValue,
@@ -475,6 +482,41 @@ pub enum DiagnosticKind {
         name: String,
         type_: String,
     },
+    EffectCanBeDerived {
+        names: String,
+        suggestion: String,
+    },
+    UnusedProp {
+        name: String,
+    },
+    UnusedState {
+        name: String,
+    },
+    UnusedSnippet {
+        name: String,
+    },
+    UnusedImport {
+        name: String,
+    },
+    ImportOnlyUsedAsType {
+        name: String,
+    },
+    ComponentUnknownProp {
+        component: String,
+        name: String,
+    },
+    ComponentMissingProp {
+        component: String,
+        name: String,
+    },
+    ComponentBindNonBindable {
+        component: String,
+        name: String,
+    },
+    ComponentSnippetPropMismatch {
+        component: String,
+        name: String,
+    },
     StoreRuneConflict {
         name: String,
     },
@@ -690,6 +732,7 @@ impl DiagnosticKind {
             Self::ScriptInvalidAttributeValue { .. } => "script_invalid_attribute_value",
             Self::ScriptInvalidContext => "script_invalid_context",
             Self::ScriptReservedAttribute { .. } => "script_reserved_attribute",
+            Self::ScriptUnsupportedLang { .. } => "script_unsupported_lang",
             Self::SlotAttributeDuplicate { .. } => "slot_attribute_duplicate",
             Self::SlotAttributeInvalid => "slot_attribute_invalid",
             Self::SlotAttributeInvalidPlacement => "slot_attribute_invalid_placement",
@@ -703,6 +746,7 @@ impl DiagnosticKind {
             Self::SnippetShadowingProp { .. } => "snippet_shadowing_prop",
             Self::StyleDirectiveInvalidModifier => "style_directive_invalid_modifier",
             Self::StyleDuplicate => "style_duplicate",
+            Self::StyleUnsupportedLang { .. } => "style_unsupported_lang",
             Self::SvelteBodyIllegalAttribute => "svelte_body_illegal_attribute",
             Self::SvelteBoundaryInvalidAttribute => "svelte_boundary_invalid_attribute",
             Self::SvelteBoundaryInvalidAttributeValue => "svelte_boundary_invalid_attribute_value",
@@ -809,6 +853,16 @@ impl DiagnosticKind {
                 "reactive_declaration_module_script_dependency"
             }
             Self::StateReferencedLocally { .. } => "state_referenced_locally",
+            Self::EffectCanBeDerived { .. } => "effect_can_be_derived",
+            Self::UnusedProp { .. } => "unused_prop",
+            Self::UnusedState { .. } => "unused_state",
+            Self::UnusedSnippet { .. } => "unused_snippet",
+            Self::UnusedImport { .. } => "unused_import",
+            Self::ImportOnlyUsedAsType { .. } => "import_only_used_as_type",
+            Self::ComponentUnknownProp { .. } => "component_unknown_prop",
+            Self::ComponentMissingProp { .. } => "component_missing_prop",
+            Self::ComponentBindNonBindable { .. } => "component_bind_non_bindable",
+            Self::ComponentSnippetPropMismatch { .. } => "component_snippet_prop_mismatch",
             Self::StoreRuneConflict { .. } => "store_rune_conflict",
             Self::CssUnusedSelector { .. } => "css_unused_selector",
             Self::AttributeAvoidIs => "attribute_avoid_is",
@@ -995,6 +1049,7 @@ impl DiagnosticKind {
             Self::ScriptInvalidAttributeValue { name } => format!("If the `{name}` attribute is supplied, it must be a boolean attribute"),
             Self::ScriptInvalidContext => "If the context attribute is supplied, its value must be \"module\"".into(),
             Self::ScriptReservedAttribute { name } => format!("The `{name}` attribute is reserved and cannot be used"),
+            Self::ScriptUnsupportedLang { lang } => format!("`<script lang=\"{lang}\">` is not supported. Run a preprocessor that compiles it to JavaScript or TypeScript before compiling"),
             Self::SlotAttributeDuplicate { name, component } => format!("Duplicate slot name '{name}' in <{component}>"),
             Self::SlotAttributeInvalid => "slot attribute must be a static value".into(),
             Self::SlotAttributeInvalidPlacement => "Element with a slot='...' attribute must be a child of a component or a descendant of a custom element".into(),
@@ -1008,6 +1063,7 @@ impl DiagnosticKind {
             Self::SnippetShadowingProp { prop } => format!("This snippet is shadowing the prop `{prop}` with the same name"),
             Self::StyleDirectiveInvalidModifier => "`style:` directive can only use the `important` modifier".into(),
             Self::StyleDuplicate => "A component can have a single top-level `<style>` element".into(),
+            Self::StyleUnsupportedLang { lang } => format!("`<style lang=\"{lang}\">` is not supported. Run a preprocessor that compiles it to CSS before compiling"),
             Self::SvelteBodyIllegalAttribute => "`<svelte:body>` does not support non-event attributes or spread attributes".into(),
             Self::SvelteBoundaryInvalidAttribute => "Valid attributes on `<svelte:boundary>` are `onerror` and `failed`".into(),
             Self::SvelteBoundaryInvalidAttributeValue => "Attribute value must be a non-string expression".into(),
@@ -1099,6 +1155,16 @@ impl DiagnosticKind {
             Self::ReactiveDeclarationInvalidPlacement => "Reactive declarations only exist at the top level of the instance script".into(),
             Self::ReactiveDeclarationModuleScriptDependency => "Reassignments of module-level declarations will not cause reactive statements to update".into(),
             Self::StateReferencedLocally { name, type_ } => format!("This reference only captures the initial value of `{name}`. Did you mean to reference it inside a {type_} instead?"),
+            Self::EffectCanBeDerived { names, suggestion } => format!("This `$effect` only keeps {names} in sync with other reactive values. Declare it with `$derived` instead: `{suggestion}`"),
+            Self::UnusedProp { name } => format!("Prop `{name}` is declared but never read"),
+            Self::UnusedState { name } => format!("`{name}` is declared with `$state` but its value is never read"),
+            Self::UnusedSnippet { name } => format!("Snippet `{name}` is declared but never rendered"),
+            Self::UnusedImport { name } => format!("`{name}` is imported but never used"),
+            Self::ImportOnlyUsedAsType { name } => format!("`{name}` is only used as a type. Import it with `import type` instead"),
+            Self::ComponentUnknownProp { component, name } => format!("`<{component}>` does not declare a prop named `{name}`"),
+            Self::ComponentMissingProp { component, name } => format!("`<{component}>` requires the `{name}` prop, but it was not passed"),
+            Self::ComponentBindNonBindable { component, name } => format!("Cannot use `bind:{name}` on `<{component}>` because `{name}` is not declared with `$bindable()`"),
+            Self::ComponentSnippetPropMismatch { component, name } => format!("`<{component}>` expects `{name}` to be a value, but a snippet was passed"),
             Self::StoreRuneConflict { name } => format!("It looks like you're using the `${name}` rune, but there is a local binding called `{name}`. Referencing a local variable with a `$` prefix will create a store subscription. Please rename `{name}` to avoid the ambiguity"),
             Self::CssUnusedSelector { name } => format!("Unused CSS selector \"{name}\""),
             Self::AttributeAvoidIs => "The \"is\" attribute is not supported cross-browser and should be avoided".into(),
@@ -1184,6 +1250,16 @@ impl DiagnosticKind {
             | Self::ReactiveDeclarationInvalidPlacement
             | Self::ReactiveDeclarationModuleScriptDependency
             | Self::StateReferencedLocally { .. }
+            | Self::EffectCanBeDerived { .. }
+            | Self::UnusedProp { .. }
+            | Self::UnusedState { .. }
+            | Self::UnusedSnippet { .. }
+            | Self::UnusedImport { .. }
+            | Self::ImportOnlyUsedAsType { .. }
+            | Self::ComponentUnknownProp { .. }
+            | Self::ComponentMissingProp { .. }
+            | Self::ComponentBindNonBindable { .. }
+            | Self::ComponentSnippetPropMismatch { .. }
             | Self::StoreRuneConflict { .. }
             | Self::CssUnusedSelector { .. }
             | Self::AttributeAvoidIs
@@ -1211,7 +1287,21 @@ impl DiagnosticKind {
     pub fn svelte_doc_url(&self) -> Option<String> {
         let code = self.code();
         match self {
-            Self::UnexpectedToken | Self::UnknownDirective | Self::InternalError(_) => None,
+            Self::UnexpectedToken
+            | Self::UnknownDirective
+            | Self::InternalError(_)
+            | Self::EffectCanBeDerived { .. }
+            | Self::UnusedProp { .. }
+            | Self::UnusedState { .. }
+            | Self::UnusedSnippet { .. }
+            | Self::UnusedImport { .. }
+            | Self::ImportOnlyUsedAsType { .. }
+            | Self::ComponentUnknownProp { .. }
+            | Self::ComponentMissingProp { .. }
+            | Self::ComponentBindNonBindable { .. }
+            | Self::ComponentSnippetPropMismatch { .. }
+            | Self::ScriptUnsupportedLang { .. }
+            | Self::StyleUnsupportedLang { .. } => None,
             _ => Some(format!("https://svelte.dev/e/{code}")),
         }
     }
@@ -1278,6 +1368,16 @@ impl DiagnosticKind {
             "reactive_declaration_invalid_placement",
             "reactive_declaration_module_script_dependency",
             "state_referenced_locally",
+            "effect_can_be_derived",
+            "unused_prop",
+            "unused_state",
+            "unused_snippet",
+            "unused_import",
+            "import_only_used_as_type",
+            "component_unknown_prop",
+            "component_missing_prop",
+            "component_bind_non_bindable",
+            "component_snippet_prop_mismatch",
             "store_rune_conflict",
             "css_unused_selector",
             "attribute_avoid_is",
@@ -1302,11 +1402,36 @@ impl DiagnosticKind {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct TextEdit {
+    pub span: Span,
+    pub replacement: String,
+}
+
+impl TextEdit {
+    pub fn new(span: Span, replacement: impl Into<String>) -> Self {
+        Self {
+            span,
+            replacement: replacement.into(),
+        }
+    }
+
+    pub fn insert(offset: u32, text: impl Into<String>) -> Self {
+        Self::new(Span::new(offset, offset), text)
+    }
+
+    pub fn delete(span: Span) -> Self {
+        Self::new(span, String::new())
+    }
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct Diagnostic {
     pub kind: DiagnosticKind,
     pub span: Span,
     pub severity: Severity,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixes: Vec<TextEdit>,
 }
 
 impl fmt::Display for Diagnostic {
@@ -1325,6 +1450,7 @@ impl Diagnostic {
             kind,
             span,
             severity: Severity::Error,
+            fixes: Vec::new(),
         }
     }
 
@@ -1333,6 +1459,7 @@ impl Diagnostic {
             kind,
             span,
             severity: Severity::Warning,
+            fixes: Vec::new(),
         }
     }
 
@@ -1380,6 +1507,10 @@ impl Diagnostic {
         Self::error(DiagnosticKind::InvalidExpression, span)
     }
 
+    pub fn js_parse_error(message: String, span: Span) -> Self {
+        Self::error(DiagnosticKind::JsParseError { message }, span)
+    }
+
     pub fn only_single_top_level_script(span: Span) -> Self {
         Self::error(DiagnosticKind::OnlyOneTopLevelScript, span)
     }
@@ -1409,6 +1540,7 @@ impl Diagnostic {
             kind: DiagnosticKind::SvelteOptionsUnknownAttribute(name),
             span,
             severity: Severity::Error,
+            fixes: Vec::new(),
         }
     }
 
@@ -1417,6 +1549,7 @@ impl Diagnostic {
             kind: DiagnosticKind::SvelteOptionsInvalidAttributeValue(expected),
             span,
             severity: Severity::Error,
+            fixes: Vec::new(),
         }
     }
 
@@ -1425,6 +1558,7 @@ impl Diagnostic {
             kind: DiagnosticKind::SvelteOptionsInvalidCustomElementTag,
             span,
             severity: Severity::Error,
+            fixes: Vec::new(),
         }
     }
 
@@ -1433,6 +1567,7 @@ impl Diagnostic {
             kind: DiagnosticKind::SvelteOptionsReservedTagName,
             span,
             severity: Severity::Error,
+            fixes: Vec::new(),
         }
     }
 
@@ -1441,6 +1576,7 @@ impl Diagnostic {
             kind: DiagnosticKind::SvelteOptionsNoChildren,
             span,
             severity: Severity::Error,
+            fixes: Vec::new(),
         }
     }
 
@@ -1449,6 +1585,7 @@ impl Diagnostic {
             kind: DiagnosticKind::SvelteOptionsInvalidAttribute,
             span,
             severity: Severity::Error,
+            fixes: Vec::new(),
         }
     }
 
@@ -1457,6 +1594,7 @@ impl Diagnostic {
             kind: DiagnosticKind::SvelteOptionsDuplicate,
             span,
             severity: Severity::Error,
+            fixes: Vec::new(),
         }
     }
     pub fn svelte_options_deprecated_tag(span: Span) -> Self {
@@ -1464,34 +1602,107 @@ impl Diagnostic {
             kind: DiagnosticKind::SvelteOptionsDeprecatedTag,
             span,
             severity: Severity::Warning,
+            fixes: Vec::new(),
         }
     }
 
     pub fn internal_error(message: String) -> Self {
+        Self::internal_error_at(message, Span::new(0, 0))
+    }
+
+    pub fn internal_error_at(message: String, span: Span) -> Self {
         Diagnostic {
             kind: DiagnosticKind::InternalError(message),
-            span: Span::new(0, 0),
+            span,
             severity: Severity::Error,
+            fixes: Vec::new(),
         }
     }
 
+    pub fn with_fixes(mut self, fixes: Vec<TextEdit>) -> Self {
+        self.fixes = fixes;
+        self
+    }
+
     pub fn as_err<T>(self) -> Result<T, Diagnostic> {
         Err(self)
     }
 }
+
+pub fn apply_fixes(source: &str, diagnostics: &[Diagnostic]) -> String {
+    let fits = |edit: &TextEdit| {
+        let (start, end) = (edit.span.start as usize, edit.span.end as usize);
+        start <= end
+            && end <= source.len()
+            && source.is_char_boundary(start)
+            && source.is_char_boundary(end)
+    };
+    let overlaps = |a: &TextEdit, b: &TextEdit| {
+        (a.span.start < b.span.end && b.span.start < a.span.end)
+            || (a.span.start == b.span.start && (a.span.size() == 0 || b.span.size() == 0))
+    };
+
+    // A diagnostic's edits form one fix: they are applied together or not at all.
+    let mut edits: Vec<&TextEdit> = Vec::new();
+    for diagnostic in diagnostics {
+        let fresh: Vec<&TextEdit> = diagnostic
+            .fixes
+            .iter()
+            .filter(|edit| !edits.contains(edit))
+            .collect();
+        let accepted = fresh
+            .iter()
+            .all(|edit| fits(edit) && !edits.iter().any(|applied| overlaps(applied, edit)));
+        if accepted {
+            edits.extend(fresh);
+        }
+    }
+    edits.sort_by_key(|edit| (edit.span.start, edit.span.end));
+
+    let mut out = String::with_capacity(source.len());
+    let mut cursor = 0usize;
+    for edit in edits {
+        let start = edit.span.start as usize;
+        out.push_str(&source[cursor..start]);
+        out.push_str(&edit.replacement);
+        cursor = edit.span.end as usize;
+    }
+    out.push_str(&source[cursor..]);
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub character: usize,
+}
+
 pub struct LineIndex {
     line_starts: Vec<usize>,
+    utf16_line_starts: Vec<usize>,
+    char_line_starts: Vec<usize>,
 }
 
 impl LineIndex {
     pub fn new(source: &str) -> Self {
         let mut line_starts = vec![0];
-        for (i, ch) in source.char_indices() {
+        let mut utf16_line_starts = vec![0];
+        let mut char_line_starts = vec![0];
+        let mut utf16 = 0;
+        for (chars, (i, ch)) in source.char_indices().enumerate() {
+            utf16 += ch.len_utf16();
             if ch == '\n' {
                 line_starts.push(i + 1);
+                utf16_line_starts.push(utf16);
+                char_line_starts.push(chars + 1);
             }
         }
-        LineIndex { line_starts }
+        LineIndex {
+            line_starts,
+            utf16_line_starts,
+            char_line_starts,
+        }
     }
     pub fn line_col(&self, offset: usize) -> (usize, usize) {
         let line = self
@@ -1501,6 +1712,39 @@ impl LineIndex {
         let col = offset - self.line_starts[line];
         (line, col)
     }
+    pub fn line_col_utf16(&self, source: &str, offset: usize) -> (usize, usize) {
+        let (line, prefix) = self.line_prefix(source, offset);
+        (line, prefix.encode_utf16().count())
+    }
+    pub fn line_col_chars(&self, source: &str, offset: usize) -> (usize, usize) {
+        let (line, prefix) = self.line_prefix(source, offset);
+        (line, prefix.chars().count())
+    }
+    pub fn utf16_offset(&self, source: &str, offset: usize) -> usize {
+        let (line, col) = self.line_col_utf16(source, offset);
+        self.utf16_line_starts[line] + col
+    }
+    pub fn char_offset(&self, source: &str, offset: usize) -> usize {
+        let (line, col) = self.line_col_chars(source, offset);
+        self.char_line_starts[line] + col
+    }
+    pub fn location(&self, source: &str, offset: usize) -> Location {
+        let (line, column) = self.line_col_utf16(source, offset);
+        Location {
+            line: line + 1,
+            column,
+            character: self.utf16_line_starts[line] + column,
+        }
+    }
+    fn line_prefix<'s>(&self, source: &'s str, offset: usize) -> (usize, &'s str) {
+        let mut offset = offset.min(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let (line, col) = self.line_col(offset);
+        let start = self.line_starts[line];
+        (line, &source[start..start + col])
+    }
     pub fn code_frame(&self, source: &str, span: Span) -> Option<String> {
         let total_lines = self.line_starts.len();
         if total_lines == 0 {
@@ -1535,9 +1779,13 @@ impl LineIndex {
                     display_line,
                     width = gutter_width
                 ));
-                let pointer_col = lines[i][..error_col.min(lines[i].len())]
+                let mut caret_offset = error_col.min(lines[i].len());
+                while !lines[i].is_char_boundary(caret_offset) {
+                    caret_offset -= 1;
+                }
+                let pointer_col = lines[i][..caret_offset]
                     .chars()
-                    .map(|c| if c == '\t' { 2 } else { 1 })
+                    .map(|c| if c == '\t' { 2 } else { c.width().unwrap_or(0) })
                     .sum::<usize>();
                 out.push_str(&format!(
                     "{:>width$} | {}^\n",
@@ -1591,6 +1839,13 @@ mod tests {
             DiagnosticKind::InternalError("test".into()).code(),
             "internal_error"
         );
+        assert_eq!(
+            DiagnosticKind::JsParseError {
+                message: "Unexpected token".into()
+            }
+            .code(),
+            "js_parse_error"
+        );
     }
 
     #[test]
@@ -1668,7 +1923,7 @@ mod tests {
     #[test]
     fn all_warning_codes_complete() {
         let codes = DiagnosticKind::all_warning_codes();
-        assert_eq!(codes.len(), 81);
+        assert_eq!(codes.len(), 91);
         assert!(codes.contains(&"block_empty"));
         assert!(codes.contains(&"a11y_accesskey"));
         assert!(codes.contains(&"css_unused_selector"));
@@ -1696,6 +1951,13 @@ mod tests {
             DiagnosticKind::InternalError("oops".into()).message(),
             "Internal compiler error: oops"
         );
+        assert_eq!(
+            DiagnosticKind::JsParseError {
+                message: "Unexpected token".into()
+            }
+            .message(),
+            "Unexpected token"
+        );
     }
 
     #[test]
@@ -1718,6 +1980,18 @@ mod tests {
             DiagnosticKind::InternalError("x".into()).svelte_doc_url(),
             None
         );
+        assert_eq!(
+            DiagnosticKind::EffectCanBeDerived {
+                names: "`doubled`".into(),
+                suggestion: "let doubled = $derived(count * 2);".into()
+            }
+            .svelte_doc_url(),
+            None
+        );
+        assert_eq!(
+            DiagnosticKind::UnusedImport { name: "x".into() }.svelte_doc_url(),
+            None
+        );
     }
 
     #[test]
@@ -1735,6 +2009,45 @@ mod tests {
         assert_eq!(output, "Unexpected token");
     }
 
+    #[test]
+    fn apply_fixes_dedupes_and_skips_overlaps() {
+        let source = "<div autofocus />";
+        let expand = TextEdit::new(Span::new(14, 17), "></div>");
+        let diags = vec![
+            Diagnostic::warning(DiagnosticKind::A11yAutofocus, Span::new(0, 17))
+                .with_fixes(vec![TextEdit::delete(Span::new(4, 14))]),
+            Diagnostic::warning(
+                DiagnosticKind::ElementInvalidSelfClosingTag { name: "div".into() },
+                Span::new(0, 17),
+            )
+            .with_fixes(vec![expand.clone()]),
+            Diagnostic::warning(
+                DiagnosticKind::ElementInvalidSelfClosingTag { name: "div".into() },
+                Span::new(0, 17),
+            )
+            .with_fixes(vec![expand]),
+            Diagnostic::warning(DiagnosticKind::A11yAutofocus, Span::new(0, 17)).with_fixes(vec![
+                TextEdit::insert(0, "<!-- -->"),
+                TextEdit::new(Span::new(10, 16), "x"),
+            ]),
+        ];
+        assert_eq!(apply_fixes(source, &diags), "<div></div>");
+    }
+
+    #[test]
+    fn fixes_are_omitted_from_json_when_empty() {
+        let d = Diagnostic::warning(DiagnosticKind::BlockEmpty, Span::new(0, 1));
+        let json = serde_json::to_value(&d).expect("diagnostic serializes");
+        assert!(json.get("fixes").is_none());
+
+        let d = d.with_fixes(vec![TextEdit::insert(1, "x")]);
+        let json = serde_json::to_value(&d).expect("diagnostic serializes");
+        assert_eq!(
+            json["fixes"],
+            serde_json::json!([{ "span": { "start": 1, "end": 1 }, "replacement": "x" }])
+        );
+    }
+
     #[test]
     fn code_frame_basic() {
         let source = "line1\nline2\nline3\nline4\nline5";
@@ -1782,4 +2095,38 @@ mod tests {
         assert!(frame.contains("  indented"));
         assert!(frame.contains("  ^"));
     }
+
+    #[test]
+    fn line_index_utf16_and_char_positions() {
+        let source = "<p>Привет</p>\n<b>你好 😀 {x}</b>";
+        let idx = LineIndex::new(source);
+        let x = source.find('x').expect("source contains x");
+        assert_eq!(idx.line_col(x), (1, 16));
+        assert_eq!(idx.line_col_chars(source, x), (1, 9));
+        assert_eq!(idx.line_col_utf16(source, x), (1, 10));
+        assert_eq!(idx.char_offset(source, x), 23);
+        assert_eq!(idx.utf16_offset(source, x), 24);
+        assert_eq!(
+            idx.location(source, x),
+            Location {
+                line: 2,
+                column: 10,
+                character: 24,
+            }
+        );
+        let inside_emoji = source.find('😀').expect("source contains emoji") + 1;
+        assert_eq!(idx.line_col_utf16(source, inside_emoji), (1, 6));
+    }
+
+    #[test]
+    fn code_frame_caret_uses_display_width() {
+        let source = "<b>你好 {x}</b>";
+        let idx = LineIndex::new(source);
+        let x = source.find('x').expect("source contains x") as u32;
+        let frame = idx
+            .code_frame(source, Span::new(x, x + 1))
+            .expect("code_frame returns Some for valid spans");
+        let caret_line = frame.lines().last().expect("frame has a caret line");
+        assert_eq!(caret_line, format!("  | {}^", " ".repeat(9)));
+    }
 }
@@ -1,9 +1,9 @@
 use rustc_hash::FxHashSet;
 use svelte_ast::{
-    AnimateDirective, Attribute, BindDirective, BooleanAttribute, ClassDirective, ConcatPart,
-    ConcatenationAttribute, ExprRef, ExpressionAttribute, LetDirectiveLegacy, OnDirectiveLegacy,
-    SpreadAttribute, StmtRef, StringAttribute, StyleDirective, StyleDirectiveValue,
-    TransitionDirection, TransitionDirective, UseDirective,
+    AnimateDirective, ArenaVec, Atom, Attribute, BindDirective, BooleanAttribute, ClassDirective,
+    ConcatPart, ConcatenationAttribute, ExprRef, ExpressionAttribute, LetDirectiveLegacy,
+    OnDirectiveLegacy, SpreadAttribute, StmtRef, StringAttribute, StyleDirective,
+    StyleDirectiveValue, TransitionDirection, TransitionDirective, UseDirective,
 };
 use svelte_diagnostics::{Diagnostic, DiagnosticKind};
 use svelte_span::{GetSpan, Span};
@@ -33,14 +33,14 @@ impl<'a> Parser<'a> {
         &mut self,
         token_attrs: &[token::Attribute],
         is_component: bool,
-    ) -> Vec<Attribute> {
-        let mut attributes = Vec::new();
+    ) -> &'a [Attribute<'a>] {
+        let mut attributes = ArenaVec::with_capacity_in(token_attrs.len(), self.allocator);
 
         let mut seen: FxHashSet<(&str, &str)> = FxHashSet::default();
 
         for attr in token_attrs {
-            let attr_id = self.reserve_id();
             let attr_span = attr.span();
+            let attr_id = self.reserve_id(attr_span);
             match attr {
                 token::Attribute::HTMLAttribute(html_attr) => {
                     let name = html_attr.name_span.source_text(self.source);
@@ -69,13 +69,13 @@ impl<'a> Parser<'a> {
                             Attribute::StringAttribute(StringAttribute {
                                 id: attr_id,
                                 span: attr_span,
-                                name: name.to_string(),
+                                name: Atom::from(name),
                                 value_span: *span,
                             })
                         }
                         token::AttributeValue::ExpressionTag(expr_tag) => {
-                            let name = name.to_string();
-                            let event_name = name.strip_prefix("on").map(|s| s.to_string());
+                            let event_name = name.strip_prefix("on").map(Atom::from);
+                            let name = Atom::from(name);
                             Attribute::ExpressionAttribute(ExpressionAttribute {
                                 id: attr_id,
                                 span: attr_span,
@@ -90,7 +90,7 @@ impl<'a> Parser<'a> {
                             Attribute::ConcatenationAttribute(ConcatenationAttribute {
                                 id: attr_id,
                                 span: attr_span,
-                                name: name.to_string(),
+                                name: Atom::from(name),
                                 quoted: matches!(
                                     self.source.as_bytes().get(concat.span.start as usize),
                                     Some(b'"') | Some(b'\'')
@@ -102,7 +102,7 @@ impl<'a> Parser<'a> {
                             Attribute::BooleanAttribute(BooleanAttribute {
                                 id: attr_id,
                                 span: attr_span,
-                                name: name.to_string(),
+                                name: Atom::from(name),
                             })
                         }
                     };
@@ -124,10 +124,7 @@ impl<'a> Parser<'a> {
                             expression: ExprRef::new(span),
                         }));
                     } else {
-                        let name = expr_tag
-                            .expression_span
-                            .source_text(self.source)
-                            .to_string();
+                        let name = Atom::from(expr_tag.expression_span.source_text(self.source));
                         attributes.push(Attribute::ExpressionAttribute(ExpressionAttribute {
                             id: attr_id,
                             span: attr_span,
@@ -151,7 +148,7 @@ impl<'a> Parser<'a> {
                     attributes.push(Attribute::ClassDirective(ClassDirective {
                         id: attr_id,
                         span: attr_span,
-                        name: cd_name.to_string(),
+                        name: Atom::from(cd_name),
                         expression: ExprRef::new(cd.expression_span),
                         shorthand: cd.shorthand,
                     }));
@@ -174,9 +171,7 @@ impl<'a> Parser<'a> {
                                 (StyleDirectiveValue::Expression, et.expression_span)
                             }
                             token::AttributeValue::String(span) => (
-                                StyleDirectiveValue::String(
-                                    span.source_text(self.source).to_string(),
-                                ),
+                                StyleDirectiveValue::String(span.source_text(self.source)),
                                 *span,
                             ),
                             token::AttributeValue::Concatenation(c) => {
@@ -200,7 +195,7 @@ impl<'a> Parser<'a> {
                     attributes.push(Attribute::StyleDirective(StyleDirective {
                         id: attr_id,
                         span: attr_span,
-                        name: sd_name.to_string(),
+                        name: Atom::from(sd_name),
                         expression: ExprRef::new(expression_span),
                         shorthand: sd.shorthand,
                         value,
@@ -220,7 +215,7 @@ impl<'a> Parser<'a> {
                     attributes.push(Attribute::BindDirective(BindDirective {
                         id: attr_id,
                         span: attr_span,
-                        name: bd_name.to_string(),
+                        name: Atom::from(bd_name),
                         expression: ExprRef::new(bd.expression_span),
                         shorthand: bd.shorthand,
                     }));
@@ -234,7 +229,7 @@ impl<'a> Parser<'a> {
                     attributes.push(Attribute::LetDirectiveLegacy(LetDirectiveLegacy {
                         id: attr_id,
                         span: attr_span,
-                        name: ld.name_span.source_text(self.source).to_string(),
+                        name: Atom::from(ld.name_span.source_text(self.source)),
                         name_span: ld.name_span,
                         binding: Some(StmtRef::new(binding_span)),
                     }));
@@ -262,14 +257,10 @@ impl<'a> Parser<'a> {
                     attributes.push(Attribute::OnDirectiveLegacy(OnDirectiveLegacy {
                         id: attr_id,
                         span: attr_span,
-                        name: od.name_span.source_text(self.source).to_string(),
+                        name: Atom::from(od.name_span.source_text(self.source)),
                         name_span: od.name_span,
                         expression: expression_span.map(ExprRef::new),
-                        modifiers: od
-                            .modifiers
-                            .iter()
-                            .map(|m| m.source_text(self.source).to_string())
-                            .collect(),
+                        modifiers: self.convert_modifiers(&od.modifiers),
                     }));
                 }
                 token::Attribute::TransitionDirective(td) => {
@@ -288,11 +279,7 @@ impl<'a> Parser<'a> {
                         span: attr_span,
                         name_ref: ExprRef::new(td.name_span),
                         expression: expression_span.map(ExprRef::new),
-                        modifiers: td
-                            .modifiers
-                            .iter()
-                            .map(|m| m.source_text(self.source).to_string())
-                            .collect(),
+                        modifiers: self.convert_modifiers(&td.modifiers),
                         direction,
                     }));
                 }
@@ -319,7 +306,52 @@ impl<'a> Parser<'a> {
             }
         }
 
-        attributes
+        attributes.into_bump_slice()
+    }
+
+    pub(crate) fn convert_static_attributes(
+        &mut self,
+        token_attrs: &[token::Attribute],
+    ) -> &'a [Attribute<'a>] {
+        let mut attributes = ArenaVec::with_capacity_in(token_attrs.len(), self.allocator);
+        for attr in token_attrs {
+            // `<script>`/`<style>` attributes are read statically; dynamic
+            // values and directives carry no `lang`/`context` information.
+            let token::Attribute::HTMLAttribute(html_attr) = attr else {
+                continue;
+            };
+            let span = attr.span();
+            let name = Atom::from(html_attr.name_span.source_text(self.source));
+            let attribute = match &html_attr.value {
+                token::AttributeValue::String(value_span) => {
+                    Attribute::StringAttribute(StringAttribute {
+                        id: self.reserve_id(span),
+                        span,
+                        name,
+                        value_span: *value_span,
+                    })
+                }
+                token::AttributeValue::Empty => Attribute::BooleanAttribute(BooleanAttribute {
+                    id: self.reserve_id(span),
+                    span,
+                    name,
+                }),
+                token::AttributeValue::ExpressionTag(_)
+                | token::AttributeValue::Concatenation(_) => continue,
+            };
+            attributes.push(attribute);
+        }
+        attributes.into_bump_slice()
+    }
+
+    fn convert_modifiers(&self, modifiers: &[Span]) -> &'a [Atom<'a>] {
+        ArenaVec::from_iter_in(
+            modifiers
+                .iter()
+                .map(|m| Atom::from(m.source_text(self.source))),
+            self.allocator,
+        )
+        .into_bump_slice()
     }
 
     pub(crate) fn classify_this_attribute(attributes: &[svelte_ast::Attribute]) -> (Span, bool) {
@@ -337,18 +369,19 @@ impl<'a> Parser<'a> {
         (Span::new(0, 0), false)
     }
 
-    fn convert_concat_parts(&mut self, parts: &[token::ConcatenationPart]) -> Vec<ConcatPart> {
-        parts
-            .iter()
-            .map(|part| match part {
+    fn convert_concat_parts(&mut self, parts: &[token::ConcatenationPart]) -> &'a [ConcatPart<'a>] {
+        let mut converted = ArenaVec::with_capacity_in(parts.len(), self.allocator);
+        for part in parts {
+            converted.push(match part {
                 token::ConcatenationPart::String(span) => {
-                    ConcatPart::Static(span.source_text(self.source).to_string())
+                    ConcatPart::Static(span.source_text(self.source))
                 }
                 token::ConcatenationPart::Expression(et) => ConcatPart::Dynamic {
-                    id: self.reserve_id(),
+                    id: self.reserve_id(et.expression_span),
                     expr: ExprRef::new(et.expression_span),
                 },
-            })
-            .collect()
+            });
+        }
+        converted.into_bump_slice()
     }
 }
@@ -9,7 +9,8 @@ use svelte_span::Span;
 
 use crate::scanner::{self, token};
 use crate::{
-    AwaitPhase, IfBlockEntry, Parser, StackEntry, is_component_name, pop_children, push_child,
+    AwaitPhase, Children, IfBlockEntry, Parser, StackEntry, is_component_name, pop_children,
+    push_child,
 };
 
 impl<'a> Parser<'a> {
@@ -17,8 +18,8 @@ impl<'a> Parser<'a> {
         &mut self,
         tag: &token::EndTag,
         span: Span,
-        entry_stack: &mut Vec<StackEntry>,
-        children_stack: &mut Vec<Vec<NodeId>>,
+        entry_stack: &mut Vec<StackEntry<'a>>,
+        children_stack: &mut Vec<Children<'a>>,
     ) {
         let tag_name = tag.name_span.source_text(self.source);
 
@@ -36,32 +37,19 @@ impl<'a> Parser<'a> {
             .iter()
             .rposition(|e| matches!(e, StackEntry::Element(el) if el.name == tag_name));
 
-        match match_idx {
-            None => {
-                self.recover(Diagnostic::no_element_to_close(span));
-                let id = self.push_node(Node::Error(svelte_ast::ErrorNode {
-                    id: NodeId(0),
-                    span,
-                }));
-                push_child(children_stack, id);
-            }
+        let matched = match match_idx {
             Some(idx) => {
-                let entries_to_close = entry_stack.len() - 1 - idx;
-                for _ in 0..entries_to_close {
-                    let entry = entry_stack
-                        .pop()
-                        .expect("entries_to_close is derived from stack length");
+                for entry in entry_stack.drain(idx + 1..).rev() {
                     self.auto_close_entry(entry, children_stack);
                 }
+                entry_stack.pop_if(|e| matches!(e, StackEntry::Element(_)))
+            }
+            None => None,
+        };
 
-                let entry = entry_stack
-                    .pop()
-                    .expect("matching element at idx guarantees stack is non-empty");
-                let StackEntry::Element(el) = entry else {
-                    unreachable!();
-                };
-
-                let children = pop_children(children_stack);
+        match matched {
+            Some(StackEntry::Element(el)) => {
+                let children = pop_children(children_stack, self.allocator);
                 let merged_span = el.span_start.merge(&span);
 
                 let node = if el.name == SVELTE_COMPONENT {
@@ -77,7 +65,7 @@ impl<'a> Parser<'a> {
                         fragment,
                         legacy_slots,
                     })
-                } else if is_component_name(&el.name) {
+                } else if is_component_name(el.name.as_str()) {
                     let (default_children, legacy_slots) =
                         self.partition_component_children(children);
                     let fragment =
@@ -106,6 +94,14 @@ impl<'a> Parser<'a> {
                 let id = self.push_node(node);
                 push_child(children_stack, id);
             }
+            _ => {
+                self.recover(Diagnostic::no_element_to_close(span));
+                let id = self.push_node(Node::Error(svelte_ast::ErrorNode {
+                    id: NodeId(0),
+                    span,
+                }));
+                push_child(children_stack, id);
+            }
         }
     }
 
@@ -113,54 +109,42 @@ impl<'a> Parser<'a> {
         &mut self,
         else_tag: &token::ElseTag,
         span: Span,
-        entry_stack: &mut Vec<StackEntry>,
-        children_stack: &mut Vec<Vec<NodeId>>,
+        entry_stack: &mut Vec<StackEntry<'a>>,
+        children_stack: &mut Vec<Children<'a>>,
     ) {
-        let consequent_children = pop_children(children_stack);
+        let consequent_children = pop_children(children_stack, self.allocator);
 
-        if else_tag.elseif {
-            let valid = entry_stack
-                .last()
-                .is_some_and(|e| matches!(e, StackEntry::IfBlock(_)));
-            if !valid {
+        if let Some(test_span) = else_tag.expression_span {
+            let Some(StackEntry::IfBlock(ib)) = entry_stack.last_mut() else {
                 self.recover(Diagnostic::no_if_block_for_else(span));
                 children_stack.push(consequent_children);
                 return;
-            }
-            let entry = entry_stack
-                .last_mut()
-                .expect("valid check above guarantees non-empty stack");
-            let StackEntry::IfBlock(ib) = entry else {
-                unreachable!()
             };
             ib.consequent = Some(consequent_children);
             ib.in_alternate = true;
 
-            children_stack.push(vec![]);
+            children_stack.push(self.new_children());
 
-            let expr_span = else_tag
-                .expression_span
-                .expect("elseif tag always carries an expression span");
             entry_stack.push(StackEntry::IfBlock(IfBlockEntry {
                 span,
-                test_span: expr_span,
+                test_span,
                 elseif: true,
                 consequent: None,
                 in_alternate: false,
             }));
-            children_stack.push(vec![]);
+            children_stack.push(self.new_children());
         } else {
             match entry_stack.last_mut() {
                 Some(StackEntry::IfBlock(ib)) => {
                     ib.consequent = Some(consequent_children);
                     ib.in_alternate = true;
                     ib.span = ib.span.merge(&span);
-                    children_stack.push(vec![]);
+                    children_stack.push(self.new_children());
                 }
                 Some(StackEntry::EachBlock(eb)) => {
                     eb.body_children = Some(consequent_children);
                     eb.in_fallback = true;
-                    children_stack.push(vec![]);
+                    children_stack.push(self.new_children());
                 }
                 _ => {
                     self.recover(Diagnostic::no_if_block_for_else(span));
@@ -173,8 +157,8 @@ impl<'a> Parser<'a> {
     pub(crate) fn handle_end_each_tag(
         &mut self,
         span: Span,
-        entry_stack: &mut Vec<StackEntry>,
-        children_stack: &mut Vec<Vec<NodeId>>,
+        entry_stack: &mut Vec<StackEntry<'a>>,
+        children_stack: &mut Vec<Children<'a>>,
     ) {
         let entry = entry_stack.pop();
 
@@ -186,11 +170,11 @@ impl<'a> Parser<'a> {
             return;
         };
 
-        let last_children = pop_children(children_stack);
+        let last_children = pop_children(children_stack, self.allocator);
         let merged_span = eb.span.merge(&span);
 
         let (body_children, fallback) = if eb.in_fallback {
-            let body = eb.body_children.unwrap_or_default();
+            let body = eb.body_children.unwrap_or_else(|| self.new_children());
             let fb = self.new_fragment(FragmentRole::EachFallback, last_children);
             (body, Some(fb))
         } else {
@@ -198,7 +182,7 @@ impl<'a> Parser<'a> {
         };
 
         let body = self.new_fragment(FragmentRole::EachBody, body_children);
-        let key_id = eb.key_span.map(|_| self.reserve_id());
+        let key_id = eb.key_span.map(|span| self.reserve_id(span));
         let id = self.push_node(Node::EachBlock(EachBlock {
             id: NodeId(0),
             span: merged_span,
@@ -217,8 +201,8 @@ impl<'a> Parser<'a> {
     pub(crate) fn handle_end_snippet_tag(
         &mut self,
         span: Span,
-        entry_stack: &mut Vec<StackEntry>,
-        children_stack: &mut Vec<Vec<NodeId>>,
+        entry_stack: &mut Vec<StackEntry<'a>>,
+        children_stack: &mut Vec<Children<'a>>,
     ) {
         let entry = entry_stack.pop();
 
@@ -230,7 +214,7 @@ impl<'a> Parser<'a> {
             return;
         };
 
-        let body_children = pop_children(children_stack);
+        let body_children = pop_children(children_stack, self.allocator);
         let merged_span = sb.span_start.merge(&span);
 
         let body = self.new_fragment(FragmentRole::SnippetBody, body_children);
@@ -247,8 +231,8 @@ impl<'a> Parser<'a> {
     pub(crate) fn handle_end_key_tag(
         &mut self,
         span: Span,
-        entry_stack: &mut Vec<StackEntry>,
-        children_stack: &mut Vec<Vec<NodeId>>,
+        entry_stack: &mut Vec<StackEntry<'a>>,
+        children_stack: &mut Vec<Children<'a>>,
     ) {
         let entry = entry_stack.pop();
 
@@ -260,7 +244,7 @@ impl<'a> Parser<'a> {
             return;
         };
 
-        let body_children = pop_children(children_stack);
+        let body_children = pop_children(children_stack, self.allocator);
         let merged_span = kb.span.merge(&span);
 
         let fragment = self.new_fragment(FragmentRole::KeyBlockBody, body_children);
@@ -278,8 +262,8 @@ impl<'a> Parser<'a> {
         &mut self,
         clause_tag: &scanner::token::AwaitClauseTag,
         span: Span,
-        entry_stack: &mut Vec<StackEntry>,
-        children_stack: &mut Vec<Vec<NodeId>>,
+        entry_stack: &mut Vec<StackEntry<'a>>,
+        children_stack: &mut Vec<Children<'a>>,
     ) {
         let entry = entry_stack.last_mut();
 
@@ -308,7 +292,7 @@ impl<'a> Parser<'a> {
             return;
         }
 
-        let current_children = pop_children(children_stack);
+        let current_children = pop_children(children_stack, self.allocator);
         match ab.phase {
             AwaitPhase::Pending => {
                 ab.pending_children = Some(current_children);
@@ -333,14 +317,14 @@ impl<'a> Parser<'a> {
             }
         }
 
-        children_stack.push(vec![]);
+        children_stack.push(self.new_children());
     }
 
     pub(crate) fn handle_end_await_tag(
         &mut self,
         span: Span,
-        entry_stack: &mut Vec<StackEntry>,
-        children_stack: &mut Vec<Vec<NodeId>>,
+        entry_stack: &mut Vec<StackEntry<'a>>,
+        children_stack: &mut Vec<Children<'a>>,
     ) {
         let entry = entry_stack.pop();
 
@@ -352,7 +336,7 @@ impl<'a> Parser<'a> {
             return;
         };
 
-        let current_children = pop_children(children_stack);
+        let current_children = pop_children(children_stack, self.allocator);
         let merged_span = ab.span.merge(&span);
 
         let (pending, then, catch) = match ab.phase {
@@ -399,8 +383,8 @@ impl<'a> Parser<'a> {
 
     pub(crate) fn auto_close_entries(
         &mut self,
-        entry_stack: &mut Vec<StackEntry>,
-        children_stack: &mut Vec<Vec<NodeId>>,
+        entry_stack: &mut Vec<StackEntry<'a>>,
+        children_stack: &mut Vec<Children<'a>>,
     ) {
         while let Some(entry) = entry_stack.pop() {
             self.auto_close_entry(entry, children_stack);
@@ -409,8 +393,8 @@ impl<'a> Parser<'a> {
 
     pub(crate) fn auto_close_entry(
         &mut self,
-        entry: StackEntry,
-        children_stack: &mut Vec<Vec<NodeId>>,
+        entry: StackEntry<'a>,
+        children_stack: &mut Vec<Children<'a>>,
     ) {
         let eof_pos = self.source.len() as u32;
         let eof_span = Span::new(eof_pos, eof_pos);
@@ -418,7 +402,7 @@ impl<'a> Parser<'a> {
         match entry {
             StackEntry::Element(el) => {
                 self.recover(Diagnostic::unclosed_node(el.span_start));
-                let children = pop_children(children_stack);
+                let children = pop_children(children_stack, self.allocator);
                 let merged_span = el.span_start.merge(&eof_span);
 
                 let node = if el.name == SVELTE_COMPONENT {
@@ -434,7 +418,7 @@ impl<'a> Parser<'a> {
                         fragment,
                         legacy_slots,
                     })
-                } else if is_component_name(&el.name) {
+                } else if is_component_name(el.name.as_str()) {
                     let (default_children, legacy_slots) =
                         self.partition_component_children(children);
                     let fragment =
@@ -465,7 +449,7 @@ impl<'a> Parser<'a> {
             }
             StackEntry::IfBlock(ib) => {
                 self.recover(Diagnostic::unclosed_node(ib.span));
-                let last_children = pop_children(children_stack);
+                let last_children = pop_children(children_stack, self.allocator);
 
                 let (consequent, alternate) = if let Some(cons) = ib.consequent {
                     let alt = self.new_fragment(FragmentRole::IfAlternate, last_children);
@@ -490,11 +474,11 @@ impl<'a> Parser<'a> {
             }
             StackEntry::EachBlock(eb) => {
                 self.recover(Diagnostic::unclosed_node(eb.span));
-                let last_children = pop_children(children_stack);
+                let last_children = pop_children(children_stack, self.allocator);
                 let merged_span = eb.span.merge(&eof_span);
 
                 let (body_children, fallback) = if eb.in_fallback {
-                    let body = eb.body_children.unwrap_or_default();
+                    let body = eb.body_children.unwrap_or_else(|| self.new_children());
                     let fb = self.new_fragment(FragmentRole::EachFallback, last_children);
                     (body, Some(fb))
                 } else {
@@ -502,7 +486,7 @@ impl<'a> Parser<'a> {
                 };
 
                 let body = self.new_fragment(FragmentRole::EachBody, body_children);
-                let key_id = eb.key_span.map(|_| self.reserve_id());
+                let key_id = eb.key_span.map(|span| self.reserve_id(span));
                 let id = self.push_node(Node::EachBlock(EachBlock {
                     id: NodeId(0),
                     span: merged_span,
@@ -519,7 +503,7 @@ impl<'a> Parser<'a> {
             }
             StackEntry::SnippetBlock(sb) => {
                 self.recover(Diagnostic::unclosed_node(sb.span_start));
-                let body_children = pop_children(children_stack);
+                let body_children = pop_children(children_stack, self.allocator);
                 let merged_span = sb.span_start.merge(&eof_span);
 
                 let body = self.new_fragment(FragmentRole::SnippetBody, body_children);
@@ -534,7 +518,7 @@ impl<'a> Parser<'a> {
             }
             StackEntry::KeyBlock(kb) => {
                 self.recover(Diagnostic::unclosed_node(kb.span));
-                let body_children = pop_children(children_stack);
+                let body_children = pop_children(children_stack, self.allocator);
                 let merged_span = kb.span.merge(&eof_span);
 
                 let fragment = self.new_fragment(FragmentRole::KeyBlockBody, body_children);
@@ -549,7 +533,7 @@ impl<'a> Parser<'a> {
             }
             StackEntry::AwaitBlock(ab) => {
                 self.recover(Diagnostic::unclosed_node(ab.span));
-                let current_children = pop_children(children_stack);
+                let current_children = pop_children(children_stack, self.allocator);
                 let merged_span = ab.span.merge(&eof_span);
 
                 let (pending, then, catch) = match ab.phase {
@@ -598,8 +582,8 @@ impl<'a> Parser<'a> {
     pub(crate) fn close_if_chain(
         &mut self,
         end_span: Span,
-        entry_stack: &mut Vec<StackEntry>,
-        children_stack: &mut Vec<Vec<NodeId>>,
+        entry_stack: &mut Vec<StackEntry<'a>>,
+        children_stack: &mut Vec<Children<'a>>,
     ) {
         loop {
             let Some(entry) = entry_stack.pop() else {
@@ -613,7 +597,7 @@ impl<'a> Parser<'a> {
                 return;
             };
 
-            let last_children = pop_children(children_stack);
+            let last_children = pop_children(children_stack, self.allocator);
 
             let (consequent, alternate) = if let Some(cons) = ib.consequent {
                 let alt = self.new_fragment(FragmentRole::IfAlternate, last_children);
@@ -0,0 +1,934 @@
+use oxc_allocator::CloneIn;
+use svelte_ast::{
+    Allocator, ArenaVec, AstStore, Atom, Attribute, Component, ConcatPart, CustomElementConfig,
+    ErrorNode, ExprRef, FragmentId, LegacySlot, Node, NodeId, RawBlock, Script, StmtRef,
+    StyleDirectiveValue, SvelteOptions,
+};
+use svelte_diagnostics::{Diagnostic, TextEdit};
+use svelte_span::Span;
+
+use crate::Parser;
+use crate::svelte_elements::for_each_child_fragment;
+use crate::types::JsAst;
+use crate::walk_js;
+
+const CONTEXT_SENSITIVE_TAGS: &[&str] = &[
+    "<script",
+    "<style",
+    "<svelte:options",
+    "<svelte:head",
+    "<svelte:window",
+    "<svelte:document",
+    "<svelte:body",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReparseMode {
+    Incremental,
+    Full,
+}
+
+pub struct Reparsed<'a> {
+    pub component: Component<'a>,
+    pub diagnostics: Vec<Diagnostic>,
+    pub mode: ReparseMode,
+}
+
+/// The edit does not fit the previous source: it is reversed, reaches past
+/// the end, or splits a UTF-8 character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidEdit {
+    pub span: Span,
+    pub source_len: u32,
+}
+
+impl std::fmt::Display for InvalidEdit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "edit {}..{} does not fit a source of {} bytes",
+            self.span.start, self.span.end, self.source_len
+        )
+    }
+}
+
+impl std::error::Error for InvalidEdit {}
+
+/// What the incremental path parsed again, in positions of the previous
+/// revision.
+#[derive(Clone, Copy)]
+enum Replaced {
+    /// The template node with this id and old span; the new store keeps the id.
+    Node(NodeId, Span),
+    /// The content of a `<script>` or `<style>` block.
+    Raw(Span),
+}
+
+/// Applies `edit` to `previous` and parses the result into `allocator`.
+///
+/// Nothing of the new revision points into the previous arena, so callers
+/// should pass a fresh allocator per edit and drop the old one afterwards.
+/// Reused nodes keep their `NodeId`s and take their names and text from the
+/// new source; once more than half of the store is dead, the edit falls back
+/// to a full parse, which compacts it again.
+pub fn reparse<'a>(
+    allocator: &'a Allocator,
+    previous: &Component<'_>,
+    previous_diagnostics: &[Diagnostic],
+    edit: &TextEdit,
+) -> Result<Reparsed<'a>, InvalidEdit> {
+    reparse_in(allocator, previous, previous_diagnostics, edit).map(|(reparsed, _)| reparsed)
+}
+
+fn reparse_in<'a>(
+    allocator: &'a Allocator,
+    previous: &Component<'_>,
+    previous_diagnostics: &[Diagnostic],
+    edit: &TextEdit,
+) -> Result<(Reparsed<'a>, Option<Replaced>), InvalidEdit> {
+    let start = edit.span.start as usize;
+    let end = edit.span.end as usize;
+    let old_source = previous.source;
+    if start > end || !old_source.is_char_boundary(start) || !old_source.is_char_boundary(end) {
+        return Err(InvalidEdit {
+            span: edit.span,
+            source_len: old_source.len() as u32,
+        });
+    }
+    let mut text = String::with_capacity(old_source.len() - (end - start) + edit.replacement.len());
+    text.push_str(&old_source[..start]);
+    text.push_str(&edit.replacement);
+    text.push_str(&old_source[end..]);
+    let source: &'a str = allocator.alloc_str(&text);
+
+    if previous_diagnostics.is_empty()
+        && let Some((component, replaced)) =
+            Incremental::new(allocator, previous, source, edit).run()
+    {
+        let reparsed = Reparsed {
+            component,
+            diagnostics: Vec::new(),
+            mode: ReparseMode::Incremental,
+        };
+        return Ok((reparsed, Some(replaced)));
+    }
+
+    let (component, diagnostics) =
+        Parser::for_range(allocator, source, Span::new(0, source.len() as u32)).parse();
+    let reparsed = Reparsed {
+        component,
+        diagnostics,
+        mode: ReparseMode::Full,
+    };
+    Ok((reparsed, None))
+}
+
+/// [`reparse`] plus the JS of the new revision.
+///
+/// After an incremental reparse only the replaced node or the edited
+/// `<script>` is parsed again. The other expressions, patterns and programs of
+/// `previous_js` are cloned into `allocator` under their shifted offsets (their
+/// own spans are relative to the snippet), so `previous_js` must be the result
+/// of the previous parse before analysis drains it; a drained one makes the
+/// whole JS parse again.
+pub fn reparse_with_js<'a>(
+    allocator: &'a Allocator,
+    previous: &Component<'_>,
+    previous_js: &JsAst<'_>,
+    previous_diagnostics: &[Diagnostic],
+    edit: &TextEdit,
+) -> Result<(Reparsed<'a>, JsAst<'a>), InvalidEdit> {
+    let (mut reparsed, replaced) = reparse_in(allocator, previous, previous_diagnostics, edit)?;
+    let component = &reparsed.component;
+    let diagnostics = &mut reparsed.diagnostics;
+    let mut js = JsAst::new();
+    match replaced {
+        Some(replaced) if !previous_js.is_drained() => {
+            let carry = CarryJs {
+                allocator,
+                edit: edit.span,
+                delta: edit.replacement.len() as i64 - edit.span.size() as i64,
+                replaced,
+            };
+            carry.run(previous_js, &mut js);
+            match replaced {
+                Replaced::Node(id, _) => {
+                    walk_js::parse_node_js(allocator, component, id, &mut js, diagnostics);
+                }
+                Replaced::Raw(content) => {
+                    if previous_js.script_content_span == Some(content) {
+                        walk_js::parse_instance_script(allocator, component, &mut js, diagnostics);
+                    }
+                    if previous_js.module_script_content_span == Some(content) {
+                        walk_js::parse_module_script(allocator, component, &mut js, diagnostics);
+                    }
+                }
+            }
+        }
+        _ => walk_js::parse_js(allocator, component, &mut js, diagnostics),
+    }
+    Ok((reparsed, js))
+}
+
+/// Moves the JS that the edit did not touch into the new revision.
+struct CarryJs<'a> {
+    allocator: &'a Allocator,
+    edit: Span,
+    delta: i64,
+    replaced: Replaced,
+}
+
+impl<'a> CarryJs<'a> {
+    fn run(&self, previous: &JsAst<'_>, js: &mut JsAst<'a>) {
+        js.typescript = previous.typescript;
+        if let Some(program) = &previous.program
+            && let Some(content) = previous.script_content_span
+            && !self.is_replaced_raw(content)
+        {
+            js.program = Some(program.clone_in(self.allocator));
+            js.script_content_span = Some(self.span(content));
+        }
+        if let Some(program) = &previous.module_program
+            && let Some(content) = previous.module_script_content_span
+            && !self.is_replaced_raw(content)
+        {
+            js.module_program = Some(program.clone_in(self.allocator));
+            js.module_script_content_span = Some(self.span(content));
+        }
+        for (offset, expr) in previous.pending_exprs() {
+            if !self.is_replaced_node(offset) {
+                js.alloc_expr(self.shift(offset), expr.clone_in(self.allocator));
+            }
+        }
+        for (offset, stmt) in previous.pending_stmts() {
+            if !self.is_replaced_node(offset) {
+                js.alloc_stmt(self.shift(offset), stmt.clone_in(self.allocator));
+            }
+        }
+    }
+
+    fn is_replaced_node(&self, offset: u32) -> bool {
+        matches!(self.replaced, Replaced::Node(_, span) if span.start <= offset && offset < span.end)
+    }
+
+    fn is_replaced_raw(&self, content: Span) -> bool {
+        matches!(self.replaced, Replaced::Raw(span) if span == content)
+    }
+
+    fn shift(&self, pos: u32) -> u32 {
+        shift(pos, self.edit, self.delta)
+    }
+
+    fn span(&self, span: Span) -> Span {
+        Span::new(self.shift(span.start), self.shift(span.end))
+    }
+}
+
+/// Maps a position of the previous source to the new one; positions inside
+/// the edit are not meaningful and stay put.
+fn shift(pos: u32, edit: Span, delta: i64) -> u32 {
+    if pos >= edit.end {
+        (pos as i64 + delta) as u32
+    } else {
+        pos
+    }
+}
+
+struct Incremental<'p, 'o, 'a> {
+    previous: &'p Component<'o>,
+    allocator: &'a Allocator,
+    source: &'a str,
+    edit: Span,
+    delta: i64,
+}
+
+impl<'p, 'o, 'a> Incremental<'p, 'o, 'a> {
+    fn new(
+        allocator: &'a Allocator,
+        previous: &'p Component<'o>,
+        source: &'a str,
+        edit: &TextEdit,
+    ) -> Self {
+        Self {
+            previous,
+            allocator,
+            source,
+            edit: edit.span,
+            delta: edit.replacement.len() as i64 - edit.span.size() as i64,
+        }
+    }
+
+    fn shift(&self, pos: u32) -> u32 {
+        shift(pos, self.edit, self.delta)
+    }
+
+    fn run(&self) -> Option<(Component<'a>, Replaced)> {
+        if let Some(content) = self.edited_raw_block() {
+            return self
+                .rebuild(None)
+                .map(|component| (component, Replaced::Raw(content)));
+        }
+        let target = self.find_target()?;
+        let old = self.previous.store.get(target);
+        let span = Span::new(old.span().start, self.shift(old.span().end));
+        if has_context_sensitive_tag(span.source_text(self.source)) {
+            return None;
+        }
+
+        let (snippet, diagnostics) = Parser::for_range(self.allocator, self.source, span).parse();
+        if !diagnostics.is_empty()
+            || snippet.instance_script.is_some()
+            || snippet.module_script.is_some()
+            || snippet.css.is_some()
+            || snippet.options.is_some()
+        {
+            return None;
+        }
+        let &[top] = snippet.fragment_nodes(snippet.root) else {
+            return None;
+        };
+        let new = snippet.store.get(top);
+        if new.span() != span
+            || std::mem::discriminant(new) != std::mem::discriminant(old)
+            || has_slot_attribute(old)
+            || has_slot_attribute(new)
+        {
+            return None;
+        }
+
+        self.rebuild(Some((target, &snippet, top)))
+            .map(|component| (component, Replaced::Node(target, old.span())))
+    }
+
+    fn edited_raw_block(&self) -> Option<Span> {
+        let previous = self.previous;
+        let scripts = [&previous.instance_script, &previous.module_script];
+        for script in scripts.into_iter().flatten() {
+            if self.raw_content_contains_edit(script.content_span, "</script") {
+                return Some(script.content_span);
+            }
+        }
+        previous
+            .css
+            .as_ref()
+            .map(|css| css.content_span)
+            .filter(|&content| self.raw_content_contains_edit(content, "</style"))
+    }
+
+    fn raw_content_contains_edit(&self, content: Span, closing: &str) -> bool {
+        if content.start >= self.edit.start || self.edit.end > content.end {
+            return false;
+        }
+        let new_content = Span::new(content.start, self.shift(content.end));
+        !contains_ignore_ascii_case(new_content.source_text(self.source), closing)
+    }
+
+    fn find_target(&self) -> Option<NodeId> {
+        let store = &self.previous.store;
+        let mut target = None;
+        let mut fragments = vec![self.previous.root];
+        while let Some(id) = fragments.iter().find_map(|&fragment| {
+            store.fragment_nodes(fragment).iter().copied().find(|&id| {
+                let span = store.get(id).span();
+                span.start < self.edit.start && self.edit.end < span.end
+            })
+        }) {
+            let node = store.get(id);
+            if !matches!(
+                node,
+                Node::Error(_) | Node::IfBlock(svelte_ast::IfBlock { elseif: true, .. })
+            ) {
+                target = Some(id);
+            }
+            fragments.clear();
+            for_each_child_fragment(node, |fragment| fragments.push(fragment));
+        }
+        target
+    }
+
+    fn rebuild(
+        &self,
+        replacement: Option<(NodeId, &Component<'a>, NodeId)>,
+    ) -> Option<Component<'a>> {
+        let previous = self.previous;
+        let old_store = &previous.store;
+        let node_count = old_store.len();
+        let fragment_count = old_store.fragments_len();
+
+        let mut dead_nodes = vec![false; node_count as usize];
+        let mut dead_fragments = vec![false; fragment_count as usize];
+        if let Some((target, _, _)) = replacement {
+            mark_subtree(old_store, target, &mut dead_nodes, &mut dead_fragments);
+            dead_nodes[target.0 as usize] = false;
+        }
+        let dead = (0..node_count)
+            .filter(|&raw| dead_nodes[raw as usize] || is_dead(old_store.get(NodeId(raw))))
+            .count() as u32;
+        let snippet_count = replacement.map_or(0, |(_, snippet, _)| snippet.node_count());
+        if dead * 2 > node_count + snippet_count {
+            return None;
+        }
+
+        let shift = |pos: u32| self.shift(pos);
+        let old = Relocator {
+            allocator: self.allocator,
+            old_source: previous.source,
+            new_source: self.source,
+            pos: &shift,
+            node: &|id| id,
+            fragment: &|fragment| fragment,
+        };
+
+        let (snippet_root, snippet_top) = replacement.map_or(
+            (FragmentId(u32::MAX), NodeId(u32::MAX)),
+            |(_, snippet, top)| (snippet.root, top),
+        );
+        let target = replacement.map_or(NodeId(u32::MAX), |(target, _, _)| target);
+        let snippet_node = |id: NodeId| {
+            if id == snippet_top {
+                target
+            } else {
+                NodeId(node_count + id.0 - u32::from(id.0 > snippet_top.0))
+            }
+        };
+        let snippet_fragment = |fragment: FragmentId| {
+            FragmentId(fragment_count + fragment.0 - u32::from(fragment.0 > snippet_root.0))
+        };
+        let unchanged = |pos: u32| pos;
+        let fresh = Relocator {
+            allocator: self.allocator,
+            old_source: previous.source,
+            new_source: self.source,
+            pos: &unchanged,
+            node: &snippet_node,
+            fragment: &snippet_fragment,
+        };
+
+        let mut store =
+            AstStore::with_capacity(self.allocator, (node_count + snippet_count) as usize);
+        for raw in 0..node_count {
+            let id = NodeId(raw);
+            let node = match replacement {
+                Some((_, snippet, top)) if id == target => fresh.node(snippet.store.get(top)),
+                _ if dead_nodes[raw as usize] => dead_node(id),
+                _ => old.node(old_store.get(id)),
+            };
+            store.push(node);
+        }
+        for raw in 0..fragment_count {
+            let fragment = old_store.fragment(FragmentId(raw));
+            if dead_fragments[raw as usize] {
+                store.reserve_fragment(fragment.role);
+            } else {
+                let nodes = ArenaVec::from_iter_in(fragment.nodes.iter().copied(), self.allocator);
+                store.push_fragment(fragment.role, nodes);
+            }
+        }
+        if let Some((_, snippet, top)) = replacement {
+            for raw in (0..snippet.node_count()).filter(|&raw| raw != top.0) {
+                store.push(fresh.node(snippet.store.get(NodeId(raw))));
+            }
+            for raw in (0..snippet.fragment_count()).filter(|&raw| raw != snippet.root.0) {
+                let fragment = snippet.store.fragment(FragmentId(raw));
+                let nodes = ArenaVec::from_iter_in(
+                    fragment.nodes.iter().map(|&id| snippet_node(id)),
+                    self.allocator,
+                );
+                store.push_fragment(fragment.role, nodes);
+            }
+        }
+
+        let mut component = Component::new(
+            self.source,
+            previous.root,
+            store,
+            previous.instance_script.as_ref().map(|s| old.script(s)),
+            previous.module_script.as_ref().map(|s| old.script(s)),
+            previous.css.as_ref().map(|css| RawBlock {
+                span: old.span(css.span),
+                content_span: old.span(css.content_span),
+                attributes: old.attributes(css.attributes),
+            }),
+        );
+        component.options = previous.options.as_ref().map(|o| old.options(o));
+        Parser::populate_fragment_owners(&mut component.store);
+        component.store.freeze_node_fragments();
+        Some(component)
+    }
+}
+
+fn has_context_sensitive_tag(text: &str) -> bool {
+    CONTEXT_SENSITIVE_TAGS
+        .iter()
+        .any(|tag| contains_ignore_ascii_case(text, tag))
+}
+
+fn contains_ignore_ascii_case(haystack: &str, needle: &str) -> bool {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .any(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+fn offset_in(source: &str, text: &str) -> Option<u32> {
+    let offset = (text.as_ptr() as usize).checked_sub(source.as_ptr() as usize)?;
+    (offset + text.len() <= source.len()).then_some(offset as u32)
+}
+
+fn has_slot_attribute(node: &Node<'_>) -> bool {
+    node.attributes()
+        .iter()
+        .any(|attr| matches!(attr, Attribute::StringAttribute(a) if a.name == "slot"))
+}
+
+fn is_dead(node: &Node<'_>) -> bool {
+    matches!(node, Node::Error(e) if e.span == Span::new(0, 0))
+}
+
+fn dead_node(id: NodeId) -> Node<'static> {
+    Node::Error(ErrorNode {
+        id,
+        span: Span::new(0, 0),
+    })
+}
+
+fn mark_subtree(
+    store: &AstStore<'_>,
+    root: NodeId,
+    dead_nodes: &mut [bool],
+    dead_fragments: &mut [bool],
+) {
+    let mut stack = vec![root];
+    while let Some(id) = stack.pop() {
+        dead_nodes[id.0 as usize] = true;
+        let node = store.get(id);
+        for attr in node.attributes() {
+            dead_nodes[attr.id().0 as usize] = true;
+            let parts = match attr {
+                Attribute::ConcatenationAttribute(a) => a.parts,
+                Attribute::StyleDirective(a) => match a.value {
+                    StyleDirectiveValue::Concatenation(parts) => parts,
+                    _ => &[],
+                },
+                _ => &[],
+            };
+            for part in parts {
+                if let ConcatPart::Dynamic { id, .. } = part {
+                    dead_nodes[id.0 as usize] = true;
+                }
+            }
+        }
+        if let Node::EachBlock(block) = node
+            && let Some(key_id) = block.key_id
+        {
+            dead_nodes[key_id.0 as usize] = true;
+        }
+        for_each_child_fragment(node, |fragment| {
+            dead_fragments[fragment.0 as usize] = true;
+            stack.extend_from_slice(store.fragment_nodes(fragment));
+        });
+    }
+}
+
+struct Relocator<'r, 'a> {
+    allocator: &'a Allocator,
+    old_source: &'r str,
+    new_source: &'a str,
+    pos: &'r dyn Fn(u32) -> u32,
+    node: &'r dyn Fn(NodeId) -> NodeId,
+    fragment: &'r dyn Fn(FragmentId) -> FragmentId,
+}
+
+impl<'a> Relocator<'_, 'a> {
+    /// Text that is a slice of either source is taken from the new source;
+    /// only text that is not (decoded entities) is copied.
+    fn str(&self, text: &str) -> &'a str {
+        let start = offset_in(self.new_source, text)
+            .or_else(|| offset_in(self.old_source, text).map(|offset| (self.pos)(offset)));
+        start
+            .and_then(|start| {
+                self.new_source
+                    .get(start as usize..start as usize + text.len())
+            })
+            .filter(|slice| *slice == text)
+            .unwrap_or_else(|| self.allocator.alloc_str(text))
+    }
+
+    fn atom(&self, atom: Atom<'_>) -> Atom<'a> {
+        Atom::from(self.str(atom.as_str()))
+    }
+
+    fn atoms(&self, atoms: &[Atom<'_>]) -> &'a [Atom<'a>] {
+        ArenaVec::from_iter_in(atoms.iter().map(|&atom| self.atom(atom)), self.allocator)
+            .into_bump_slice()
+    }
+
+    fn span(&self, span: Span) -> Span {
+        Span::new((self.pos)(span.start), (self.pos)(span.end))
+    }
+
+    fn id(&self, id: NodeId) -> NodeId {
+        (self.node)(id)
+    }
+
+    fn fragment(&self, fragment: FragmentId) -> FragmentId {
+        (self.fragment)(fragment)
+    }
+
+    fn expr(&self, expr: &ExprRef) -> ExprRef {
+        ExprRef::new(self.span(expr.span))
+    }
+
+    fn stmt(&self, stmt: &StmtRef) -> StmtRef {
+        StmtRef::new(self.span(stmt.span))
+    }
+
+    fn script(&self, script: &Script<'_>) -> Script<'a> {
+        Script {
+            id: self.id(script.id),
+            span: self.span(script.span),
+            content_span: self.span(script.content_span),
+            context: script.context,
+            language: script.language,
+            attributes: self.attributes(script.attributes),
+            context_deprecated: script.context_deprecated,
+        }
+    }
+
+    fn options(&self, options: &SvelteOptions<'_>) -> SvelteOptions<'a> {
+        SvelteOptions {
+            span: self.span(options.span),
+            runes: options.runes,
+            namespace: options.namespace,
+            css: options.css,
+            custom_element: options.custom_element.as_ref().map(|config| match config {
+                CustomElementConfig::Tag(tag) => CustomElementConfig::Tag(self.str(tag)),
+                CustomElementConfig::Expression(span) => {
+                    CustomElementConfig::Expression(self.span(*span))
+                }
+            }),
+            immutable: options.immutable,
+            accessors: options.accessors,
+            preserve_whitespace: options.preserve_whitespace,
+            attributes: self.attributes(options.attributes),
+        }
+    }
+
+    fn legacy_slots(&self, slots: &[LegacySlot<'_>]) -> &'a [LegacySlot<'a>] {
+        if slots.is_empty() {
+            return &[];
+        }
+        ArenaVec::from_iter_in(
+            slots.iter().map(|slot| LegacySlot {
+                name: self.atom(slot.name),
+                fragment: self.fragment(slot.fragment),
+            }),
+            self.allocator,
+        )
+        .into_bump_slice()
+    }
+
+    fn parts(&self, parts: &[ConcatPart<'_>]) -> &'a [ConcatPart<'a>] {
+        ArenaVec::from_iter_in(
+            parts.iter().map(|part| match part {
+                ConcatPart::Static(text) => ConcatPart::Static(self.str(text)),
+                ConcatPart::Dynamic { id, expr } => ConcatPart::Dynamic {
+                    id: self.id(*id),
+                    expr: self.expr(expr),
+                },
+            }),
+            self.allocator,
+        )
+        .into_bump_slice()
+    }
+
+    fn attributes(&self, attributes: &[Attribute<'_>]) -> &'a [Attribute<'a>] {
+        if attributes.is_empty() {
+            return &[];
+        }
+        ArenaVec::from_iter_in(
+            attributes.iter().map(|attr| self.attribute(attr)),
+            self.allocator,
+        )
+        .into_bump_slice()
+    }
+
+    fn attribute(&self, attr: &Attribute<'_>) -> Attribute<'a> {
+        use svelte_ast::*;
+        match attr {
+            Attribute::StringAttribute(a) => Attribute::StringAttribute(StringAttribute {
+                id: self.id(a.id),
+                span: self.span(a.span),
+                name: self.atom(a.name),
+                value_span: self.span(a.value_span),
+            }),
+            Attribute::ExpressionAttribute(a) => {
+                Attribute::ExpressionAttribute(ExpressionAttribute {
+                    id: self.id(a.id),
+                    span: self.span(a.span),
+                    name: self.atom(a.name),
+                    expression: self.expr(&a.expression),
+                    shorthand: a.shorthand,
+                    event_name: a.event_name.map(|name| self.atom(name)),
+                })
+            }
+            Attribute::BooleanAttribute(a) => Attribute::BooleanAttribute(BooleanAttribute {
+                id: self.id(a.id),
+                span: self.span(a.span),
+                name: self.atom(a.name),
+            }),
+            Attribute::ConcatenationAttribute(a) => {
+                Attribute::ConcatenationAttribute(ConcatenationAttribute {
+                    id: self.id(a.id),
+                    span: self.span(a.span),
+                    name: self.atom(a.name),
+                    quoted: a.quoted,
+                    parts: self.parts(a.parts),
+                })
+            }
+            Attribute::SpreadAttribute(a) => Attribute::SpreadAttribute(SpreadAttribute {
+                id: self.id(a.id),
+                span: self.span(a.span),
+                expression: self.expr(&a.expression),
+            }),
+            Attribute::ClassDirective(a) => Attribute::ClassDirective(ClassDirective {
+                id: self.id(a.id),
+                span: self.span(a.span),
+                name: self.atom(a.name),
+                expression: self.expr(&a.expression),
+                shorthand: a.shorthand,
+            }),
+            Attribute::StyleDirective(a) => Attribute::StyleDirective(StyleDirective {
+                id: self.id(a.id),
+                span: self.span(a.span),
+                name: self.atom(a.name),
+                expression: self.expr(&a.expression),
+                shorthand: a.shorthand,
+                value: match a.value {
+                    StyleDirectiveValue::Expression => StyleDirectiveValue::Expression,
+                    StyleDirectiveValue::String(text) => {
+                        StyleDirectiveValue::String(self.str(text))
+                    }
+                    StyleDirectiveValue::Concatenation(parts) => {
+                        StyleDirectiveValue::Concatenation(self.parts(parts))
+                    }
+                },
+                important: a.important,
+            }),
+            Attribute::BindDirective(a) => Attribute::BindDirective(BindDirective {
+                id: self.id(a.id),
+                span: self.span(a.span),
+                name: self.atom(a.name),
+                expression: self.expr(&a.expression),
+                shorthand: a.shorthand,
+            }),
+            Attribute::LetDirectiveLegacy(a) => Attribute::LetDirectiveLegacy(LetDirectiveLegacy {
+                id: self.id(a.id),
+                span: self.span(a.span),
+                name: self.atom(a.name),
+                name_span: self.span(a.name_span),
+                binding: a.binding.as_ref().map(|b| self.stmt(b)),
+            }),
+            Attribute::UseDirective(a) => Attribute::UseDirective(UseDirective {
+                id: self.id(a.id),
+                span: self.span(a.span),
+                name_ref: self.expr(&a.name_ref),
+                expression: a.expression.as_ref().map(|e| self.expr(e)),
+            }),
+            Attribute::OnDirectiveLegacy(a) => Attribute::OnDirectiveLegacy(OnDirectiveLegacy {
+                id: self.id(a.id),
+                span: self.span(a.span),
+                name: self.atom(a.name),
+                name_span: self.span(a.name_span),
+                expression: a.expression.as_ref().map(|e| self.expr(e)),
+                modifiers: self.atoms(a.modifiers),
+            }),
+            Attribute::TransitionDirective(a) => {
+                Attribute::TransitionDirective(TransitionDirective {
+                    id: self.id(a.id),
+                    span: self.span(a.span),
+                    name_ref: self.expr(&a.name_ref),
+                    expression: a.expression.as_ref().map(|e| self.expr(e)),
+                    modifiers: self.atoms(a.modifiers),
+                    direction: a.direction.clone(),
+                })
+            }
+            Attribute::AnimateDirective(a) => Attribute::AnimateDirective(AnimateDirective {
+                id: self.id(a.id),
+                span: self.span(a.span),
+                name_ref: self.expr(&a.name_ref),
+                expression: a.expression.as_ref().map(|e| self.expr(e)),
+            }),
+            Attribute::AttachTag(a) => Attribute::AttachTag(AttachTag {
+                id: self.id(a.id),
+                span: self.span(a.span),
+                expression: self.expr(&a.expression),
+            }),
+        }
+    }
+
+    fn node(&self, node: &Node<'_>) -> Node<'a> {
+        use svelte_ast::*;
+        match node {
+            Node::Text(n) => Node::Text(Text {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                decoded: n.decoded.map(|text| self.str(text)),
+            }),
+            Node::Element(n) => Node::Element(Element {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                name: self.atom(n.name),
+                self_closing: n.self_closing,
+                attributes: self.attributes(n.attributes),
+                fragment: self.fragment(n.fragment),
+            }),
+            Node::SlotElementLegacy(n) => Node::SlotElementLegacy(SlotElementLegacy {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                attributes: self.attributes(n.attributes),
+                fragment: self.fragment(n.fragment),
+            }),
+            Node::ComponentNode(n) => Node::ComponentNode(ComponentNode {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                name: self.atom(n.name),
+                self_closing: n.self_closing,
+                attributes: self.attributes(n.attributes),
+                fragment: self.fragment(n.fragment),
+                legacy_slots: self.legacy_slots(n.legacy_slots),
+            }),
+            Node::Comment(n) => Node::Comment(Comment {
+                id: self.id(n.id),
+                span: self.span(n.span),
+            }),
+            Node::ExpressionTag(n) => Node::ExpressionTag(ExpressionTag {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                expression: self.expr(&n.expression),
+            }),
+            Node::IfBlock(n) => Node::IfBlock(IfBlock {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                test: self.expr(&n.test),
+                elseif: n.elseif,
+                consequent: self.fragment(n.consequent),
+                alternate: n.alternate.map(|f| self.fragment(f)),
+            }),
+            Node::EachBlock(n) => Node::EachBlock(EachBlock {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                expression: self.expr(&n.expression),
+                context: n.context.as_ref().map(|s| self.stmt(s)),
+                index: n.index.as_ref().map(|s| self.stmt(s)),
+                key: n.key.as_ref().map(|e| self.expr(e)),
+                key_id: n.key_id.map(|id| self.id(id)),
+                body: self.fragment(n.body),
+                fallback: n.fallback.map(|f| self.fragment(f)),
+            }),
+            Node::SnippetBlock(n) => Node::SnippetBlock(SnippetBlock {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                decl: self.stmt(&n.decl),
+                body: self.fragment(n.body),
+            }),
+            Node::RenderTag(n) => Node::RenderTag(RenderTag {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                expression: self.expr(&n.expression),
+            }),
+            Node::HtmlTag(n) => Node::HtmlTag(HtmlTag {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                expression: self.expr(&n.expression),
+            }),
+            Node::ConstTag(n) => Node::ConstTag(ConstTag {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                decl: self.stmt(&n.decl),
+            }),
+            Node::DebugTag(n) => Node::DebugTag(DebugTag {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                identifier_refs: ArenaVec::from_iter_in(
+                    n.identifier_refs.iter().map(|e| self.expr(e)),
+                    self.allocator,
+                )
+                .into_bump_slice(),
+            }),
+            Node::KeyBlock(n) => Node::KeyBlock(KeyBlock {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                expression: self.expr(&n.expression),
+                fragment: self.fragment(n.fragment),
+            }),
+            Node::SvelteHead(n) => Node::SvelteHead(SvelteHead {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                attributes: self.attributes(n.attributes),
+                fragment: self.fragment(n.fragment),
+            }),
+            Node::SvelteFragmentLegacy(n) => Node::SvelteFragmentLegacy(SvelteFragmentLegacy {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                attributes: self.attributes(n.attributes),
+                fragment: self.fragment(n.fragment),
+            }),
+            Node::SvelteComponentLegacy(n) => Node::SvelteComponentLegacy(SvelteComponentLegacy {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                self_closing: n.self_closing,
+                attributes: self.attributes(n.attributes),
+                fragment: self.fragment(n.fragment),
+                legacy_slots: self.legacy_slots(n.legacy_slots),
+            }),
+            Node::SvelteElement(n) => Node::SvelteElement(SvelteElement {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                tag_span: self.span(n.tag_span),
+                static_tag: n.static_tag,
+                attributes: self.attributes(n.attributes),
+                fragment: self.fragment(n.fragment),
+            }),
+            Node::SvelteWindow(n) => Node::SvelteWindow(SvelteWindow {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                attributes: self.attributes(n.attributes),
+                fragment: self.fragment(n.fragment),
+            }),
+            Node::SvelteDocument(n) => Node::SvelteDocument(SvelteDocument {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                attributes: self.attributes(n.attributes),
+                fragment: self.fragment(n.fragment),
+            }),
+            Node::SvelteBody(n) => Node::SvelteBody(SvelteBody {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                attributes: self.attributes(n.attributes),
+                fragment: self.fragment(n.fragment),
+            }),
+            Node::SvelteBoundary(n) => Node::SvelteBoundary(SvelteBoundary {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                attributes: self.attributes(n.attributes),
+                fragment: self.fragment(n.fragment),
+            }),
+            Node::AwaitBlock(n) => Node::AwaitBlock(AwaitBlock {
+                id: self.id(n.id),
+                span: self.span(n.span),
+                expression: self.expr(&n.expression),
+                value: n.value.as_ref().map(|s| self.stmt(s)),
+                error: n.error.as_ref().map(|s| self.stmt(s)),
+                pending: n.pending.map(|f| self.fragment(f)),
+                then: n.then.map(|f| self.fragment(f)),
+                catch: n.catch.map(|f| self.fragment(f)),
+            }),
+            Node::Error(n) if is_dead(node) => dead_node(self.id(n.id)),
+            Node::Error(n) => Node::Error(ErrorNode {
+                id: self.id(n.id),
+                span: self.span(n.span),
+            }),
+        }
+    }
+}
@@ -2,15 +2,16 @@ use scanner::{Scanner, token::TokenType};
 use svelte_span::Span;
 
 use svelte_ast::{
-    AstStore, Attribute, Comment, Component, ComponentNode, ConstTag, DebugTag, Element,
-    FragmentId, FragmentRole, HtmlTag, Node, NodeId, RawBlock, RenderTag, SVELTE_COMPONENT,
-    SVELTE_SELF, Script, ScriptContext, ScriptLanguage, Text,
+    Allocator, ArenaVec, AstStore, Atom, Attribute, Comment, Component, ComponentNode, ConstTag,
+    DebugTag, Element, FragmentId, FragmentRole, HtmlTag, Node, NodeId, RawBlock, RenderTag,
+    SVELTE_COMPONENT, SVELTE_SELF, Script, ScriptContext, ScriptLanguage, Text,
 };
 
 use svelte_diagnostics::Diagnostic;
 
 mod html;
 mod html_entities;
+mod incremental;
 pub mod parse_js;
 pub mod scanner;
 pub mod types;
@@ -20,6 +21,7 @@ mod attr_convert;
 mod handlers;
 mod svelte_elements;
 
+pub use incremental::{InvalidEdit, ReparseMode, Reparsed, reparse, reparse_with_js};
 pub use types::{CePropConfig, CeShadowMode, JsAst, ParsedCeConfig};
 
 pub fn parse_module<'a>(
@@ -40,33 +42,42 @@ pub fn parse_with_js<'a>(
     alloc: &'a oxc_allocator::Allocator,
     source: &str,
 ) -> (
-    svelte_ast::Component,
+    svelte_ast::Component<'a>,
     crate::types::JsAst<'a>,
     Vec<Diagnostic>,
 ) {
-    let (component, mut diagnostics) = Parser::new(source).parse();
+    let (component, mut diagnostics) = Parser::new(alloc, source).parse();
     let mut result = crate::types::JsAst::new();
     walk_js::parse_js(alloc, &component, &mut result, &mut diagnostics);
 
     (component, result, diagnostics)
 }
 
+pub fn decode_html_entities(input: &str) -> std::borrow::Cow<'_, str> {
+    html::decode_text(input).map_or(std::borrow::Cow::Borrowed(input), std::borrow::Cow::Owned)
+}
+
 pub fn parse_css_block(
     component: &svelte_ast::Component,
 ) -> Option<(svelte_css::StyleSheet, Vec<svelte_diagnostics::Diagnostic>)> {
-    let css_block = component.css.as_ref()?;
+    let css_block = component
+        .css
+        .as_ref()
+        .filter(|css| css.has_supported_lang(component.source))?;
     let css_text = component.source_text(css_block.content_span);
     let (stylesheet, diags) = svelte_css::parse(css_text);
     Some((stylesheet, diags))
 }
 
-enum StackEntry {
-    Element(ElementEntry),
-    IfBlock(IfBlockEntry),
-    EachBlock(EachBlockEntry),
+type Children<'a> = ArenaVec<'a, NodeId>;
+
+enum StackEntry<'a> {
+    Element(ElementEntry<'a>),
+    IfBlock(IfBlockEntry<'a>),
+    EachBlock(EachBlockEntry<'a>),
     SnippetBlock(SnippetBlockEntry),
     KeyBlock(KeyBlockEntry),
-    AwaitBlock(AwaitBlockEntry),
+    AwaitBlock(AwaitBlockEntry<'a>),
 }
 
 struct KeyBlockEntry {
@@ -74,30 +85,30 @@ struct KeyBlockEntry {
     expression_span: Span,
 }
 
-struct ElementEntry {
-    name: String,
+struct ElementEntry<'a> {
+    name: Atom<'a>,
     span_start: Span,
-    attributes: Vec<Attribute>,
+    attributes: &'a [Attribute<'a>],
 }
 
-struct IfBlockEntry {
+struct IfBlockEntry<'a> {
     span: Span,
     test_span: Span,
     elseif: bool,
 
-    consequent: Option<Vec<NodeId>>,
+    consequent: Option<Children<'a>>,
 
     in_alternate: bool,
 }
 
-struct EachBlockEntry {
+struct EachBlockEntry<'a> {
     span: Span,
     expression_span: Span,
     context_span: Option<Span>,
     index_span: Option<Span>,
     key_span: Option<Span>,
 
-    body_children: Option<Vec<NodeId>>,
+    body_children: Option<Children<'a>>,
     in_fallback: bool,
 }
 
@@ -112,7 +123,7 @@ enum AwaitPhase {
     Catch,
 }
 
-struct AwaitBlockEntry {
+struct AwaitBlockEntry<'a> {
     span: Span,
     expression_span: Span,
     value_span: Option<Span>,
@@ -120,15 +131,15 @@ struct AwaitBlockEntry {
 
     phase: AwaitPhase,
 
-    pending_children: Option<Vec<NodeId>>,
+    pending_children: Option<Children<'a>>,
 
-    then_children: Option<Vec<NodeId>>,
+    then_children: Option<Children<'a>>,
 
-    catch_children: Option<Vec<NodeId>>,
+    catch_children: Option<Children<'a>>,
 }
 
 #[allow(clippy::ptr_arg)]
-fn push_child(children_stack: &mut Vec<Vec<NodeId>>, id: NodeId) {
+fn push_child(children_stack: &mut Vec<Children<'_>>, id: NodeId) {
     debug_assert!(
         !children_stack.is_empty(),
         "children_stack empty when pushing child"
@@ -138,42 +149,60 @@ fn push_child(children_stack: &mut Vec<Vec<NodeId>>, id: NodeId) {
     }
 }
 
-fn pop_children(children_stack: &mut Vec<Vec<NodeId>>) -> Vec<NodeId> {
+fn pop_children<'a>(
+    children_stack: &mut Vec<Children<'a>>,
+    allocator: &'a Allocator,
+) -> Children<'a> {
     debug_assert!(
         !children_stack.is_empty(),
         "children_stack empty when popping"
     );
-    children_stack.pop().unwrap_or_default()
+    children_stack
+        .pop()
+        .unwrap_or_else(|| ArenaVec::new_in(allocator))
 }
 
 pub struct Parser<'a> {
+    allocator: &'a Allocator,
     source: &'a str,
-    store: AstStore,
+    range: Span,
+    store: AstStore<'a>,
     diagnostics: Vec<Diagnostic>,
 }
 
 impl<'a> Parser<'a> {
-    pub fn new(source: &'a str) -> Parser<'a> {
+    pub fn new(allocator: &'a Allocator, source: &str) -> Parser<'a> {
+        let source = allocator.alloc_str(source);
+        Self::for_range(allocator, source, Span::new(0, source.len() as u32))
+    }
+
+    pub(crate) fn for_range(allocator: &'a Allocator, source: &'a str, range: Span) -> Parser<'a> {
         Parser {
+            allocator,
             source,
-            store: AstStore::with_capacity(source.len() / 10),
+            range,
+            store: AstStore::with_capacity(allocator, range.size() / 10),
             diagnostics: Vec::new(),
         }
     }
 
+    fn new_children(&self) -> Children<'a> {
+        ArenaVec::new_in(self.allocator)
+    }
+
     fn recover(&mut self, diagnostic: Diagnostic) {
         self.diagnostics.push(diagnostic);
     }
 
-    fn push_node(&mut self, node: Node) -> NodeId {
+    fn push_node(&mut self, node: Node<'a>) -> NodeId {
         self.store.push(node)
     }
 
-    fn reserve_id(&mut self) -> NodeId {
-        self.store.reserve()
+    fn reserve_id(&mut self, span: Span) -> NodeId {
+        self.store.reserve(span)
     }
 
-    pub(crate) fn new_fragment(&mut self, role: FragmentRole, nodes: Vec<NodeId>) -> FragmentId {
+    pub(crate) fn new_fragment(&mut self, role: FragmentRole, nodes: Children<'a>) -> FragmentId {
         self.store.push_fragment(role, nodes)
     }
 
@@ -183,10 +212,10 @@ impl<'a> Parser<'a> {
 
     pub(crate) fn partition_component_children(
         &mut self,
-        children: Vec<NodeId>,
-    ) -> (Vec<NodeId>, Vec<svelte_ast::LegacySlot>) {
-        let mut default = Vec::with_capacity(children.len());
-        let mut slots: Vec<svelte_ast::LegacySlot> = Vec::new();
+        children: Children<'a>,
+    ) -> (Children<'a>, &'a [svelte_ast::LegacySlot<'a>]) {
+        let mut default = ArenaVec::with_capacity_in(children.len(), self.allocator);
+        let mut slots: ArenaVec<'a, svelte_ast::LegacySlot<'a>> = ArenaVec::new_in(self.allocator);
 
         for child in children {
             match self.slot_name_of(child) {
@@ -203,13 +232,13 @@ impl<'a> Parser<'a> {
             }
         }
 
-        (default, slots)
+        (default, slots.into_bump_slice())
     }
 
-    fn slot_name_of(&self, child: NodeId) -> Option<String> {
+    fn slot_name_of(&self, child: NodeId) -> Option<Atom<'a>> {
         let attrs = match self.store.get(child) {
-            Node::Element(el) => &el.attributes,
-            Node::ComponentNode(cn) => &cn.attributes,
+            Node::Element(el) => el.attributes,
+            Node::ComponentNode(cn) => cn.attributes,
             _ => return None,
         };
 
@@ -221,19 +250,22 @@ impl<'a> Parser<'a> {
                 if value.is_empty() {
                     return None;
                 }
-                return Some(value.to_string());
+                return Some(Atom::from(value));
             }
         }
         None
     }
 
-    pub fn parse(mut self) -> (Component, Vec<Diagnostic>) {
-        let mut scanner = Scanner::new(self.source);
+    pub fn parse(mut self) -> (Component<'a>, Vec<Diagnostic>) {
+        let mut scanner = Scanner::new_at(
+            &self.source[..self.range.end as usize],
+            self.range.start as usize,
+        );
         let (tokens, scan_diagnostics) = scanner.scan_tokens();
         self.diagnostics.extend(scan_diagnostics);
 
-        let mut children_stack: Vec<Vec<NodeId>> = vec![vec![]];
-        let mut entry_stack: Vec<StackEntry> = vec![];
+        let mut children_stack: Vec<Children<'a>> = vec![self.new_children()];
+        let mut entry_stack: Vec<StackEntry<'a>> = vec![];
         let mut instance_script_data: Option<ScriptData> = None;
         let mut module_script_data: Option<ScriptData> = None;
         let mut css_data: Option<CssData> = None;
@@ -245,7 +277,8 @@ impl<'a> Parser<'a> {
                     let id = self.push_node(Node::Text(Text {
                         id: NodeId(0),
                         span: token.span,
-                        decoded: html::decode_text(raw),
+                        decoded: html::decode_text(raw)
+                            .map(|decoded| self.allocator.alloc_str(&decoded)),
                     }));
                     push_child(&mut children_stack, id);
                 }
@@ -269,7 +302,7 @@ impl<'a> Parser<'a> {
                     let is_component = is_component_name(name);
                     let attrs = self.convert_attributes(&tag.attributes, is_component);
                     if tag.self_closing {
-                        let name = name.to_string();
+                        let name = Atom::from(name);
                         let role = if is_component {
                             FragmentRole::ComponentChildren
                         } else {
@@ -283,7 +316,7 @@ impl<'a> Parser<'a> {
                                 self_closing: true,
                                 attributes: attrs,
                                 fragment,
-                                legacy_slots: Vec::new(),
+                                legacy_slots: &[],
                             })
                         } else if is_component_name(&name) {
                             Node::ComponentNode(ComponentNode {
@@ -293,7 +326,7 @@ impl<'a> Parser<'a> {
                                 self_closing: true,
                                 attributes: attrs,
                                 fragment,
-                                legacy_slots: Vec::new(),
+                                legacy_slots: &[],
                             })
                         } else {
                             Node::Element(Element {
@@ -309,11 +342,11 @@ impl<'a> Parser<'a> {
                         push_child(&mut children_stack, id);
                     } else {
                         entry_stack.push(StackEntry::Element(ElementEntry {
-                            name: name.to_string(),
+                            name: Atom::from(name),
                             span_start: token.span,
                             attributes: attrs,
                         }));
-                        children_stack.push(vec![]);
+                        children_stack.push(self.new_children());
                     }
                 }
                 TokenType::EndTag(tag) => {
@@ -327,7 +360,7 @@ impl<'a> Parser<'a> {
                         consequent: None,
                         in_alternate: false,
                     }));
-                    children_stack.push(vec![]);
+                    children_stack.push(self.new_children());
                 }
                 TokenType::ElseTag(else_tag) => {
                     self.handle_else_tag(
@@ -350,7 +383,7 @@ impl<'a> Parser<'a> {
                         body_children: None,
                         in_fallback: false,
                     }));
-                    children_stack.push(vec![]);
+                    children_stack.push(self.new_children());
                 }
                 TokenType::EndEachTag => {
                     self.handle_end_each_tag(token.span, &mut entry_stack, &mut children_stack);
@@ -360,7 +393,7 @@ impl<'a> Parser<'a> {
                         span_start: token.span,
                         expression_span: snippet_tag.expression_span,
                     }));
-                    children_stack.push(vec![]);
+                    children_stack.push(self.new_children());
                 }
                 TokenType::EndSnippetTag => {
                     self.handle_end_snippet_tag(token.span, &mut entry_stack, &mut children_stack);
@@ -370,7 +403,7 @@ impl<'a> Parser<'a> {
                         span: token.span,
                         expression_span: key_tag.expression_span,
                     }));
-                    children_stack.push(vec![]);
+                    children_stack.push(self.new_children());
                 }
                 TokenType::EndKeyTag => {
                     self.handle_end_key_tag(token.span, &mut entry_stack, &mut children_stack);
@@ -392,7 +425,7 @@ impl<'a> Parser<'a> {
                         then_children: None,
                         catch_children: None,
                     }));
-                    children_stack.push(vec![]);
+                    children_stack.push(self.new_children());
                 }
                 TokenType::AwaitClauseTag(clause_tag) => {
                     self.handle_await_clause_tag(
@@ -430,11 +463,11 @@ impl<'a> Parser<'a> {
                     push_child(&mut children_stack, id);
                 }
                 TokenType::DebugTag(dt) => {
-                    let identifier_refs = dt
-                        .identifiers
-                        .iter()
-                        .map(|s| svelte_ast::ExprRef::new(*s))
-                        .collect();
+                    let identifier_refs = ArenaVec::from_iter_in(
+                        dt.identifiers.iter().map(|s| svelte_ast::ExprRef::new(*s)),
+                        self.allocator,
+                    )
+                    .into_bump_slice();
                     let id = self.push_node(Node::DebugTag(DebugTag {
                         id: NodeId(0),
                         span: token.span,
@@ -443,12 +476,6 @@ impl<'a> Parser<'a> {
                     push_child(&mut children_stack, id);
                 }
                 TokenType::ScriptTag(script_tag) => {
-                    let language = if script_tag.is_typescript {
-                        ScriptLanguage::TypeScript
-                    } else {
-                        ScriptLanguage::JavaScript
-                    };
-
                     if script_tag.is_module {
                         if module_script_data.is_some() {
                             self.recover(Diagnostic::error(
@@ -460,7 +487,7 @@ impl<'a> Parser<'a> {
                         module_script_data = Some(ScriptData {
                             span: token.span,
                             content_span: script_tag.content_span,
-                            language,
+                            attributes: self.convert_static_attributes(&script_tag.attributes),
                             context: ScriptContext::Module,
                             context_deprecated: script_tag.context_deprecated,
                         });
@@ -475,7 +502,7 @@ impl<'a> Parser<'a> {
                         instance_script_data = Some(ScriptData {
                             span: token.span,
                             content_span: script_tag.content_span,
-                            language,
+                            attributes: self.convert_static_attributes(&script_tag.attributes),
                             context: ScriptContext::Default,
                             context_deprecated: false,
                         });
@@ -490,6 +517,7 @@ impl<'a> Parser<'a> {
                     css_data = Some(CssData {
                         span: token.span,
                         content_span: style_tag.content_span,
+                        attributes: self.convert_static_attributes(&style_tag.attributes),
                     });
                 }
                 TokenType::EOF => break,
@@ -498,35 +526,38 @@ impl<'a> Parser<'a> {
 
         self.auto_close_entries(&mut entry_stack, &mut children_stack);
 
-        let roots = pop_children(&mut children_stack);
+        let roots = pop_children(&mut children_stack, self.allocator);
 
         let instance_script = instance_script_data.map(|sd| Script {
-            id: self.reserve_id(),
+            id: self.reserve_id(sd.span),
             span: sd.span,
             content_span: sd.content_span,
             context: sd.context,
-            language: sd.language,
+            language: script_language(sd.attributes, self.source),
+            attributes: sd.attributes,
             context_deprecated: sd.context_deprecated,
         });
 
         let module_script = module_script_data.map(|sd| Script {
-            id: self.reserve_id(),
+            id: self.reserve_id(sd.span),
             span: sd.span,
             content_span: sd.content_span,
             context: sd.context,
-            language: sd.language,
+            language: script_language(sd.attributes, self.source),
+            attributes: sd.attributes,
             context_deprecated: sd.context_deprecated,
         });
 
         let css = css_data.map(|cd| RawBlock {
             span: cd.span,
             content_span: cd.content_span,
+            attributes: cd.attributes,
         });
 
         let root_fragment = self.new_fragment(FragmentRole::Root, roots);
-        let store = std::mem::take(&mut self.store);
+        let store = std::mem::replace(&mut self.store, AstStore::new(self.allocator));
         let mut component = Component::new(
-            self.source.to_string(),
+            self.source,
             root_fragment,
             store,
             instance_script,
@@ -559,59 +590,19 @@ impl<'a> Parser<'a> {
         (component, self.diagnostics)
     }
 
-    fn populate_fragment_owners(store: &mut AstStore) {
+    pub(crate) fn populate_fragment_owners(store: &mut AstStore<'_>) {
         let total = store.fragments_len();
+        let mut child_frags = Vec::new();
         for fid_raw in 0..total {
             let fid = svelte_ast::FragmentId(fid_raw);
             let nodes_len = store.fragment_nodes(fid).len();
             for i in 0..nodes_len {
                 let nid = store.fragment_nodes(fid)[i];
-                let mut child_frags: Vec<svelte_ast::FragmentId> = Vec::new();
-                let node = store.get(nid);
-                match node {
-                    Node::Element(el) => child_frags.push(el.fragment),
-                    Node::SlotElementLegacy(el) => child_frags.push(el.fragment),
-                    Node::ComponentNode(cn) => {
-                        child_frags.push(cn.fragment);
-                        for slot in &cn.legacy_slots {
-                            child_frags.push(slot.fragment);
-                        }
-                    }
-                    Node::IfBlock(b) => {
-                        child_frags.push(b.consequent);
-                        if let Some(alt) = b.alternate {
-                            child_frags.push(alt);
-                        }
-                    }
-                    Node::EachBlock(b) => {
-                        child_frags.push(b.body);
-                        if let Some(fb) = b.fallback {
-                            child_frags.push(fb);
-                        }
-                    }
-                    Node::SnippetBlock(b) => child_frags.push(b.body),
-                    Node::KeyBlock(b) => child_frags.push(b.fragment),
-                    Node::SvelteHead(h) => child_frags.push(h.fragment),
-                    Node::SvelteFragmentLegacy(f) => child_frags.push(f.fragment),
-                    Node::SvelteElement(el) => child_frags.push(el.fragment),
-                    Node::SvelteWindow(w) => child_frags.push(w.fragment),
-                    Node::SvelteDocument(d) => child_frags.push(d.fragment),
-                    Node::SvelteBody(b) => child_frags.push(b.fragment),
-                    Node::SvelteBoundary(b) => child_frags.push(b.fragment),
-                    Node::AwaitBlock(b) => {
-                        if let Some(p) = b.pending {
-                            child_frags.push(p);
-                        }
-                        if let Some(t) = b.then {
-                            child_frags.push(t);
-                        }
-                        if let Some(c) = b.catch {
-                            child_frags.push(c);
-                        }
-                    }
-                    _ => {}
-                }
-                for cf in child_frags {
+                child_frags.clear();
+                svelte_elements::for_each_child_fragment(store.get(nid), |fid| {
+                    child_frags.push(fid)
+                });
+                for &cf in &child_frags {
                     store.set_fragment_owner(cf, nid);
                 }
             }
@@ -664,17 +655,24 @@ fn is_component_name(name: &str) -> bool {
         || name == SVELTE_SELF
 }
 
-struct ScriptData {
+struct ScriptData<'a> {
     span: Span,
     content_span: Span,
-    language: ScriptLanguage,
+    attributes: &'a [Attribute<'a>],
     context: ScriptContext,
     context_deprecated: bool,
 }
 
-struct CssData {
+struct CssData<'a> {
     span: Span,
     content_span: Span,
+    attributes: &'a [Attribute<'a>],
+}
+
+fn script_language(attributes: &[Attribute<'_>], source: &str) -> Option<ScriptLanguage> {
+    ScriptLanguage::from_lang(svelte_ast::static_attribute_value(
+        attributes, "lang", source,
+    ))
 }
 
 #[cfg(test)]
@@ -2,6 +2,7 @@ use std::cell::Cell;
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Expression;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_parser::Parser as OxcParser;
 use oxc_span::SourceType;
 use oxc_syntax::node::NodeId;
@@ -14,16 +15,16 @@ pub fn parse_expression_with_alloc<'a>(
     source: &'a str,
     offset: u32,
     typescript: bool,
-) -> Result<Expression<'a>, Diagnostic> {
+) -> Result<Expression<'a>, Vec<Diagnostic>> {
     let src_type = if typescript {
         SourceType::default().with_typescript(true)
     } else {
         SourceType::default()
     };
     let parser = OxcParser::new(alloc, source, src_type);
-    let mut expr = parser.parse_expression().map_err(|_| {
-        Diagnostic::invalid_expression(Span::new(offset, offset + source.len() as u32))
-    })?;
+    let mut expr = parser
+        .parse_expression()
+        .map_err(|errors| js_parse_errors(&errors, source, offset, 0))?;
     if typescript {
         strip_ts_expression(&mut expr, alloc);
     }
@@ -45,25 +46,58 @@ pub fn parse_script_with_alloc<'a>(
     let result = OxcParser::new(alloc, source, source_type).parse();
 
     if !result.errors.is_empty() {
-        return Err(result
-            .errors
-            .iter()
-            .map(|_| {
-                Diagnostic::invalid_expression(Span::new(offset, offset + source.len() as u32))
-            })
-            .collect());
+        return Err(js_parse_errors(&result.errors, source, offset, 0));
     }
 
     Ok(result.program)
 }
 
+fn js_parse_errors(
+    errors: &[OxcDiagnostic],
+    source: &str,
+    offset: u32,
+    prefix_len: usize,
+) -> Vec<Diagnostic> {
+    let local = |pos: usize| pos.saturating_sub(prefix_len).min(source.len()) as u32;
+    errors
+        .iter()
+        .map(|error| {
+            let label = error
+                .labels
+                .as_deref()
+                .and_then(|labels| labels.iter().find(|l| l.primary()).or(labels.first()));
+            let span = match label {
+                Some(label) => Span::new(
+                    offset + local(label.offset()),
+                    offset + local(label.offset() + label.len()),
+                ),
+                None => Span::new(offset, offset + source.len() as u32),
+            };
+            Diagnostic::js_parse_error(error.message.to_string(), span)
+        })
+        .collect()
+}
+
 pub fn parse_const_declaration_with_alloc<'a>(
     alloc: &'a Allocator,
     source: &'a str,
     offset: u32,
     typescript: bool,
-) -> Result<oxc_ast::ast::Statement<'a>, Diagnostic> {
-    let wrapped_owned = format!("const {};", source);
+) -> Result<oxc_ast::ast::Statement<'a>, Vec<Diagnostic>> {
+    parse_declaration_with_alloc(alloc, source, source.len(), offset, typescript)
+}
+
+/// Parses `const {source};`. Only the first `user_len` bytes of `source` come
+/// from the component, so error spans are clamped to them.
+fn parse_declaration_with_alloc<'a>(
+    alloc: &'a Allocator,
+    source: &'a str,
+    user_len: usize,
+    offset: u32,
+    typescript: bool,
+) -> Result<oxc_ast::ast::Statement<'a>, Vec<Diagnostic>> {
+    const PREFIX: &str = "const ";
+    let wrapped_owned = format!("{PREFIX}{source};");
     let wrapped_str: &'a str = alloc.alloc_str(&wrapped_owned);
 
     let src_type = if typescript {
@@ -76,15 +110,20 @@ pub fn parse_const_declaration_with_alloc<'a>(
     let result = OxcParser::new(alloc, wrapped_str, src_type).parse();
 
     if !result.errors.is_empty() {
-        return Err(Diagnostic::invalid_expression(Span::new(
+        return Err(js_parse_errors(
+            &result.errors,
+            &source[..user_len],
             offset,
-            offset + source.len() as u32,
-        )));
+            PREFIX.len(),
+        ));
     }
 
     let program = result.program;
     let mut stmt = program.body.into_iter().next().ok_or_else(|| {
-        Diagnostic::invalid_expression(Span::new(offset, offset + source.len() as u32))
+        vec![Diagnostic::invalid_expression(Span::new(
+            offset,
+            offset + user_len as u32,
+        ))]
     })?;
 
     if typescript
@@ -170,10 +209,10 @@ pub(crate) fn parse_slot_let_decl_with_alloc<'a>(
     slot_prop_name: &str,
     offset: u32,
     typescript: bool,
-) -> Result<oxc_ast::ast::Statement<'a>, Diagnostic> {
+) -> Result<oxc_ast::ast::Statement<'a>, Vec<Diagnostic>> {
     let source = format!("{pattern_source} = $$slotProps.{slot_prop_name}");
     let source: &'a str = alloc.alloc_str(&source);
-    parse_const_declaration_with_alloc(alloc, source, offset, typescript)
+    parse_declaration_with_alloc(alloc, source, pattern_source.len(), offset, typescript)
 }
 
 fn strip_ts_expression<'a>(expr: &mut Expression<'a>, alloc: &'a Allocator) {
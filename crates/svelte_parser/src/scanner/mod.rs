@@ -59,6 +59,14 @@ impl<'a> Scanner<'a> {
         }
     }
 
+    pub fn new_at(source: &'a str, start: usize) -> Scanner<'a> {
+        let mut scanner = Scanner::new(source);
+        scanner.current = start;
+        scanner.prev = start;
+        scanner.start = start;
+        scanner
+    }
+
     pub fn scan_tokens(&mut self) -> (Vec<Token>, Vec<Diagnostic>) {
         while !self.is_at_end() {
             self.start = self.current;
@@ -143,18 +151,11 @@ impl<'a> Scanner<'a> {
 
     fn advance(&mut self) -> char {
         self.prev = self.current;
-        let b = self.bytes[self.current];
-        if b < 0x80 {
-            self.current += 1;
-            b as char
-        } else {
-            let ch = self.source[self.current..]
-                .chars()
-                .next()
-                .expect("source slice is non-empty — b was read above");
-            self.current += ch.len_utf8();
-            ch
-        }
+        let Some(ch) = self.peek() else {
+            return '\0';
+        };
+        self.current += ch.len_utf8();
+        ch
     }
 
     #[inline]
@@ -331,6 +332,7 @@ impl<'a> Scanner<'a> {
         }
     }
 
+    #[inline]
     fn peek(&self) -> Option<char> {
         if self.current >= self.bytes.len() {
             return None;
@@ -410,9 +412,9 @@ impl<'a> Scanner<'a> {
         if matches!(name, "script" | "style") && !self_closing {
             return if self.fragment_depth == 0 {
                 if name == "script" {
-                    self.script_tag(&attributes, name_span)
+                    self.script_tag(attributes, name_span)
                 } else {
-                    self.style_tag(name_span)
+                    self.style_tag(attributes, name_span)
                 }
             } else {
                 self.raw_text_element(name, name_span, attributes)
@@ -1246,8 +1248,7 @@ impl<'a> Scanner<'a> {
             await_clause: None,
         };
 
-        while !self.is_at_end() {
-            let ch = self.peek().expect("loop condition guarantees not at end");
+        while let Some(ch) = self.peek() {
             match ch {
                 '\'' | '"' => {
                     self.advance();
@@ -1402,8 +1403,7 @@ impl<'a> Scanner<'a> {
             await_clause: None,
         };
 
-        while !self.is_at_end() {
-            let ch = self.peek().expect("loop condition guarantees not at end");
+        while let Some(ch) = self.peek() {
             match ch {
                 '\'' | '"' => {
                     self.advance();
@@ -1757,7 +1757,11 @@ impl<'a> Scanner<'a> {
         }
     }
 
-    fn script_tag(&mut self, attributes: &[Attribute], _name_span: Span) -> Result<(), Diagnostic> {
+    fn script_tag(
+        &mut self,
+        attributes: Vec<Attribute>,
+        _name_span: Span,
+    ) -> Result<(), Diagnostic> {
         let start = self.current;
         let mut end = start;
 
@@ -1781,15 +1785,6 @@ impl<'a> Scanner<'a> {
             }
         }
 
-        let is_typescript = attributes.iter().any(|item| match item {
-            Attribute::HTMLAttribute(attr) => {
-                let name = attr.name_span.source_text(self.source);
-                name == "lang"
-                    && matches!(attr.value, AttributeValue::String(span) if span.source_text(self.source) == "ts")
-            }
-            _ => false,
-        });
-
         let context_deprecated = attributes.iter().any(|item| match item {
             Attribute::HTMLAttribute(attr) => {
                 let name = attr.name_span.source_text(self.source);
@@ -1816,7 +1811,7 @@ impl<'a> Scanner<'a> {
 
             self.add_token(TokenType::ScriptTag(ScriptTag {
                 content_span: self.span(start, self.current),
-                is_typescript,
+                attributes,
                 is_module,
                 context_deprecated,
             }));
@@ -1835,7 +1830,7 @@ impl<'a> Scanner<'a> {
 
         self.add_token(TokenType::ScriptTag(ScriptTag {
             content_span: self.span(start, end),
-            is_typescript,
+            attributes,
             is_module,
             context_deprecated,
         }));
@@ -1843,7 +1838,11 @@ impl<'a> Scanner<'a> {
         Ok(())
     }
 
-    fn style_tag(&mut self, _name_span: Span) -> Result<(), Diagnostic> {
+    fn style_tag(
+        &mut self,
+        attributes: Vec<Attribute>,
+        _name_span: Span,
+    ) -> Result<(), Diagnostic> {
         let start = self.current;
         let mut end = start;
 
@@ -1875,6 +1874,7 @@ impl<'a> Scanner<'a> {
 
             self.add_token(TokenType::StyleTag(token::StyleTag {
                 content_span: self.span(start, self.current),
+                attributes,
             }));
 
             return Ok(());
@@ -1891,6 +1891,7 @@ impl<'a> Scanner<'a> {
 
         self.add_token(TokenType::StyleTag(token::StyleTag {
             content_span: self.span(start, end),
+            attributes,
         }));
 
         Ok(())
@@ -2089,8 +2090,7 @@ impl<'a> Scanner<'a> {
 
         let mut no_as_index_span: Option<Span> = None;
 
-        while !self.is_at_end() {
-            let ch = self.peek().expect("loop condition guarantees not at end");
+        while let Some(ch) = self.peek() {
             match ch {
                 '\'' | '"' | '`' => {
                     self.advance();
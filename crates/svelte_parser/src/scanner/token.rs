@@ -32,7 +32,7 @@ pub enum TokenType {
 #[derive(Debug, PartialEq, Eq)]
 pub struct ScriptTag {
     pub content_span: Span,
-    pub is_typescript: bool,
+    pub attributes: Vec<Attribute>,
     pub is_module: bool,
 
     pub context_deprecated: bool,
@@ -248,6 +248,7 @@ impl GetSpan for Attribute {
 #[derive(Debug, PartialEq, Eq)]
 pub struct StyleTag {
     pub content_span: Span,
+    pub attributes: Vec<Attribute>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
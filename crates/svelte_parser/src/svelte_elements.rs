@@ -10,7 +10,7 @@ use svelte_span::Span;
 use crate::{Parser, TagError, validate_custom_element_tag};
 
 impl<'a> Parser<'a> {
-    pub(crate) fn validate_root_only_special_elements(&mut self, component: &Component) {
+    pub(crate) fn validate_root_only_special_elements(&mut self, component: &Component<'_>) {
         #[derive(Default)]
         struct Seen {
             head: bool,
@@ -20,7 +20,7 @@ impl<'a> Parser<'a> {
         }
 
         let mut seen = Seen::default();
-        let mut current_level = component.root_fragment().nodes.clone();
+        let mut current_level = component.root_fragment().nodes.to_vec();
         let mut next_level = Vec::new();
         let mut at_root = true;
 
@@ -31,42 +31,29 @@ impl<'a> Parser<'a> {
                 let node = component.store.get(id);
 
                 if let Node::Element(el) = node
-                    && matches!(
-                        el.name.as_str(),
-                        "svelte:head" | "svelte:window" | "svelte:document" | "svelte:body"
-                    )
+                    && let Some(already_seen) = match el.name.as_str() {
+                        "svelte:head" => Some(&mut seen.head),
+                        "svelte:window" => Some(&mut seen.window),
+                        "svelte:document" => Some(&mut seen.document),
+                        "svelte:body" => Some(&mut seen.body),
+                        _ => None,
+                    }
                 {
-                    let already_seen = match el.name.as_str() {
-                        "svelte:head" => seen.head,
-                        "svelte:window" => seen.window,
-                        "svelte:document" => seen.document,
-                        "svelte:body" => seen.body,
-                        _ => unreachable!("only root-only special elements are tracked here"),
-                    };
-
-                    if already_seen {
+                    if *already_seen {
                         self.recover(Diagnostic::error(
                             svelte_diagnostics::DiagnosticKind::SvelteMetaDuplicate {
-                                name: el.name.clone(),
+                                name: el.name.to_string(),
                             },
                             Span::new(el.span.start, el.span.start),
                         ));
                     } else {
-                        match el.name.as_str() {
-                            "svelte:head" => seen.head = true,
-                            "svelte:window" => seen.window = true,
-                            "svelte:document" => seen.document = true,
-                            "svelte:body" => seen.body = true,
-                            _ => {
-                                unreachable!("only root-only special elements are tracked here")
-                            }
-                        }
+                        *already_seen = true;
                     }
 
                     if !at_root {
                         self.recover(Diagnostic::error(
                             svelte_diagnostics::DiagnosticKind::SvelteMetaInvalidPlacement {
-                                name: el.name.clone(),
+                                name: el.name.to_string(),
                             },
                             Span::new(el.span.start, el.span.start),
                         ));
@@ -82,7 +69,7 @@ impl<'a> Parser<'a> {
         }
     }
 
-    pub(crate) fn extract_svelte_options(&mut self, component: &mut Component) {
+    pub(crate) fn extract_svelte_options(&mut self, component: &mut Component<'a>) {
         let root_id = component.root;
         let options_idx = component
             .store
@@ -102,10 +89,9 @@ impl<'a> Parser<'a> {
         };
 
         let node_id = component.store.fragment_mut(root_id).nodes.remove(idx);
-        let node = component.store.get(node_id);
-        let el = node
-            .as_element()
-            .expect("node was found via options_idx — must be an element");
+        let Some(el) = component.store.get(node_id).as_element() else {
+            return;
+        };
 
         let has_another = component.fragment_nodes(root_id).iter().any(|&id| {
             component
@@ -126,7 +112,7 @@ impl<'a> Parser<'a> {
         component.options = Some(self.read_svelte_options(el));
     }
 
-    fn read_svelte_options(&mut self, el: &Element) -> SvelteOptions {
+    fn read_svelte_options(&mut self, el: &Element<'a>) -> SvelteOptions<'a> {
         let mut options = SvelteOptions {
             span: el.span,
             runes: None,
@@ -136,17 +122,17 @@ impl<'a> Parser<'a> {
             immutable: None,
             accessors: None,
             preserve_whitespace: None,
-            attributes: el.attributes.clone(),
+            attributes: el.attributes,
         };
 
-        for attr in &el.attributes {
+        for attr in el.attributes {
             match attr {
                 Attribute::BooleanAttribute(ba) => {
                     self.process_svelte_option_bool(&ba.name, true, el.span, &mut options);
                 }
                 Attribute::StringAttribute(sa) => {
-                    let value = sa.value_span.source_text(self.source).to_string();
-                    self.process_svelte_option_string(&sa.name, &value, el.span, &mut options);
+                    let value = sa.value_span.source_text(self.source);
+                    self.process_svelte_option_string(&sa.name, value, el.span, &mut options);
                 }
                 Attribute::ExpressionAttribute(ea) => {
                     let expr_text = ea.expression.span.source_text(self.source).trim();
@@ -184,7 +170,7 @@ impl<'a> Parser<'a> {
         name: &str,
         value: bool,
         span: Span,
-        options: &mut SvelteOptions,
+        options: &mut SvelteOptions<'a>,
     ) {
         match name {
             "runes" => options.runes = Some(value),
@@ -212,9 +198,9 @@ impl<'a> Parser<'a> {
     fn process_svelte_option_string(
         &mut self,
         name: &str,
-        value: &str,
+        value: &'a str,
         span: Span,
-        options: &mut SvelteOptions,
+        options: &mut SvelteOptions<'a>,
     ) {
         match name {
             "namespace" => match value {
@@ -253,7 +239,7 @@ impl<'a> Parser<'a> {
                         }
                     }
                 } else {
-                    options.custom_element = Some(CustomElementConfig::Tag(value.to_string()));
+                    options.custom_element = Some(CustomElementConfig::Tag(value));
                 }
             }
             "runes" | "immutable" | "accessors" | "preserveWhitespace" => {
@@ -278,7 +264,7 @@ impl<'a> Parser<'a> {
         &mut self,
         expression_span: Span,
         el_span: Span,
-        options: &mut SvelteOptions,
+        options: &mut SvelteOptions<'a>,
     ) {
         let expr_text = expression_span.source_text(self.source).trim();
         if expr_text == "null" {
@@ -290,7 +276,7 @@ impl<'a> Parser<'a> {
         }
         options.custom_element = Some(CustomElementConfig::Expression(expression_span));
     }
-    pub(crate) fn convert_svelte_head(component: &mut Component) {
+    pub(crate) fn convert_svelte_head(component: &mut Component<'a>) {
         let root_id = component.root;
         let len = component.fragment_nodes(root_id).len();
         for i in 0..len {
@@ -303,8 +289,8 @@ impl<'a> Parser<'a> {
             {
                 continue;
             }
-            let Node::Element(el) = component.store.take(id) else {
-                unreachable!()
+            let Some(el) = take_element(&mut component.store, id) else {
+                continue;
             };
             component.store.fragment_mut(el.fragment).role =
                 svelte_ast::FragmentRole::SvelteHeadBody;
@@ -319,7 +305,7 @@ impl<'a> Parser<'a> {
             );
         }
     }
-    pub(crate) fn convert_svelte_window(component: &mut Component) {
+    pub(crate) fn convert_svelte_window(component: &mut Component<'a>) {
         let root_id = component.root;
         let len = component.fragment_nodes(root_id).len();
         for i in 0..len {
@@ -332,8 +318,8 @@ impl<'a> Parser<'a> {
             {
                 continue;
             }
-            let Node::Element(el) = component.store.take(id) else {
-                unreachable!()
+            let Some(el) = take_element(&mut component.store, id) else {
+                continue;
             };
             component.store.replace(
                 id,
@@ -346,7 +332,7 @@ impl<'a> Parser<'a> {
             );
         }
     }
-    pub(crate) fn convert_svelte_document(component: &mut Component) {
+    pub(crate) fn convert_svelte_document(component: &mut Component<'a>) {
         let root_id = component.root;
         let len = component.fragment_nodes(root_id).len();
         for i in 0..len {
@@ -359,8 +345,8 @@ impl<'a> Parser<'a> {
             {
                 continue;
             }
-            let Node::Element(el) = component.store.take(id) else {
-                unreachable!()
+            let Some(el) = take_element(&mut component.store, id) else {
+                continue;
             };
             component.store.replace(
                 id,
@@ -373,7 +359,7 @@ impl<'a> Parser<'a> {
             );
         }
     }
-    pub(crate) fn convert_svelte_body(component: &mut Component) {
+    pub(crate) fn convert_svelte_body(component: &mut Component<'a>) {
         let root_id = component.root;
         let len = component.fragment_nodes(root_id).len();
         for i in 0..len {
@@ -386,8 +372,8 @@ impl<'a> Parser<'a> {
             {
                 continue;
             }
-            let Node::Element(el) = component.store.take(id) else {
-                unreachable!()
+            let Some(el) = take_element(&mut component.store, id) else {
+                continue;
             };
             component.store.replace(
                 id,
@@ -400,7 +386,7 @@ impl<'a> Parser<'a> {
             );
         }
     }
-    pub(crate) fn convert_slot_element_legacy(store: &mut AstStore, node_ids: &[NodeId]) {
+    pub(crate) fn convert_slot_element_legacy(store: &mut AstStore<'a>, node_ids: &[NodeId]) {
         let mut next_level = Vec::new();
         for &id in node_ids {
             if store
@@ -408,8 +394,8 @@ impl<'a> Parser<'a> {
                 .as_element()
                 .is_some_and(|el| el.name == "slot")
             {
-                let Node::Element(el) = store.take(id) else {
-                    unreachable!()
+                let Some(el) = take_element(store, id) else {
+                    continue;
                 };
                 let inner_nodes = store.fragment_nodes(el.fragment).to_vec();
                 Self::convert_slot_element_legacy(store, &inner_nodes);
@@ -431,7 +417,7 @@ impl<'a> Parser<'a> {
             Self::convert_slot_element_legacy(store, &next_level);
         }
     }
-    pub(crate) fn convert_svelte_fragment_legacy(store: &mut AstStore, node_ids: &[NodeId]) {
+    pub(crate) fn convert_svelte_fragment_legacy(store: &mut AstStore<'a>, node_ids: &[NodeId]) {
         let mut next_level = Vec::new();
         for &id in node_ids {
             if store
@@ -439,8 +425,8 @@ impl<'a> Parser<'a> {
                 .as_element()
                 .is_some_and(|el| el.name == SVELTE_FRAGMENT)
             {
-                let Node::Element(el) = store.take(id) else {
-                    unreachable!()
+                let Some(el) = take_element(store, id) else {
+                    continue;
                 };
                 let inner_nodes = store.fragment_nodes(el.fragment).to_vec();
                 Self::convert_svelte_fragment_legacy(store, &inner_nodes);
@@ -463,7 +449,7 @@ impl<'a> Parser<'a> {
         }
     }
     pub(crate) fn convert_svelte_element(
-        store: &mut AstStore,
+        store: &mut AstStore<'a>,
         diagnostics: &mut Vec<Diagnostic>,
         node_ids: &[NodeId],
     ) {
@@ -487,7 +473,7 @@ impl<'a> Parser<'a> {
                         continue;
                     }
                 };
-                let (tag_span, static_tag) = Self::classify_this_attribute(&el.attributes);
+                let (tag_span, static_tag) = Self::classify_this_attribute(el.attributes);
                 let inner_nodes = store.fragment_nodes(el.fragment).to_vec();
                 Self::convert_svelte_element(store, diagnostics, &inner_nodes);
                 store.fragment_mut(el.fragment).role = svelte_ast::FragmentRole::SvelteElementBody;
@@ -511,7 +497,7 @@ impl<'a> Parser<'a> {
             Self::convert_svelte_element(store, diagnostics, &next_level);
         }
     }
-    pub(crate) fn convert_svelte_boundary(store: &mut AstStore, node_ids: &[NodeId]) {
+    pub(crate) fn convert_svelte_boundary(store: &mut AstStore<'a>, node_ids: &[NodeId]) {
         let mut next_level = Vec::new();
         for &id in node_ids {
             if store
@@ -519,8 +505,8 @@ impl<'a> Parser<'a> {
                 .as_element()
                 .is_some_and(|el| el.name == SVELTE_BOUNDARY)
             {
-                let Node::Element(el) = store.take(id) else {
-                    unreachable!()
+                let Some(el) = take_element(store, id) else {
+                    continue;
                 };
                 let inner_nodes = store.fragment_nodes(el.fragment).to_vec();
                 Self::convert_svelte_boundary(store, &inner_nodes);
@@ -544,52 +530,52 @@ impl<'a> Parser<'a> {
         }
     }
 }
-fn collect_child_fragments(node: &Node, buf: &mut Vec<svelte_ast::FragmentId>) {
+pub(crate) fn for_each_child_fragment(node: &Node<'_>, mut f: impl FnMut(svelte_ast::FragmentId)) {
     match node {
-        Node::Element(el) => buf.push(el.fragment),
-        Node::SlotElementLegacy(el) => buf.push(el.fragment),
+        Node::Element(el) => f(el.fragment),
+        Node::SlotElementLegacy(el) => f(el.fragment),
         Node::ComponentNode(cn) => {
-            buf.push(cn.fragment);
-            for slot in &cn.legacy_slots {
-                buf.push(slot.fragment);
+            f(cn.fragment);
+            for slot in cn.legacy_slots {
+                f(slot.fragment);
             }
         }
         Node::IfBlock(block) => {
-            buf.push(block.consequent);
+            f(block.consequent);
             if let Some(alt) = block.alternate {
-                buf.push(alt);
+                f(alt);
             }
         }
         Node::EachBlock(block) => {
-            buf.push(block.body);
+            f(block.body);
             if let Some(fb) = block.fallback {
-                buf.push(fb);
+                f(fb);
             }
         }
-        Node::SnippetBlock(block) => buf.push(block.body),
-        Node::KeyBlock(block) => buf.push(block.fragment),
-        Node::SvelteWindow(window) => buf.push(window.fragment),
-        Node::SvelteDocument(document) => buf.push(document.fragment),
-        Node::SvelteBody(body) => buf.push(body.fragment),
-        Node::SvelteHead(head) => buf.push(head.fragment),
-        Node::SvelteFragmentLegacy(fragment) => buf.push(fragment.fragment),
-        Node::SvelteElement(el) => buf.push(el.fragment),
+        Node::SnippetBlock(block) => f(block.body),
+        Node::KeyBlock(block) => f(block.fragment),
+        Node::SvelteWindow(window) => f(window.fragment),
+        Node::SvelteDocument(document) => f(document.fragment),
+        Node::SvelteBody(body) => f(body.fragment),
+        Node::SvelteHead(head) => f(head.fragment),
+        Node::SvelteFragmentLegacy(fragment) => f(fragment.fragment),
+        Node::SvelteElement(el) => f(el.fragment),
         Node::SvelteComponentLegacy(el) => {
-            buf.push(el.fragment);
-            for slot in &el.legacy_slots {
-                buf.push(slot.fragment);
+            f(el.fragment);
+            for slot in el.legacy_slots {
+                f(slot.fragment);
             }
         }
-        Node::SvelteBoundary(b) => buf.push(b.fragment),
+        Node::SvelteBoundary(b) => f(b.fragment),
         Node::AwaitBlock(block) => {
             if let Some(pending) = block.pending {
-                buf.push(pending);
+                f(pending);
             }
             if let Some(then) = block.then {
-                buf.push(then);
+                f(then);
             }
             if let Some(catch) = block.catch {
-                buf.push(catch);
+                f(catch);
             }
         }
         Node::Text(_)
@@ -603,10 +589,19 @@ fn collect_child_fragments(node: &Node, buf: &mut Vec<svelte_ast::FragmentId>) {
     }
 }
 
-fn extend_child_node_ids(store: &AstStore, node: &Node, buf: &mut Vec<NodeId>) {
-    let mut frags = Vec::new();
-    collect_child_fragments(node, &mut frags);
-    for fid in frags {
-        buf.extend_from_slice(&store.fragment(fid).nodes);
+/// Takes the element at `id` out of the store; any other node is put back.
+fn take_element<'a>(store: &mut AstStore<'a>, id: NodeId) -> Option<Element<'a>> {
+    match store.take(id) {
+        Node::Element(el) => Some(el),
+        other => {
+            store.replace(id, other);
+            None
+        }
     }
 }
+
+fn extend_child_node_ids(store: &AstStore<'_>, node: &Node<'_>, buf: &mut Vec<NodeId>) {
+    for_each_child_fragment(node, |fid| {
+        buf.extend_from_slice(&store.fragment(fid).nodes);
+    });
+}
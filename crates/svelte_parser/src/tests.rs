@@ -1,17 +1,26 @@
 use super::*;
+use svelte_diagnostics::TextEdit;
 
-fn parse(source: &str) -> Component {
-    let (component, diagnostics) = Parser::new(source).parse();
+fn test_allocator() -> &'static svelte_ast::Allocator {
+    Box::leak(Box::default())
+}
+
+fn parse(source: &str) -> Component<'static> {
+    let (component, diagnostics) = Parser::new(test_allocator(), source).parse();
     assert!(
         diagnostics.is_empty(),
         "unexpected diagnostics: {diagnostics:?}"
     );
     component
 }
-fn node_at(c: &Component, index: usize) -> &Node {
+fn node_at<'a>(c: &'a Component<'a>, index: usize) -> &'a Node<'a> {
     c.store.get(c.store.fragment(c.root).nodes[index])
 }
-fn frag_node_at(c: &Component, fragment_id: svelte_ast::FragmentId, index: usize) -> &Node {
+fn frag_node_at<'a>(
+    c: &'a Component<'a>,
+    fragment_id: svelte_ast::FragmentId,
+    index: usize,
+) -> &'a Node<'a> {
     c.store.get(c.store.fragment(fragment_id).nodes[index])
 }
 
@@ -102,7 +111,76 @@ fn script_tag_lang_ts() {
         .instance_script
         .as_ref()
         .expect("expected instance script");
-    assert_eq!(script.language, ScriptLanguage::TypeScript);
+    assert_eq!(script.language, Some(ScriptLanguage::TypeScript));
+}
+
+#[test]
+fn script_tag_lang_typescript_and_attributes() {
+    let c = parse(
+        r#"<script lang="typescript" generics='T extends string' module>const i = 1;</script>"#,
+    );
+    let script = c.module_script.as_ref().expect("expected module script");
+    assert_eq!(script.language, Some(ScriptLanguage::TypeScript));
+    assert_eq!(script.lang(c.source), Some("typescript"));
+    let names: Vec<&str> = script
+        .attributes
+        .iter()
+        .map(|attr| match attr {
+            Attribute::StringAttribute(a) => a.name.as_str(),
+            Attribute::BooleanAttribute(a) => a.name.as_str(),
+            _ => panic!("expected static attribute"),
+        })
+        .collect();
+    assert_eq!(names, ["lang", "generics", "module"]);
+    assert_eq!(
+        svelte_ast::static_attribute_value(script.attributes, "generics", c.source),
+        Some("T extends string")
+    );
+}
+
+#[test]
+fn script_attributes_use_scanned_name_and_value_spans() {
+    let c = parse(r#"<script lang=ts data-q="a=b" {x}>let i: number = 1;</script>"#);
+    let script = c
+        .instance_script
+        .as_ref()
+        .expect("expected instance script");
+    assert!(script.is_typescript());
+    assert_eq!(script.lang(c.source), Some("ts"));
+    assert_eq!(
+        svelte_ast::static_attribute_value(script.attributes, "data-q", c.source),
+        Some("a=b")
+    );
+    assert_eq!(script.attributes.len(), 2);
+}
+
+#[test]
+fn style_tag_keeps_attributes() {
+    let c = parse(r#"<style lang=scss global>a { b { color: red } }</style>"#);
+    let css = c.css.as_ref().expect("expected style block");
+    assert_eq!(css.lang(c.source), Some("scss"));
+    assert!(!css.has_supported_lang(c.source));
+    assert!(matches!(
+        css.attributes.get(1),
+        Some(Attribute::BooleanAttribute(a)) if a.name == "global"
+    ));
+    assert!(crate::parse_css_block(&c).is_none());
+}
+
+#[test]
+fn unsupported_script_lang_is_not_parsed_as_js() {
+    let alloc = test_allocator();
+    let (c, js, diags) = crate::parse_with_js(
+        alloc,
+        r#"<script lang="coffee">square = (x) -> x * x</script>"#,
+    );
+    assert!(diags.is_empty(), "{diags:?}");
+    let script = c
+        .instance_script
+        .as_ref()
+        .expect("expected instance script");
+    assert!(!script.has_supported_lang());
+    assert!(js.program.is_none());
 }
 
 #[test]
@@ -212,7 +290,7 @@ fn component_name_with_underscore_non_self_closing_parses() {
 
 #[test]
 fn lowercase_tag_name_with_underscore_is_rejected() {
-    let (_, diagnostics) = Parser::new("<div_foo></div_foo>").parse();
+    let (_, diagnostics) = Parser::new(test_allocator(), "<div_foo></div_foo>").parse();
     assert!(
         !diagnostics.is_empty(),
         "expected diagnostic for lowercase tag name with underscore"
@@ -247,7 +325,7 @@ fn nested_if_in_element() {
 
 #[test]
 fn unclosed_element_returns_diagnostic() {
-    let (component, diagnostics) = Parser::new("<div>").parse();
+    let (component, diagnostics) = Parser::new(test_allocator(), "<div>").parse();
     assert!(
         !diagnostics.is_empty(),
         "expected diagnostics for unclosed element"
@@ -300,7 +378,7 @@ fn assert_snippet_block(
     expected_expression: &str,
 ) {
     if let Node::SnippetBlock(sb) = node_at(c, index) {
-        assert_eq!(sb.name(&c.source), expected_name);
+        assert_eq!(sb.name(c.source), expected_name);
         assert_eq!(c.source_text(sb.decl.span), expected_expression);
     } else {
         panic!("expected SnippetBlock at index {index}");
@@ -475,7 +553,8 @@ fn style_tag_with_script() {
 
 #[test]
 fn duplicate_style_tag_returns_diagnostic() {
-    let (_, diagnostics) = Parser::new("<style>a{}</style><style>b{}</style>").parse();
+    let (_, diagnostics) =
+        Parser::new(test_allocator(), "<style>a{}</style><style>b{}</style>").parse();
     assert!(
         !diagnostics.is_empty(),
         "expected diagnostic for duplicate style"
@@ -601,8 +680,8 @@ fn spread_attribute_on_element() {
     }
 }
 
-fn parse_with_diagnostics(source: &str) -> (Component, Vec<Diagnostic>) {
-    Parser::new(source).parse()
+fn parse_with_diagnostics(source: &str) -> (Component<'static>, Vec<Diagnostic>) {
+    Parser::new(test_allocator(), source).parse()
 }
 
 #[test]
@@ -797,7 +876,7 @@ fn assert_options_custom_element_tag(c: &Component, expected_tag: &str) {
     let opts = c.options.as_ref().expect("expected svelte:options");
     match &opts.custom_element {
         Some(svelte_ast::CustomElementConfig::Tag(tag)) => {
-            assert_eq!(tag, expected_tag, "expected customElement tag");
+            assert_eq!(*tag, expected_tag, "expected customElement tag");
         }
         _ => panic!("expected CustomElementConfig::Tag"),
     }
@@ -1108,7 +1187,7 @@ fn debug_tag_multiple() {
 
 #[test]
 fn debug_tag_member_expression_error() {
-    let (_, diagnostics) = Parser::new("{@debug x.y}").parse();
+    let (_, diagnostics) = Parser::new(test_allocator(), "{@debug x.y}").parse();
     assert!(
         !diagnostics.is_empty(),
         "expected diagnostic for member expression in debug tag"
@@ -1117,7 +1196,7 @@ fn debug_tag_member_expression_error() {
 
 #[test]
 fn debug_tag_call_expression_error() {
-    let (_, diagnostics) = Parser::new("{@debug fn()}").parse();
+    let (_, diagnostics) = Parser::new(test_allocator(), "{@debug fn()}").parse();
     assert!(
         !diagnostics.is_empty(),
         "expected diagnostic for call expression in debug tag"
@@ -1279,13 +1358,13 @@ fn let_directive_legacy_converts_to_dedicated_attribute() {
     };
 
     assert_eq!(dir.name, "item");
-    assert_eq!(dir.name_span.source_text(&c.source), "item");
+    assert_eq!(dir.name_span.source_text(c.source), "item");
     assert_eq!(
         dir.binding
             .as_ref()
             .expect("expected expression")
             .span
-            .source_text(&c.source),
+            .source_text(c.source),
         "processed"
     );
 }
@@ -1312,10 +1391,86 @@ mod js_parse_tests {
             .expect("test invariant");
         assert!(!program.body.is_empty());
     }
+
+    fn js_error_spans(source: &str, offset: u32) -> Vec<(String, u32, u32)> {
+        let alloc = Allocator::default();
+        let arena_source = alloc.alloc_str(source);
+        let diags = crate::parse_js::parse_script_with_alloc(&alloc, arena_source, offset, false)
+            .err()
+            .unwrap_or_default();
+        diags
+            .into_iter()
+            .map(|d| {
+                assert_eq!(d.kind.code(), "js_parse_error");
+                (d.kind.message(), d.span.start, d.span.end)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_script_error_keeps_oxc_message_and_span() {
+        let errors = js_error_spans("let a = 1;\nlet b = ;", 8);
+        assert_eq!(errors, vec![("Unexpected token".to_string(), 27, 28)]);
+    }
+
+    #[test]
+    fn parse_script_reports_every_recovered_error() {
+        let errors = js_error_spans("'\\u{zz}';\n'\\u{yy}';", 100);
+        assert_eq!(
+            errors,
+            vec![
+                ("Invalid escape sequence".to_string(), 101, 104),
+                ("Invalid escape sequence".to_string(), 111, 114),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_expression_error_is_shifted_by_offset() {
+        let alloc = Allocator::default();
+        let diags = crate::parse_js::parse_expression_with_alloc(&alloc, "a +", 40, false)
+            .err()
+            .unwrap_or_default();
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].kind.code(), "js_parse_error");
+        assert_eq!(diags[0].kind.message(), "Unexpected token");
+        assert_eq!((diags[0].span.start, diags[0].span.end), (43, 43));
+    }
+
+    #[test]
+    fn parse_const_declaration_error_points_inside_the_tag() {
+        let alloc = Allocator::default();
+        let diags =
+            crate::parse_js::parse_const_declaration_with_alloc(&alloc, "x = 1 +", 20, false)
+                .err()
+                .unwrap_or_default();
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].kind.code(), "js_parse_error");
+        assert_eq!(diags[0].kind.message(), "Unexpected token");
+        assert_eq!((diags[0].span.start, diags[0].span.end), (27, 27));
+    }
+
+    #[test]
+    fn parse_slot_let_error_stays_within_the_pattern() {
+        let alloc = Allocator::default();
+        let diags =
+            crate::parse_js::parse_slot_let_decl_with_alloc(&alloc, "{ a", "item", 10, false)
+                .err()
+                .unwrap_or_default();
+        assert!(!diags.is_empty());
+        for diag in &diags {
+            assert_eq!(diag.kind.code(), "js_parse_error");
+            assert!(
+                10 <= diag.span.start && diag.span.end <= 13,
+                "{:?} is outside the pattern",
+                diag.span
+            );
+        }
+    }
 }
 
 fn parse_with_diags(source: &str) -> Vec<svelte_diagnostics::Diagnostic> {
-    Parser::new(source).parse().1
+    Parser::new(test_allocator(), source).parse().1
 }
 
 #[test]
@@ -1354,14 +1509,14 @@ fn attribute_duplicate_style_directive() {
     );
 }
 
-fn component_at(c: &Component, index: usize) -> &svelte_ast::ComponentNode {
+fn component_at<'a>(c: &'a Component<'a>, index: usize) -> &'a svelte_ast::ComponentNode<'a> {
     let Node::ComponentNode(cn) = node_at(c, index) else {
         panic!("expected ComponentNode at index {index}");
     };
     cn
 }
 
-fn slot_names(cn: &svelte_ast::ComponentNode) -> Vec<&str> {
+fn slot_names<'a>(cn: &svelte_ast::ComponentNode<'a>) -> Vec<&'a str> {
     cn.legacy_slots.iter().map(|s| s.name.as_str()).collect()
 }
 
@@ -1518,3 +1673,299 @@ fn named_slot_fragment_role_is_named_slot() {
         FragmentRole::NamedSlot
     );
 }
+
+fn dump_tree(c: &Component<'_>) -> String {
+    fn walk(c: &Component<'_>, fragment: FragmentId, depth: usize, out: &mut String) {
+        let frag = c.store.fragment(fragment);
+        out.push_str(&format!("{}{:?}\n", "  ".repeat(depth), frag.role));
+        for &id in frag.nodes.iter() {
+            let node = c.store.get(id);
+            let span = node.span();
+            out.push_str(&format!(
+                "{}{:?} {}..{} {:?}\n",
+                "  ".repeat(depth + 1),
+                std::mem::discriminant(node),
+                span.start,
+                span.end,
+                c.source_text(span)
+            ));
+            for attr in node.attributes() {
+                let span = attr.span();
+                out.push_str(&format!(
+                    "{}@{} {}..{}\n",
+                    "  ".repeat(depth + 2),
+                    attr.html_name(),
+                    span.start,
+                    span.end
+                ));
+            }
+            if let Node::ExpressionTag(tag) = node {
+                let span = tag.expression.span;
+                out.push_str(&format!(
+                    "{}= {}..{}\n",
+                    "  ".repeat(depth + 2),
+                    span.start,
+                    span.end
+                ));
+            }
+            let mut children = Vec::new();
+            svelte_elements::for_each_child_fragment(node, |f| children.push(f));
+            for child in children {
+                assert_eq!(c.store.fragment(child).owner, Some(id));
+                walk(c, child, depth + 2, out);
+            }
+        }
+    }
+    let mut out = String::new();
+    walk(c, c.root, 0, &mut out);
+    for script in [&c.instance_script, &c.module_script].into_iter().flatten() {
+        out.push_str(&format!(
+            "script {:?}\n",
+            c.source_text(script.content_span)
+        ));
+    }
+    out
+}
+
+fn assert_reparse(source: &str, edit: TextEdit, expected: ReparseMode) {
+    let alloc = test_allocator();
+    let (previous, diagnostics) = Parser::new(alloc, source).parse();
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    let reparsed =
+        reparse(test_allocator(), &previous, &diagnostics, &edit).expect("edit fits the source");
+    let (full, full_diagnostics) = Parser::new(alloc, reparsed.component.source).parse();
+    assert_eq!(reparsed.mode, expected);
+    assert_eq!(dump_tree(&reparsed.component), dump_tree(&full));
+    assert_eq!(reparsed.diagnostics.len(), full_diagnostics.len());
+}
+
+#[test]
+fn reparse_text_edit_inside_nested_element_is_incremental() {
+    assert_reparse(
+        "<div><p>hello</p><span>{a}</span></div>\n<b>tail {x}</b>",
+        TextEdit::new(Span::new(10, 10), ", world"),
+        ReparseMode::Incremental,
+    );
+}
+
+#[test]
+fn reparse_attribute_value_edit_is_incremental() {
+    assert_reparse(
+        r#"<section><input class="a {b} c" bind:value={v} on:input|once={h} /></section><p>{x}</p>"#,
+        TextEdit::new(Span::new(23, 24), "item"),
+        ReparseMode::Incremental,
+    );
+}
+
+#[test]
+fn reparse_edit_inside_block_keeps_sibling_blocks() {
+    assert_reparse(
+        "{#each items as item (item.id)}<li>{item.name}</li>{:else}none{/each}\n{#if ok}yes{:else if maybe}perhaps{:else}no{/if}",
+        TextEdit::new(Span::new(97, 104), "possibly"),
+        ReparseMode::Incremental,
+    );
+}
+
+#[test]
+fn reparse_script_edit_shifts_template() {
+    assert_reparse(
+        "<script>let count = 0;</script>\n<button on:click={() => count++}>{count}</button>",
+        TextEdit::new(Span::new(20, 21), "10"),
+        ReparseMode::Incremental,
+    );
+}
+
+#[test]
+fn reparse_falls_back_when_block_structure_changes() {
+    assert_reparse(
+        "<div><p>one</p></div>",
+        TextEdit::new(Span::new(8, 8), "</p><p>"),
+        ReparseMode::Full,
+    );
+    assert_reparse(
+        "<div>{#if a}x{/if}</div>",
+        TextEdit::new(Span::new(12, 12), "{/if}{#if b}"),
+        ReparseMode::Full,
+    );
+}
+
+#[test]
+fn reparse_falls_back_for_slotted_children_and_script_tags() {
+    assert_reparse(
+        r#"<Comp><div slot="a">A</div></Comp>"#,
+        TextEdit::new(Span::new(17, 18), "b"),
+        ReparseMode::Full,
+    );
+    assert_reparse(
+        "<div>text</div>",
+        TextEdit::new(Span::new(7, 7), "<script>x</script>"),
+        ReparseMode::Full,
+    );
+}
+
+#[test]
+fn reparse_with_previous_diagnostics_is_full() {
+    let alloc = test_allocator();
+    let (previous, diagnostics) = Parser::new(alloc, "<div><p>a</div>").parse();
+    assert!(!diagnostics.is_empty());
+    let reparsed = reparse(
+        test_allocator(),
+        &previous,
+        &diagnostics,
+        &TextEdit::new(Span::new(8, 9), "b"),
+    )
+    .expect("edit fits the source");
+    assert_eq!(reparsed.mode, ReparseMode::Full);
+    assert_eq!(reparsed.component.source, "<div><p>b</div>");
+}
+
+#[test]
+fn reparse_with_js_parses_template_expressions() {
+    let alloc = test_allocator();
+    let (previous, previous_js, diagnostics) = parse_with_js(alloc, "<p>{a}</p><p>{b + c}</p>");
+    assert!(diagnostics.is_empty());
+    let (reparsed, js) = reparse_with_js(
+        test_allocator(),
+        &previous,
+        &previous_js,
+        &diagnostics,
+        &TextEdit::new(Span::new(4, 5), "value"),
+    )
+    .expect("edit fits the source");
+    assert_eq!(reparsed.mode, ReparseMode::Incremental);
+    assert!(reparsed.diagnostics.is_empty());
+    for id in [0, 1] {
+        let Node::Element(p) = node_at(&reparsed.component, id) else {
+            panic!("expected element");
+        };
+        let tag = reparsed.component.store.fragment(p.fragment).nodes[0];
+        let Node::ExpressionTag(tag) = reparsed.component.store.get(tag) else {
+            panic!("expected expression tag");
+        };
+        assert!(js.has_pending_expr(tag.expression.span.start));
+    }
+}
+
+fn dump_js(js: &JsAst<'_>, source_len: usize) -> String {
+    let mut out = format!(
+        "{:?}\n{:?}\n{:?}\n{:?}\n{}\n",
+        js.program,
+        js.module_program,
+        js.script_content_span,
+        js.module_script_content_span,
+        js.typescript
+    );
+    for offset in 0..source_len as u32 {
+        if let Some(expr) = js.pending_expr(offset) {
+            out.push_str(&format!("expr {offset}: {expr:?}\n"));
+        }
+        if let Some(stmt) = js.pending_stmt(offset) {
+            out.push_str(&format!("stmt {offset}: {stmt:?}\n"));
+        }
+    }
+    out
+}
+
+fn assert_reparse_with_js(source: &str, edit: TextEdit) {
+    let (previous, previous_js, diagnostics) = parse_with_js(test_allocator(), source);
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    let (reparsed, js) = reparse_with_js(
+        test_allocator(),
+        &previous,
+        &previous_js,
+        &diagnostics,
+        &edit,
+    )
+    .expect("edit fits the source");
+    let new_source = reparsed.component.source;
+    let (_, full_js, full_diagnostics) = parse_with_js(test_allocator(), new_source);
+    assert_eq!(reparsed.mode, ReparseMode::Incremental);
+    assert_eq!(reparsed.diagnostics.len(), full_diagnostics.len());
+    assert_eq!(
+        dump_js(&js, new_source.len()),
+        dump_js(&full_js, new_source.len())
+    );
+}
+
+#[test]
+fn reparse_with_js_carries_untouched_js_to_shifted_offsets() {
+    let source = "<script module>/** m */ export const k = 1;</script>\n<script lang=\"ts\">let items: string[] = $state([]); // items\n</script>\n{#each items as item, i (item)}<p class=\"a {item}\" on:click={() => i}>{item.length}</p>{/each}\n{#snippet row({ a }: { a: number })}{@const b = a * 2}<span>{b}</span>{/snippet}\n<section><b>{k}</b></section>";
+    let text = source.find("{item.length}").expect("fixture has the tag") as u32 + 1;
+    assert_reparse_with_js(source, TextEdit::new(Span::new(text, text + 4), "value"));
+    let section = source.find("{k}").expect("fixture has the tag") as u32 + 1;
+    assert_reparse_with_js(
+        source,
+        TextEdit::new(Span::new(section, section + 1), "k + 1"),
+    );
+}
+
+#[test]
+fn reparse_with_js_parses_only_the_edited_script() {
+    let source = "<script>let count = $state(0); /* c */ let double = $derived(count * 2);</script>\n<button onclick={() => count++}>{count} {double}</button>\n<script module>// module\nexport const name = 'x';</script>";
+    let count = source.find("0)").expect("fixture has the initializer") as u32;
+    assert_reparse_with_js(source, TextEdit::new(Span::new(count, count + 1), "100"));
+    let name = source.find("'x'").expect("fixture has the initializer") as u32;
+    assert_reparse_with_js(source, TextEdit::new(Span::new(name, name + 3), "'longer'"));
+}
+
+#[test]
+fn reparse_reuses_source_text_for_names() {
+    let (previous, diagnostics) = Parser::new(
+        test_allocator(),
+        "<div class=\"a\">x</div><section id=\"b\">y</section>",
+    )
+    .parse();
+    let reparsed = reparse(
+        test_allocator(),
+        &previous,
+        &diagnostics,
+        &TextEdit::new(Span::new(15, 16), "xx"),
+    )
+    .expect("edit fits the source");
+    assert_eq!(reparsed.mode, ReparseMode::Incremental);
+    let source = reparsed.component.source.as_bytes().as_ptr_range();
+    let Node::Element(section) = node_at(&reparsed.component, 1) else {
+        panic!("expected element");
+    };
+    assert!(source.contains(&section.name.as_str().as_ptr()));
+    assert!(source.contains(&section.attributes[0].html_name().as_ptr()));
+}
+
+#[test]
+fn reparse_outlives_previous_arena() {
+    let next = svelte_ast::Allocator::default();
+    let reparsed = {
+        let previous_arena = svelte_ast::Allocator::default();
+        let (previous, diagnostics) = Parser::new(
+            &previous_arena,
+            r#"<div class="a" on:click|once={f}>é {x}</div>"#,
+        )
+        .parse();
+        reparse(
+            &next,
+            &previous,
+            &diagnostics,
+            &TextEdit::new(Span::new(33, 35), "e"),
+        )
+        .expect("edit fits the source")
+    };
+    let (full, _) = Parser::new(test_allocator(), reparsed.component.source).parse();
+    assert_eq!(reparsed.mode, ReparseMode::Incremental);
+    assert_eq!(dump_tree(&reparsed.component), dump_tree(&full));
+}
+
+#[test]
+fn reparse_rejects_edits_outside_the_source() {
+    let alloc = test_allocator();
+    let (previous, diagnostics) = Parser::new(alloc, "<p>é</p>").parse();
+    for span in [Span::new(3, 20), Span::new(4, 4), Span::new(5, 3)] {
+        let result = reparse(alloc, &previous, &diagnostics, &TextEdit::new(span, "x"));
+        assert_eq!(
+            result.err(),
+            Some(InvalidEdit {
+                span,
+                source_len: 9,
+            })
+        );
+    }
+}
@@ -70,6 +70,23 @@ impl<'a> JsAst<'a> {
         self.pending_exprs.remove(&offset)
     }
 
+    pub(crate) fn pending_exprs(&self) -> impl Iterator<Item = (u32, &Expression<'a>)> {
+        self.pending_exprs
+            .iter()
+            .map(|(&offset, expr)| (offset, expr))
+    }
+
+    pub(crate) fn pending_stmts(&self) -> impl Iterator<Item = (u32, &Statement<'a>)> {
+        self.pending_stmts
+            .iter()
+            .map(|(&offset, stmt)| (offset, stmt))
+    }
+
+    /// `drain_pending` has moved template expressions out of the offset maps.
+    pub(crate) fn is_drained(&self) -> bool {
+        !self.exprs.is_empty() || !self.stmts.is_empty()
+    }
+
     pub fn expr_at_offset(&self, offset: u32) -> Option<&Expression<'a>> {
         self.pending_exprs.get(&offset)
     }
@@ -1,5 +1,5 @@
 use oxc_allocator::Allocator;
-use svelte_ast::{AstStore, Attribute, Component, ConcatPart, FragmentId, Node, ScriptLanguage};
+use svelte_ast::{AstStore, Attribute, Component, ConcatPart, FragmentId, Node, NodeId};
 use svelte_diagnostics::Diagnostic;
 
 use crate::parse_js::{
@@ -15,14 +15,62 @@ pub(crate) fn parse_js<'a>(
     result: &mut JsAst<'a>,
     diags: &mut Vec<Diagnostic>,
 ) {
-    let template_typescript = component
-        .instance_script
+    parse_instance_script(alloc, component, result, diags);
+    parse_module_script(alloc, component, result, diags);
+
+    let typescript = template_typescript(component);
+    walk_fragment(
+        alloc,
+        component.root,
+        &component.store,
+        component,
+        typescript,
+        result,
+        diags,
+    );
+
+    if let Some(svelte_ast::CustomElementConfig::Expression(span)) = component
+        .options
         .as_ref()
-        .or(component.module_script.as_ref())
-        .is_some_and(|s| matches!(s.language, ScriptLanguage::TypeScript));
+        .and_then(|o| o.custom_element.as_ref())
+    {
+        parse_span(alloc, component, *span, typescript, result, diags);
+    }
+}
+
+/// Parses the expressions and patterns of one template node and its subtree.
+pub(crate) fn parse_node_js<'a>(
+    alloc: &'a Allocator,
+    component: &Component,
+    id: NodeId,
+    result: &mut JsAst<'a>,
+    diags: &mut Vec<Diagnostic>,
+) {
+    let typescript = template_typescript(component);
+    let node = component.store.get(id);
+    walk_node(
+        alloc,
+        node,
+        &component.store,
+        component,
+        typescript,
+        result,
+        diags,
+    );
+}
 
-    if let Some(script) = &component.instance_script {
-        let typescript = matches!(script.language, ScriptLanguage::TypeScript);
+pub(crate) fn parse_instance_script<'a>(
+    alloc: &'a Allocator,
+    component: &Component,
+    result: &mut JsAst<'a>,
+    diags: &mut Vec<Diagnostic>,
+) {
+    if let Some(script) = component
+        .instance_script
+        .as_ref()
+        .filter(|script| script.has_supported_lang())
+    {
+        let typescript = script.is_typescript();
         let source = component.source_text(script.content_span);
         let arena_source: &'a str = alloc.alloc_str(source);
         match parse_script_with_alloc(alloc, arena_source, script.content_span.start, typescript) {
@@ -34,9 +82,20 @@ pub(crate) fn parse_js<'a>(
         }
         result.typescript = typescript;
     }
+}
 
-    if let Some(script) = &component.module_script {
-        let typescript = matches!(script.language, ScriptLanguage::TypeScript);
+pub(crate) fn parse_module_script<'a>(
+    alloc: &'a Allocator,
+    component: &Component,
+    result: &mut JsAst<'a>,
+    diags: &mut Vec<Diagnostic>,
+) {
+    if let Some(script) = component
+        .module_script
+        .as_ref()
+        .filter(|script| script.has_supported_lang())
+    {
+        let typescript = script.is_typescript();
         let source = component.source_text(script.content_span);
         let arena_source: &'a str = alloc.alloc_str(source);
         match parse_script_with_alloc(alloc, arena_source, script.content_span.start, typescript) {
@@ -47,24 +106,14 @@ pub(crate) fn parse_js<'a>(
             Err(errs) => diags.extend(errs),
         }
     }
+}
 
-    walk_fragment(
-        alloc,
-        component.root,
-        &component.store,
-        component,
-        template_typescript,
-        result,
-        diags,
-    );
-
-    if let Some(svelte_ast::CustomElementConfig::Expression(span)) = component
-        .options
+fn template_typescript(component: &Component) -> bool {
+    component
+        .instance_script
         .as_ref()
-        .and_then(|o| o.custom_element.as_ref())
-    {
-        parse_span(alloc, component, *span, template_typescript, result, diags);
-    }
+        .or(component.module_script.as_ref())
+        .is_some_and(|s| s.is_typescript())
 }
 
 fn parse_directive_name_span<'a>(
@@ -82,7 +131,7 @@ fn parse_directive_name_span<'a>(
         Ok(expr) => {
             result.alloc_expr(name_span.start, expr);
         }
-        Err(diag) => diags.push(diag),
+        Err(errs) => diags.extend(errs),
     }
 }
 
@@ -129,7 +178,7 @@ fn parse_span<'a>(
         Ok(expr) => {
             result.alloc_expr(span.start, expr);
         }
-        Err(diag) => diags.push(diag),
+        Err(errs) => diags.extend(errs),
     }
 }
 
@@ -186,7 +235,7 @@ fn walk_node<'a>(
             );
         }
         Node::Element(el) => {
-            walk_attrs(alloc, &el.attributes, component, typescript, result, diags);
+            walk_attrs(alloc, el.attributes, component, typescript, result, diags);
             walk_fragment(
                 alloc,
                 el.fragment,
@@ -198,7 +247,7 @@ fn walk_node<'a>(
             );
         }
         Node::SlotElementLegacy(el) => {
-            walk_attrs(alloc, &el.attributes, component, typescript, result, diags);
+            walk_attrs(alloc, el.attributes, component, typescript, result, diags);
             walk_fragment(
                 alloc,
                 el.fragment,
@@ -210,7 +259,7 @@ fn walk_node<'a>(
             );
         }
         Node::ComponentNode(cn) => {
-            walk_attrs(alloc, &cn.attributes, component, typescript, result, diags);
+            walk_attrs(alloc, cn.attributes, component, typescript, result, diags);
             walk_fragment(
                 alloc,
                 cn.fragment,
@@ -220,7 +269,7 @@ fn walk_node<'a>(
                 result,
                 diags,
             );
-            for slot in &cn.legacy_slots {
+            for slot in cn.legacy_slots {
                 walk_fragment(
                     alloc,
                     slot.fragment,
@@ -374,7 +423,7 @@ fn walk_node<'a>(
                 Ok(stmt) => {
                     result.alloc_stmt(tag.decl.span.start, stmt);
                 }
-                Err(diag) => diags.push(diag),
+                Err(errs) => diags.extend(errs),
             }
         }
         Node::SvelteHead(head) => {
@@ -389,7 +438,7 @@ fn walk_node<'a>(
             );
         }
         Node::SvelteFragmentLegacy(el) => {
-            walk_attrs(alloc, &el.attributes, component, typescript, result, diags);
+            walk_attrs(alloc, el.attributes, component, typescript, result, diags);
             walk_fragment(
                 alloc,
                 el.fragment,
@@ -401,7 +450,7 @@ fn walk_node<'a>(
             );
         }
         Node::SvelteElement(el) => {
-            walk_attrs(alloc, &el.attributes, component, typescript, result, diags);
+            walk_attrs(alloc, el.attributes, component, typescript, result, diags);
             walk_fragment(
                 alloc,
                 el.fragment,
@@ -413,7 +462,7 @@ fn walk_node<'a>(
             );
         }
         Node::SvelteComponentLegacy(el) => {
-            walk_attrs(alloc, &el.attributes, component, typescript, result, diags);
+            walk_attrs(alloc, el.attributes, component, typescript, result, diags);
             walk_fragment(
                 alloc,
                 el.fragment,
@@ -425,22 +474,22 @@ fn walk_node<'a>(
             );
         }
         Node::SvelteWindow(w) => {
-            walk_attrs(alloc, &w.attributes, component, typescript, result, diags);
+            walk_attrs(alloc, w.attributes, component, typescript, result, diags);
         }
         Node::SvelteDocument(d) => {
-            walk_attrs(alloc, &d.attributes, component, typescript, result, diags);
+            walk_attrs(alloc, d.attributes, component, typescript, result, diags);
         }
         Node::SvelteBody(b) => {
-            walk_attrs(alloc, &b.attributes, component, typescript, result, diags);
+            walk_attrs(alloc, b.attributes, component, typescript, result, diags);
         }
         Node::SvelteBoundary(b) => {
-            walk_attrs(alloc, &b.attributes, component, typescript, result, diags);
+            walk_attrs(alloc, b.attributes, component, typescript, result, diags);
             walk_fragment(
                 alloc, b.fragment, store, component, typescript, result, diags,
             );
         }
         Node::DebugTag(tag) => {
-            for r in &tag.identifier_refs {
+            for r in tag.identifier_refs {
                 parse_span(alloc, component, r.span, typescript, result, diags);
             }
         }
@@ -469,7 +518,7 @@ fn walk_attrs<'a>(
                 );
             }
             Attribute::ConcatenationAttribute(a) => {
-                for part in &a.parts {
+                for part in a.parts {
                     if let ConcatPart::Dynamic { expr, .. } = part {
                         parse_span(alloc, component, expr.span, typescript, result, diags);
                     }
@@ -499,7 +548,7 @@ fn walk_attrs<'a>(
                         );
                     }
                     StyleDirectiveValue::Concatenation(parts) => {
-                        for part in parts {
+                        for part in *parts {
                             if let ConcatPart::Dynamic { expr, .. } = part {
                                 parse_span(alloc, component, expr.span, typescript, result, diags);
                             }
@@ -531,7 +580,7 @@ fn walk_attrs<'a>(
                     Ok(stmt) => {
                         result.alloc_stmt(a.name_span.start, stmt);
                     }
-                    Err(diag) => diags.push(diag),
+                    Err(errs) => diags.extend(errs),
                 }
             }
             Attribute::SpreadAttribute(a) => {
@@ -0,0 +1,26 @@
+use svelte_ast::NodeId;
+use svelte_span::Span;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransformError {
+    pub node_id: Option<NodeId>,
+    pub span: Option<Span>,
+    pub message: String,
+}
+
+impl TransformError {
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.span = self
+            .span
+            .map(|span| Span::new(span.start + offset, span.end + offset));
+        self
+    }
+}
+
+impl std::fmt::Display for TransformError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "transform: {}", self.message)
+    }
+}
+
+impl std::error::Error for TransformError {}
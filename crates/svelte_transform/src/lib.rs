@@ -1,8 +1,10 @@
 mod data;
+mod error;
 pub mod rune_refs;
 pub mod transformer;
 
 pub use data::TransformData;
+pub use error::TransformError;
 
 pub use transformer::{
     IgnoreQuery, TransformScriptOutput, compute_line_col, sanitize_location, transform_script,
@@ -17,7 +19,7 @@ use svelte_ast::{Attribute, Component, ConcatPart, Node};
 pub fn transform_component<'a>(
     ctx: &mut svelte_types::CompileContext<'a, '_>,
     options: &svelte_types::TransformOptions,
-) -> TransformData {
+) -> Result<TransformData, TransformError> {
     let alloc = ctx.alloc;
     let component = ctx.component;
     let analysis = ctx.analysis;
@@ -95,11 +97,11 @@ fn walk_node<'a>(
             record_expr(ctx, parsed, &tag.expression, Some(tag.id));
         }
         Node::Element(el) => {
-            walk_attrs(ctx, &el.attributes, parsed);
+            walk_attrs(ctx, el.attributes, parsed);
             walk_fragment(ctx, el.fragment, component, parsed, scope);
         }
         Node::SlotElementLegacy(el) => {
-            walk_attrs(ctx, &el.attributes, parsed);
+            walk_attrs(ctx, el.attributes, parsed);
             walk_fragment(ctx, el.fragment, component, parsed, scope);
         }
         Node::ComponentNode(_) | Node::SvelteComponentLegacy(_) => {
@@ -167,22 +169,22 @@ fn walk_node<'a>(
             walk_fragment(ctx, head.fragment, component, parsed, child_scope);
         }
         Node::SvelteFragmentLegacy(el) => {
-            walk_attrs(ctx, &el.attributes, parsed);
+            walk_attrs(ctx, el.attributes, parsed);
             walk_fragment(ctx, el.fragment, component, parsed, scope);
         }
         Node::SvelteElement(el) => {
             if let Some(tag_ref) = el.this_expr() {
                 record_expr(ctx, parsed, tag_ref, Some(el.id));
             }
-            walk_attrs(ctx, &el.attributes, parsed);
+            walk_attrs(ctx, el.attributes, parsed);
             let child_scope = ctx.analysis.effective_fragment_scope(el.fragment, scope);
             walk_fragment(ctx, el.fragment, component, parsed, child_scope);
         }
-        Node::SvelteWindow(w) => walk_attrs(ctx, &w.attributes, parsed),
-        Node::SvelteDocument(d) => walk_attrs(ctx, &d.attributes, parsed),
-        Node::SvelteBody(b) => walk_attrs(ctx, &b.attributes, parsed),
+        Node::SvelteWindow(w) => walk_attrs(ctx, w.attributes, parsed),
+        Node::SvelteDocument(d) => walk_attrs(ctx, d.attributes, parsed),
+        Node::SvelteBody(b) => walk_attrs(ctx, b.attributes, parsed),
         Node::SvelteBoundary(b) => {
-            walk_attrs(ctx, &b.attributes, parsed);
+            walk_attrs(ctx, b.attributes, parsed);
             let child_scope = ctx.analysis.effective_fragment_scope(b.fragment, scope);
             walk_fragment(ctx, b.fragment, component, parsed, child_scope);
         }
@@ -202,7 +204,7 @@ fn walk_node<'a>(
             }
         }
         Node::DebugTag(tag) => {
-            for ident_ref in &tag.identifier_refs {
+            for ident_ref in tag.identifier_refs {
                 ctx.expr_handles.push((ident_ref.id(), Some(tag.id)));
             }
         }
@@ -229,8 +231,7 @@ fn walk_component_like<'a>(
     parsed: &mut JsAst<'a>,
     scope: ScopeId,
 ) {
-    let component_has_slot_attr =
-        attrs_static_slot_name(attributes, component.source.as_str()).is_some();
+    let component_has_slot_attr = attrs_static_slot_name(attributes, component.source).is_some();
     let default_scope = if component_has_slot_attr {
         scope
     } else {
@@ -331,7 +332,7 @@ fn walk_attrs<'a>(ctx: &mut TransformCtx<'a, '_>, attrs: &[Attribute], parsed: &
         }
 
         let concat_parts: Option<&[ConcatPart]> = match attr {
-            Attribute::ConcatenationAttribute(a) => Some(&a.parts),
+            Attribute::ConcatenationAttribute(a) => Some(a.parts),
             Attribute::StyleDirective(a) => match &a.value {
                 svelte_ast::StyleDirectiveValue::Concatenation(parts) => Some(parts),
                 _ => None,
@@ -380,7 +381,7 @@ fn get_directive_name_id(attr: &Attribute) -> Option<OxcNodeId> {
     }
 }
 
-fn attrs_static_slot_name<'a>(attrs: &'a [Attribute], source: &'a str) -> Option<&'a str> {
+fn attrs_static_slot_name<'a>(attrs: &'a [Attribute<'a>], source: &'a str) -> Option<&'a str> {
     attrs.iter().find_map(|attr| match attr {
         Attribute::StringAttribute(attr) if attr.name.as_str() == "slot" => {
             Some(&source[attr.value_span.start as usize..attr.value_span.end as usize])
@@ -413,13 +414,13 @@ mod tests {
 
     fn find_snippet_block<'a>(
         fragment_id: svelte_ast::FragmentId,
-        component: &'a Component,
+        component: &'a Component<'a>,
         name: &str,
     ) -> Option<&'a svelte_ast::SnippetBlock> {
         let nodes = component.fragment_nodes(fragment_id).to_vec();
         for id in nodes {
             match component.store.get(id) {
-                Node::SnippetBlock(block) if block.name(component.source.as_str()) == name => {
+                Node::SnippetBlock(block) if block.name(component.source) == name => {
                     return Some(block);
                 }
                 Node::IfBlock(block) => {
@@ -465,7 +466,7 @@ mod tests {
 
     fn find_expr_tag<'a>(
         fragment_id: svelte_ast::FragmentId,
-        component: &'a Component,
+        component: &'a Component<'a>,
         needle: &str,
     ) -> Option<&'a svelte_ast::ExpressionTag> {
         let nodes = component.fragment_nodes(fragment_id).to_vec();
@@ -508,7 +509,7 @@ mod tests {
             parse_diags.is_empty(),
             "unexpected parse diags: {parse_diags:?}"
         );
-        let (analysis, mut parsed, diags) = analyze(&component, js_result);
+        let (analysis, mut parsed, diags) = analyze(&component, js_result).expect("analyze");
         assert!(diags.is_empty(), "unexpected analyze diags: {diags:?}");
 
         let mut ident_gen = IdentGen::new();
@@ -519,7 +520,8 @@ mod tests {
             js_arena: &mut parsed,
             ident_gen: &mut ident_gen,
         };
-        transform_component(&mut ctx, &svelte_types::TransformOptions::default());
+        transform_component(&mut ctx, &svelte_types::TransformOptions::default())
+            .expect("transform succeeds");
 
         let snippet = find_snippet_block(component.root, &component, "withDefault")
             .unwrap_or_else(|| panic!("missing snippet"));
@@ -556,7 +558,7 @@ mod tests {
             parse_diags.is_empty(),
             "unexpected parse diags: {parse_diags:?}"
         );
-        let (analysis, mut parsed, diags) = analyze(&component, js_result);
+        let (analysis, mut parsed, diags) = analyze(&component, js_result).expect("analyze");
         assert!(diags.is_empty(), "unexpected analyze diags: {diags:?}");
 
         let mut ident_gen = IdentGen::new();
@@ -567,7 +569,8 @@ mod tests {
             js_arena: &mut parsed,
             ident_gen: &mut ident_gen,
         };
-        transform_component(&mut ctx, &svelte_types::TransformOptions::default());
+        transform_component(&mut ctx, &svelte_types::TransformOptions::default())
+            .expect("transform succeeds");
 
         let snippet = find_snippet_block(component.root, &component, "withDefault")
             .unwrap_or_else(|| panic!("missing snippet"));
@@ -581,4 +584,49 @@ mod tests {
             "unexpected transformed expr: {expr:?}"
         );
     }
+
+    #[test]
+    fn corrupted_bind_expression_returns_transform_error() {
+        let source = "<script>let value = $state('');</script><input bind:value />";
+        let alloc = Allocator::default();
+        let (component, js_result, parse_diags) = svelte_parser::parse_with_js(&alloc, source);
+        assert!(
+            parse_diags.is_empty(),
+            "unexpected parse diags: {parse_diags:?}"
+        );
+        let (analysis, mut parsed, diags) = analyze(&component, js_result).expect("analyze");
+        assert!(diags.is_empty(), "unexpected analyze diags: {diags:?}");
+
+        let input_id = component
+            .fragment_nodes(component.root)
+            .iter()
+            .copied()
+            .find(|&id| matches!(component.store.get(id), Node::Element(_)))
+            .unwrap_or_else(|| panic!("missing input element"));
+        let Some(svelte_ast::Attribute::BindDirective(bind)) = component
+            .store
+            .element(input_id)
+            .and_then(|el| el.attributes.first())
+        else {
+            panic!("missing bind directive");
+        };
+        parsed.replace_expr(
+            bind.expression.id(),
+            oxc_ast::AstBuilder::new(&alloc).expression_null_literal(oxc_span::SPAN),
+        );
+
+        let mut ident_gen = IdentGen::new();
+        let mut ctx = svelte_types::CompileContext {
+            alloc: &alloc,
+            component: &component,
+            analysis: &analysis,
+            js_arena: &mut parsed,
+            ident_gen: &mut ident_gen,
+        };
+        let err = transform_component(&mut ctx, &svelte_types::TransformOptions::default())
+            .err()
+            .unwrap_or_else(|| panic!("transform should reject a non-assignable binding"));
+        assert_eq!(err.node_id, Some(bind.id));
+        assert!(err.message.contains("not assignable"), "got: {err}");
+    }
 }
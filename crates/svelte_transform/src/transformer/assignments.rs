@@ -1,4 +1,5 @@
 use oxc_ast::ast::Expression;
+use oxc_span::GetSpan;
 use oxc_traverse::{Ancestor, TraverseCtx};
 use svelte_analyze::{ReferenceSemantics, RuneKind};
 
@@ -171,7 +172,8 @@ impl<'a> ComponentTransformer<'_, 'a> {
         segments: Vec<Expression<'a>>,
     ) {
         let Expression::AssignmentExpression(assign) = node else {
-            unreachable!();
+            self.internal_error(node.span(), "expected an assignment expression".to_string());
+            return;
         };
 
         let bindable_prop_source_root_name =
@@ -233,7 +235,9 @@ impl<'a> ComponentTransformer<'_, 'a> {
             return;
         }
 
-        let fn_name = fn_name.unwrap_or_else(|| unreachable!());
+        let Some(fn_name) = fn_name else {
+            return;
+        };
 
         let offset = self.script_content_start + left_span_start;
         let (line, col) = super::location::compute_line_col(self.component_source, offset);
@@ -244,48 +248,50 @@ impl<'a> ComponentTransformer<'_, 'a> {
             col
         );
 
-        let whole = self.b.move_expr(node);
-        let Expression::AssignmentExpression(assign_box) = whole else {
-            unreachable!();
-        };
-        let assign = assign_box.unbox();
-
-        if is_static {
-            let oxc_ast::ast::AssignmentTarget::StaticMemberExpression(m) = assign.left else {
-                unreachable!();
-            };
-            let m = m.unbox();
-            let key = self.b.str_expr(m.property.name.as_str());
-            *node = self.b.call_expr(
-                fn_name,
-                [
-                    Arg::Expr(m.object),
-                    Arg::Expr(key),
-                    Arg::Expr(assign.right),
-                    Arg::Str(loc),
-                ],
-            );
-        } else {
-            let oxc_ast::ast::AssignmentTarget::ComputedMemberExpression(m) = assign.left else {
-                unreachable!();
-            };
-            let m = m.unbox();
-            *node = self.b.call_expr(
-                fn_name,
-                [
-                    Arg::Expr(m.object),
-                    Arg::Expr(m.expression),
-                    Arg::Expr(assign.right),
-                    Arg::Str(loc),
-                ],
-            );
-        }
+        self.rewrite_member_assignment_call(node, fn_name, loc);
         if let Some(source_root_name) = bindable_prop_source_root_name {
             self.wrap_bindable_prop_source_mutation(node, &source_root_name);
         }
         self.wrap_prop_mutation_validation(node, prop_alias, root_name, segments, left_span_start);
     }
 
+    fn rewrite_member_assignment_call(
+        &mut self,
+        node: &mut Expression<'a>,
+        fn_name: &str,
+        loc: String,
+    ) {
+        let Expression::AssignmentExpression(assign) = node else {
+            self.internal_error(node.span(), "expected an assignment expression".to_string());
+            return;
+        };
+        let (object, key) = match &mut assign.left {
+            oxc_ast::ast::AssignmentTarget::StaticMemberExpression(m) => {
+                let key = self.b.str_expr(m.property.name.as_str());
+                (self.b.move_expr(&mut m.object), key)
+            }
+            oxc_ast::ast::AssignmentTarget::ComputedMemberExpression(m) => (
+                self.b.move_expr(&mut m.object),
+                self.b.move_expr(&mut m.expression),
+            ),
+            _ => {
+                let span = assign.span;
+                self.internal_error(span, "expected a member assignment target".to_string());
+                return;
+            }
+        };
+        let right = self.b.move_expr(&mut assign.right);
+        *node = self.b.call_expr(
+            fn_name,
+            [
+                Arg::Expr(object),
+                Arg::Expr(key),
+                Arg::Expr(right),
+                Arg::Str(loc),
+            ],
+        );
+    }
+
     fn finish_semantic_prop_member_update(
         &mut self,
         node: &mut Expression<'a>,
@@ -296,7 +302,8 @@ impl<'a> ComponentTransformer<'_, 'a> {
         segments: Vec<Expression<'a>>,
     ) {
         let Expression::UpdateExpression(upd) = node else {
-            unreachable!();
+            self.internal_error(node.span(), "expected an update expression".to_string());
+            return;
         };
         let span_start = upd.span.start;
 
@@ -449,15 +456,13 @@ impl<'a> ComponentTransformer<'_, 'a> {
             return;
         }
 
-        let is_identifier_target = {
-            let Expression::AssignmentExpression(assign) = &*node else {
-                unreachable!();
-            };
-            matches!(
+        let is_identifier_target = matches!(
+            &*node,
+            Expression::AssignmentExpression(assign) if matches!(
                 &assign.left,
                 oxc_ast::ast::AssignmentTarget::AssignmentTargetIdentifier(_)
             )
-        };
+        );
 
         if is_identifier_target && self.dispatch_identifier_assignment(node, false) {
             return;
@@ -506,7 +511,9 @@ impl<'a> ComponentTransformer<'_, 'a> {
             return;
         }
 
-        let fn_name = fn_name.unwrap_or_else(|| unreachable!());
+        let Some(fn_name) = fn_name else {
+            return;
+        };
 
         let offset = self.script_content_start + left_span_start;
         let (line, col) = super::location::compute_line_col(self.component_source, offset);
@@ -517,42 +524,7 @@ impl<'a> ComponentTransformer<'_, 'a> {
             col
         );
 
-        let whole = self.b.move_expr(node);
-        let Expression::AssignmentExpression(assign_box) = whole else {
-            unreachable!();
-        };
-        let assign = assign_box.unbox();
-
-        if is_static {
-            let oxc_ast::ast::AssignmentTarget::StaticMemberExpression(m) = assign.left else {
-                unreachable!();
-            };
-            let m = m.unbox();
-            let key = self.b.str_expr(m.property.name.as_str());
-            *node = self.b.call_expr(
-                fn_name,
-                [
-                    Arg::Expr(m.object),
-                    Arg::Expr(key),
-                    Arg::Expr(assign.right),
-                    Arg::Str(loc),
-                ],
-            );
-        } else {
-            let oxc_ast::ast::AssignmentTarget::ComputedMemberExpression(m) = assign.left else {
-                unreachable!();
-            };
-            let m = m.unbox();
-            *node = self.b.call_expr(
-                fn_name,
-                [
-                    Arg::Expr(m.object),
-                    Arg::Expr(m.expression),
-                    Arg::Expr(assign.right),
-                    Arg::Str(loc),
-                ],
-            );
-        }
+        self.rewrite_member_assignment_call(node, fn_name, loc);
     }
 
     pub(crate) fn transform_update(
@@ -564,15 +536,13 @@ impl<'a> ComponentTransformer<'_, 'a> {
             return;
         }
 
-        let is_identifier_target = {
-            let Expression::UpdateExpression(upd) = &*node else {
-                unreachable!();
-            };
-            matches!(
+        let is_identifier_target = matches!(
+            &*node,
+            Expression::UpdateExpression(upd) if matches!(
                 &upd.argument,
                 oxc_ast::ast::SimpleAssignmentTarget::AssignmentTargetIdentifier(_)
             )
-        };
+        );
 
         if is_identifier_target && self.dispatch_identifier_update(node) {
             return;
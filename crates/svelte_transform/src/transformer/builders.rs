@@ -252,15 +252,12 @@ impl<'a> ComponentTransformer<'_, 'a> {
         right: Expression<'a>,
     ) -> Expression<'a> {
         let ast = self.b.ast;
-        if operator.is_assign() {
-            return right;
-        }
         if let Some(bin_op) = operator.to_binary_operator() {
             ast.expression_binary(SPAN, left_read, bin_op, right)
         } else if let Some(log_op) = operator.to_logical_operator() {
             ast.expression_logical(SPAN, left_read, log_op, right)
         } else {
-            unreachable!("all compound assignment operators are either binary or logical")
+            right
         }
     }
 
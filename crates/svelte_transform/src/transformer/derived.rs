@@ -104,34 +104,27 @@ fn wrap_derived_thunks_in_stmts<'a>(
                                 call.arguments.push(arg);
                             }
                             call.callee = b.rid_expr("$.async_derived");
-                            let call_expr = b.move_expr(
-                                declarator
-                                    .init
-                                    .as_mut()
-                                    .expect("$derived declarator always has an initializer"),
-                            );
-                            declarator.init =
-                                Some(match async_mode.unwrap_or(AsyncDerivedMode::Await) {
-                                    AsyncDerivedMode::Await => b.await_expr(call_expr),
-                                    AsyncDerivedMode::Save => {
-                                        let saved = b.call_expr("$.save", [Arg::Expr(call_expr)]);
-                                        b.call_expr_callee(
-                                            b.await_expr(saved),
-                                            std::iter::empty::<Arg<'a, '_>>(),
-                                        )
-                                    }
-                                });
+                            if let Some(call_expr) = declarator.init.take() {
+                                declarator.init =
+                                    Some(match async_mode.unwrap_or(AsyncDerivedMode::Await) {
+                                        AsyncDerivedMode::Await => b.await_expr(call_expr),
+                                        AsyncDerivedMode::Save => {
+                                            let saved =
+                                                b.call_expr("$.save", [Arg::Expr(call_expr)]);
+                                            b.call_expr_callee(
+                                                b.await_expr(saved),
+                                                std::iter::empty::<Arg<'a, '_>>(),
+                                            )
+                                        }
+                                    });
+                            }
                         } else {
                             let thunk = b.thunk(arg_expr);
                             call.arguments[0] = oxc_ast::ast::Argument::from(thunk);
 
-                            if dev_ctx.is_some() {
-                                let derived_expr = b.move_expr(
-                                    declarator
-                                        .init
-                                        .as_mut()
-                                        .expect("$derived declarator always has an initializer"),
-                                );
+                            if dev_ctx.is_some()
+                                && let Some(derived_expr) = declarator.init.take()
+                            {
                                 declarator.init = Some(b.call_expr(
                                     "$.tag",
                                     [Arg::Expr(derived_expr), Arg::Str(var_name)],
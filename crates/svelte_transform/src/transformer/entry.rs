@@ -32,7 +32,7 @@ pub fn transform_script<'a, 'b>(
     experimental_async: bool,
     ignore_query: IgnoreQuery<'_, 'a>,
     prepare_semantic: bool,
-) -> TransformScriptOutput {
+) -> Result<TransformScriptOutput, crate::TransformError> {
     let is_ts = program.source_type.is_typescript();
     if prepare_semantic {
         let _ = SemanticBuilder::new().build(program);
@@ -70,10 +70,12 @@ pub fn transform_script<'a, 'b>(
         enclosing_stmt_start: Vec::new(),
         template_owner_node: None,
         in_bind_setter_traverse: false,
+        errors: Vec::new(),
     };
 
     let empty_scoping = oxc_semantic::Scoping::default();
     traverse_mut(&mut transformer, allocator, program, empty_scoping, ());
+    transformer.take_error()?;
 
     if let Some(analysis) = analysis {
         super::legacy_reactive::rewrite_legacy_reactive(b, program, analysis);
@@ -95,8 +97,8 @@ pub fn transform_script<'a, 'b>(
         );
     }
 
-    TransformScriptOutput {
+    Ok(TransformScriptOutput {
         has_tracing: transformer.has_tracing,
         needs_ownership_validator: transformer.needs_ownership_validator,
-    }
+    })
 }
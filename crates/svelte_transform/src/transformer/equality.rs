@@ -0,0 +1,30 @@
+use oxc_ast::ast::{BinaryOperator, Expression};
+
+use svelte_ast_builder::Arg;
+
+use super::model::ComponentTransformer;
+
+impl<'a> ComponentTransformer<'_, 'a> {
+    pub(crate) fn rewrite_dev_equality(&self, node: &mut Expression<'a>) {
+        if !self.dev {
+            return;
+        }
+        let Expression::BinaryExpression(binary) = node else {
+            return;
+        };
+        let (callee, negated) = match binary.operator {
+            BinaryOperator::StrictEquality => ("$.strict_equals", false),
+            BinaryOperator::StrictInequality => ("$.strict_equals", true),
+            BinaryOperator::Equality => ("$.equals", false),
+            BinaryOperator::Inequality => ("$.equals", true),
+            _ => return,
+        };
+        let left = self.b.move_expr(&mut binary.left);
+        let right = self.b.move_expr(&mut binary.right);
+        let mut args = vec![Arg::Expr(left), Arg::Expr(right)];
+        if negated {
+            args.push(Arg::Bool(false));
+        }
+        *node = self.b.call_expr(callee, args);
+    }
+}
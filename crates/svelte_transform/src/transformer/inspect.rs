@@ -57,25 +57,28 @@ impl<'a> ComponentTransformer<'_, 'a> {
         if !is_inspect_trace_call(&es.expression) {
             return;
         }
+        let trace_span = es.span;
 
-        let info = self
-            .function_info_stack
-            .last()
-            .unwrap_or_else(|| panic!("$inspect.trace() outside function"));
-
-        let trace_stmt = body.statements.remove(0);
-        let Statement::ExpressionStatement(es) = trace_stmt else {
-            unreachable!()
-        };
-        let Expression::CallExpression(call) = es.unbox().expression else {
-            unreachable!()
+        let Some(info) = self.function_info_stack.last() else {
+            self.internal_error(trace_span, "$inspect.trace() outside function".to_string());
+            return;
         };
-        let mut call = call.unbox();
 
-        let label_expr = if !call.arguments.is_empty() {
+        let label_arg = if let Some(Statement::ExpressionStatement(es)) =
+            body.statements.first_mut()
+            && let Expression::CallExpression(call) = &mut es.expression
+            && let Some(first) = call.arguments.first_mut()
+        {
             let mut dummy = oxc_ast::ast::Argument::from(self.b.cheap_expr());
-            std::mem::swap(&mut call.arguments[0], &mut dummy);
-            dummy.into_expression()
+            std::mem::swap(first, &mut dummy);
+            Some(dummy.into_expression())
+        } else {
+            None
+        };
+        body.statements.remove(0);
+
+        let label_expr = if let Some(label_arg) = label_arg {
+            label_arg
         } else {
             let func_name = info.name.as_deref().unwrap_or("trace");
             let full_offset = self.script_content_start + info.span_start;
@@ -116,8 +119,16 @@ impl<'a> ComponentTransformer<'_, 'a> {
             && let Expression::Identifier(id) = &inner_call.callee
             && matches!(id.name.as_str(), "$inspect" | "$.inspect")
         {
-            let Expression::CallExpression(outer_call) = node else {
-                unreachable!()
+            let outer_call = &mut **outer_call;
+            let thunk = if let Expression::StaticMemberExpression(member) = &mut outer_call.callee
+                && let Expression::CallExpression(inner_call) = &mut member.object
+                && let Some(first) = inner_call.arguments.first_mut()
+            {
+                let mut dummy = oxc_ast::ast::Argument::from(self.b.cheap_expr());
+                std::mem::swap(first, &mut dummy);
+                dummy.into_expression()
+            } else {
+                self.build_inspect_thunk(Vec::new())
             };
 
             let cb = if outer_call.arguments.is_empty() {
@@ -128,23 +139,6 @@ impl<'a> ComponentTransformer<'_, 'a> {
                 dummy.into_expression()
             };
 
-            let Expression::StaticMemberExpression(member) =
-                self.b.move_expr(&mut outer_call.callee)
-            else {
-                unreachable!()
-            };
-            let member = member.unbox();
-            let Expression::CallExpression(inner_call) = member.object else {
-                unreachable!()
-            };
-            let mut inner_call = inner_call.unbox();
-
-            let thunk = {
-                let mut dummy = oxc_ast::ast::Argument::from(self.b.cheap_expr());
-                std::mem::swap(&mut inner_call.arguments[0], &mut dummy);
-                dummy.into_expression()
-            };
-
             let inspector = self.build_inspect_arrow(cb);
             return Some(
                 self.b
@@ -155,10 +149,7 @@ impl<'a> ComponentTransformer<'_, 'a> {
         if let Expression::Identifier(id) = &outer_call.callee
             && id.name.as_str() == "$inspect"
         {
-            let Expression::CallExpression(call) = node else {
-                unreachable!()
-            };
-            let inspect_args: Vec<Expression<'a>> = call
+            let inspect_args: Vec<Expression<'a>> = outer_call
                 .arguments
                 .drain(..)
                 .map(|a| a.into_expression())
@@ -213,7 +204,12 @@ impl<'a> ComponentTransformer<'_, 'a> {
         let Expression::Identifier(console_id) = &member.object else {
             return None;
         };
-        if console_id.name != "console" {
+        if console_id.name != "console"
+            || self
+                .component_scoping
+                .symbol_for_identifier_reference(console_id)
+                .is_some()
+        {
             return None;
         }
         let method_name = member.property.name.as_str();
@@ -237,8 +233,10 @@ impl<'a> ComponentTransformer<'_, 'a> {
                 arg,
                 Argument::StringLiteral(_)
                     | Argument::NumericLiteral(_)
+                    | Argument::BigIntLiteral(_)
                     | Argument::BooleanLiteral(_)
                     | Argument::NullLiteral(_)
+                    | Argument::RegExpLiteral(_)
             )
         });
         if !has_potential_state {
@@ -246,9 +244,6 @@ impl<'a> ComponentTransformer<'_, 'a> {
         }
 
         let method_str = method_name.to_string();
-        let Expression::CallExpression(call) = node else {
-            unreachable!()
-        };
         let callee = self.b.move_expr(&mut call.callee);
         let mut inner_args: Vec<Arg<'a, '_>> = vec![Arg::Str(method_str)];
         for arg in call.arguments.drain(..) {
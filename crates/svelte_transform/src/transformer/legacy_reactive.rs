@@ -156,8 +156,9 @@ fn build_deps_thunk<'a>(
         return b.thunk_block(Vec::new());
     }
 
-    if dep_exprs.len() == 1 {
-        let single = dep_exprs.into_iter().next().expect("len==1");
+    if dep_exprs.len() == 1
+        && let Some(single) = dep_exprs.pop()
+    {
         return b.arrow_expr(b.no_params(), [b.expr_stmt(single)]);
     }
 
@@ -2,6 +2,7 @@ mod assignments;
 mod builders;
 mod derived;
 mod entry;
+mod equality;
 mod inspect;
 pub(crate) mod legacy_reactive;
 mod location;
@@ -367,6 +368,7 @@ impl<'a> Traverse<'a, ()> for ComponentTransformer<'_, 'a> {
                 return;
             }
             self.rewrite_dev_await_tracking(node);
+            self.rewrite_dev_equality(node);
         }
     }
 }
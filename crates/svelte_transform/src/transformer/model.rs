@@ -108,9 +108,33 @@ pub(crate) struct ComponentTransformer<'b, 'a> {
     pub(crate) template_owner_node: Option<svelte_ast::NodeId>,
 
     pub(crate) in_bind_setter_traverse: bool,
+
+    pub(crate) errors: Vec<crate::TransformError>,
 }
 
 impl<'b, 'a> ComponentTransformer<'b, 'a> {
+    pub(crate) fn internal_error(&mut self, span: oxc_span::Span, message: String) {
+        let span = match self.mode {
+            TransformMode::Template => None,
+            TransformMode::Script => Some(svelte_span::Span::shifted_from_oxc(
+                self.script_content_start,
+                span,
+            )),
+        };
+        self.errors.push(crate::TransformError {
+            node_id: self.template_owner_node,
+            span,
+            message,
+        });
+    }
+
+    pub(crate) fn take_error(&mut self) -> Result<(), crate::TransformError> {
+        match self.errors.drain(..).next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
     pub(crate) fn is_in_ignored_stmt(&self, code: &str) -> bool {
         self.enclosing_stmt_start
             .last()
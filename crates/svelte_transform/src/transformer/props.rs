@@ -78,6 +78,7 @@ impl<'b, 'a> ComponentTransformer<'b, 'a> {
                         return None;
                     };
                     let prop_name = static_prop_key_name(&prop.key)?;
+                    let prop_span = prop.span;
 
                     let (local_name, default_expr): (String, Option<Expression<'a>>) =
                         match &mut prop.value {
@@ -141,7 +142,13 @@ impl<'b, 'a> ComponentTransformer<'b, 'a> {
                                     args.push(Arg::Num(flags as f64));
 
                                     let default_expr = default_expr.unwrap_or_else(|| {
-                                        panic!("default expr missing for prop {}", local_name)
+                                        self.internal_error(
+                                            prop_span,
+                                            format!(
+                                                "default value missing for prop `{local_name}`"
+                                            ),
+                                        );
+                                        self.b.void_zero_expr()
                                     });
                                     let default_expr = if default_needs_proxy {
                                         let proxied =
@@ -183,7 +183,7 @@ impl<'a> ComponentTransformer<'_, 'a> {
         let mut declarators: Vec<(&'a str, Expression<'a>)> = vec![(tmp_name, init)];
 
         let mut array_helpers: Vec<ArrayHelper<'a>> = Vec::new();
-        let mut leaf_helpers: Vec<Option<usize>> = Vec::with_capacity(leaves.len());
+        let mut leaf_helpers: Vec<Option<(&'a str, u32)>> = Vec::with_capacity(leaves.len());
         for leaf in &leaves {
             let Some(idx) = leaf.array_index else {
                 leaf_helpers.push(None);
@@ -209,7 +209,7 @@ impl<'a> ComponentTransformer<'_, 'a> {
                     array_helpers.len() - 1
                 }
             };
-            leaf_helpers.push(Some(pos));
+            leaf_helpers.push(Some((array_helpers[pos].name, idx)));
         }
         self.ident_counter += 1;
 
@@ -234,10 +234,7 @@ impl<'a> ComponentTransformer<'_, 'a> {
             else {
                 continue;
             };
-            let source = if let Some(idx) = leaf.array_index {
-                let helper_name = array_helpers[leaf_helpers[leaf_idx]
-                    .expect("helper slot allocated above for array-index leaf")]
-                .name;
+            let source = if let Some((helper_name, idx)) = leaf_helpers[leaf_idx] {
                 let get_call = self.b.call_expr("$.get", [Arg::Ident(helper_name)]);
                 self.b
                     .computed_member_expr(get_call, self.b.num_expr(idx as f64))
@@ -312,14 +309,24 @@ impl<'a> ComponentTransformer<'_, 'a> {
             }
             PropDefaultLowering::Eager => {
                 args.push(Arg::Num(flags_bits as f64));
-                let default_expr = default_init
-                    .unwrap_or_else(|| panic!("eager default missing for legacy prop {local}"));
+                let default_expr = default_init.unwrap_or_else(|| {
+                    self.internal_error(
+                        oxc_span::SPAN,
+                        format!("default value missing for prop `{local}`"),
+                    );
+                    self.b.void_zero_expr()
+                });
                 args.push(Arg::Expr(default_expr));
             }
             PropDefaultLowering::Lazy => {
                 args.push(Arg::Num(flags_bits as f64));
-                let default_expr = default_init
-                    .unwrap_or_else(|| panic!("lazy default missing for legacy prop {local}"));
+                let default_expr = default_init.unwrap_or_else(|| {
+                    self.internal_error(
+                        oxc_span::SPAN,
+                        format!("default value missing for prop `{local}`"),
+                    );
+                    self.b.void_zero_expr()
+                });
                 let lazy = derived::wrap_lazy(self.b, default_expr);
                 args.push(Arg::Expr(lazy));
             }
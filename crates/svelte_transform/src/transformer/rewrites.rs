@@ -13,7 +13,7 @@ use super::model::ComponentTransformer;
 use crate::rune_refs;
 
 impl<'a> ComponentTransformer<'_, 'a> {
-    pub(crate) fn dispatch_identifier_read(&self, expr: &mut Expression<'a>) -> bool {
+    pub(crate) fn dispatch_identifier_read(&mut self, expr: &mut Expression<'a>) -> bool {
         let Some(analysis) = self.analysis else {
             return false;
         };
@@ -50,12 +50,13 @@ impl<'a> ComponentTransformer<'_, 'a> {
                 true
             }
             ReferenceSemantics::PropRead(PropReferenceSemantics::NonSource { symbol }) => {
-                let prop_name = analysis.binding_origin_key(symbol).unwrap_or_else(|| {
-                    panic!(
-                        "NonSource prop read missing binding origin key for ref {:?}",
-                        ref_id
-                    )
-                });
+                let Some(prop_name) = analysis.binding_origin_key(symbol) else {
+                    self.internal_error(
+                        id.span,
+                        format!("prop read `{name}` is missing its binding origin key"),
+                    );
+                    return false;
+                };
                 *expr = self.make_props_access(prop_name);
                 true
             }
@@ -864,9 +865,6 @@ impl<'a> ComponentTransformer<'_, 'a> {
                 true
             }
             ("$state", "snapshot") => {
-                let Expression::CallExpression(call) = expr else {
-                    unreachable!()
-                };
                 call.callee = self.make_dollar_member("snapshot");
                 if dev_snapshot_uncloneable_ignored {
                     call.arguments.push(oxc_ast::ast::Argument::from(
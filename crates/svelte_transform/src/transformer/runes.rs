@@ -264,7 +264,7 @@ impl<'a> ComponentTransformer<'_, 'a> {
             return;
         }
 
-        let oxc_ast::ast::Expression::CallExpression(call) = &*node else {
+        let oxc_ast::ast::Expression::CallExpression(call) = node else {
             return;
         };
         let new_callee = match &call.callee {
@@ -286,9 +286,6 @@ impl<'a> ComponentTransformer<'_, 'a> {
             _ => None,
         };
         if let Some(callee_name) = new_callee {
-            let oxc_ast::ast::Expression::CallExpression(call) = node else {
-                unreachable!()
-            };
             call.callee = self.b.rid_expr(callee_name);
         }
     }
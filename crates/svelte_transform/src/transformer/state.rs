@@ -3,6 +3,7 @@ use rustc_hash::FxHashSet;
 use oxc_allocator::CloneIn;
 use oxc_ast::NONE;
 use oxc_ast::ast::{Expression, Statement};
+use oxc_span::GetSpanMut;
 use oxc_syntax::node::NodeId as OxcNodeId;
 use svelte_analyze::RuneKind;
 
@@ -30,51 +31,54 @@ impl<'b, 'a> ComponentTransformer<'b, 'a> {
     fn rewrite_destructured_rune_decls(
         &mut self,
         stmts: &mut oxc_allocator::Vec<'a, Statement<'a>>,
-        mut predicate: impl FnMut(&oxc_ast::ast::VariableDeclarator<'a>, Option<RuneKind>) -> bool,
+        mut predicate: impl FnMut(
+            &oxc_ast::ast::BindingPattern<'a>,
+            RuneKind,
+            &oxc_ast::ast::CallExpression<'a>,
+        ) -> bool,
         mut rewrite: impl FnMut(
             &mut Self,
             oxc_ast::ast::VariableDeclarationKind,
             u32,
-            oxc_ast::ast::VariableDeclarator<'a>,
+            &oxc_ast::ast::BindingPattern<'a>,
+            oxc_allocator::Box<'a, oxc_ast::ast::CallExpression<'a>>,
             RuneKind,
         ) -> Statement<'a>,
     ) {
-        let mut i = 0;
-        while i < stmts.len() {
-            let Some((should_rewrite, rune_kind)) = (match &stmts[i] {
-                Statement::VariableDeclaration(decl) if decl.declarations.len() == 1 => {
-                    let declarator = &decl.declarations[0];
-                    let rune_kind = self.rune_kind_for_declarator(declarator);
-                    Some((predicate(declarator, rune_kind), rune_kind))
-                }
-                _ => None,
-            }) else {
-                i += 1;
+        for stmt in stmts.iter_mut() {
+            let Statement::VariableDeclaration(decl) = stmt else {
                 continue;
             };
-
-            if !should_rewrite {
-                i += 1;
+            let decl_kind = decl.kind;
+            let decl_span = decl.span;
+            let [declarator] = decl.declarations.as_mut_slice() else {
                 continue;
-            }
-
-            let stmt = stmts.remove(i);
-            let Statement::VariableDeclaration(mut decl) = stmt else {
-                unreachable!();
             };
-            let decl_kind = decl.kind;
-            let decl_span_start = decl.span.start;
-            let declarator = decl.declarations.remove(0);
-            let replacement = rewrite(
+            let Some(rune_kind) = self.rune_kind_for_declarator(declarator) else {
+                continue;
+            };
+            let call = match declarator.init.take() {
+                Some(Expression::CallExpression(call))
+                    if predicate(&declarator.id, rune_kind, &call) =>
+                {
+                    call
+                }
+                init => {
+                    declarator.init = init;
+                    continue;
+                }
+            };
+            let mut replacement = rewrite(
                 self,
                 decl_kind,
-                decl_span_start,
-                declarator,
-                rune_kind.expect("predicate returned true only for known rune kinds"),
+                decl_span.start,
+                &declarator.id,
+                call,
+                rune_kind,
             );
-            stmts.insert(i, replacement);
+            *replacement.span_mut() = decl_span;
+            *stmt = replacement;
             self.ident_counter += 1;
-            i += 1;
         }
     }
 
@@ -124,57 +128,37 @@ impl<'b, 'a> ComponentTransformer<'b, 'a> {
         let dev = self.dev;
         self.rewrite_destructured_rune_decls(
             stmts,
-            |declarator, rune_kind| {
-                !matches!(declarator.id, oxc_ast::ast::BindingPattern::BindingIdentifier(_))
-                    && matches!(rune_kind, Some(RuneKind::Derived | RuneKind::DerivedBy))
-                    && declarator.init.as_ref().is_some_and(|init| {
-                        if let Expression::CallExpression(call) = init {
-                            call.arguments.first()
-                                .and_then(|arg| arg.as_expression())
-                                .is_some_and(|expr| {
-                                    !(matches!(expr, Expression::AwaitExpression(_))
-                                        || (dev
-                                            && matches!(expr, Expression::CallExpression(c)
-                                                if c.arguments.is_empty() && matches!(&c.callee, Expression::AwaitExpression(_)))))
-                                })
-                        } else {
-                            false
-                        }
-                    })
+            |id, rune_kind, call| {
+                !matches!(id, oxc_ast::ast::BindingPattern::BindingIdentifier(_))
+                    && matches!(rune_kind, RuneKind::Derived | RuneKind::DerivedBy)
+                    && call.arguments.first()
+                        .and_then(|arg| arg.as_expression())
+                        .is_some_and(|expr| {
+                            !(matches!(expr, Expression::AwaitExpression(_))
+                                || (dev
+                                    && matches!(expr, Expression::CallExpression(c)
+                                        if c.arguments.is_empty() && matches!(&c.callee, Expression::AwaitExpression(_)))))
+                        })
             },
-            |this, decl_kind, _decl_span_start, mut declarator, rune_kind| {
-                let init = declarator
-                    .init
-                    .take()
-                    .expect("predicate matched only declarators with an initializer");
-                this.gen_sync_derived_destructuring(&declarator.id, init, rune_kind, decl_kind)
+            |this, decl_kind, _decl_span_start, id, call, rune_kind| {
+                this.gen_sync_derived_destructuring(id, call, rune_kind, decl_kind)
             },
         );
         self.rewrite_destructured_rune_decls(
             stmts,
-            |declarator, rune_kind| {
-                !matches!(declarator.id, oxc_ast::ast::BindingPattern::BindingIdentifier(_))
-                    && matches!(rune_kind, Some(RuneKind::Derived))
-                    && declarator.init.as_ref().is_some_and(|init| {
-                        if let Expression::CallExpression(call) = init {
-                            call.arguments.first()
-                                .and_then(|arg| arg.as_expression())
-                                .is_some_and(|expr| {
-                                    matches!(expr, Expression::AwaitExpression(_))
-                                    || (dev && matches!(expr, Expression::CallExpression(c)
-                                        if c.arguments.is_empty() && matches!(&c.callee, Expression::AwaitExpression(_))))
-                                })
-                        } else {
-                            false
-                        }
-                    })
+            |id, rune_kind, call| {
+                !matches!(id, oxc_ast::ast::BindingPattern::BindingIdentifier(_))
+                    && rune_kind == RuneKind::Derived
+                    && call.arguments.first()
+                        .and_then(|arg| arg.as_expression())
+                        .is_some_and(|expr| {
+                            matches!(expr, Expression::AwaitExpression(_))
+                            || (dev && matches!(expr, Expression::CallExpression(c)
+                                if c.arguments.is_empty() && matches!(&c.callee, Expression::AwaitExpression(_))))
+                        })
             },
-            |this, decl_kind, decl_span_start, mut declarator, _| {
-                let init = declarator
-                    .init
-                    .take()
-                    .expect("predicate matched only declarators with an initializer");
-                this.gen_async_derived_destructuring(&declarator.id, init, decl_span_start, decl_kind)
+            |this, decl_kind, decl_span_start, id, call, _| {
+                this.gen_async_derived_destructuring(id, call, decl_span_start, decl_kind)
             },
         );
     }
@@ -182,13 +166,10 @@ impl<'b, 'a> ComponentTransformer<'b, 'a> {
     fn gen_sync_derived_destructuring(
         &mut self,
         pattern: &oxc_ast::ast::BindingPattern<'a>,
-        init: Expression<'a>,
+        mut call: oxc_allocator::Box<'a, oxc_ast::ast::CallExpression<'a>>,
         rune_kind: RuneKind,
         decl_kind: oxc_ast::ast::VariableDeclarationKind,
     ) -> Statement<'a> {
-        let Expression::CallExpression(mut call) = init else {
-            unreachable!("sync derived destructuring should be a call");
-        };
         call.callee = self.b.rid_expr("$.derived");
 
         let mut declarators = Vec::new();
@@ -249,33 +230,22 @@ impl<'b, 'a> ComponentTransformer<'b, 'a> {
     ) {
         self.rewrite_destructured_rune_decls(
             stmts,
-            |declarator, rune_kind| {
-                !matches!(
-                    declarator.id,
-                    oxc_ast::ast::BindingPattern::BindingIdentifier(_)
-                ) && matches!(rune_kind, Some(RuneKind::State | RuneKind::StateRaw))
-                    && declarator.init.is_some()
+            |id, rune_kind, _| {
+                !matches!(id, oxc_ast::ast::BindingPattern::BindingIdentifier(_))
+                    && matches!(rune_kind, RuneKind::State | RuneKind::StateRaw)
             },
-            |this, decl_kind, _decl_span_start, mut declarator, rune_kind| {
-                let init = declarator
-                    .init
-                    .take()
-                    .expect("predicate matched only declarators with an initializer");
-                let value = if let Expression::CallExpression(mut call) = init {
-                    if call.arguments.is_empty() {
-                        this.b
-                            .ast
-                            .expression_object(oxc_span::SPAN, this.b.ast.vec())
-                    } else {
-                        let mut dummy = oxc_ast::ast::Argument::from(this.b.cheap_expr());
-                        std::mem::swap(&mut call.arguments[0], &mut dummy);
-                        dummy.into_expression()
-                    }
+            |this, decl_kind, _decl_span_start, id, mut call, rune_kind| {
+                let value = if call.arguments.is_empty() {
+                    this.b
+                        .ast
+                        .expression_object(oxc_span::SPAN, this.b.ast.vec())
                 } else {
-                    unreachable!()
+                    let mut dummy = oxc_ast::ast::Argument::from(this.b.cheap_expr());
+                    std::mem::swap(&mut call.arguments[0], &mut dummy);
+                    dummy.into_expression()
                 };
 
-                this.gen_state_destructuring(&declarator.id, value, rune_kind, decl_kind)
+                this.gen_state_destructuring(id, value, rune_kind, decl_kind)
             },
         );
     }
@@ -569,14 +539,10 @@ impl<'b, 'a> ComponentTransformer<'b, 'a> {
     fn gen_async_derived_destructuring(
         &mut self,
         pattern: &oxc_ast::ast::BindingPattern<'a>,
-        init: Expression<'a>,
+        mut call: oxc_allocator::Box<'a, oxc_ast::ast::CallExpression<'a>>,
         decl_span_start: u32,
         decl_kind: oxc_ast::ast::VariableDeclarationKind,
     ) -> Statement<'a> {
-        let Expression::CallExpression(mut call) = init else {
-            unreachable!("async derived destructuring should be a call");
-        };
-
         let init_span_start = call.span.start;
         let mut dummy = oxc_ast::ast::Argument::from(self.b.cheap_expr());
         std::mem::swap(&mut call.arguments[0], &mut dummy);
@@ -945,15 +911,12 @@ impl<'b, 'a> ComponentTransformer<'b, 'a> {
 
         let mut new_body: Vec<ClassElement<'a>> = Vec::new();
 
-        for field_info in info.fields.iter().filter(|f| {
+        for (field_info, name) in info.fields.iter().filter_map(|f| {
             f.public_name
                 .as_deref()
-                .is_some_and(|n| info.ctor_synth_names.contains(n))
+                .filter(|n| info.ctor_synth_names.contains(*n))
+                .map(|n| (f, n))
         }) {
-            let name = field_info
-                .public_name
-                .as_deref()
-                .expect("field_info with public_name is required by caller filter");
             new_body.push(self.b.class_private_field(&field_info.private_name, None));
             self.emit_getter_setter(&mut new_body, field_info, name);
         }
@@ -1046,17 +1009,16 @@ impl<'b, 'a> ComponentTransformer<'b, 'a> {
                 }
                 _ => {}
             }
-            if self.dev && rune_kind.is_some() {
+            if self.dev
+                && rune_kind.is_some()
+                && let Some(value) = prop.value.as_mut()
+            {
                 let field_name = match &prop.key {
                     oxc_ast::ast::PropertyKey::PrivateIdentifier(id) => format!("#{}", id.name),
                     _ => String::new(),
                 };
                 let label = self.class_tag_label(&field_name);
-                let value = self.b.move_expr(
-                    prop.value
-                        .as_mut()
-                        .expect("rune property definitions always carry an initializer"),
-                );
+                let value = self.b.move_expr(value);
                 prop.value = Some(
                     self.b
                         .call_expr("$.tag", [Arg::Expr(value), Arg::Str(label)]),
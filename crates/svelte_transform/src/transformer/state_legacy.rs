@@ -2,6 +2,7 @@ use oxc_allocator::{CloneIn, Vec as OxcVec};
 use oxc_ast::ast::{
     AssignmentTarget, BindingPattern, Expression, PropertyKey, Statement, VariableDeclarationKind,
 };
+use oxc_span::GetSpanMut;
 use svelte_analyze::DeclaratorSemantics;
 use svelte_ast_builder::Arg;
 use svelte_component_semantics::{Access, walk_bindings};
@@ -21,7 +22,7 @@ impl<'a> ComponentTransformer<'_, 'a> {
         };
         let mut i = 0;
         while i < stmts.len() {
-            let Statement::VariableDeclaration(decl) = &stmts[i] else {
+            let Statement::VariableDeclaration(decl) = &mut stmts[i] else {
                 i += 1;
                 continue;
             };
@@ -29,34 +30,23 @@ impl<'a> ComponentTransformer<'_, 'a> {
                 i += 1;
                 continue;
             }
-            let declarator = &decl.declarations[0];
+            let kind = decl.kind;
+            let decl_span = decl.span;
+            let declarator = &mut decl.declarations[0];
             let DeclaratorSemantics::LegacyStateDestructure { leaves } =
                 analysis.declarator_semantics(declarator.node_id())
             else {
                 i += 1;
                 continue;
             };
-            let Some(_) = declarator.init.as_ref() else {
+            let Some(leaf_paths) = collect_leaf_paths(&declarator.id) else {
                 i += 1;
                 continue;
             };
-
-            let Some(leaf_paths) = collect_leaf_paths(&declarator.id) else {
+            let Some(init) = declarator.init.take() else {
                 i += 1;
                 continue;
             };
-            let kind = decl.kind;
-
-            let stmt = stmts.remove(i);
-            let Statement::VariableDeclaration(decl_box) = stmt else {
-                unreachable!();
-            };
-            let mut decl = decl_box.unbox();
-            let mut declarator = decl.declarations.remove(0);
-            let init = declarator
-                .init
-                .take()
-                .expect("predicate matched only declarators with an init");
 
             let tmp_name_owned = self.gen_unique_name("tmp");
             let tmp_name: &'a str = self.b.alloc_str(&tmp_name_owned);
@@ -75,13 +65,14 @@ impl<'a> ComponentTransformer<'_, 'a> {
                 declarators_out.push((leaf_name, init_expr));
             }
 
-            let replacement = match kind {
+            let mut replacement = match kind {
                 VariableDeclarationKind::Let | VariableDeclarationKind::Var => {
                     self.b.let_multi_stmt(declarators_out)
                 }
                 _ => self.b.let_multi_stmt(declarators_out),
             };
-            stmts.insert(i, replacement);
+            *replacement.span_mut() = decl_span;
+            stmts[i] = replacement;
             self.ident_counter += 1;
             i += 1;
         }
@@ -114,13 +105,7 @@ impl<'a> ComponentTransformer<'_, 'a> {
             return false;
         };
         let n = leaves.len() as f64;
-        let placeholder = self.b.cheap_expr();
-        let owned = std::mem::replace(node, placeholder);
-        let Expression::AssignmentExpression(assign_box) = owned else {
-            unreachable!();
-        };
-        let assign = assign_box.unbox();
-        let rhs = assign.right;
+        let rhs = self.b.move_expr(&mut assign_box.right);
 
         let value_param = self.b.alloc_str("$$value");
         let array_var = self.b.alloc_str("$$array");
@@ -159,7 +144,7 @@ impl<'a> ComponentTransformer<'_, 'a> {
         let Some(analysis) = self.analysis else {
             return false;
         };
-        let Expression::AssignmentExpression(assign_box) = &*node else {
+        let Expression::AssignmentExpression(assign_box) = node else {
             return false;
         };
         let AssignmentTarget::ObjectAssignmentTarget(obj) = &assign_box.left else {
@@ -197,13 +182,7 @@ impl<'a> ComponentTransformer<'_, 'a> {
             return false;
         }
 
-        let placeholder = self.b.cheap_expr();
-        let owned = std::mem::replace(node, placeholder);
-        let Expression::AssignmentExpression(assign_box) = owned else {
-            unreachable!();
-        };
-        let assign = assign_box.unbox();
-        let rhs = assign.right;
+        let rhs = self.b.move_expr(&mut assign_box.right);
 
         let allocator = self.b.ast.allocator;
         let mut seq: oxc_allocator::Vec<'a, Expression<'a>> =
@@ -1,5 +1,5 @@
 use oxc_ast::ast::{BindingPattern, Statement};
-use oxc_span::{GetSpan, GetSpanMut};
+use oxc_span::GetSpanMut;
 use svelte_analyze::{BindingSemantics, StateKind};
 
 use super::inspect::{is_inspect_call, is_inspect_trace_call};
@@ -119,22 +119,15 @@ impl<'a> ComponentTransformer<'_, 'a> {
         stmts: &mut oxc_allocator::Vec<'a, oxc_ast::ast::Statement<'a>>,
     ) {
         for j in 0..stmts.len() {
-            let is_candidate = matches!(
-                &stmts[j],
-                oxc_ast::ast::Statement::VariableDeclaration(decl)
-                    if Self::is_props_declaration(decl)
-            );
-            if !is_candidate {
+            let oxc_ast::ast::Statement::VariableDeclaration(decl) = &mut stmts[j] else {
                 continue;
             };
+            if !Self::is_props_declaration(decl) {
+                continue;
+            }
 
-            let stmt_span = stmts[j].span();
-            let replacement = {
-                let oxc_ast::ast::Statement::VariableDeclaration(decl) = &mut stmts[j] else {
-                    unreachable!()
-                };
-                self.try_gen_props_declaration_semantic(decl)
-            };
+            let stmt_span = decl.span;
+            let replacement = self.try_gen_props_declaration_semantic(decl);
             if let Some(mut replacement) = replacement {
                 if let Some(first) = replacement.first_mut() {
                     *first.span_mut() = stmt_span;
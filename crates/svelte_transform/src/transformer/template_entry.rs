@@ -1,6 +1,6 @@
 use oxc_allocator::{Allocator, CloneIn};
 use oxc_ast::AstBuilder;
-use oxc_ast::ast::Statement;
+use oxc_ast::ast::{Expression, Program, Statement};
 use oxc_span::{SPAN, SourceType};
 use oxc_traverse::ReusableTraverseCtx;
 
@@ -10,6 +10,7 @@ use svelte_analyze::{AnalysisData, ComponentScoping, JsAst};
 use svelte_ast_builder::Builder;
 
 use super::model::{ComponentTransformer, IgnoreQuery, TransformMode};
+use crate::TransformError;
 use crate::data::TransformData;
 
 pub(crate) fn run_template<'a>(
@@ -22,7 +23,7 @@ pub(crate) fn run_template<'a>(
     transform_data: TransformData,
     parsed: &mut JsAst<'a>,
     dev: bool,
-) -> TransformData {
+) -> Result<TransformData, TransformError> {
     let b = Builder::new(alloc);
     let is_ts = parsed.typescript;
 
@@ -58,6 +59,7 @@ pub(crate) fn run_template<'a>(
         enclosing_stmt_start: Vec::new(),
         template_owner_node: None,
         in_bind_setter_traverse: false,
+        errors: Vec::new(),
     };
 
     let ast = AstBuilder::new(alloc);
@@ -87,14 +89,9 @@ pub(crate) fn run_template<'a>(
 
         oxc_traverse::traverse_mut_with_ctx(&mut transformer, &mut program, &mut reusable);
 
-        let Statement::ExpressionStatement(es) = program
-            .body
-            .pop()
-            .expect("body was pushed with a single statement above")
-        else {
-            unreachable!()
-        };
-        parsed.replace_expr(handle, es.unbox().expression);
+        transformer.take_error()?;
+        let expr = pop_expression(&mut program, owner)?;
+        parsed.replace_expr(handle, expr);
     }
 
     for (handle, owner) in stmt_handles {
@@ -107,13 +104,12 @@ pub(crate) fn run_template<'a>(
 
         oxc_traverse::traverse_mut_with_ctx(&mut transformer, &mut program, &mut reusable);
 
-        parsed.replace_stmt(
-            handle,
-            program
-                .body
-                .pop()
-                .expect("body was pushed with a single statement above"),
-        );
+        transformer.take_error()?;
+        let stmt = program
+            .body
+            .pop()
+            .ok_or_else(|| missing_statement_error(owner))?;
+        parsed.replace_stmt(handle, stmt);
     }
 
     for (handle, owner) in bind_expr_handles {
@@ -128,17 +124,17 @@ pub(crate) fn run_template<'a>(
         program.body.clear();
         program.body.push(ast.statement_expression(SPAN, orig));
         oxc_traverse::traverse_mut_with_ctx(&mut transformer, &mut program, &mut reusable);
-        let Statement::ExpressionStatement(es) = program
-            .body
-            .pop()
-            .expect("body was pushed with a single statement above")
-        else {
-            unreachable!()
-        };
-        let getter_body = es.unbox().expression;
+        transformer.take_error()?;
+        let getter_body = pop_expression(&mut program, Some(owner))?;
 
         let value_ident = b.rid_expr("$$value");
-        let assign_target = b.expr_to_assignment_target(setter_lhs_expr);
+        let assign_target = b
+            .expr_to_assignment_target(setter_lhs_expr)
+            .ok_or_else(|| TransformError {
+                node_id: Some(owner),
+                span: None,
+                message: "bind expression is not assignable".to_string(),
+            })?;
         let assign_expr = b.assign_expr_raw(assign_target, value_ident);
 
         program.body.clear();
@@ -148,14 +144,8 @@ pub(crate) fn run_template<'a>(
         transformer.in_bind_setter_traverse = true;
         oxc_traverse::traverse_mut_with_ctx(&mut transformer, &mut program, &mut reusable);
         transformer.in_bind_setter_traverse = false;
-        let Statement::ExpressionStatement(es) = program
-            .body
-            .pop()
-            .expect("body was pushed with a single statement above")
-        else {
-            unreachable!()
-        };
-        let setter_body = es.unbox().expression;
+        transformer.take_error()?;
+        let setter_body = pop_expression(&mut program, Some(owner))?;
 
         let (getter, setter) = if dev {
             (
@@ -183,5 +173,23 @@ pub(crate) fn run_template<'a>(
         parsed.replace_expr(handle, seq);
     }
 
-    transformer.transform_data
+    Ok(transformer.transform_data)
+}
+
+fn pop_expression<'a>(
+    program: &mut Program<'a>,
+    owner: Option<svelte_ast::NodeId>,
+) -> Result<Expression<'a>, TransformError> {
+    match program.body.pop() {
+        Some(Statement::ExpressionStatement(es)) => Ok(es.unbox().expression),
+        _ => Err(missing_statement_error(owner)),
+    }
+}
+
+fn missing_statement_error(owner: Option<svelte_ast::NodeId>) -> TransformError {
+    TransformError {
+        node_id: owner,
+        span: None,
+        message: "template expression was lost during transform".to_string(),
+    }
 }
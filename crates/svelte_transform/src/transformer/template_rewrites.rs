@@ -36,8 +36,17 @@ pub(crate) fn rewrite_template_exit<'a>(
     it: &mut Expression<'a>,
 ) {
     t.rewrite_shared_call(it, false);
+    t.rewrite_dev_equality(it);
+    if t.dev
+        && let Some(replacement) = t.transform_console_log(it)
+    {
+        *it = replacement;
+        return;
+    }
 
-    let analysis = t.analysis.expect("Template mode requires analysis");
+    let Some(analysis) = t.analysis else {
+        return;
+    };
 
     if let Expression::AwaitExpression(await_expr) = it {
         let ignored = t.template_owner_node.is_some_and(|id| {
@@ -57,9 +66,6 @@ pub(crate) fn rewrite_template_exit<'a>(
         if is_pickled {
             let save_call = t.make_dollar_call("save", arg);
             await_expr.argument = save_call;
-            let Expression::AwaitExpression(_) = &*it else {
-                unreachable!()
-            };
             let awaited =
                 std::mem::replace(it, ast.expression_identifier(oxc_span::SPAN, ast.atom("")));
             *it = ast.expression_call(oxc_span::SPAN, awaited, oxc_ast::NONE, ast.vec(), false);
@@ -67,9 +73,6 @@ pub(crate) fn rewrite_template_exit<'a>(
         } else if t.dev && !ignored {
             let track_call = t.make_dollar_call("track_reactivity_loss", arg);
             await_expr.argument = track_call;
-            let Expression::AwaitExpression(_) = &*it else {
-                unreachable!()
-            };
             let awaited =
                 std::mem::replace(it, ast.expression_identifier(oxc_span::SPAN, ast.atom("")));
             *it = ast.expression_call(oxc_span::SPAN, awaited, oxc_ast::NONE, ast.vec(), false);
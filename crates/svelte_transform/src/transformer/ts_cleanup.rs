@@ -172,24 +172,17 @@ impl<'a> ComponentTransformer<'_, 'a> {
         if self.is_ts {
             #[allow(clippy::while_let_loop)]
             loop {
-                match node {
-                    Expression::TSAsExpression(_)
-                    | Expression::TSSatisfiesExpression(_)
-                    | Expression::TSNonNullExpression(_)
-                    | Expression::TSTypeAssertion(_)
-                    | Expression::TSInstantiationExpression(_) => {
-                        let inner = match self.b.move_expr(node) {
-                            Expression::TSAsExpression(ts) => ts.unbox().expression,
-                            Expression::TSSatisfiesExpression(ts) => ts.unbox().expression,
-                            Expression::TSNonNullExpression(ts) => ts.unbox().expression,
-                            Expression::TSTypeAssertion(ts) => ts.unbox().expression,
-                            Expression::TSInstantiationExpression(ts) => ts.unbox().expression,
-                            _ => unreachable!(),
-                        };
-                        *node = inner;
+                let inner = match node {
+                    Expression::TSAsExpression(ts) => self.b.move_expr(&mut ts.expression),
+                    Expression::TSSatisfiesExpression(ts) => self.b.move_expr(&mut ts.expression),
+                    Expression::TSNonNullExpression(ts) => self.b.move_expr(&mut ts.expression),
+                    Expression::TSTypeAssertion(ts) => self.b.move_expr(&mut ts.expression),
+                    Expression::TSInstantiationExpression(ts) => {
+                        self.b.move_expr(&mut ts.expression)
                     }
                     _ => break,
-                }
+                };
+                *node = inner;
             }
         }
     }
@@ -6,20 +6,37 @@ use svelte_css::{
 };
 use svelte_span::Span;
 
+mod lowering;
+mod target;
+
+pub use target::CssTarget;
+
 pub fn transform_css(
     hash_class: &str,
     keyframes: &[CompactString],
     stylesheet: StyleSheet,
     source: &str,
 ) -> String {
-    transform_css_with_usage(hash_class, keyframes, None, false, stylesheet, source)
+    transform_css_with_usage(
+        hash_class,
+        keyframes,
+        None,
+        false,
+        &CssTarget::default(),
+        false,
+        stylesheet,
+        source,
+    )
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn transform_css_with_usage(
     hash_class: &str,
     keyframes: &[CompactString],
     used_selectors: Option<&FxHashSet<CssNodeId>>,
     remove_unused: bool,
+    target: &CssTarget,
+    minify: bool,
     mut stylesheet: StyleSheet,
     source: &str,
 ) -> String {
@@ -34,7 +51,10 @@ pub fn transform_css_with_usage(
         rule_depth: 0,
     };
     scoper.visit_stylesheet_mut(&mut stylesheet);
-    if let Some(used_selectors) = used_selectors {
+    lowering::lower_for_target(&mut stylesheet, target);
+    if minify {
+        svelte_css::Printer::print_minified(&stylesheet, source, used_selectors)
+    } else if let Some(used_selectors) = used_selectors {
         svelte_css::Printer::print_with_usage(&stylesheet, source, used_selectors, remove_unused)
     } else {
         svelte_css::Printer::print(&stylesheet, source)
@@ -753,7 +773,16 @@ mod tests {
         let selector_ids = top_level_selector_ids(&ss);
         let used = FxHashSet::from_iter([selector_ids[0], selector_ids[2]]);
 
-        let result = transform_css_with_usage("svelte-abc123", &[], Some(&used), true, ss, source);
+        let result = transform_css_with_usage(
+            "svelte-abc123",
+            &[],
+            Some(&used),
+            true,
+            &CssTarget::default(),
+            false,
+            ss,
+            source,
+        );
 
         assert!(result.contains(".used.svelte-abc123"), "got: {result}");
         assert!(
@@ -773,7 +802,16 @@ mod tests {
         let selector_ids = top_level_selector_ids(&ss);
         let used = FxHashSet::from_iter([selector_ids[0], selector_ids[2]]);
 
-        let result = transform_css_with_usage("svelte-abc123", &[], Some(&used), true, ss, source);
+        let result = transform_css_with_usage(
+            "svelte-abc123",
+            &[],
+            Some(&used),
+            true,
+            &CssTarget::default(),
+            false,
+            ss,
+            source,
+        );
 
         assert!(result.contains(".used.svelte-abc123"), "got: {result}");
         assert!(
@@ -0,0 +1,583 @@
+use compact_str::CompactString;
+use svelte_css::{
+    AtRule, Block, BlockChild, Combinator, CombinatorKind, ComplexSelector, CssNodeId,
+    RelativeSelector, RelativeSelectorVec, Rule, SelectorList, SimpleSelector, SimpleSelectorVec,
+    StyleRule, StyleSheet, StyleSheetChild, VisitMut,
+};
+use svelte_span::Span;
+
+use crate::CssTarget;
+
+const MAX_IS_EXPANSION: usize = 64;
+
+pub(crate) fn lower_for_target(stylesheet: &mut StyleSheet, target: &CssTarget) {
+    if target.lowers_nesting() {
+        let children = std::mem::take(&mut stylesheet.children);
+        for child in children {
+            match child {
+                StyleSheetChild::Rule(rule) => {
+                    let mut out = Vec::new();
+                    flatten_rule(rule, None, &mut out);
+                    stylesheet
+                        .children
+                        .extend(out.into_iter().map(StyleSheetChild::Rule));
+                }
+                other => stylesheet.children.push(other),
+            }
+        }
+    }
+    if target.lowers_is_selector() {
+        ExpandIsSelectors.visit_stylesheet_mut(stylesheet);
+    }
+}
+
+struct ParentRule<'p> {
+    id: CssNodeId,
+    span: Span,
+    prelude: &'p SelectorList,
+}
+
+fn flatten_rule(rule: Rule, parent: Option<&ParentRule<'_>>, out: &mut Vec<Rule>) {
+    match rule {
+        Rule::Style(style) => {
+            let StyleRule {
+                id,
+                span,
+                prelude,
+                block,
+            } = *style;
+            let prelude = match parent {
+                Some(parent) => resolve_nested_list(&prelude, parent.prelude),
+                None => prelude,
+            };
+            let current = ParentRule {
+                id,
+                span,
+                prelude: &prelude,
+            };
+            flatten_block(block, &current, out);
+        }
+        Rule::AtRule(mut at_rule) => {
+            if !is_keyframes(&at_rule)
+                && let Some(block) = at_rule.block.take()
+            {
+                at_rule.block = Some(flatten_at_rule_block(block, parent));
+            }
+            out.push(Rule::AtRule(at_rule));
+        }
+    }
+}
+
+fn flatten_block(block: Block, rule: &ParentRule<'_>, out: &mut Vec<Rule>) {
+    let block_span = block.span;
+    let mut pending: Vec<BlockChild> = Vec::new();
+    let mut emitted = false;
+    for child in block.children {
+        match child {
+            BlockChild::Rule(nested) => {
+                emitted |= flush_declarations(&mut pending, rule, block_span, false, out);
+                flatten_rule(nested, Some(rule), out);
+            }
+            other => pending.push(other),
+        }
+    }
+    flush_declarations(&mut pending, rule, block_span, !emitted, out);
+}
+
+fn flush_declarations(
+    pending: &mut Vec<BlockChild>,
+    rule: &ParentRule<'_>,
+    block_span: Span,
+    keep_empty: bool,
+    out: &mut Vec<Rule>,
+) -> bool {
+    let has_declarations = pending
+        .iter()
+        .any(|child| matches!(child, BlockChild::Declaration(_)));
+    if !has_declarations && !keep_empty {
+        pending.clear();
+        return false;
+    }
+    out.push(Rule::Style(Box::new(StyleRule {
+        id: rule.id,
+        span: rule.span,
+        prelude: rule.prelude.clone(),
+        block: Block {
+            span: block_span,
+            children: std::mem::take(pending),
+        },
+    })));
+    true
+}
+
+fn flatten_at_rule_block(block: Block, parent: Option<&ParentRule<'_>>) -> Block {
+    let Block { span, children } = block;
+    let mut out = Vec::new();
+    match parent {
+        Some(parent) => flatten_block(Block { span, children }, parent, &mut out),
+        None => {
+            let mut flattened = Vec::with_capacity(children.len());
+            for child in children {
+                match child {
+                    BlockChild::Rule(rule) => {
+                        flatten_rule(rule, None, &mut out);
+                        flattened.extend(out.drain(..).map(BlockChild::Rule));
+                    }
+                    other => flattened.push(other),
+                }
+            }
+            return Block {
+                span,
+                children: flattened,
+            };
+        }
+    }
+    Block {
+        span,
+        children: out.into_iter().map(BlockChild::Rule).collect(),
+    }
+}
+
+fn is_keyframes(rule: &AtRule) -> bool {
+    rule.name == "keyframes"
+        || rule
+            .name
+            .strip_prefix('-')
+            .and_then(|s| s.split_once('-'))
+            .is_some_and(|(_, rest)| rest == "keyframes")
+}
+
+fn resolve_nested_list(child: &SelectorList, parent: &SelectorList) -> SelectorList {
+    let mut children = svelte_css::SelectorVec::new();
+    for complex in &child.children {
+        let explicit = complex_has_nesting(complex);
+        for parent_complex in &parent.children {
+            children.push(if explicit {
+                replace_nesting(complex, parent_complex, parent)
+            } else {
+                prefix_with_parent(complex, parent_complex)
+            });
+        }
+    }
+    SelectorList {
+        span: child.span,
+        children,
+    }
+}
+
+fn prefix_with_parent(complex: &ComplexSelector, parent: &ComplexSelector) -> ComplexSelector {
+    let mut children = parent.children.clone();
+    for (idx, rel) in complex.children.iter().enumerate() {
+        let mut rel = rel.clone();
+        if idx == 0 && rel.combinator.is_none() {
+            rel.combinator = Some(descendant());
+        }
+        children.push(rel);
+    }
+    ComplexSelector {
+        id: complex.id,
+        span: complex.span,
+        children,
+    }
+}
+
+fn replace_nesting(
+    complex: &ComplexSelector,
+    parent: &ComplexSelector,
+    parent_list: &SelectorList,
+) -> ComplexSelector {
+    let mut children = RelativeSelectorVec::new();
+    for rel in &complex.children {
+        let mut rel = rel.clone();
+        for simple in &mut rel.selectors {
+            replace_nesting_in_args(simple, parent_list);
+        }
+        if !rel
+            .selectors
+            .iter()
+            .any(|sel| matches!(sel, SimpleSelector::Nesting(_)))
+        {
+            if !children.is_empty() && rel.combinator.is_none() {
+                rel.combinator = Some(descendant());
+            }
+            children.push(rel);
+            continue;
+        }
+
+        let mut substituted = parent.children.clone();
+        if let Some(first) = substituted.first_mut()
+            && !children.is_empty()
+        {
+            first.combinator = Some(rel.combinator.unwrap_or_else(descendant));
+        }
+        if let Some(last) = substituted.last_mut() {
+            let extra = rel
+                .selectors
+                .into_iter()
+                .filter(|sel| !matches!(sel, SimpleSelector::Nesting(_)));
+            last.selectors = merge_compound(std::mem::take(&mut last.selectors), extra);
+        }
+        children.extend(substituted);
+    }
+    ComplexSelector {
+        id: complex.id,
+        span: complex.span,
+        children,
+    }
+}
+
+fn replace_nesting_in_args(simple: &mut SimpleSelector, parent_list: &SelectorList) {
+    let args = match simple {
+        SimpleSelector::PseudoClass(pc) => pc.args.as_deref_mut(),
+        SimpleSelector::Global { args, .. } => args.as_deref_mut(),
+        _ => None,
+    };
+    let Some(args) = args else {
+        return;
+    };
+    if !args.children.iter().any(complex_has_nesting) {
+        return;
+    }
+    let mut children = svelte_css::SelectorVec::new();
+    for complex in &args.children {
+        if complex_has_nesting(complex) {
+            for parent in &parent_list.children {
+                children.push(replace_nesting(complex, parent, parent_list));
+            }
+        } else {
+            children.push(complex.clone());
+        }
+    }
+    args.children = children;
+}
+
+fn merge_compound(
+    base: SimpleSelectorVec,
+    extra: impl IntoIterator<Item = SimpleSelector>,
+) -> SimpleSelectorVec {
+    let mut merged = base;
+    for sel in extra {
+        if matches!(sel, SimpleSelector::Type { .. }) {
+            merged.insert(0, sel);
+        } else {
+            merged.push(sel);
+        }
+    }
+    merged
+}
+
+fn complex_has_nesting(complex: &ComplexSelector) -> bool {
+    complex
+        .children
+        .iter()
+        .flat_map(|rel| rel.selectors.iter())
+        .any(simple_has_nesting)
+}
+
+fn simple_has_nesting(simple: &SimpleSelector) -> bool {
+    match simple {
+        SimpleSelector::Nesting(_) => true,
+        SimpleSelector::PseudoClass(pc) => pc
+            .args
+            .as_ref()
+            .is_some_and(|args| args.children.iter().any(complex_has_nesting)),
+        SimpleSelector::Global {
+            args: Some(args), ..
+        } => args.children.iter().any(complex_has_nesting),
+        _ => false,
+    }
+}
+
+fn descendant() -> Combinator {
+    Combinator {
+        span: Span::new(0, 0),
+        kind: CombinatorKind::Descendant,
+    }
+}
+
+struct ExpandIsSelectors;
+
+impl VisitMut for ExpandIsSelectors {
+    fn visit_style_rule_mut(&mut self, node: &mut StyleRule) {
+        node.prelude.children = node
+            .prelude
+            .children
+            .iter()
+            .flat_map(expand_complex)
+            .collect();
+        self.visit_block_mut(&mut node.block);
+    }
+
+    fn visit_at_rule_mut(&mut self, node: &mut AtRule) {
+        if is_keyframes(node) {
+            return;
+        }
+        svelte_css::visit::walk_at_rule_mut(self, node);
+    }
+}
+
+fn expand_complex(complex: &ComplexSelector) -> Vec<ComplexSelector> {
+    let mut results: Vec<RelativeSelectorVec> = vec![RelativeSelectorVec::new()];
+    for rel in &complex.children {
+        let Some(compounds) = expand_compound(&rel.selectors) else {
+            return vec![complex.clone()];
+        };
+        if results.len() * compounds.len() > MAX_IS_EXPANSION {
+            return vec![complex.clone()];
+        }
+        results = results
+            .iter()
+            .flat_map(|prefix| {
+                compounds.iter().map(move |compound| {
+                    let mut next = prefix.clone();
+                    next.push(RelativeSelector {
+                        id: rel.id,
+                        span: rel.span,
+                        combinator: rel.combinator,
+                        selectors: compound.clone(),
+                    });
+                    next
+                })
+            })
+            .collect();
+    }
+    results
+        .into_iter()
+        .map(|children| ComplexSelector {
+            id: complex.id,
+            span: complex.span,
+            children,
+        })
+        .collect()
+}
+
+fn expand_compound(selectors: &SimpleSelectorVec) -> Option<Vec<SimpleSelectorVec>> {
+    let mut compounds: Vec<SimpleSelectorVec> = vec![SimpleSelectorVec::new()];
+    for simple in selectors {
+        let alternatives = match simple {
+            SimpleSelector::PseudoClass(pc) if is_forgiving_pseudo(&pc.name) => {
+                let args = pc.args.as_deref()?;
+                let mut alternatives = Vec::new();
+                for arg in &args.children {
+                    let [rel] = arg.children.as_slice() else {
+                        return None;
+                    };
+                    alternatives.extend(expand_compound(&rel.selectors)?);
+                }
+                alternatives
+            }
+            other => vec![SimpleSelectorVec::from_iter([other.clone()])],
+        };
+        if compounds.len() * alternatives.len() > MAX_IS_EXPANSION {
+            return None;
+        }
+        compounds = compounds
+            .iter()
+            .flat_map(|prefix| {
+                alternatives
+                    .iter()
+                    .filter_map(move |alt| combine_compound(prefix, alt))
+            })
+            .collect();
+    }
+    Some(compounds)
+}
+
+fn combine_compound(
+    prefix: &SimpleSelectorVec,
+    extra: &SimpleSelectorVec,
+) -> Option<SimpleSelectorVec> {
+    let prefix_type = type_name(prefix);
+    let extra_type = type_name(extra);
+    let mut combined = SimpleSelectorVec::new();
+    match (prefix_type, extra_type) {
+        (Some(a), Some(b)) if a != b && a != "*" && b != "*" => return None,
+        (Some("*"), Some(_)) => {
+            combined.extend(extra.iter().filter(|s| is_type(s)).cloned());
+            combined.extend(prefix.iter().filter(|s| !is_type(s)).cloned());
+            combined.extend(extra.iter().filter(|s| !is_type(s)).cloned());
+            return Some(combined);
+        }
+        (Some(_), Some(_)) => {
+            combined.extend(prefix.iter().cloned());
+            combined.extend(extra.iter().filter(|s| !is_type(s)).cloned());
+            return Some(combined);
+        }
+        _ => {}
+    }
+    combined.extend(extra.iter().filter(|s| is_type(s)).cloned());
+    combined.extend(prefix.iter().cloned());
+    combined.extend(extra.iter().filter(|s| !is_type(s)).cloned());
+    Some(combined)
+}
+
+fn type_name(compound: &SimpleSelectorVec) -> Option<&str> {
+    compound.iter().find_map(|sel| match sel {
+        SimpleSelector::Type { name, .. } => Some(name.as_str()),
+        _ => None,
+    })
+}
+
+fn is_type(sel: &SimpleSelector) -> bool {
+    matches!(sel, SimpleSelector::Type { .. })
+}
+
+/// `:where()` is expanded like `:is()`: targets without `:is()` lack `:where()`
+/// too and would drop the whole rule, so its zero specificity is given up.
+fn is_forgiving_pseudo(name: &CompactString) -> bool {
+    matches!(name.as_str(), "is" | "where" | "matches" | "any")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CssTarget, transform_css_with_usage};
+
+    fn lower(source: &str, query: &str) -> String {
+        let (ss, diags) = svelte_css::parse(source);
+        assert!(diags.is_empty(), "unexpected css diagnostics: {diags:?}");
+        let target = CssTarget::parse(query).expect("valid target");
+        transform_css_with_usage("svelte-abc", &[], None, false, &target, false, ss, source)
+    }
+
+    fn compact(css: &str) -> String {
+        css.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    #[test]
+    fn modern_target_keeps_nesting() {
+        let result = lower("div { span { color: red; } }", "chrome 124");
+        assert!(
+            result.contains("span:where(.svelte-abc) {"),
+            "got: {result}"
+        );
+        assert!(!result.contains("div.svelte-abc span"), "got: {result}");
+    }
+
+    #[test]
+    fn implicit_nesting_becomes_descendant() {
+        let result = compact(&lower(
+            "div { color: red; span { color: blue; } }",
+            "safari 16",
+        ));
+        assert_eq!(
+            result,
+            "div.svelte-abc { color: red; } div.svelte-abc span:where(.svelte-abc) { color: blue; }"
+        );
+    }
+
+    #[test]
+    fn explicit_nesting_selector_is_substituted() {
+        let result = compact(&lower(
+            "div { &:hover { a: 1; } & > p { b: 2; } }",
+            "safari 16",
+        ));
+        assert!(
+            result.contains("div.svelte-abc:hover { a: 1; }"),
+            "got: {result}"
+        );
+        assert!(
+            result.contains("div.svelte-abc > p:where(.svelte-abc) { b: 2; }"),
+            "got: {result}"
+        );
+    }
+
+    #[test]
+    fn global_prefix_before_nesting_selector() {
+        let result = compact(&lower(
+            "div { :global(.dark) & { color: white; } }",
+            "safari 16",
+        ));
+        assert!(
+            result.contains(".dark div.svelte-abc { color: white; }"),
+            "got: {result}"
+        );
+    }
+
+    #[test]
+    fn parent_selector_list_is_expanded() {
+        let result = compact(&lower("h1, h2 { b { x: 1; } }", "safari 16"));
+        assert!(
+            result.contains(
+                "h1.svelte-abc b:where(.svelte-abc), h2.svelte-abc b:where(.svelte-abc) { x: 1; }"
+            ),
+            "got: {result}"
+        );
+    }
+
+    #[test]
+    fn declarations_keep_order_around_nested_rules() {
+        let result = compact(&lower(
+            "div { color: red; p { x: 1; } color: blue; }",
+            "safari 16",
+        ));
+        assert_eq!(
+            result,
+            "div.svelte-abc { color: red; } div.svelte-abc p:where(.svelte-abc) { x: 1; } div.svelte-abc { color: blue; }"
+        );
+    }
+
+    #[test]
+    fn nested_media_is_hoisted_with_parent_selector() {
+        let result = compact(&lower(
+            "div { @media (min-width: 1px) { color: green; em { k: 1; } } }",
+            "safari 16",
+        ));
+        assert!(
+            result.contains(
+                "@media (min-width: 1px) { div.svelte-abc { color: green; } div.svelte-abc em:where(.svelte-abc) { k: 1; } }"
+            ),
+            "got: {result}"
+        );
+    }
+
+    #[test]
+    fn global_block_inside_rule_is_flattened_unscoped() {
+        let result = compact(&lower(".foo { :global { .bar { a: b; } } }", "safari 16"));
+        assert!(
+            result.contains(".foo.svelte-abc .bar { a: b; }"),
+            "got: {result}"
+        );
+    }
+
+    #[test]
+    fn is_selector_is_expanded_for_old_targets() {
+        let result = compact(&lower(
+            ":is(h1, h2) span { color: red; } div { p { x: 1; } }",
+            "chrome 80",
+        ));
+        assert!(
+            result.contains("h1.svelte-abc span.svelte-abc, h2.svelte-abc span.svelte-abc"),
+            "got: {result}"
+        );
+        assert!(
+            result.contains("div.svelte-abc p.svelte-abc { x: 1; }"),
+            "got: {result}"
+        );
+        assert!(!result.contains(":where("), "got: {result}");
+    }
+
+    #[test]
+    fn where_selector_is_expanded_for_old_targets() {
+        let result = lower(":where(h1, h2) span { color: red; }", "chrome 80");
+        assert!(
+            result.contains("h1.svelte-abc span.svelte-abc, h2.svelte-abc span.svelte-abc"),
+            "got: {result}"
+        );
+        assert!(!result.contains(":where("), "got: {result}");
+    }
+
+    #[test]
+    fn where_selector_is_kept_when_is_is_supported() {
+        let result = lower(":where(h1, h2) span { color: red; }", "safari 16");
+        assert!(
+            result.contains(":where(h1.svelte-abc, h2.svelte-abc) span.svelte-abc"),
+            "got: {result}"
+        );
+    }
+
+    #[test]
+    fn complex_is_arguments_are_left_alone() {
+        let result = lower(":is(.a .b) { color: red; }", "chrome 80");
+        assert!(result.contains(":is("), "got: {result}");
+    }
+}
@@ -0,0 +1,177 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Browser {
+    Chrome,
+    Edge,
+    Firefox,
+    Safari,
+    IosSafari,
+    Opera,
+    Samsung,
+    Ie,
+}
+
+impl Browser {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "chrome" | "and_chr" => Self::Chrome,
+            "edge" => Self::Edge,
+            "firefox" | "ff" | "and_ff" => Self::Firefox,
+            "safari" => Self::Safari,
+            "ios_saf" | "ios" => Self::IosSafari,
+            "opera" => Self::Opera,
+            "samsung" => Self::Samsung,
+            "ie" | "explorer" => Self::Ie,
+            _ => return None,
+        })
+    }
+
+    fn nesting_since(self) -> Option<Version> {
+        match self {
+            Self::Chrome | Self::Edge => Some(Version::new(120, 0)),
+            Self::Firefox => Some(Version::new(117, 0)),
+            Self::Safari | Self::IosSafari => Some(Version::new(17, 2)),
+            Self::Opera => Some(Version::new(106, 0)),
+            Self::Samsung => Some(Version::new(25, 0)),
+            Self::Ie => None,
+        }
+    }
+
+    fn is_selector_since(self) -> Option<Version> {
+        match self {
+            Self::Chrome | Self::Edge => Some(Version::new(88, 0)),
+            Self::Firefox => Some(Version::new(78, 0)),
+            Self::Safari | Self::IosSafari => Some(Version::new(14, 0)),
+            Self::Opera => Some(Version::new(74, 0)),
+            Self::Samsung => Some(Version::new(15, 0)),
+            Self::Ie => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Version {
+    major: u32,
+    minor: u32,
+}
+
+impl Version {
+    const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        let (major, minor) = raw.split_once('.').unwrap_or((raw, "0"));
+        let minor = minor.split('.').next().unwrap_or("0");
+        Some(Self::new(major.parse().ok()?, minor.parse().ok()?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BrowserVersion {
+    browser: Browser,
+    version: Version,
+}
+
+impl BrowserVersion {
+    fn lacks(self, since: fn(Browser) -> Option<Version>) -> bool {
+        since(self.browser).is_none_or(|min| self.version < min)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CssTarget {
+    browsers: Vec<BrowserVersion>,
+}
+
+impl CssTarget {
+    pub fn parse(query: &str) -> Result<Self, String> {
+        let mut browsers = Vec::new();
+        for entry in query.split([',', '\n']) {
+            let entry = entry.trim().to_ascii_lowercase();
+            if entry.is_empty() {
+                continue;
+            }
+            let mut parts = entry.split_whitespace();
+            let name = parts.next().unwrap_or_default();
+            let browser = Browser::from_name(name)
+                .ok_or_else(|| format!("unknown browser `{name}` in css target `{entry}`"))?;
+            let version = match (parts.next(), parts.next()) {
+                (Some(">="), Some(version)) | (Some(version), None) => {
+                    version.strip_prefix(">=").unwrap_or(version)
+                }
+                _ => return Err(format!("unsupported css target query `{entry}`")),
+            };
+            let version = Version::parse(version)
+                .ok_or_else(|| format!("invalid browser version in css target `{entry}`"))?;
+            browsers.push(BrowserVersion { browser, version });
+        }
+        Ok(Self { browsers })
+    }
+
+    pub fn is_modern(&self) -> bool {
+        !self.lowers_nesting() && !self.lowers_is_selector()
+    }
+
+    pub fn lowers_nesting(&self) -> bool {
+        self.browsers
+            .iter()
+            .any(|target| target.lacks(Browser::nesting_since))
+    }
+
+    pub fn lowers_is_selector(&self) -> bool {
+        self.browsers
+            .iter()
+            .any(|target| target.lacks(Browser::is_selector_since))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_query_is_modern() {
+        let target = CssTarget::parse("").expect("empty query");
+        assert!(target.is_modern());
+        assert_eq!(target, CssTarget::default());
+    }
+
+    #[test]
+    fn old_safari_lowers_nesting_but_keeps_is() {
+        let target = CssTarget::parse("chrome >= 120, safari 16.4").expect("valid query");
+        assert!(target.lowers_nesting());
+        assert!(!target.lowers_is_selector());
+    }
+
+    #[test]
+    fn old_chrome_lowers_everything() {
+        let target = CssTarget::parse("Chrome >=80").expect("valid query");
+        assert!(target.lowers_nesting());
+        assert!(target.lowers_is_selector());
+    }
+
+    #[test]
+    fn ie_always_lowers() {
+        let target = CssTarget::parse("ie 11").expect("valid query");
+        assert!(target.lowers_nesting() && target.lowers_is_selector());
+    }
+
+    #[test]
+    fn recent_browsers_are_modern() {
+        let target = CssTarget::parse("chrome 124, firefox 125, safari 17.4, ios_saf 17.4")
+            .expect("valid query");
+        assert!(target.is_modern());
+    }
+
+    #[test]
+    fn rejects_unknown_browser() {
+        let err = CssTarget::parse("netscape 4").expect_err("unknown browser");
+        assert!(err.contains("netscape"), "got: {err}");
+    }
+
+    #[test]
+    fn rejects_invalid_version() {
+        assert!(CssTarget::parse("chrome latest").is_err());
+        assert!(CssTarget::parse("chrome < 100").is_err());
+    }
+}
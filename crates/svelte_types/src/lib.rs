@@ -4,7 +4,7 @@ use svelte_ast::Component;
 
 pub struct CompileContext<'a, 'ctx> {
     pub alloc: &'a Allocator,
-    pub component: &'ctx Component,
+    pub component: &'ctx Component<'a>,
     pub analysis: &'ctx AnalysisData<'a>,
     pub js_arena: &'ctx mut JsAst<'a>,
     pub ident_gen: &'ctx mut IdentGen,
@@ -20,4 +20,7 @@ pub struct CodegenOptions {
     pub dev: bool,
     pub experimental_async: bool,
     pub filename: String,
+    pub minify: bool,
+    pub runtime_path: Option<String>,
+    pub stats: bool,
 }
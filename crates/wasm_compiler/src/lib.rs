@@ -3,8 +3,10 @@ use oxc_codegen::Codegen;
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 use serde::Serialize;
-use svelte_compiler::{CompileOptions, CompileResult, ModuleCompileOptions};
-use svelte_diagnostics::LineIndex;
+use svelte_compiler::{
+    CompileOptions, CompileResult, CompileStats, Dependency, ModuleCompileOptions, StatsClock,
+};
+use svelte_diagnostics::{LineIndex, Location};
 use wasm_bindgen::prelude::*;
 
 #[derive(Serialize)]
@@ -16,7 +18,17 @@ struct WasmDiagnostic {
     start_col: usize,
     end_line: usize,
     end_col: usize,
+    start: Location,
+    end: Location,
     frame: Option<String>,
+    fixes: Vec<WasmTextEdit>,
+}
+
+#[derive(Serialize)]
+struct WasmTextEdit {
+    start: u32,
+    end: u32,
+    replacement: String,
 }
 
 #[derive(Serialize)]
@@ -24,6 +36,70 @@ struct WasmCompileResult {
     js: Option<String>,
     css: Option<String>,
     diagnostics: Vec<WasmDiagnostic>,
+    dependencies: Vec<Dependency>,
+    stats: Option<WasmCompileStats>,
+}
+
+#[derive(Serialize)]
+struct WasmCompileStats {
+    total_ms: f64,
+    phases: Vec<WasmTiming>,
+    analyze_passes: Vec<WasmTiming>,
+    nodes: u32,
+    fragments: u32,
+    symbols: u32,
+    template_effects: u32,
+    css_rules_pruned: u32,
+}
+
+#[derive(Serialize)]
+struct WasmTiming {
+    name: String,
+    ms: f64,
+}
+
+fn to_wasm_stats(stats: CompileStats) -> WasmCompileStats {
+    WasmCompileStats {
+        total_ms: stats.total().as_secs_f64() * 1000.0,
+        phases: stats
+            .phases
+            .into_iter()
+            .map(|timing| WasmTiming {
+                name: timing.phase.to_string(),
+                ms: timing.duration.as_secs_f64() * 1000.0,
+            })
+            .collect(),
+        analyze_passes: stats
+            .analyze_passes
+            .into_iter()
+            .map(|timing| WasmTiming {
+                name: timing.pass,
+                ms: timing.duration.as_secs_f64() * 1000.0,
+            })
+            .collect(),
+        nodes: stats.nodes,
+        fragments: stats.fragments,
+        symbols: stats.symbols,
+        template_effects: stats.template_effects,
+        css_rules_pruned: stats.css_rules_pruned,
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = performance, js_name = now)]
+    fn performance_now() -> f64;
+}
+
+#[cfg(target_arch = "wasm32")]
+fn stats_clock() -> Option<StatsClock> {
+    Some(|| std::time::Duration::from_secs_f64(performance_now() / 1000.0))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn stats_clock() -> Option<StatsClock> {
+    None
 }
 
 fn to_wasm_result(result: CompileResult, source: &str) -> WasmCompileResult {
@@ -33,8 +109,8 @@ fn to_wasm_result(result: CompileResult, source: &str) -> WasmCompileResult {
         .diagnostics
         .iter()
         .map(|d| {
-            let (start_line, start_col) = line_index.line_col(d.span.start as usize);
-            let (end_line, end_col) = line_index.line_col(d.span.end as usize);
+            let start = line_index.location(source, d.span.start as usize);
+            let end = line_index.location(source, d.span.end as usize);
             let mut message = d.kind.message();
             if let Some(url) = d.kind.svelte_doc_url() {
                 message.push('\n');
@@ -44,11 +120,22 @@ fn to_wasm_result(result: CompileResult, source: &str) -> WasmCompileResult {
                 code: d.kind.code().to_string(),
                 message,
                 severity: format!("{:?}", d.severity),
-                start_line,
-                start_col,
-                end_line,
-                end_col,
+                start_line: start.line - 1,
+                start_col: start.column,
+                end_line: end.line - 1,
+                end_col: end.column,
+                start,
+                end,
                 frame: line_index.code_frame(source, d.span),
+                fixes: d
+                    .fixes
+                    .iter()
+                    .map(|edit| WasmTextEdit {
+                        start: line_index.utf16_offset(source, edit.span.start as usize) as u32,
+                        end: line_index.utf16_offset(source, edit.span.end as usize) as u32,
+                        replacement: edit.replacement.clone(),
+                    })
+                    .collect(),
             }
         })
         .collect();
@@ -57,6 +144,8 @@ fn to_wasm_result(result: CompileResult, source: &str) -> WasmCompileResult {
         js: result.js,
         css: result.css,
         diagnostics,
+        dependencies: result.dependencies,
+        stats: result.stats.map(to_wasm_stats),
     }
 }
 
@@ -77,11 +166,12 @@ impl WasmCompiler {
         source: &str,
         options: JsValue,
     ) -> Result<JsValue, serde_wasm_bindgen::Error> {
-        let opts: CompileOptions = if options.is_undefined() || options.is_null() {
+        let mut opts: CompileOptions = if options.is_undefined() || options.is_null() {
             CompileOptions::default()
         } else {
             serde_wasm_bindgen::from_value(options)?
         };
+        opts.stats_clock = stats_clock();
         let result = svelte_compiler::compile(source, &opts);
         serde_wasm_bindgen::to_value(&to_wasm_result(result, source))
     }
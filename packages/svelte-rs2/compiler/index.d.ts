@@ -2,9 +2,22 @@ export type Warning = {
   code: string;
   message: string;
   filename: string | null;
-  start: { line: number; column: number } | null;
-  end: { line: number; column: number } | null;
+  start: Location | null;
+  end: Location | null;
   frame: string | null;
+  fixes: TextEdit[];
+};
+
+export type Location = {
+  line: number;
+  column: number;
+  character: number;
+};
+
+export type TextEdit = {
+  start: number;
+  end: number;
+  replacement: string;
 };
 
 export type CompileJsResult = {
@@ -28,6 +41,34 @@ export type CompileMetadata = {
   };
 };
 
+export type Timing = {
+  name: string;
+  ms: number;
+};
+
+export type CompileStats = {
+  totalMs: number;
+  phases: Timing[];
+  analyzePasses: Timing[];
+  nodes: number;
+  fragments: number;
+  symbols: number;
+  templateEffects: number;
+  cssRulesPruned: number;
+};
+
+export type Dependency = {
+  specifier: string;
+  kind: 'import' | 'component' | 'css_import';
+};
+
+export type CssHashGetter = (args: {
+  name: string;
+  filename: string;
+  css: string;
+  hash: (input: string) => string;
+}) => string;
+
 export interface CompileOptions {
   dev?: boolean;
   filename?: string;
@@ -36,11 +77,17 @@ export interface CompileOptions {
   customElement?: boolean;
   namespace?: 'html' | 'svg' | 'mathml';
   css?: 'external' | 'injected';
+  cssHash?: string | CssHashGetter;
+  cssTarget?: string;
+  cssMinify?: boolean;
   runes?: boolean;
   preserveComments?: boolean;
   preserveWhitespace?: boolean;
   discloseVersion?: boolean;
   hmr?: boolean;
+  minify?: boolean;
+  inlineConstants?: boolean;
+  runtimePath?: string;
   accessors?: boolean;
   immutable?: boolean;
   compatibility?: {
@@ -49,7 +96,13 @@ export interface CompileOptions {
   experimental?: {
     async?: boolean;
   };
+  lint?: {
+    effectToDerived?: boolean;
+    unusedDeclarations?: boolean;
+    strictHtmlNesting?: boolean;
+  };
   generate?: 'client' | 'server' | false;
+  stats?: boolean;
   modernAst?: boolean;
   ast?: never;
   sourcemap?: never;
@@ -60,6 +113,8 @@ export interface ModuleCompileOptions {
   dev?: boolean;
   filename?: string;
   rootDir?: string;
+  minify?: boolean;
+  runtimePath?: string;
   generate?: 'client' | 'server' | false;
   modernAst?: boolean;
   ast?: never;
@@ -72,8 +127,15 @@ export type CompileResult = {
   css: CompileCssResult | null;
   warnings: Warning[];
   metadata: CompileMetadata;
+  stats: CompileStats | null;
+  dependencies: Dependency[];
   ast: null;
 };
 
 export declare function compile(source: string, options?: CompileOptions): CompileResult;
 export declare function compileModule(source: string, options?: ModuleCompileOptions): CompileResult;
+export declare class Compiler {
+  constructor(options?: CompileOptions);
+  compile(source: string, filename?: string): CompileResult;
+  compileModule(source: string, filename?: string): CompileResult;
+}
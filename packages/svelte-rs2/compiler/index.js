@@ -106,12 +106,21 @@ function normalizeCompileOptions(options = {}) {
     namespace:
       options.namespace === 'svg' || options.namespace === 'mathml' ? options.namespace : 'html',
     css: options.css === 'injected' ? 'injected' : 'external',
+    cssHash:
+      typeof options.cssHash === 'string' || typeof options.cssHash === 'function'
+        ? options.cssHash
+        : undefined,
+    cssTarget: typeof options.cssTarget === 'string' ? options.cssTarget : undefined,
+    cssMinify: Boolean(options.cssMinify),
     runes: typeof options.runes === 'boolean' ? options.runes : undefined,
     preserve_comments: Boolean(options.preserveComments),
     preserve_whitespace: Boolean(options.preserveWhitespace),
     disclose_version:
       typeof options.discloseVersion === 'boolean' ? options.discloseVersion : undefined,
     hmr: Boolean(options.hmr),
+    minify: Boolean(options.minify),
+    inlineConstants: Boolean(options.inlineConstants),
+    runtimePath: typeof options.runtimePath === 'string' ? options.runtimePath : undefined,
     accessors: Boolean(options.accessors),
     immutable: Boolean(options.immutable),
     compatibility_component_api:
@@ -119,7 +128,11 @@ function normalizeCompileOptions(options = {}) {
         ? options.compatibility.componentApi
         : undefined,
     experimental_async: Boolean(options.experimental?.async),
-    generate: normalizeGenerate(options.generate)
+    lintEffectToDerived: Boolean(options.lint?.effectToDerived),
+    lintUnusedDeclarations: Boolean(options.lint?.unusedDeclarations),
+    lintStrictHtmlNesting: Boolean(options.lint?.strictHtmlNesting),
+    generate: normalizeGenerate(options.generate),
+    stats: Boolean(options.stats)
   };
 }
 
@@ -130,6 +143,8 @@ function normalizeModuleCompileOptions(options = {}) {
     dev: Boolean(options.dev),
     filename: typeof options.filename === 'string' ? options.filename : '(unknown)',
     root_dir: typeof options.rootDir === 'string' ? options.rootDir : undefined,
+    minify: Boolean(options.minify),
+    runtimePath: typeof options.runtimePath === 'string' ? options.runtimePath : undefined,
     generate: normalizeGenerate(options.generate)
   };
 }
@@ -139,15 +154,14 @@ function normalizeDiagnostic(diagnostic, filenameFallback) {
     code: diagnostic.code,
     message: diagnostic.message,
     filename: filenameFallback,
-    start: {
-      line: diagnostic.start_line,
-      column: diagnostic.start_col
-    },
-    end: {
-      line: diagnostic.end_line,
-      column: diagnostic.end_col
-    },
-    frame: diagnostic.frame ?? null
+    start: diagnostic.start ?? null,
+    end: diagnostic.end ?? null,
+    frame: diagnostic.frame ?? null,
+    fixes: (diagnostic.fixes ?? []).map((fix) => ({
+      start: fix.start,
+      end: fix.end,
+      replacement: fix.replacement
+    }))
   };
 }
 
@@ -192,6 +206,11 @@ function normalizeCompileResponse(nativeResult, filename, optionWarnings = []) {
         unsupportedOptions: ['ast', 'sourcemap', 'outputFilename']
       }
     },
+    stats: nativeResult.stats ?? null,
+    dependencies: (nativeResult.dependencies ?? []).map((dependency) => ({
+      specifier: dependency.specifier,
+      kind: dependency.kind
+    })),
     ast: null
   };
 }
@@ -217,3 +236,34 @@ export function compileModule(source, options = {}) {
   const nativeResult = native.compileModule(source, normalizedOptions);
   return normalizeCompileResponse(nativeResult, normalizedOptions.filename, optionWarnings);
 }
+
+export class Compiler {
+  #native;
+  #filename;
+  #optionWarnings;
+
+  constructor(options = {}) {
+    const normalizedOptions = normalizeCompileOptions(options);
+    this.#native = new native.Compiler(normalizedOptions);
+    this.#filename = normalizedOptions.filename;
+    this.#optionWarnings = collectOptionWarnings(options);
+  }
+
+  compile(source, filename = this.#filename) {
+    if (typeof source !== 'string') {
+      throw new TypeError('Compiler.compile(source, filename): source must be a string');
+    }
+
+    const nativeResult = this.#native.compile(source, filename);
+    return normalizeCompileResponse(nativeResult, filename, this.#optionWarnings);
+  }
+
+  compileModule(source, filename = this.#filename) {
+    if (typeof source !== 'string') {
+      throw new TypeError('Compiler.compileModule(source, filename): source must be a string');
+    }
+
+    const nativeResult = this.#native.compileModule(source, filename);
+    return normalizeCompileResponse(nativeResult, filename, this.#optionWarnings);
+  }
+}
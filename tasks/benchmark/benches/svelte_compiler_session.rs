@@ -0,0 +1,59 @@
+use std::fs::read_to_string;
+
+use benchmark::{Criterion, count_allocations, criterion_group, criterion_main};
+use glob::glob;
+
+/// Compiles every small fixture once per iteration, either with a fresh
+/// `compile` call per file or through one reused `Compiler` session. Before
+/// timing, prints the heap allocations of one pass in each mode.
+fn bench_svelte_compiler_session(criterion: &mut Criterion) {
+    let files: Vec<(String, String)> = glob("./benches/compiler/**/*.svelte")
+        .expect("Не удалось считать компоненты")
+        .map(|entry| entry.expect("test invariant"))
+        .filter(|path| !path.ends_with("big_v6.svelte"))
+        .map(|path| {
+            let source = read_to_string(&path).expect("test invariant");
+            (path.display().to_string(), source)
+        })
+        .collect();
+
+    let fresh = || {
+        for (filename, source) in &files {
+            let opts = svelte_compiler::CompileOptions {
+                filename: filename.clone(),
+                ..svelte_compiler::CompileOptions::default()
+            };
+            svelte_compiler::compile(source, &opts);
+        }
+    };
+    let mut session = svelte_compiler::Compiler::new(svelte_compiler::CompileOptions::default())
+        .expect("default options are valid");
+    let mut reused = || {
+        for (filename, source) in &files {
+            session.compile(source, filename);
+        }
+    };
+
+    // The first reused pass grows the arena; later passes only reset it.
+    reused();
+    let (_, fresh_count) = count_allocations(fresh);
+    let (_, reused_count) = count_allocations(&mut reused);
+    println!("{:<8} {:>12} {:>12}", "session", "allocations", "bytes");
+    println!(
+        "{:<8} {:>12} {:>12}",
+        "fresh", fresh_count.allocations, fresh_count.bytes
+    );
+    println!(
+        "{:<8} {:>12} {:>12}",
+        "reused", reused_count.allocations, reused_count.bytes
+    );
+
+    let mut group = criterion.benchmark_group("session");
+    group.bench_function("fresh", |b| b.iter(fresh));
+    group.bench_function("reused", |b| b.iter(&mut reused));
+
+    group.finish();
+}
+
+criterion_group!(compiler_session, bench_svelte_compiler_session);
+criterion_main!(compiler_session);
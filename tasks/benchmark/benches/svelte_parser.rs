@@ -0,0 +1,52 @@
+use std::fs::read_to_string;
+
+use benchmark::{BenchmarkId, Criterion, count_allocations, criterion_group, criterion_main};
+use glob::glob;
+use svelte_ast::Allocator;
+
+/// Parse-only numbers: the Svelte template parse (`Parser::parse`) and the full parse
+/// including script and template expressions (`parse_with_js`). Each parse gets a fresh
+/// arena, so arena chunk allocations are part of the count.
+fn bench_svelte_parser(criterion: &mut Criterion) {
+    let files: Vec<(String, String)> = glob("./benches/compiler/**/*.svelte")
+        .expect("Не удалось считать компоненты")
+        .map(|entry| {
+            let path = entry.expect("test invariant");
+            let source = read_to_string(&path).expect("test invariant");
+            (path.display().to_string(), source)
+        })
+        .collect();
+
+    println!(
+        "{:<48} {:>12} {:>12} {:>12} {:>12}",
+        "parse allocations", "template", "bytes", "with js", "bytes"
+    );
+    for (name, source) in &files {
+        let (_, template) = count_allocations(|| {
+            let alloc = Allocator::default();
+            drop(svelte_parser::Parser::new(&alloc, source).parse());
+        });
+        let (_, with_js) = count_allocations(|| {
+            let alloc = Allocator::default();
+            drop(svelte_parser::parse_with_js(&alloc, source));
+        });
+        println!(
+            "{name:<48} {:>12} {:>12} {:>12} {:>12}",
+            template.allocations, template.bytes, with_js.allocations, with_js.bytes
+        );
+    }
+
+    let mut group = criterion.benchmark_group("parse");
+    for (name, source) in &files {
+        group.bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.iter(|| {
+                let alloc = Allocator::default();
+                drop(svelte_parser::parse_with_js(&alloc, source));
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(parser, bench_svelte_parser);
+criterion_main!(parser);
@@ -0,0 +1,58 @@
+use std::fs::read_to_string;
+
+use benchmark::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use svelte_ast::{Allocator, Span};
+use svelte_diagnostics::TextEdit;
+use svelte_parser::{ReparseMode, parse_with_js, reparse_with_js};
+
+/// Keystroke latency on a ~2,000-line component: a full `parse_with_js` of the
+/// edited source against `reparse_with_js` from the previous revision. Both
+/// start from a fresh arena, like an editor that drops the old revision.
+fn bench_svelte_parser_incremental(criterion: &mut Criterion) {
+    let source = read_to_string("./benches/incremental/component_2000.svelte")
+        .expect("Не удалось считать компонент");
+    let edit_at = |needle: &str, replacement: &str| {
+        let start = source.rfind(needle).expect("test invariant") as u32;
+        TextEdit::new(Span::new(start, start + needle.len() as u32), replacement)
+    };
+    let edits = [
+        ("template", edit_at("$metrics.length", "$metrics.size")),
+        ("script", edit_at("$state(0)", "$state(10)")),
+    ];
+
+    let previous_alloc = Allocator::default();
+    let (previous, previous_js, diagnostics) = parse_with_js(&previous_alloc, &source);
+    assert!(diagnostics.is_empty());
+
+    let mut group = criterion.benchmark_group("incremental");
+    for (name, edit) in &edits {
+        let alloc = Allocator::default();
+        let (reparsed, _) = reparse_with_js(&alloc, &previous, &previous_js, &diagnostics, edit)
+            .expect("test invariant");
+        assert_eq!(reparsed.mode, ReparseMode::Incremental);
+        let edited = reparsed.component.source;
+
+        group.bench_function(BenchmarkId::new("full", name), |b| {
+            b.iter(|| {
+                let alloc = Allocator::default();
+                drop(parse_with_js(&alloc, edited));
+            });
+        });
+        group.bench_function(BenchmarkId::new("incremental", name), |b| {
+            b.iter(|| {
+                let alloc = Allocator::default();
+                drop(reparse_with_js(
+                    &alloc,
+                    &previous,
+                    &previous_js,
+                    &diagnostics,
+                    edit,
+                ));
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(parser_incremental, bench_svelte_parser_incremental);
+criterion_main!(parser_incremental);
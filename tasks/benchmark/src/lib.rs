@@ -1,4 +1,5 @@
 use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 pub use criterion::*;
 
@@ -24,12 +25,39 @@ static GLOBAL: NeverGrowInPlaceAllocator = NeverGrowInPlaceAllocator;
 /// [`GlobalAlloc::realloc`] implementation which *never* grows in place.
 /// It therefore represents the "worse case scenario" for memory allocation performance.
 /// This behavior is consistent and predictable, and therefore stabilizes benchmark results.
+///
+/// Every allocation is also counted, so benches can report heap allocations next to time
+/// (see [`count_allocations`]). A `realloc` goes through `alloc` and counts as one.
 struct NeverGrowInPlaceAllocator;
 
-// SAFETY: Methods simply delegate to `System` allocator
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocationCount {
+    pub allocations: usize,
+    pub bytes: usize,
+}
+
+/// Runs `f` and returns the heap allocations it made. Benches are single-threaded, so
+/// the global counters only see `f`.
+pub fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, AllocationCount) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let result = f();
+    let count = AllocationCount {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes,
+    };
+    (result, count)
+}
+
+// SAFETY: Methods simply delegate to `System` allocator after bumping the counters
 #[allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
 unsafe impl GlobalAlloc for NeverGrowInPlaceAllocator {
     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
         unsafe { System.alloc(layout) }
     }
 
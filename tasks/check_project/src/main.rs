@@ -0,0 +1,83 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use svelte_compiler::{CompileOptions, ProjectFile, check_project};
+use svelte_diagnostics::LineIndex;
+
+const USAGE: &str = "usage: check_project <project-dir>";
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let (Some(root), None) = (args.next(), args.next()) else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+
+    let mut paths = Vec::new();
+    if let Err(err) = collect_svelte_files(Path::new(&root), &mut paths) {
+        eprintln!("check_project: read {root}: {err}");
+        return ExitCode::from(2);
+    }
+    paths.sort();
+
+    let mut files = Vec::with_capacity(paths.len());
+    for path in paths {
+        match fs::read_to_string(&path) {
+            Ok(source) => files.push(ProjectFile {
+                filename: path.to_string_lossy().replace('\\', "/"),
+                source,
+            }),
+            Err(err) => eprintln!("check_project: read {}: {err}", path.display()),
+        }
+    }
+
+    let results = check_project(&files, &CompileOptions::default());
+    let mut count = 0;
+    for result in &results {
+        let Some(file) = files.iter().find(|f| f.filename == result.filename) else {
+            continue;
+        };
+        let index = LineIndex::new(&file.source);
+        for d in &result.diagnostics {
+            let (line, col) = index.line_col(d.span.start as usize);
+            println!(
+                "{}:{}:{}: {}: {}",
+                file.filename,
+                line + 1,
+                col + 1,
+                d.kind.code(),
+                d.kind.message()
+            );
+            count += 1;
+        }
+    }
+
+    println!("checked {} components, {count} problems", files.len());
+    if count == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    }
+}
+
+fn collect_svelte_files(dir: &Path, out: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        if name.starts_with('.') || name == "node_modules" {
+            continue;
+        }
+        if path.is_dir() {
+            collect_svelte_files(&path, out)?;
+        } else if path.extension().is_some_and(|ext| ext == "svelte") {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
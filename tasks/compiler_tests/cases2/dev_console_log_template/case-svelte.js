@@ -0,0 +1,20 @@
+App[$.FILENAME] = "(unknown)";
+import * as $ from "svelte/internal/client";
+var root = $.add_locations($.from_html(`<button></button>`), App[$.FILENAME], [[8, 0]]);
+export default function App($$anchor, $$props) {
+	$.check_target(new.target);
+	$.push($$props, true, App);
+	let count = 0;
+	function local(console) {
+		console.log(count);
+	}
+	var $$exports = { ...$.legacy_api() };
+	var button = root();
+	button.textContent = "0";
+	$.delegated("click", button, function click() {
+		console.log(...$.log_if_contains_state("log", "count", count));
+	});
+	$.append($$anchor, button);
+	return $.pop($$exports);
+}
+$.delegate(["click"]);
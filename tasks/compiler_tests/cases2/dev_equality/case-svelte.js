@@ -0,0 +1,38 @@
+App[$.FILENAME] = "(unknown)";
+import * as $ from "svelte/internal/client";
+var root_1 = $.add_locations($.from_html(`<p> </p>`), App[$.FILENAME], [[13, 1]]);
+var root = $.add_locations($.from_html(`<button> </button> <!>`, 1), App[$.FILENAME], [[10, 0]]);
+export default function App($$anchor, $$props) {
+	$.check_target(new.target);
+	$.push($$props, true, App);
+	let count = $.tag($.state(0), "count");
+	let items = $.tag_proxy($.proxy([]), "items");
+	function isItems(value) {
+		return $.strict_equals(value, items);
+	}
+	var $$exports = { ...$.legacy_api() };
+	var fragment = root();
+	var button = $.first_child(fragment);
+	var text = $.child(button, true);
+	$.reset(button);
+	var node = $.sibling(button, 2);
+	{
+		var consequent = ($$anchor) => {
+			var p = root_1();
+			var text_1 = $.child(p, true);
+			$.reset(p);
+			$.template_effect(($0) => $.set_text(text_1, $0), [() => isItems(items)]);
+			$.append($$anchor, p);
+		};
+		$.add_svelte_meta(() => $.if(node, ($$render) => {
+			if ($.strict_equals($.get(count), 0, false)) $$render(consequent);
+		}), "if", App, 12, 0);
+	}
+	$.template_effect(() => $.set_text(text, $.equals($.get(count), 1) ? "one" : "many"));
+	$.delegated("click", button, function click() {
+		$.update(count);
+	});
+	$.append($$anchor, fragment);
+	return $.pop($$exports);
+}
+$.delegate(["click"]);
@@ -0,0 +1,11 @@
+import * as $ from "svelte/internal/client";
+let count = $.tag($.state(0), "count");
+export function increment() {
+	$.update(count);
+}
+export function isZero() {
+	return $.strict_equals($.get(count), 0);
+}
+export function differs(value) {
+	return $.equals(value, $.get(count), false);
+}
@@ -0,0 +1,13 @@
+let count = $state(0);
+
+export function increment() {
+	count++;
+}
+
+export function isZero() {
+	return count === 0;
+}
+
+export function differs(value) {
+	return value != count;
+}
@@ -1757,6 +1757,11 @@ fn module_dev_console_log_wrap() {
     assert_compiler_module("module_dev_console_log_wrap");
 }
 
+#[rstest]
+fn module_dev_equality() {
+    assert_compiler_module("module_dev_equality");
+}
+
 #[rstest]
 fn script_module_exports() {
     assert_compiler("script_module_exports");
@@ -3272,6 +3277,16 @@ fn state_assign_dev() {
     assert_compiler("state_assign_dev");
 }
 
+#[rstest]
+fn dev_equality() {
+    assert_compiler("dev_equality");
+}
+
+#[rstest]
+fn dev_console_log_template() {
+    assert_compiler("dev_console_log_template");
+}
+
 #[rstest]
 fn css_scoped_class_selector() {
     assert_compiler("css_scoped_class_selector");
@@ -0,0 +1,121 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use svelte_compiler::{MessageCatalog, extract_strings_from_source};
+
+const USAGE: &str = "usage: extract_strings <file-or-dir>... [--json] [--output <path>]";
+
+fn main() -> ExitCode {
+    let mut inputs = Vec::new();
+    let mut json = false;
+    let mut output = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--pot" => json = false,
+            "--output" | "-o" => match args.next() {
+                Some(path) => output = Some(path),
+                None => {
+                    eprintln!("{USAGE}");
+                    return ExitCode::from(2);
+                }
+            },
+            _ if !arg.starts_with('-') => inputs.push(PathBuf::from(arg)),
+            _ => {
+                eprintln!("{USAGE}");
+                return ExitCode::from(2);
+            }
+        }
+    }
+    if inputs.is_empty() {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    }
+
+    let mut paths = Vec::new();
+    for input in &inputs {
+        if input.is_dir() {
+            if let Err(err) = collect_svelte_files(input, &mut paths) {
+                eprintln!("extract_strings: read {}: {err}", input.display());
+                return ExitCode::from(2);
+            }
+        } else {
+            paths.push(input.clone());
+        }
+    }
+    paths.sort();
+
+    let mut catalog = MessageCatalog::new();
+    let mut failed = false;
+    for path in &paths {
+        let filename = path.to_string_lossy().replace('\\', "/");
+        let source = match fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(err) => {
+                eprintln!("extract_strings: read {filename}: {err}");
+                failed = true;
+                continue;
+            }
+        };
+        match extract_strings_from_source(&source) {
+            Ok(strings) => catalog.add(&filename, &strings),
+            Err(diagnostics) => {
+                eprintln!("extract_strings: {filename} has errors");
+                for d in diagnostics {
+                    eprintln!("  {d}");
+                }
+                failed = true;
+            }
+        }
+    }
+
+    let rendered = if json {
+        match serde_json::to_string_pretty(&catalog) {
+            Ok(json) => json + "\n",
+            Err(err) => {
+                eprintln!("extract_strings: serialize: {err}");
+                return ExitCode::from(1);
+            }
+        }
+    } else {
+        catalog.to_pot()
+    };
+    match output {
+        Some(path) => {
+            if let Err(err) = fs::write(&path, rendered) {
+                eprintln!("extract_strings: write {path}: {err}");
+                return ExitCode::from(1);
+            }
+        }
+        None => print!("{rendered}"),
+    }
+
+    if failed {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn collect_svelte_files(dir: &Path, out: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        if name.starts_with('.') || name == "node_modules" {
+            continue;
+        }
+        if path.is_dir() {
+            collect_svelte_files(&path, out)?;
+        } else if path.extension().is_some_and(|ext| ext == "svelte") {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use std::{env, fs, process::ExitCode};
+
+use svelte_compiler::{CompileOptions, reactivity_graph};
+
+const USAGE: &str = "usage: reactivity_graph <path-to-.svelte-file> [--dot] [--legacy]";
+
+fn main() -> ExitCode {
+    let mut path = None;
+    let mut dot = false;
+    let mut legacy = false;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--dot" => dot = true,
+            "--json" => dot = false,
+            "--legacy" => legacy = true,
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(arg),
+            _ => {
+                eprintln!("{USAGE}");
+                return ExitCode::from(2);
+            }
+        }
+    }
+    let Some(path) = path else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+
+    let source = match fs::read_to_string(&path) {
+        Ok(s) => s,
+        Err(err) => {
+            eprintln!("reactivity_graph: read {path}: {err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let options = CompileOptions {
+        filename: path.clone(),
+        runes: legacy.then_some(false),
+        ..CompileOptions::default()
+    };
+    let graph = match reactivity_graph(&source, &options) {
+        Ok(graph) => graph,
+        Err(diagnostics) => {
+            eprintln!("reactivity_graph: {path} has errors");
+            for d in diagnostics {
+                eprintln!("  {d}");
+            }
+            return ExitCode::from(1);
+        }
+    };
+
+    if dot {
+        print!("{}", graph.to_dot());
+        return ExitCode::SUCCESS;
+    }
+    match serde_json::to_string_pretty(&graph) {
+        Ok(json) => {
+            println!("{json}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("reactivity_graph: serialize: {err}");
+            ExitCode::from(1)
+        }
+    }
+}